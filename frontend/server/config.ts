@@ -3,12 +3,24 @@ import { env } from "node:process";
 interface Config {
 	http_port: number;
 	grpc_uri: string;
+	grpc_auth_token: string;
+	grpc_s3_access_key_id: string;
+	grpc_s3_secret_access_key: string;
+	grpc_tls_ca: string;
+	grpc_tls_client_cert: string;
+	grpc_tls_client_key: string;
 	redis_uri: string;
 }
 
 // biome-ignore-start lint/complexity/useLiteralKeys: environment
 // biome-ignore lint/style/useNamingConvention: singleton
 export const config: Config = {
+	grpc_auth_token: env["GRPC_AUTH_TOKEN"] || "",
+	grpc_s3_access_key_id: env["GRPC_S3_ACCESS_KEY_ID"] || "",
+	grpc_s3_secret_access_key: env["GRPC_S3_SECRET_ACCESS_KEY"] || "",
+	grpc_tls_ca: env["GRPC_TLS_CA"] || "",
+	grpc_tls_client_cert: env["GRPC_TLS_CLIENT_CERT"] || "",
+	grpc_tls_client_key: env["GRPC_TLS_CLIENT_KEY"] || "",
 	grpc_uri: env["GRPC_URI"] || "http://localhost:50051",
 	http_port: Number(env["HTTP_PORT"] || 3000),
 	redis_uri: env["REDIS_URI"] || "redis://localhost:6379",
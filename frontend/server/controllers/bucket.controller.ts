@@ -11,7 +11,7 @@ import type {
 	BucketKeyPermissionItem,
 } from "../generated/bucket";
 
-import { bucket_client, object_client } from "../grpc";
+import { bucket_client, object_client, s3_credentials } from "../grpc";
 
 class BucketController {
 	public async create(context: Context): Promise<Response> {
@@ -241,12 +241,14 @@ class BucketController {
 			const list_response = await object_client.ListObjects({
 				bucket: bucket_name,
 				continuation_token,
+				credentials: s3_credentials(),
 			});
 
 			if (list_response.data.length > 0) {
 				const keys = list_response.data.map((obj) => obj.key);
 				await object_client.DeleteObject({
 					bucket: bucket_name,
+					credentials: s3_credentials(),
 					keys,
 				});
 			}
@@ -6,7 +6,7 @@ import type { GetThumbnailContract } from "@shared/contracts/thumbnail.contract"
 import type { ObjectList } from "@shared/entity/object.entity";
 import type { Context } from "hono";
 
-import { object_client } from "../grpc";
+import { object_client, s3_credentials } from "../grpc";
 import { thumbnail_service } from "../services/thumbnail.service";
 import { stream_object } from "../utils/download.util";
 import { download_and_generate_thumbnails } from "../utils/thumbnail.util";
@@ -55,6 +55,7 @@ class ObjectController {
 		const response = await object_client.ListObjects({
 			bucket: payload.bucket_name,
 			continuation_token: payload.continuation_token,
+			credentials: s3_credentials(),
 			delimiter: payload.delimiter,
 			max_keys: payload.max_keys,
 			prefix: payload.prefix,
@@ -138,6 +139,7 @@ class ObjectController {
 
 		await object_client.DeleteObject({
 			bucket: payload.bucket_name,
+			credentials: s3_credentials(),
 			keys: payload.key,
 		});
 
@@ -7,7 +7,7 @@ import type {
 
 import { filter, firstValueFrom, Subject, take } from "rxjs";
 
-import { object_client } from "../grpc/object.client";
+import { object_client, s3_credentials } from "../grpc/object.client";
 import { thumbnail_service } from "../services/thumbnail.service";
 import { download_and_generate_thumbnails } from "../utils/thumbnail.util";
 
@@ -53,6 +53,7 @@ class UploadController {
 				bucket: payload.bucket_name,
 				content_length: payload.content_length,
 				content_type: payload.content_type,
+				credentials: s3_credentials(),
 				key: payload.key,
 			},
 		};
@@ -115,6 +116,7 @@ class UploadController {
 		try {
 			await object_client.AbortUpload({
 				bucket: session.bucket,
+				credentials: s3_credentials(),
 				key: session.key,
 				upload_id: session.upload_id,
 			});
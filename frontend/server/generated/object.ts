@@ -80,11 +80,15 @@ export interface ListObjectsRequest {
     | undefined;
   /** 分隔符，通常是 "/" 用於虛擬資料夾分頁 */
   delimiter?: string | undefined;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 export interface GetObjectMetadataRequest {
   bucket: string;
   key: string;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 /** Upload request - first message must be metadata, followed by chunks */
@@ -102,6 +106,8 @@ export interface UploadMetadata {
   key: string;
   content_type: string;
   content_length: number;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 /** Upload response - server sends upload_id after receiving metadata, then final result */
@@ -136,6 +142,8 @@ export interface UploadProgress {
 export interface DownloadObjectRequest {
   bucket: string;
   key: string;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 /** Download response - first message is metadata, followed by chunks */
@@ -166,6 +174,8 @@ export interface GetUploadUrlRequest {
     | undefined;
   /** Default: 3600 (1 hour) */
   expires_in_seconds?: number | undefined;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 export interface GetDownloadUrlRequest {
@@ -173,11 +183,15 @@ export interface GetDownloadUrlRequest {
   key: string;
   /** Default: 3600 (1 hour) */
   expires_in_seconds?: number | undefined;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 export interface DeleteObjectRequest {
   bucket: string;
   keys: string[];
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 export interface CopyObjectRequest {
@@ -185,12 +199,22 @@ export interface CopyObjectRequest {
   source_key: string;
   dest_bucket: string;
   dest_key: string;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
 }
 
 export interface AbortUploadRequest {
   bucket: string;
   key: string;
   upload_id: string;
+  /** Run as this access key instead of the server's default S3 key */
+  credentials?: S3Credentials | undefined;
+}
+
+/** Access key used for the S3 call; the secret is looked up from Garage when omitted (requires scope write:keys) */
+export interface S3Credentials {
+  access_key_id: string;
+  secret_access_key?: string | undefined;
 }
 
 export interface ObjectInfo {
@@ -1166,7 +1190,7 @@ export const PreSignedUrlResponse: MessageFns<PreSignedUrlResponse> = {
 };
 
 function createBaseListObjectsRequest(): ListObjectsRequest {
-  return { bucket: "", prefix: undefined, continuation_token: undefined, max_keys: undefined, delimiter: undefined };
+  return { bucket: "", prefix: undefined, continuation_token: undefined, max_keys: undefined, delimiter: undefined, credentials: undefined };
 }
 
 export const ListObjectsRequest: MessageFns<ListObjectsRequest> = {
@@ -1186,6 +1210,9 @@ export const ListObjectsRequest: MessageFns<ListObjectsRequest> = {
     if (message.delimiter !== undefined) {
       writer.uint32(42).string(message.delimiter);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(50).fork()).join();
+    }
     return writer;
   },
 
@@ -1236,6 +1263,14 @@ export const ListObjectsRequest: MessageFns<ListObjectsRequest> = {
           message.delimiter = reader.string();
           continue;
         }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1260,6 +1295,7 @@ export const ListObjectsRequest: MessageFns<ListObjectsRequest> = {
         ? globalThis.Number(object.max_keys)
         : undefined,
       delimiter: isSet(object.delimiter) ? globalThis.String(object.delimiter) : undefined,
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -1280,6 +1316,9 @@ export const ListObjectsRequest: MessageFns<ListObjectsRequest> = {
     if (message.delimiter !== undefined) {
       obj.delimiter = message.delimiter;
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -1293,12 +1332,15 @@ export const ListObjectsRequest: MessageFns<ListObjectsRequest> = {
     message.continuation_token = object.continuation_token ?? undefined;
     message.max_keys = object.max_keys ?? undefined;
     message.delimiter = object.delimiter ?? undefined;
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
 
 function createBaseGetObjectMetadataRequest(): GetObjectMetadataRequest {
-  return { bucket: "", key: "" };
+  return { bucket: "", key: "", credentials: undefined };
 }
 
 export const GetObjectMetadataRequest: MessageFns<GetObjectMetadataRequest> = {
@@ -1309,6 +1351,9 @@ export const GetObjectMetadataRequest: MessageFns<GetObjectMetadataRequest> = {
     if (message.key !== "") {
       writer.uint32(18).string(message.key);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(26).fork()).join();
+    }
     return writer;
   },
 
@@ -1335,6 +1380,14 @@ export const GetObjectMetadataRequest: MessageFns<GetObjectMetadataRequest> = {
           message.key = reader.string();
           continue;
         }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1348,6 +1401,7 @@ export const GetObjectMetadataRequest: MessageFns<GetObjectMetadataRequest> = {
     return {
       bucket: isSet(object.bucket) ? globalThis.String(object.bucket) : "",
       key: isSet(object.key) ? globalThis.String(object.key) : "",
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -1359,6 +1413,9 @@ export const GetObjectMetadataRequest: MessageFns<GetObjectMetadataRequest> = {
     if (message.key !== "") {
       obj.key = message.key;
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -1369,6 +1426,9 @@ export const GetObjectMetadataRequest: MessageFns<GetObjectMetadataRequest> = {
     const message = createBaseGetObjectMetadataRequest();
     message.bucket = object.bucket ?? "";
     message.key = object.key ?? "";
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
@@ -1452,7 +1512,7 @@ export const UploadChunkRequest: MessageFns<UploadChunkRequest> = {
 };
 
 function createBaseUploadMetadata(): UploadMetadata {
-  return { bucket: "", key: "", content_type: "", content_length: 0 };
+  return { bucket: "", key: "", content_type: "", content_length: 0, credentials: undefined };
 }
 
 export const UploadMetadata: MessageFns<UploadMetadata> = {
@@ -1469,6 +1529,9 @@ export const UploadMetadata: MessageFns<UploadMetadata> = {
     if (message.content_length !== 0) {
       writer.uint32(32).int64(message.content_length);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(42).fork()).join();
+    }
     return writer;
   },
 
@@ -1511,6 +1574,14 @@ export const UploadMetadata: MessageFns<UploadMetadata> = {
           message.content_length = longToNumber(reader.int64());
           continue;
         }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1534,6 +1605,7 @@ export const UploadMetadata: MessageFns<UploadMetadata> = {
         : isSet(object.content_length)
         ? globalThis.Number(object.content_length)
         : 0,
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -1551,6 +1623,9 @@ export const UploadMetadata: MessageFns<UploadMetadata> = {
     if (message.content_length !== 0) {
       obj.contentLength = Math.round(message.content_length);
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -1563,6 +1638,9 @@ export const UploadMetadata: MessageFns<UploadMetadata> = {
     message.key = object.key ?? "";
     message.content_type = object.content_type ?? "";
     message.content_length = object.content_length ?? 0;
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
@@ -1886,7 +1964,7 @@ export const UploadProgress: MessageFns<UploadProgress> = {
 };
 
 function createBaseDownloadObjectRequest(): DownloadObjectRequest {
-  return { bucket: "", key: "" };
+  return { bucket: "", key: "", credentials: undefined };
 }
 
 export const DownloadObjectRequest: MessageFns<DownloadObjectRequest> = {
@@ -1897,6 +1975,9 @@ export const DownloadObjectRequest: MessageFns<DownloadObjectRequest> = {
     if (message.key !== "") {
       writer.uint32(18).string(message.key);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(26).fork()).join();
+    }
     return writer;
   },
 
@@ -1923,6 +2004,14 @@ export const DownloadObjectRequest: MessageFns<DownloadObjectRequest> = {
           message.key = reader.string();
           continue;
         }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1936,6 +2025,7 @@ export const DownloadObjectRequest: MessageFns<DownloadObjectRequest> = {
     return {
       bucket: isSet(object.bucket) ? globalThis.String(object.bucket) : "",
       key: isSet(object.key) ? globalThis.String(object.key) : "",
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -1947,6 +2037,9 @@ export const DownloadObjectRequest: MessageFns<DownloadObjectRequest> = {
     if (message.key !== "") {
       obj.key = message.key;
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -1957,6 +2050,9 @@ export const DownloadObjectRequest: MessageFns<DownloadObjectRequest> = {
     const message = createBaseDownloadObjectRequest();
     message.bucket = object.bucket ?? "";
     message.key = object.key ?? "";
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
@@ -2212,7 +2308,7 @@ export const DownloadMetadata: MessageFns<DownloadMetadata> = {
 };
 
 function createBaseGetUploadUrlRequest(): GetUploadUrlRequest {
-  return { bucket: "", key: "", content_type: undefined, expires_in_seconds: undefined };
+  return { bucket: "", key: "", content_type: undefined, expires_in_seconds: undefined, credentials: undefined };
 }
 
 export const GetUploadUrlRequest: MessageFns<GetUploadUrlRequest> = {
@@ -2229,6 +2325,9 @@ export const GetUploadUrlRequest: MessageFns<GetUploadUrlRequest> = {
     if (message.expires_in_seconds !== undefined) {
       writer.uint32(32).int32(message.expires_in_seconds);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(42).fork()).join();
+    }
     return writer;
   },
 
@@ -2271,6 +2370,14 @@ export const GetUploadUrlRequest: MessageFns<GetUploadUrlRequest> = {
           message.expires_in_seconds = reader.int32();
           continue;
         }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2294,6 +2401,7 @@ export const GetUploadUrlRequest: MessageFns<GetUploadUrlRequest> = {
         : isSet(object.expires_in_seconds)
         ? globalThis.Number(object.expires_in_seconds)
         : undefined,
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -2311,6 +2419,9 @@ export const GetUploadUrlRequest: MessageFns<GetUploadUrlRequest> = {
     if (message.expires_in_seconds !== undefined) {
       obj.expiresInSeconds = Math.round(message.expires_in_seconds);
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -2323,12 +2434,15 @@ export const GetUploadUrlRequest: MessageFns<GetUploadUrlRequest> = {
     message.key = object.key ?? "";
     message.content_type = object.content_type ?? undefined;
     message.expires_in_seconds = object.expires_in_seconds ?? undefined;
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
 
 function createBaseGetDownloadUrlRequest(): GetDownloadUrlRequest {
-  return { bucket: "", key: "", expires_in_seconds: undefined };
+  return { bucket: "", key: "", expires_in_seconds: undefined, credentials: undefined };
 }
 
 export const GetDownloadUrlRequest: MessageFns<GetDownloadUrlRequest> = {
@@ -2342,6 +2456,9 @@ export const GetDownloadUrlRequest: MessageFns<GetDownloadUrlRequest> = {
     if (message.expires_in_seconds !== undefined) {
       writer.uint32(24).int32(message.expires_in_seconds);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(34).fork()).join();
+    }
     return writer;
   },
 
@@ -2376,6 +2493,14 @@ export const GetDownloadUrlRequest: MessageFns<GetDownloadUrlRequest> = {
           message.expires_in_seconds = reader.int32();
           continue;
         }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2394,6 +2519,7 @@ export const GetDownloadUrlRequest: MessageFns<GetDownloadUrlRequest> = {
         : isSet(object.expires_in_seconds)
         ? globalThis.Number(object.expires_in_seconds)
         : undefined,
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -2408,6 +2534,9 @@ export const GetDownloadUrlRequest: MessageFns<GetDownloadUrlRequest> = {
     if (message.expires_in_seconds !== undefined) {
       obj.expiresInSeconds = Math.round(message.expires_in_seconds);
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -2419,12 +2548,15 @@ export const GetDownloadUrlRequest: MessageFns<GetDownloadUrlRequest> = {
     message.bucket = object.bucket ?? "";
     message.key = object.key ?? "";
     message.expires_in_seconds = object.expires_in_seconds ?? undefined;
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
 
 function createBaseDeleteObjectRequest(): DeleteObjectRequest {
-  return { bucket: "", keys: [] };
+  return { bucket: "", keys: [], credentials: undefined };
 }
 
 export const DeleteObjectRequest: MessageFns<DeleteObjectRequest> = {
@@ -2435,6 +2567,9 @@ export const DeleteObjectRequest: MessageFns<DeleteObjectRequest> = {
     for (const v of message.keys) {
       writer.uint32(18).string(v!);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(26).fork()).join();
+    }
     return writer;
   },
 
@@ -2461,6 +2596,14 @@ export const DeleteObjectRequest: MessageFns<DeleteObjectRequest> = {
           message.keys.push(reader.string());
           continue;
         }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2474,6 +2617,7 @@ export const DeleteObjectRequest: MessageFns<DeleteObjectRequest> = {
     return {
       bucket: isSet(object.bucket) ? globalThis.String(object.bucket) : "",
       keys: globalThis.Array.isArray(object?.keys) ? object.keys.map((e: any) => globalThis.String(e)) : [],
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -2485,6 +2629,9 @@ export const DeleteObjectRequest: MessageFns<DeleteObjectRequest> = {
     if (message.keys?.length) {
       obj.keys = message.keys;
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -2495,12 +2642,15 @@ export const DeleteObjectRequest: MessageFns<DeleteObjectRequest> = {
     const message = createBaseDeleteObjectRequest();
     message.bucket = object.bucket ?? "";
     message.keys = object.keys?.map((e) => e) || [];
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
 
 function createBaseCopyObjectRequest(): CopyObjectRequest {
-  return { source_bucket: "", source_key: "", dest_bucket: "", dest_key: "" };
+  return { source_bucket: "", source_key: "", dest_bucket: "", dest_key: "", credentials: undefined };
 }
 
 export const CopyObjectRequest: MessageFns<CopyObjectRequest> = {
@@ -2517,6 +2667,9 @@ export const CopyObjectRequest: MessageFns<CopyObjectRequest> = {
     if (message.dest_key !== "") {
       writer.uint32(34).string(message.dest_key);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(42).fork()).join();
+    }
     return writer;
   },
 
@@ -2559,6 +2712,14 @@ export const CopyObjectRequest: MessageFns<CopyObjectRequest> = {
           message.dest_key = reader.string();
           continue;
         }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2590,6 +2751,7 @@ export const CopyObjectRequest: MessageFns<CopyObjectRequest> = {
         : isSet(object.dest_key)
         ? globalThis.String(object.dest_key)
         : "",
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -2607,6 +2769,9 @@ export const CopyObjectRequest: MessageFns<CopyObjectRequest> = {
     if (message.dest_key !== "") {
       obj.destKey = message.dest_key;
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -2619,12 +2784,15 @@ export const CopyObjectRequest: MessageFns<CopyObjectRequest> = {
     message.source_key = object.source_key ?? "";
     message.dest_bucket = object.dest_bucket ?? "";
     message.dest_key = object.dest_key ?? "";
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
     return message;
   },
 };
 
 function createBaseAbortUploadRequest(): AbortUploadRequest {
-  return { bucket: "", key: "", upload_id: "" };
+  return { bucket: "", key: "", upload_id: "", credentials: undefined };
 }
 
 export const AbortUploadRequest: MessageFns<AbortUploadRequest> = {
@@ -2638,6 +2806,9 @@ export const AbortUploadRequest: MessageFns<AbortUploadRequest> = {
     if (message.upload_id !== "") {
       writer.uint32(26).string(message.upload_id);
     }
+    if (message.credentials !== undefined) {
+      S3Credentials.encode(message.credentials, writer.uint32(34).fork()).join();
+    }
     return writer;
   },
 
@@ -2672,6 +2843,14 @@ export const AbortUploadRequest: MessageFns<AbortUploadRequest> = {
           message.upload_id = reader.string();
           continue;
         }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.credentials = S3Credentials.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2690,6 +2869,7 @@ export const AbortUploadRequest: MessageFns<AbortUploadRequest> = {
         : isSet(object.upload_id)
         ? globalThis.String(object.upload_id)
         : "",
+      credentials: isSet(object.credentials) ? S3Credentials.fromJSON(object.credentials) : undefined,
     };
   },
 
@@ -2704,6 +2884,9 @@ export const AbortUploadRequest: MessageFns<AbortUploadRequest> = {
     if (message.upload_id !== "") {
       obj.uploadId = message.upload_id;
     }
+    if (message.credentials !== undefined) {
+      obj.credentials = S3Credentials.toJSON(message.credentials);
+    }
     return obj;
   },
 
@@ -2715,6 +2898,93 @@ export const AbortUploadRequest: MessageFns<AbortUploadRequest> = {
     message.bucket = object.bucket ?? "";
     message.key = object.key ?? "";
     message.upload_id = object.upload_id ?? "";
+    message.credentials = (object.credentials !== undefined && object.credentials !== null)
+      ? S3Credentials.fromPartial(object.credentials)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseS3Credentials(): S3Credentials {
+  return { access_key_id: "", secret_access_key: undefined };
+}
+
+export const S3Credentials: MessageFns<S3Credentials> = {
+  encode(message: S3Credentials, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.access_key_id !== "") {
+      writer.uint32(10).string(message.access_key_id);
+    }
+    if (message.secret_access_key !== undefined) {
+      writer.uint32(18).string(message.secret_access_key);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): S3Credentials {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    const end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseS3Credentials();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.access_key_id = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.secret_access_key = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): S3Credentials {
+    return {
+      access_key_id: isSet(object.accessKeyId)
+        ? globalThis.String(object.accessKeyId)
+        : isSet(object.access_key_id)
+        ? globalThis.String(object.access_key_id)
+        : "",
+      secret_access_key: isSet(object.secretAccessKey)
+        ? globalThis.String(object.secretAccessKey)
+        : isSet(object.secret_access_key)
+        ? globalThis.String(object.secret_access_key)
+        : undefined,
+    };
+  },
+
+  toJSON(message: S3Credentials): unknown {
+    const obj: any = {};
+    if (message.access_key_id !== "") {
+      obj.accessKeyId = message.access_key_id;
+    }
+    if (message.secret_access_key !== undefined) {
+      obj.secretAccessKey = message.secret_access_key;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<S3Credentials>, I>>(base?: I): S3Credentials {
+    return S3Credentials.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<S3Credentials>, I>>(object: I): S3Credentials {
+    const message = createBaseS3Credentials();
+    message.access_key_id = object.access_key_id ?? "";
+    message.secret_access_key = object.secret_access_key ?? undefined;
     return message;
   },
 };
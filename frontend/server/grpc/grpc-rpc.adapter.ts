@@ -1,11 +1,17 @@
 import type {
 	ChannelCredentials,
 	Client,
+	Interceptor,
 	ServiceDefinition,
 } from "@grpc/grpc-js";
 import type { Observable } from "rxjs";
 
-import { credentials, makeGenericClientConstructor } from "@grpc/grpc-js";
+import {
+	credentials,
+	InterceptingCall,
+	makeGenericClientConstructor,
+} from "@grpc/grpc-js";
+import { readFileSync } from "node:fs";
 import { Subject } from "rxjs";
 
 import { config } from "../config";
@@ -45,6 +51,23 @@ interface GenericServiceDefinition {
 	};
 }
 
+function read_optional_file(path: string): Buffer | null {
+	return path ? readFileSync(path) : null;
+}
+
+/**
+ * Attach the configured admin token as a Bearer token to every call
+ */
+const auth_interceptor: Interceptor = (options, next_call) =>
+	new InterceptingCall(next_call(options), {
+		start: (metadata, listener, next) => {
+			if (config.grpc_auth_token) {
+				metadata.set("authorization", `Bearer ${config.grpc_auth_token}`);
+			}
+			next(metadata, listener);
+		},
+	});
+
 class GrpcAdapter implements Rpc {
 	private readonly channel_credentials: ChannelCredentials;
 	private readonly grpc_address: string;
@@ -54,8 +77,15 @@ class GrpcAdapter implements Rpc {
 		const url = new URL(config.grpc_uri);
 		this.grpc_address = `${url.hostname}:${url.port}`;
 
-		// Use insecure credentials for now (can be configured for TLS later)
-		this.channel_credentials = credentials.createInsecure();
+		// https:// enables TLS, a client certificate is sent when configured (mTLS)
+		this.channel_credentials =
+			url.protocol === "https:"
+				? credentials.createSsl(
+						read_optional_file(config.grpc_tls_ca),
+						read_optional_file(config.grpc_tls_client_key),
+						read_optional_file(config.grpc_tls_client_cert),
+					)
+				: credentials.createInsecure();
 	}
 
 	/**
@@ -234,6 +264,7 @@ class GrpcAdapter implements Rpc {
 		client = new generic_client(
 			this.grpc_address,
 			this.channel_credentials,
+			{ interceptors: [auth_interceptor] },
 		);
 		this.client_cache.set(cache_key, client);
 
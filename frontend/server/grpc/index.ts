@@ -5,5 +5,5 @@ export { access_key_client } from "./access-key.client";
 export { bucket_client } from "./bucket.client";
 export { grpc_service } from "./grpc.service";
 export { grpc_adapter } from "./grpc-rpc.adapter";
-export { object_client } from "./object.client";
+export { object_client, s3_credentials } from "./object.client";
 // export { init_telemetry } from "./telemetry";
@@ -1,6 +1,24 @@
+import type { S3Credentials } from "../generated/object";
+
+import { config } from "../config";
 import { ObjectServiceClientImpl } from "../generated/object";
 import { grpc_adapter } from "./grpc-rpc.adapter";
 
 // biome-ignore lint/style/useNamingConvention: singleton
 export const object_client: ObjectServiceClientImpl =
 	new ObjectServiceClientImpl(grpc_adapter);
+
+/**
+ * Access key that object RPCs run as, so Garage enforces its bucket permissions.
+ * Without a secret the backend looks it up, which requires the write:keys scope.
+ * Returns undefined to use the backend's default S3 key
+ */
+export function s3_credentials(): S3Credentials | undefined {
+	if (!config.grpc_s3_access_key_id) {
+		return undefined;
+	}
+	return {
+		access_key_id: config.grpc_s3_access_key_id,
+		secret_access_key: config.grpc_s3_secret_access_key || undefined,
+	};
+}
@@ -1,6 +1,6 @@
 import type { DownloadChunkResponse } from "../generated/object";
 
-import { object_client } from "../grpc";
+import { object_client, s3_credentials } from "../grpc";
 
 /**
  * 從 gRPC 下載物件
@@ -15,7 +15,11 @@ export async function download_object(
 ): Promise<{ buffer: Buffer; metadata?: DownloadChunkResponse["metadata"] }> {
 	console.log(`[Download] Downloading ${key} from bucket ${bucket}...`);
 
-	const stream = object_client.DownloadObject({ bucket, key });
+	const stream = object_client.DownloadObject({
+		bucket,
+		credentials: s3_credentials(),
+		key,
+	});
 	const chunks: Buffer[] = [];
 	let metadata: DownloadChunkResponse["metadata"] | undefined;
 
@@ -68,7 +72,11 @@ export async function stream_object(
 }> {
 	console.log(`[Stream] Streaming ${key} from bucket ${bucket}...`);
 
-	const grpc_stream = object_client.DownloadObject({ bucket, key });
+	const grpc_stream = object_client.DownloadObject({
+		bucket,
+		credentials: s3_credentials(),
+		key,
+	});
 	let metadata: DownloadChunkResponse["metadata"] | undefined;
 	let chunk_count = 0;
 	let first_chunk: Uint8Array | undefined;
@@ -0,0 +1,42 @@
+//! Check access key expiration command
+
+use chrono::{DateTime, Utc};
+
+/// Command to run one pass of the background expiration check
+///
+/// 由背景任務定期送出，也可在測試或手動觸發時指定檢查時間
+#[derive(Debug, Clone)]
+pub struct CheckKeyExpirationCommand {
+    now: DateTime<Utc>,
+    /// 唯讀模式下只發出通知，不刪除 Key
+    purge_paused: bool,
+}
+
+impl CheckKeyExpirationCommand {
+    pub fn new() -> Self {
+        Self::at(Utc::now())
+    }
+
+    pub fn at(now: DateTime<Utc>) -> Self {
+        Self { now, purge_paused: false }
+    }
+
+    pub fn with_purge_paused(mut self, purge_paused: bool) -> Self {
+        self.purge_paused = purge_paused;
+        self
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    pub fn purge_paused(&self) -> bool {
+        self.purge_paused
+    }
+}
+
+impl Default for CheckKeyExpirationCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,147 @@
+//! Check access key expiration command handler
+
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+use crate::application::commands::access_key::CheckKeyExpirationCommand;
+use crate::domain::aggregates::AccessKeyAggregate;
+use crate::domain::entities::{
+    AccessKeyListItem, ExpirationNotice, KeyExpirationPolicy, KeyExpirationTracker,
+};
+use crate::domain::errors::DomainError;
+use crate::domain::events::{
+    AccessKeyDeletedEvent, AccessKeyEvent, AccessKeyExpiredEvent, AccessKeyExpiringSoonEvent,
+    EventBus,
+};
+use crate::domain::repositories::{
+    AccessKeyCommandRepository, AccessKeyQueryRepository, BucketRepository,
+};
+
+/// Result of one expiration check pass
+#[derive(Debug, Clone, Default)]
+pub struct KeyExpirationReport {
+    pub expiring_soon: usize,
+    pub expired: usize,
+    /// 自動刪除的 Key
+    pub deleted: Vec<String>,
+    /// 符合刪除條件但因為是 Bucket 唯一 owner 而保留的 Key
+    pub retained: Vec<String>,
+    /// 符合刪除條件但因唯讀模式暫停刪除的 Key
+    pub skipped: Vec<String>,
+}
+
+/// Handler for the background expiration check
+pub struct CheckKeyExpirationHandler {
+    query_repository: Arc<dyn AccessKeyQueryRepository>,
+    command_repository: Arc<dyn AccessKeyCommandRepository>,
+    bucket_repository: Arc<dyn BucketRepository>,
+    event_bus: Arc<dyn EventBus>,
+    tracker: Mutex<KeyExpirationTracker>,
+}
+
+impl CheckKeyExpirationHandler {
+    pub fn new(
+        query_repository: Arc<dyn AccessKeyQueryRepository>,
+        command_repository: Arc<dyn AccessKeyCommandRepository>,
+        bucket_repository: Arc<dyn BucketRepository>,
+        event_bus: Arc<dyn EventBus>,
+        policy: KeyExpirationPolicy,
+    ) -> Self {
+        Self {
+            query_repository,
+            command_repository,
+            bucket_repository,
+            event_bus,
+            tracker: Mutex::new(KeyExpirationTracker::new(policy)),
+        }
+    }
+
+    pub async fn handle(&self, command: CheckKeyExpirationCommand) -> Result<KeyExpirationReport, DomainError> {
+        let now = command.now();
+
+        // 1. 列出所有 Key
+        let keys: Vec<AccessKeyListItem> = self
+            .query_repository
+            .list()
+            .await?
+            .iter()
+            .map(AccessKeyListItem::from_response)
+            .collect();
+
+        // 2. 判定通知與可刪除的 Key
+        let (notices, purgeable) = {
+            let mut tracker = self.tracker.lock().expect("expiration tracker poisoned");
+            let notices = tracker.evaluate(&keys, now);
+            let purgeable: Vec<String> = tracker
+                .purgeable(&keys, now)
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            (notices, purgeable)
+        };
+
+        // 3. 發布事件
+        let mut report = KeyExpirationReport::default();
+        for notice in notices {
+            let event = match notice {
+                ExpirationNotice::ExpiringSoon { id, name, expiration, lead_time } => {
+                    report.expiring_soon += 1;
+                    AccessKeyEvent::ExpiringSoon(AccessKeyExpiringSoonEvent::new(id, name, expiration, lead_time))
+                }
+                ExpirationNotice::Expired { id, name, expiration } => {
+                    report.expired += 1;
+                    AccessKeyEvent::Expired(AccessKeyExpiredEvent::new(id, name, expiration))
+                }
+            };
+            self.event_bus.publish_access_key(event).await;
+        }
+
+        // 4. 唯讀模式下不刪除，待下次檢查再處理
+        if command.purge_paused() {
+            report.skipped = purgeable;
+            return Ok(report);
+        }
+
+        // 5. 依序刪除超過寬限期的 Key；單筆失敗不影響其他 Key
+        for id in purgeable {
+            match self.purge(&id).await {
+                Ok(true) => report.deleted.push(id),
+                Ok(false) => report.retained.push(id),
+                Err(e) => warn!(key_id = %id, error = %e, "Failed to delete expired key"),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 刪除已到期的 Key，若為任一 Bucket 的唯一 owner 則保留並回傳 false
+    async fn purge(&self, id: &str) -> Result<bool, DomainError> {
+        let aggregate = self.command_repository.get(id).await?;
+
+        if let Some(bucket_id) = self.solely_owned_bucket(&aggregate).await? {
+            warn!(
+                key_id = %id,
+                bucket_id = %bucket_id,
+                "Expired key is the only owner of a bucket, skipping deletion"
+            );
+            return Ok(false);
+        }
+
+        self.command_repository.delete(&aggregate).await?;
+        info!(key_id = %id, "Expired key deleted");
+
+        let event = AccessKeyEvent::Deleted(AccessKeyDeletedEvent::new(id.to_string()));
+        self.event_bus.publish_access_key(event).await;
+        Ok(true)
+    }
+
+    /// 找出此 Key 為唯一 owner 的 Bucket
+    async fn solely_owned_bucket(&self, aggregate: &AccessKeyAggregate) -> Result<Option<String>, DomainError> {
+        for bucket in aggregate.buckets().iter().filter(|b| b.permissions().owner()) {
+            let detail = self.bucket_repository.get_detail(bucket.id()).await?;
+            if !detail.has_other_owner(aggregate.id()) {
+                return Ok(Some(bucket.id().to_string()));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,46 @@
+//! Import access key command handler
+
+use std::sync::Arc;
+use crate::application::commands::access_key::ImportKeyCommand;
+use crate::domain::entities::AccessKey;
+use crate::domain::errors::DomainError;
+use crate::domain::events::{AccessKeyEvent, AccessKeyCreatedEvent, EventBus};
+use crate::domain::repositories::AccessKeyCommandRepository;
+use crate::domain::aggregates::AccessKeyAggregate;
+
+/// Handler for importing existing access keys
+pub struct ImportKeyHandler {
+    repository: Arc<dyn AccessKeyCommandRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl ImportKeyHandler {
+    pub fn new(repository: Arc<dyn AccessKeyCommandRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: ImportKeyCommand) -> Result<AccessKey, DomainError> {
+        // 1. 驗證 Command
+        command.validate()?;
+
+        // 2. 透過 Aggregate 建立（業務規則驗證在 Aggregate 中）
+        let new_aggregate = AccessKeyAggregate::import(
+            command.access_key_id,
+            command.secret_access_key,
+            command.name,
+        )?;
+
+        // 3. 持久化
+        let aggregate = self.repository.import(&new_aggregate).await?;
+
+        // 4. 發布事件
+        let event = AccessKeyEvent::Created(AccessKeyCreatedEvent::new(
+            aggregate.id().to_string(),
+            aggregate.name().to_string(),
+        ));
+        self.event_bus.publish_access_key(event).await;
+
+        // 5. 轉換為 Read Model 回傳
+        Ok(AccessKey::from_aggregate(aggregate))
+    }
+}
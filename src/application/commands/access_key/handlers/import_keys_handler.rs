@@ -0,0 +1,269 @@
+//! Bulk import access keys command handler
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use crate::application::commands::access_key::{ImportKeyCommand, ImportKeysCommand, UpdateKeyCommand};
+use crate::application::commands::bucket::{
+    BatchAllowBucketKeyCommand, BucketKeyPermissionInput, BucketKeyPermissionItem,
+};
+use crate::application::commands::bucket::handlers::{BatchAllowBucketKeyHandler, BatchPermissionResult};
+use crate::domain::entities::{garage::GarageBucketInfo, KeyTransferBucket, KeyTransferRecord};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{AccessKeyQueryRepository, BucketRepository};
+use crate::shared::{parse_datetime, UpdateField};
+use super::{ImportKeyHandler, UpdateKeyHandler};
+
+/// Result of importing a single key row
+#[derive(Debug, Clone)]
+pub struct KeyImportResult {
+    /// 來源檔案中的列號（JSON 為陣列索引，CSV 為行號，皆從 1 開始）
+    pub row: usize,
+    pub access_key_id: String,
+    pub success: bool,
+    pub error: Option<String>,
+    pub grants: Vec<BatchPermissionResult>,
+}
+
+impl KeyImportResult {
+    fn failed(row: usize, access_key_id: String, error: String) -> Self {
+        Self { row, access_key_id, success: false, error: Some(error), grants: vec![] }
+    }
+}
+
+/// Handler for bulk importing access keys
+pub struct ImportKeysHandler {
+    import_key_handler: Arc<ImportKeyHandler>,
+    update_key_handler: Arc<UpdateKeyHandler>,
+    batch_allow_handler: Arc<BatchAllowBucketKeyHandler>,
+    bucket_repository: Arc<dyn BucketRepository>,
+    key_repository: Arc<dyn AccessKeyQueryRepository>,
+}
+
+impl ImportKeysHandler {
+    pub fn new(
+        import_key_handler: Arc<ImportKeyHandler>,
+        update_key_handler: Arc<UpdateKeyHandler>,
+        batch_allow_handler: Arc<BatchAllowBucketKeyHandler>,
+        bucket_repository: Arc<dyn BucketRepository>,
+        key_repository: Arc<dyn AccessKeyQueryRepository>,
+    ) -> Self {
+        Self {
+            import_key_handler,
+            update_key_handler,
+            batch_allow_handler,
+            bucket_repository,
+            key_repository,
+        }
+    }
+
+    pub async fn handle(&self, command: ImportKeysCommand) -> Result<Vec<KeyImportResult>, DomainError> {
+        // 1. 驗證 Command
+        command.validate()?;
+
+        // 2. 建立目標叢集的 Bucket 對照表（global alias 優先，其次 bucket ID）
+        let buckets = BucketResolver::new(self.bucket_repository.list().await?);
+
+        // dry-run 需要預先回報已存在的 Key（實際匯入時由 Garage 拒絕）
+        let existing: HashSet<String> = if command.dry_run() {
+            self.key_repository.list().await?.into_iter().map(|k| k.id).collect()
+        } else {
+            HashSet::new()
+        };
+
+        // 3. 逐筆處理，保持檔案順序
+        let mut results = Vec::with_capacity(command.rows().len());
+        for row in command.rows() {
+            let result = match &row.record {
+                Ok(record) if existing.contains(&record.access_key_id) => KeyImportResult::failed(
+                    row.row,
+                    record.access_key_id.clone(),
+                    format!("Access key {} already exists", record.access_key_id),
+                ),
+                Ok(record) => self.import_record(row.row, record, &buckets, command.dry_run()).await,
+                Err(e) => KeyImportResult::failed(row.row, String::new(), e.clone()),
+            };
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    async fn import_record(
+        &self,
+        row: usize,
+        record: &KeyTransferRecord,
+        buckets: &BucketResolver,
+        dry_run: bool,
+    ) -> KeyImportResult {
+        let key_id = record.access_key_id.clone();
+
+        let Some(secret) = record.secret_access_key.clone() else {
+            return KeyImportResult::failed(
+                row,
+                key_id,
+                "secret_access_key is required to import a key".to_string(),
+            );
+        };
+
+        let import = ImportKeyCommand::new(
+            key_id.clone(),
+            secret,
+            Some(record.name.clone()).filter(|n| !n.is_empty()),
+        );
+        if let Err(e) = import.validate() {
+            return KeyImportResult::failed(row, key_id, e.to_string());
+        }
+
+        let update = match settings_command(record) {
+            Ok(update) => update,
+            Err(e) => return KeyImportResult::failed(row, key_id, e.to_string()),
+        };
+
+        // 無任何權限的授權沒有需要重建的內容
+        let grants: Vec<&KeyTransferBucket> = record.buckets.iter()
+            .filter(|b| b.read || b.write || b.owner)
+            .collect();
+
+        if dry_run {
+            let grants: Vec<_> = grants.into_iter()
+                .map(|b| match buckets.resolve(b) {
+                    Ok(bucket_id) => grant_result(bucket_id, &key_id, None),
+                    Err(e) => grant_result(b.bucket_id.clone(), &key_id, Some(e)),
+                })
+                .collect();
+            return finish(row, key_id, None, grants);
+        }
+
+        // 4. import-key
+        if let Err(e) = self.import_key_handler.handle(import).await {
+            return KeyImportResult::failed(row, key_id, e.to_string());
+        }
+
+        // 5. 還原 create bucket 權限與過期時間
+        let mut error = None;
+        if let Some(update) = update {
+            if let Err(e) = self.update_key_handler.handle(update).await {
+                error = Some(format!("Key imported but settings were not restored: {}", e));
+            }
+        }
+
+        // 6. allow-bucket-key
+        let mut results = Vec::new();
+        let mut items = Vec::new();
+        for b in grants {
+            match buckets.resolve(b) {
+                Ok(bucket_id) => items.push(BucketKeyPermissionItem::new(
+                    bucket_id,
+                    key_id.clone(),
+                    BucketKeyPermissionInput { read: b.read, write: b.write, owner: b.owner },
+                )),
+                Err(e) => results.push(grant_result(b.bucket_id.clone(), &key_id, Some(e))),
+            }
+        }
+        if !items.is_empty() {
+            match self.batch_allow_handler.handle(BatchAllowBucketKeyCommand::new(items.clone())).await {
+                Ok(allowed) => results.extend(allowed),
+                Err(e) => results.extend(items.into_iter().map(|item| {
+                    grant_result(item.bucket_id, &key_id, Some(e.to_string()))
+                })),
+            }
+        }
+
+        // 7. 重建 Key 的 local alias（需在授權之後，Garage 要求 Key 能存取該 Bucket）
+        for b in record.buckets.iter().filter(|b| !b.local_aliases.is_empty()) {
+            let bucket_id = match buckets.resolve(b) {
+                Ok(bucket_id) => bucket_id,
+                // 有授權的 Bucket 無法對應時已在上方回報
+                Err(e) if !(b.read || b.write || b.owner) => {
+                    results.push(grant_result(b.bucket_id.clone(), &key_id, Some(e)));
+                    continue;
+                }
+                Err(_) => continue,
+            };
+            for alias in &b.local_aliases {
+                if let Err(e) = self.bucket_repository.add_local_alias(&bucket_id, &key_id, alias).await {
+                    results.push(grant_result(
+                        bucket_id.clone(),
+                        &key_id,
+                        Some(format!("Local alias '{}' was not restored: {}", alias, e)),
+                    ));
+                }
+            }
+        }
+
+        finish(row, key_id, error, results)
+    }
+}
+
+/// 匯入後需要套用的 Key 設定，沒有需要變更時回傳 None
+fn settings_command(record: &KeyTransferRecord) -> Result<Option<UpdateKeyCommand>, DomainError> {
+    let expiration = match record.expiration.as_deref() {
+        Some(s) => Some(parse_datetime(s).ok_or_else(|| {
+            DomainError::ValidationError(format!("Invalid expiration '{}'", s))
+        })?),
+        None => None,
+    };
+
+    if !record.allow_create_bucket && expiration.is_none() {
+        return Ok(None);
+    }
+
+    let mut command = UpdateKeyCommand::new(record.access_key_id.clone());
+    if record.allow_create_bucket {
+        command = command.with_allow_create_bucket(UpdateField::Set(true));
+    }
+    if let Some(exp) = expiration {
+        command = command.with_expiration(UpdateField::Set(exp));
+    }
+    command.validate()?;
+    Ok(Some(command))
+}
+
+fn grant_result(bucket_id: String, access_key_id: &str, error: Option<String>) -> BatchPermissionResult {
+    BatchPermissionResult {
+        bucket_id,
+        access_key_id: access_key_id.to_string(),
+        success: error.is_none(),
+        error,
+        bucket: None,
+    }
+}
+
+fn finish(
+    row: usize,
+    access_key_id: String,
+    error: Option<String>,
+    grants: Vec<BatchPermissionResult>,
+) -> KeyImportResult {
+    let success = error.is_none() && grants.iter().all(|g| g.success);
+    KeyImportResult { row, access_key_id, success, error, grants }
+}
+
+/// 將匯出檔中的 Bucket 對應到目標叢集的 Bucket ID
+///
+/// 重建後的叢集 Bucket ID 會改變，因此優先以 global alias 對應
+struct BucketResolver {
+    by_alias: HashMap<String, String>,
+    ids: HashSet<String>,
+}
+
+impl BucketResolver {
+    fn new(buckets: Vec<GarageBucketInfo>) -> Self {
+        let mut by_alias = HashMap::new();
+        let mut ids = HashSet::new();
+        for b in buckets {
+            for alias in b.global_aliases {
+                by_alias.insert(alias, b.id.clone());
+            }
+            ids.insert(b.id);
+        }
+        Self { by_alias, ids }
+    }
+
+    fn resolve(&self, bucket: &KeyTransferBucket) -> Result<String, String> {
+        bucket.global_aliases.iter()
+            .find_map(|a| self.by_alias.get(a).cloned())
+            .or_else(|| self.ids.contains(&bucket.bucket_id).then(|| bucket.bucket_id.clone()))
+            .ok_or_else(|| format!("Bucket {} not found in this cluster", bucket.bucket_id))
+    }
+}
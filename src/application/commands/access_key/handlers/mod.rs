@@ -3,7 +3,15 @@
 mod create_key_handler;
 mod update_key_handler;
 mod delete_key_handler;
+mod import_key_handler;
+mod import_keys_handler;
+mod rotate_key_handler;
+mod check_key_expiration_handler;
 
 pub use create_key_handler::*;
 pub use update_key_handler::*;
 pub use delete_key_handler::*;
+pub use import_key_handler::*;
+pub use import_keys_handler::*;
+pub use rotate_key_handler::*;
+pub use check_key_expiration_handler::*;
@@ -0,0 +1,113 @@
+//! Rotate access key command handler
+
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use tracing::{error, warn};
+use crate::application::commands::access_key::RotateKeyCommand;
+use crate::domain::aggregates::AccessKeyAggregate;
+use crate::domain::entities::AccessKey;
+use crate::domain::errors::DomainError;
+use crate::domain::events::{AccessKeyEvent, AccessKeyRotatedEvent, EventBus};
+use crate::domain::repositories::{AccessKeyCommandRepository, BucketRepository};
+
+/// Result of a key rotation
+#[derive(Debug, Clone)]
+pub struct RotateKeyResult {
+    /// 新 Key（含 secret，只會在此回傳一次）
+    pub new_key: AccessKey,
+    pub old_key_id: String,
+    /// 舊 Key 的寬限期到期時間
+    pub old_key_expiration: Option<DateTime<Utc>>,
+}
+
+/// Handler for rotating access keys
+pub struct RotateKeyHandler {
+    key_repository: Arc<dyn AccessKeyCommandRepository>,
+    bucket_repository: Arc<dyn BucketRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl RotateKeyHandler {
+    pub fn new(
+        key_repository: Arc<dyn AccessKeyCommandRepository>,
+        bucket_repository: Arc<dyn BucketRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self { key_repository, bucket_repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: RotateKeyCommand) -> Result<RotateKeyResult, DomainError> {
+        // 1. 驗證 Command
+        command.validate()?;
+
+        // 2. 載入舊 Key，建立新 Key
+        let mut old_key = self.key_repository.get(command.id()).await?;
+        let successor = old_key.successor(command.name().cloned())?;
+        let new_key = self.key_repository.create(&successor).await?;
+
+        // 3. 複製權限並設定寬限期，任何一步失敗都刪除新 Key
+        let old_key_expiration = match self.transfer(&mut old_key, &new_key, &command).await {
+            Ok(expiration) => expiration,
+            Err(e) => {
+                self.rollback(&new_key).await;
+                return Err(DomainError::GarageApiError(format!(
+                    "Key rotation rolled back: {}", e
+                )));
+            }
+        };
+
+        // 4. 發布事件
+        let event = AccessKeyEvent::Rotated(AccessKeyRotatedEvent::new(
+            old_key.id().to_string(),
+            new_key.id().to_string(),
+            old_key_expiration,
+        ));
+        self.event_bus.publish_access_key(event).await;
+
+        Ok(RotateKeyResult {
+            new_key: AccessKey::from_aggregate(new_key),
+            old_key_id: old_key.id().to_string(),
+            old_key_expiration,
+        })
+    }
+
+    async fn transfer(
+        &self,
+        old_key: &mut AccessKeyAggregate,
+        new_key: &AccessKeyAggregate,
+        command: &RotateKeyCommand,
+    ) -> Result<Option<DateTime<Utc>>, DomainError> {
+        for bucket in old_key.buckets() {
+            let perms = bucket.permissions();
+            if perms.read() || perms.write() || perms.owner() {
+                self.bucket_repository
+                    .allow_bucket_key(bucket.id(), new_key.id(), perms.read(), perms.write(), perms.owner())
+                    .await?;
+            }
+            for alias in bucket.local_aliases() {
+                self.bucket_repository
+                    .add_local_alias(bucket.id(), new_key.id(), alias)
+                    .await?;
+            }
+        }
+
+        let Some(grace) = command.grace_period() else {
+            return Ok(None);
+        };
+        let expiration = old_key.start_grace_period(grace)?;
+        self.key_repository.save(old_key).await?;
+        Ok(Some(expiration))
+    }
+
+    /// 刪除新 Key，Garage 會一併移除其權限與 local alias
+    async fn rollback(&self, new_key: &AccessKeyAggregate) {
+        match self.key_repository.delete(new_key).await {
+            Ok(()) => warn!(key_id = %new_key.id(), "Key rotation failed, new key deleted"),
+            Err(e) => error!(
+                key_id = %new_key.id(),
+                error = %e,
+                "Key rotation failed and the new key could not be deleted"
+            ),
+        }
+    }
+}
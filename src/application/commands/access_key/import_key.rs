@@ -1,5 +1,8 @@
 //! Import access key command
 
+use crate::domain::errors::DomainError;
+use crate::domain::aggregates::AccessKeyAggregate;
+
 /// Command to import an existing access key
 #[derive(Debug, Clone)]
 pub struct ImportKeyCommand {
@@ -10,3 +13,24 @@ pub struct ImportKeyCommand {
     /// The secret access key
     pub secret_access_key: String,
 }
+
+impl ImportKeyCommand {
+    pub fn new(access_key_id: String, secret_access_key: String, name: Option<String>) -> Self {
+        Self {
+            name,
+            access_key_id,
+            secret_access_key,
+        }
+    }
+
+    /// 驗證 Command 輸入資料
+    /// 使用 Aggregate 的驗證規則，確保一致性
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AccessKeyAggregate::validate_access_key_id(&self.access_key_id)?;
+        AccessKeyAggregate::validate_secret_access_key(&self.secret_access_key)?;
+        if let Some(name) = &self.name {
+            AccessKeyAggregate::validate_name(name)?;
+        }
+        Ok(())
+    }
+}
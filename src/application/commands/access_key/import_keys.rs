@@ -0,0 +1,39 @@
+//! Bulk import access keys command
+
+use crate::domain::entities::KeyTransferRow;
+use crate::domain::errors::DomainError;
+
+/// Command to replay an exported key file
+///
+/// 每筆資料依序透過 import-key 與 allow-bucket-key 重建
+#[derive(Debug, Clone)]
+pub struct ImportKeysCommand {
+    rows: Vec<KeyTransferRow>,
+    /// 只驗證不寫入
+    dry_run: bool,
+}
+
+impl ImportKeysCommand {
+    pub fn new(rows: Vec<KeyTransferRow>, dry_run: bool) -> Self {
+        Self { rows, dry_run }
+    }
+
+    pub fn rows(&self) -> &[KeyTransferRow] {
+        &self.rows
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// 驗證 Command 輸入資料
+    /// 單筆資料的錯誤由 handler 逐筆回報，不在此拒絕整批
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.rows.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Key file contains no keys".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
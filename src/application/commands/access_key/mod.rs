@@ -5,9 +5,17 @@
 mod create_key;
 mod update_key;
 mod delete_key;
+mod import_key;
+mod import_keys;
+mod rotate_key;
+mod check_key_expiration;
 
 pub mod handlers;
 
 pub use create_key::*;
 pub use update_key::*;
 pub use delete_key::*;
+pub use import_key::*;
+pub use import_keys::*;
+pub use rotate_key::*;
+pub use check_key_expiration::*;
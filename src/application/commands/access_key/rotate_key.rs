@@ -0,0 +1,57 @@
+//! Rotate access key command
+
+use chrono::Duration;
+use crate::domain::errors::DomainError;
+use crate::domain::aggregates::AccessKeyAggregate;
+
+/// Command to replace an access key with a new one
+///
+/// 建立新 Key 並複製舊 Key 的 Bucket 權限與 local alias，
+/// 可選擇給舊 Key 一段寬限期（到期後失效），舊 Key 需另行刪除
+#[derive(Debug, Clone)]
+pub struct RotateKeyCommand {
+    id: String,
+    name: Option<String>,
+    /// 直接保留 client 傳入的秒數，於 `validate` 檢查範圍後才轉為 `Duration`
+    grace_period_secs: Option<i64>,
+}
+
+impl RotateKeyCommand {
+    pub fn new(id: String, name: Option<String>, grace_period_secs: Option<i64>) -> Self {
+        Self {
+            id,
+            name: name.filter(|n| !n.is_empty()),
+            grace_period_secs,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// 新 Key 的名稱，未指定時沿用舊 Key 名稱
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    /// 寬限期，秒數超出 `Duration` 範圍時為 None（`validate` 會拒絕）
+    pub fn grace_period(&self) -> Option<Duration> {
+        self.grace_period_secs.and_then(Duration::try_seconds)
+    }
+
+    /// 驗證 Command 輸入資料
+    /// 使用 Aggregate 的驗證規則，確保一致性
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AccessKeyAggregate::validate_id(&self.id)?;
+        if let Some(name) = &self.name {
+            AccessKeyAggregate::validate_name(name)?;
+        }
+        if let Some(secs) = self.grace_period_secs {
+            let grace = Duration::try_seconds(secs).ok_or_else(|| {
+                DomainError::ValidationError("Grace period is out of range".to_string())
+            })?;
+            AccessKeyAggregate::validate_grace_period(grace)?;
+        }
+        Ok(())
+    }
+}
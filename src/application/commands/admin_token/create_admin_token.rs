@@ -0,0 +1,45 @@
+//! Create admin token command
+
+use chrono::{DateTime, Utc};
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::errors::DomainError;
+use crate::shared::parse_datetime;
+
+/// Command to create a new admin token
+#[derive(Debug, Clone)]
+pub struct CreateAdminTokenCommand {
+    name: String,
+    expiration: Option<DateTime<Utc>>,
+    scope: Vec<String>,
+}
+
+impl CreateAdminTokenCommand {
+    pub fn new(name: String, expiration: Option<String>, scope: Vec<String>) -> Self {
+        Self {
+            name,
+            expiration: expiration.and_then(|s| parse_datetime(&s)),
+            scope,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn expiration(&self) -> Option<DateTime<Utc>> {
+        self.expiration
+    }
+
+    pub fn scope(&self) -> &[String] {
+        &self.scope
+    }
+
+    /// 驗證 Command 輸入資料
+    /// 使用 Aggregate 的驗證規則，確保一致性
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AdminTokenAggregate::validate_name(&self.name)?;
+        AdminTokenAggregate::validate_expiration_future(self.expiration)?;
+        AdminTokenAggregate::validate_scope(&self.scope)?;
+        Ok(())
+    }
+}
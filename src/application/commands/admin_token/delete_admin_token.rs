@@ -0,0 +1,35 @@
+//! Delete admin token command
+
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::errors::DomainError;
+
+/// Command to delete admin tokens
+#[derive(Debug, Clone)]
+pub struct DeleteAdminTokenCommand {
+    id: Vec<String>,
+}
+
+impl DeleteAdminTokenCommand {
+    pub fn new(id: Vec<String>) -> Self {
+        Self { id }
+    }
+
+    pub fn id(&self) -> &[String] {
+        &self.id
+    }
+
+    /// 驗證 Command 輸入資料
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.id.is_empty() {
+            return Err(DomainError::ValidationError(
+                "At least one admin token ID must be provided".to_string(),
+            ));
+        }
+
+        for id in &self.id {
+            AdminTokenAggregate::validate_id(id)?;
+        }
+
+        Ok(())
+    }
+}
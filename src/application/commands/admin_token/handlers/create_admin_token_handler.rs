@@ -0,0 +1,48 @@
+//! Create admin token command handler
+
+use std::sync::Arc;
+use crate::application::commands::admin_token::CreateAdminTokenCommand;
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::entities::AdminTokenCreated;
+use crate::domain::errors::DomainError;
+use crate::domain::events::EventBus;
+use crate::domain::repositories::{AdminTokenRepository, CreateAdminTokenInput};
+
+/// Handler for creating admin tokens
+pub struct CreateAdminTokenHandler {
+    repository: Arc<dyn AdminTokenRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CreateAdminTokenHandler {
+    pub fn new(repository: Arc<dyn AdminTokenRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    /// 建立 Admin Token
+    ///
+    /// 回傳值包含 secret token，這是唯一一次能取得 secret 的機會
+    pub async fn handle(&self, command: CreateAdminTokenCommand) -> Result<AdminTokenCreated, DomainError> {
+        // 1. 驗證 Command
+        command.validate()?;
+
+        // 2. 持久化（ID 與 secret 由 Garage 產生）
+        let created = self.repository.create(CreateAdminTokenInput {
+            name: Some(command.name().to_string()),
+            expiration: command.expiration().map(|dt| dt.to_rfc3339()),
+            scope: Some(command.scope().to_vec()),
+        }).await?;
+
+        // 3. 透過 Aggregate 產生事件
+        let (_, event) = AdminTokenAggregate::create(
+            created.id.clone(),
+            created.name.clone(),
+            created.scope.clone(),
+        )?;
+
+        // 4. 發布事件
+        self.event_bus.publish_admin_token(event).await;
+
+        Ok(created)
+    }
+}
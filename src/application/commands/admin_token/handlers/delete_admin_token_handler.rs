@@ -0,0 +1,57 @@
+//! Delete admin token command handler
+
+use std::sync::Arc;
+use futures::future::try_join_all;
+use crate::application::commands::admin_token::DeleteAdminTokenCommand;
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::errors::DomainError;
+use crate::domain::events::EventBus;
+use crate::domain::repositories::AdminTokenRepository;
+
+/// Handler for deleting admin tokens
+pub struct DeleteAdminTokenHandler {
+    repository: Arc<dyn AdminTokenRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DeleteAdminTokenHandler {
+    pub fn new(repository: Arc<dyn AdminTokenRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: DeleteAdminTokenCommand) -> Result<Vec<String>, DomainError> {
+        // 1. 驗證 Command
+        command.validate()?;
+
+        // 2. 並行刪除所有 tokens
+        let delete_tasks = command.id().iter().map(|id| {
+            let id = id.to_string();
+            let repository = Arc::clone(&self.repository);
+            let event_bus = Arc::clone(&self.event_bus);
+
+            async move {
+                // 載入 Aggregate
+                let info = repository.get(&id).await?;
+                let aggregate = AdminTokenAggregate::reconstitute(
+                    info.id,
+                    info.name,
+                    info.scope,
+                    info.expired,
+                    info.expiration,
+                );
+                let event = aggregate.delete()?;
+
+                // 執行刪除
+                repository.delete(&id).await?;
+
+                event_bus.publish_admin_token(event).await;
+
+                Ok::<String, DomainError>(id)
+            }
+        });
+
+        let deleted_ids = try_join_all(delete_tasks).await?;
+
+        Ok(deleted_ids)
+    }
+}
@@ -0,0 +1,9 @@
+//! Admin Token command handlers
+
+mod create_admin_token_handler;
+mod update_admin_token_handler;
+mod delete_admin_token_handler;
+
+pub use create_admin_token_handler::*;
+pub use update_admin_token_handler::*;
+pub use delete_admin_token_handler::*;
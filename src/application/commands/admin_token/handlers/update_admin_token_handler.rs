@@ -0,0 +1,60 @@
+//! Update admin token command handler
+
+use std::sync::Arc;
+use crate::application::commands::admin_token::UpdateAdminTokenCommand;
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::entities::AdminTokenInfo;
+use crate::domain::errors::DomainError;
+use crate::domain::events::EventBus;
+use crate::domain::repositories::{AdminTokenRepository, UpdateAdminTokenInput};
+
+/// Handler for updating admin tokens
+pub struct UpdateAdminTokenHandler {
+    repository: Arc<dyn AdminTokenRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl UpdateAdminTokenHandler {
+    pub fn new(repository: Arc<dyn AdminTokenRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: UpdateAdminTokenCommand) -> Result<AdminTokenInfo, DomainError> {
+        // 1. 驗證 Command
+        command.validate()?;
+
+        // 2. 載入現有資料
+        let info = self.repository.get(&command.id).await?;
+
+        // 3. 沒有變更，直接回傳現有資料
+        if !command.has_changes() {
+            return Ok(info);
+        }
+
+        // 4. 在 Aggregate 中套用變更（業務規則驗證）
+        let mut aggregate = AdminTokenAggregate::reconstitute(
+            info.id,
+            info.name,
+            info.scope,
+            info.expired,
+            info.expiration,
+        );
+        let events = aggregate.apply_update(&command.name, &command.expiration, &command.scope)?;
+
+        // 5. 持久化
+        let input = UpdateAdminTokenInput {
+            name: command.name.get().cloned(),
+            expiration: command.expiration.get().map(|dt| dt.to_rfc3339()),
+            never_expires: command.expiration.is_clear().then_some(true),
+            scope: command.scope.get().cloned(),
+        };
+        let updated = self.repository.update(&command.id, input).await?;
+
+        // 6. 發布事件
+        for event in events {
+            self.event_bus.publish_admin_token(event).await;
+        }
+
+        Ok(updated)
+    }
+}
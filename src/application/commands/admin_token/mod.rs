@@ -0,0 +1,13 @@
+//! Admin Token commands
+//!
+//! Commands for managing Garage admin tokens
+
+mod create_admin_token;
+mod update_admin_token;
+mod delete_admin_token;
+
+pub mod handlers;
+
+pub use create_admin_token::*;
+pub use update_admin_token::*;
+pub use delete_admin_token::*;
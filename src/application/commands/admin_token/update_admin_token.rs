@@ -0,0 +1,70 @@
+//! Update admin token command
+
+use chrono::{DateTime, Utc};
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::errors::DomainError;
+use crate::shared::{UpdateField, parse_datetime};
+
+/// Command to update an existing admin token
+///
+/// 使用 UpdateField 來區分三種更新意圖：
+/// - `NoChange`: 不更新此欄位
+/// - `Clear`: 清除此欄位（expiration 清除表示永不過期）
+/// - `Set(value)`: 設為新值
+#[derive(Debug, Clone)]
+pub struct UpdateAdminTokenCommand {
+    pub id: String,
+    pub name: UpdateField<String>,
+    pub expiration: UpdateField<DateTime<Utc>>,
+    pub scope: UpdateField<Vec<String>>,
+}
+
+impl UpdateAdminTokenCommand {
+    /// 驗證 Command 資料
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AdminTokenAggregate::validate_id(&self.id)?;
+
+        if let UpdateField::Set(ref name) = self.name {
+            AdminTokenAggregate::validate_name(name)?;
+        }
+
+        if let UpdateField::Set(exp) = self.expiration {
+            AdminTokenAggregate::validate_expiration_future(Some(exp))?;
+        }
+
+        if let UpdateField::Set(ref scope) = self.scope {
+            AdminTokenAggregate::validate_scope(scope)?;
+        }
+
+        Ok(())
+    }
+
+    /// 檢查是否有任何變更
+    pub fn has_changes(&self) -> bool {
+        self.name.has_change()
+            || self.expiration.has_change()
+            || self.scope.has_change()
+    }
+
+    /// 從 gRPC 請求轉換
+    /// expiration 已經由 infrastructure 層轉換為 UpdateField<String>
+    pub fn from_grpc_request(
+        id: String,
+        name: Option<String>,
+        expiration: UpdateField<String>,
+        scope: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            id,
+            name: UpdateField::from_option(name.filter(|n| !n.is_empty())),
+            expiration: match expiration {
+                UpdateField::NoChange => UpdateField::NoChange,
+                UpdateField::Clear => UpdateField::Clear,
+                UpdateField::Set(s) => parse_datetime(&s)
+                    .map(UpdateField::Set)
+                    .unwrap_or(UpdateField::NoChange),
+            },
+            scope: UpdateField::from_option(scope),
+        }
+    }
+}
@@ -1,22 +1,107 @@
 //! Purge blocks command handler
 
+use std::collections::HashSet;
 use std::sync::Arc;
+use futures::{StreamExt, TryStreamExt};
 use crate::application::commands::block::PurgeBlocksCommand;
-use crate::domain::entities::{MultiNodeResponse, PurgeBlocksResult};
+use crate::application::commands::confirmation::ConfirmationGuard;
+use crate::domain::entities::{
+    Confirmable, DestructiveOperation, ImpactSummary, MultiNodeResponse, PurgeBlocksResult,
+};
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::BlockRepository;
+use crate::domain::repositories::{BlockRepository, ConfirmationTokenRepository};
+
+/// 計算影響範圍時最多查詢的 block 數
+const IMPACT_MAX_BLOCKS: usize = 100;
+/// 同時向 Garage 查詢的 block 數量上限
+const IMPACT_CONCURRENCY: usize = 8;
 
 /// Handler for purging blocks
 pub struct PurgeBlocksHandler {
     repository: Arc<dyn BlockRepository>,
+    confirmation: ConfirmationGuard,
 }
 
 impl PurgeBlocksHandler {
-    pub fn new(repository: Arc<dyn BlockRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn BlockRepository>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            confirmation: ConfirmationGuard::new(confirmations),
+        }
+    }
+
+    pub async fn handle(
+        &self,
+        command: PurgeBlocksCommand,
+    ) -> Result<Confirmable<MultiNodeResponse<PurgeBlocksResult>>, DomainError> {
+        let operation = DestructiveOperation::new(
+            "PurgeBlocks",
+            std::iter::once(format!("node={}", command.node)).chain(command.block_hashes.iter().cloned()),
+        );
+        if let Some(required) = self
+            .confirmation
+            .check(&operation, command.confirmation_token.as_deref(), || {
+                self.impact(&command.node, &command.block_hashes)
+            })
+            .await?
+        {
+            return Ok(Confirmable::Required(required));
+        }
+
+        self.repository
+            .purge(&command.node, command.block_hashes)
+            .await
+            .map(Confirmable::Completed)
     }
 
-    pub async fn handle(&self, command: PurgeBlocksCommand) -> Result<MultiNodeResponse<PurgeBlocksResult>, DomainError> {
-        self.repository.purge(&command.node, command.block_hashes).await
+    /// 統計引用這些 block 的物件與未完成上傳
+    async fn impact(&self, node: &str, block_hashes: &[String]) -> Result<ImpactSummary, DomainError> {
+        let infos: Vec<_> = futures::stream::iter(block_hashes.iter().take(IMPACT_MAX_BLOCKS).cloned())
+            .map(|hash| async move { self.repository.get_info(node, &hash).await })
+            .buffer_unordered(IMPACT_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut objects = HashSet::new();
+        let mut uploads = HashSet::new();
+        for info in infos.iter().flat_map(|r| r.success.values()) {
+            for version in info.versions.iter().filter(|v| !v.deleted) {
+                objects.insert((version.bucket_id.as_str(), version.key.as_str()));
+            }
+            for upload in &info.uploads {
+                uploads.insert(upload.upload_id.as_str());
+            }
+        }
+
+        let mut details: Vec<String> = objects
+            .iter()
+            .map(|(bucket, key)| format!("object {} in bucket {}", key, bucket))
+            .collect();
+        details.sort();
+
+        // 超過上限時只統計前面的 block，摘要標示為下限
+        let at_least = if block_hashes.len() > IMPACT_MAX_BLOCKS { "at least " } else { "" };
+        if !at_least.is_empty() {
+            details.push(format!(
+                "only the first {} of {} blocks were inspected",
+                IMPACT_MAX_BLOCKS,
+                block_hashes.len()
+            ));
+        }
+
+        Ok(ImpactSummary::new(
+            format!(
+                "PurgeBlocks would delete {}{} objects and {} multipart uploads referencing {} block(s) on node {}",
+                at_least,
+                objects.len(),
+                uploads.len(),
+                block_hashes.len(),
+                node
+            ),
+            details,
+        ))
     }
 }
@@ -7,10 +7,17 @@ pub struct PurgeBlocksCommand {
     pub node: String,
     /// Block hashes to purge
     pub block_hashes: Vec<String>,
+    /// 第一次呼叫取得的確認 token
+    pub confirmation_token: Option<String>,
 }
 
 impl PurgeBlocksCommand {
     pub fn new(node: String, block_hashes: Vec<String>) -> Self {
-        Self { node, block_hashes }
+        Self { node, block_hashes, confirmation_token: None }
+    }
+
+    pub fn with_confirmation_token(mut self, token: Option<String>) -> Self {
+        self.confirmation_token = token;
+        self
     }
 }
@@ -0,0 +1,27 @@
+//! Cleanup incomplete uploads command
+
+use crate::domain::errors::DomainError;
+
+/// Command to delete stale incomplete multipart uploads
+#[derive(Debug, Clone)]
+pub struct CleanupIncompleteUploadsCommand {
+    /// 未指定時處理所有 bucket
+    pub bucket_id: Option<String>,
+    pub older_than_secs: u64,
+}
+
+impl CleanupIncompleteUploadsCommand {
+    pub fn new(bucket_id: Option<String>, older_than_secs: u64) -> Self {
+        Self { bucket_id, older_than_secs }
+    }
+
+    /// 驗證 Command 輸入資料
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.older_than_secs == 0 {
+            return Err(DomainError::ValidationError(
+                "older_than_secs must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
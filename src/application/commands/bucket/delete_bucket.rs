@@ -7,17 +7,28 @@ use crate::domain::errors::DomainError;
 #[derive(Debug, Clone)]
 pub struct DeleteBucketCommand {
     ids: Vec<String>,
+    /// 第一次呼叫取得的確認 token
+    confirmation_token: Option<String>,
 }
 
 impl DeleteBucketCommand {
     pub fn new(ids: Vec<String>) -> Self {
-        Self { ids }
+        Self { ids, confirmation_token: None }
+    }
+
+    pub fn with_confirmation_token(mut self, token: Option<String>) -> Self {
+        self.confirmation_token = token;
+        self
     }
 
     pub fn ids(&self) -> &[String] {
         &self.ids
     }
 
+    pub fn confirmation_token(&self) -> Option<&str> {
+        self.confirmation_token.as_deref()
+    }
+
     /// 驗證 Command 輸入資料
     pub fn validate(&self) -> Result<(), DomainError> {
         if self.ids.is_empty() {
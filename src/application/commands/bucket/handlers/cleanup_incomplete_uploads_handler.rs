@@ -0,0 +1,54 @@
+//! Cleanup incomplete uploads command handler
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::application::commands::bucket::CleanupIncompleteUploadsCommand;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::BucketRepository;
+
+/// Result of a cleanup pass, keyed by bucket ID
+#[derive(Debug, Clone, Default)]
+pub struct CleanupIncompleteUploadsReport {
+    pub uploads_deleted: HashMap<String, i64>,
+    pub errors: HashMap<String, String>,
+}
+
+impl CleanupIncompleteUploadsReport {
+    pub fn total_deleted(&self) -> i64 {
+        self.uploads_deleted.values().sum()
+    }
+}
+
+/// Handler for cleaning up incomplete uploads
+pub struct CleanupIncompleteUploadsHandler {
+    repository: Arc<dyn BucketRepository>,
+}
+
+impl CleanupIncompleteUploadsHandler {
+    pub fn new(repository: Arc<dyn BucketRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, command: CleanupIncompleteUploadsCommand) -> Result<CleanupIncompleteUploadsReport, DomainError> {
+        command.validate()?;
+
+        let bucket_ids = match command.bucket_id {
+            Some(id) => vec![id],
+            None => self.repository.list().await?.into_iter().map(|b| b.id).collect(),
+        };
+
+        // 個別 bucket 失敗不影響其他 bucket
+        let mut report = CleanupIncompleteUploadsReport::default();
+        for id in bucket_ids {
+            match self.repository.cleanup_incomplete_uploads(&id, command.older_than_secs).await {
+                Ok(deleted) => {
+                    report.uploads_deleted.insert(id, deleted);
+                }
+                Err(e) => {
+                    report.errors.insert(id, e.to_string());
+                }
+            }
+        }
+        Ok(report)
+    }
+}
@@ -3,26 +3,48 @@
 use std::sync::Arc;
 use futures::future::try_join_all;
 use crate::application::commands::bucket::DeleteBucketCommand;
+use crate::application::commands::confirmation::ConfirmationGuard;
+use crate::domain::entities::{Confirmable, DestructiveOperation, ImpactSummary};
 use crate::domain::errors::DomainError;
 use crate::domain::events::EventBus;
-use crate::domain::repositories::BucketRepository;
+use crate::domain::repositories::{BucketRepository, ConfirmationTokenRepository};
+use crate::shared::format_bytes;
 
 /// Delete bucket command handler
 pub struct DeleteBucketHandler {
     repository: Arc<dyn BucketRepository>,
     event_bus: Arc<dyn EventBus>,
+    confirmation: ConfirmationGuard,
 }
 
 impl DeleteBucketHandler {
-    pub fn new(repository: Arc<dyn BucketRepository>, event_bus: Arc<dyn EventBus>) -> Self {
-        Self { repository, event_bus }
+    pub fn new(
+        repository: Arc<dyn BucketRepository>,
+        event_bus: Arc<dyn EventBus>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            event_bus,
+            confirmation: ConfirmationGuard::new(confirmations),
+        }
     }
 
-    pub async fn handle(&self, command: DeleteBucketCommand) -> Result<Vec<String>, DomainError> {
+    pub async fn handle(&self, command: DeleteBucketCommand) -> Result<Confirmable<Vec<String>>, DomainError> {
         // 1. 驗證 Command
         command.validate()?;
 
-        // 2. 並行刪除所有 buckets
+        // 2. 二次確認
+        let operation = DestructiveOperation::new("DeleteBucket", command.ids().iter().cloned());
+        if let Some(required) = self
+            .confirmation
+            .check(&operation, command.confirmation_token(), || self.impact(command.ids()))
+            .await?
+        {
+            return Ok(Confirmable::Required(required));
+        }
+
+        // 3. 並行刪除所有 buckets
         let delete_tasks = command.ids().iter().map(|id| {
             let id = id.to_string();
             let repository = Arc::clone(&self.repository);
@@ -46,6 +68,36 @@ impl DeleteBucketHandler {
         // 使用 try_join_all 並行執行所有刪除操作
         let deleted_ids = try_join_all(delete_tasks).await?;
         
-        Ok(deleted_ids)
+        Ok(Confirmable::Completed(deleted_ids))
+    }
+
+    /// 每個 bucket 的物件數、容量與可存取的 key 數
+    async fn impact(&self, ids: &[String]) -> Result<ImpactSummary, DomainError> {
+        let details = try_join_all(ids.iter().map(|id| self.repository.get_detail(id))).await?;
+
+        let objects: u64 = details.iter().map(|d| d.objects).sum();
+        let bytes: u64 = details.iter().map(|d| d.bytes).sum();
+        let lines = details
+            .iter()
+            .map(|d| {
+                format!(
+                    "bucket {} has {} objects / {}, {} keys",
+                    d.global_aliases.first().unwrap_or(&d.id),
+                    d.objects,
+                    format_bytes(d.bytes),
+                    d.keys.len()
+                )
+            })
+            .collect();
+
+        Ok(ImpactSummary::new(
+            format!(
+                "Deleting {} bucket(s) with {} objects / {}",
+                details.len(),
+                objects,
+                format_bytes(bytes)
+            ),
+            lines,
+        ))
     }
 }
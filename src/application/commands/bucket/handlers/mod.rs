@@ -7,6 +7,7 @@ mod add_bucket_alias_handler;
 mod remove_bucket_alias_handler;
 mod batch_allow_bucket_key_handler;
 mod batch_deny_bucket_key_handler;
+mod cleanup_incomplete_uploads_handler;
 
 pub use create_bucket_handler::*;
 pub use update_bucket_handler::*;
@@ -15,3 +16,4 @@ pub use add_bucket_alias_handler::*;
 pub use remove_bucket_alias_handler::*;
 pub use batch_allow_bucket_key_handler::*;
 pub use batch_deny_bucket_key_handler::*;
+pub use cleanup_incomplete_uploads_handler::*;
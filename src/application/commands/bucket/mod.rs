@@ -11,6 +11,7 @@ mod alias_types;
 mod allow_bucket_key;
 mod batch_allow_bucket_key;
 mod batch_deny_bucket_key;
+mod cleanup_incomplete_uploads;
 
 pub mod handlers;
 
@@ -23,3 +24,4 @@ pub use alias_types::AliasType;
 pub use allow_bucket_key::BucketKeyPermissionInput;
 pub use batch_allow_bucket_key::{BatchAllowBucketKeyCommand, BucketKeyPermissionItem};
 pub use batch_deny_bucket_key::BatchDenyBucketKeyCommand;
+pub use cleanup_incomplete_uploads::CleanupIncompleteUploadsCommand;
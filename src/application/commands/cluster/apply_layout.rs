@@ -3,7 +3,7 @@
 /// Command to apply cluster layout changes
 #[derive(Debug, Clone)]
 pub struct ApplyLayoutCommand {
-    /// Expected cluster layout version
+    /// Layout version to apply, must be the current version + 1
     pub version: i64,
 }
 
@@ -0,0 +1,41 @@
+//! Bootstrap cluster command
+
+use crate::domain::entities::LayoutParameters;
+
+/// Command to connect nodes and assign their roles in one step
+#[derive(Debug, Clone)]
+pub struct BootstrapClusterCommand {
+    /// Nodes to set up
+    pub nodes: Vec<BootstrapNode>,
+    /// Layout parameters to stage (zone redundancy)
+    pub parameters: Option<LayoutParameters>,
+}
+
+/// Desired node and role
+#[derive(Debug, Clone)]
+pub struct BootstrapNode {
+    /// Node address (format: node_id@address:port)
+    pub address: String,
+    /// Zone name
+    pub zone: String,
+    /// Storage capacity in bytes, `None` for a gateway node
+    pub capacity: Option<u64>,
+    /// Node tags
+    pub tags: Vec<String>,
+}
+
+impl BootstrapClusterCommand {
+    pub fn new(nodes: Vec<BootstrapNode>, parameters: Option<LayoutParameters>) -> Self {
+        Self { nodes, parameters }
+    }
+}
+
+impl BootstrapNode {
+    /// `node_id@address` 中的 node ID，格式不符時為 None
+    pub fn node_id(&self) -> Option<&str> {
+        self.address
+            .split_once('@')
+            .map(|(id, _)| id)
+            .filter(|id| !id.is_empty())
+    }
+}
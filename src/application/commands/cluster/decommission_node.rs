@@ -0,0 +1,14 @@
+//! Decommission node command
+
+/// Command to remove a storage node from the layout and wait until its data has moved
+#[derive(Debug, Clone)]
+pub struct DecommissionNodeCommand {
+    /// Node ID to remove
+    pub node_id: String,
+}
+
+impl DecommissionNodeCommand {
+    pub fn new(node_id: String) -> Self {
+        Self { node_id }
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 use crate::application::commands::cluster::ApplyLayoutCommand;
+use crate::domain::aggregates::ClusterAggregate;
 use crate::domain::entities::ApplyLayoutResult;
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::ClusterRepository;
@@ -17,6 +18,10 @@ impl ApplyLayoutHandler {
     }
 
     pub async fn handle(&self, command: ApplyLayoutCommand) -> Result<ApplyLayoutResult, DomainError> {
+        // Garage 只回傳一般錯誤，先比對版本以回傳 LayoutVersionMismatch
+        let layout = self.repository.get_layout().await?;
+        ClusterAggregate::new(layout.version).ensure_staged_version(command.version)?;
+
         self.repository.apply_layout(command.version).await
     }
 }
@@ -0,0 +1,131 @@
+//! Bootstrap cluster command handler
+
+use std::sync::Arc;
+use crate::application::commands::cluster::BootstrapClusterCommand;
+use crate::domain::aggregates::{ClusterAggregate, NodeRoleConfig};
+use crate::domain::entities::{BootstrapConnectOutcome, BootstrapNodeReport, BootstrapReport, BootstrapRoleOutcome};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{ClusterRepository, UpdateLayoutInput};
+
+/// Handler for setting up a cluster in one step
+///
+/// 連線節點 → 暫存角色 → 預覽 → 套用。已完成的步驟會被略過，可重複執行
+pub struct BootstrapClusterHandler {
+    repository: Arc<dyn ClusterRepository>,
+}
+
+impl BootstrapClusterHandler {
+    pub fn new(repository: Arc<dyn ClusterRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, command: BootstrapClusterCommand) -> Result<BootstrapReport, DomainError> {
+        if command.nodes.is_empty() {
+            return Err(DomainError::ValidationError("At least one node is required".to_string()));
+        }
+        let mut desired = Vec::with_capacity(command.nodes.len());
+        for node in &command.nodes {
+            let node_id = node.node_id().ok_or_else(|| {
+                DomainError::ValidationError(format!("Expected node_id@address, got {}", node.address))
+            })?;
+            if node.zone.is_empty() {
+                return Err(DomainError::ValidationError(format!("Zone is required for node {}", node_id)));
+            }
+            desired.push(NodeRoleConfig {
+                node_id: node_id.to_string(),
+                zone: Some(node.zone.clone()),
+                capacity: node.capacity,
+                tags: Some(node.tags.clone()),
+            });
+        }
+        if let Some(parameters) = &command.parameters {
+            ClusterAggregate::validate_parameters(parameters)?;
+        }
+
+        // 1. 只連線尚未在線的節點
+        let status = self.repository.get_status().await?;
+        let is_up = |id: &str| status.nodes.iter().any(|n| n.id == id && n.is_up);
+        let pending: Vec<usize> = (0..desired.len()).filter(|&i| !is_up(&desired[i].node_id)).collect();
+
+        let mut connections = vec![BootstrapConnectOutcome::AlreadyConnected; desired.len()];
+        if !pending.is_empty() {
+            let addresses = pending.iter().map(|&i| command.nodes[i].address.clone()).collect();
+            let results = self.repository.connect_nodes(addresses).await?;
+            for (&i, result) in pending.iter().zip(results) {
+                connections[i] = if result.success {
+                    BootstrapConnectOutcome::Connected
+                } else {
+                    BootstrapConnectOutcome::Failed {
+                        error: result.error.unwrap_or_else(|| "Unknown error".to_string()),
+                    }
+                };
+            }
+        }
+
+        // 2. 比對角色；任一節點無法連線時不變更布局，避免套用不完整的布局
+        let layout = self.repository.get_layout().await?;
+        let mut roles = ClusterAggregate::plan_roles(&layout, &desired)?;
+        let connected = connections.iter().all(|c| !matches!(c, BootstrapConnectOutcome::Failed { .. }));
+        if !connected {
+            for role in roles.iter_mut().filter(|r| **r == BootstrapRoleOutcome::Staged) {
+                *role = BootstrapRoleOutcome::Skipped;
+            }
+        }
+
+        let parameters = command
+            .parameters
+            .filter(|p| connected && layout.parameters.as_ref() != Some(p) && layout.staged_parameters.as_ref() != Some(p));
+        let parameters_staged = parameters.is_some();
+        let inputs: Vec<UpdateLayoutInput> = desired
+            .iter()
+            .zip(&roles)
+            .filter(|(_, role)| **role == BootstrapRoleOutcome::Staged)
+            .map(|(config, _)| UpdateLayoutInput {
+                node_id: config.node_id.clone(),
+                zone: config.zone.clone(),
+                capacity: config.capacity,
+                tags: config.tags.clone(),
+                remove: false,
+            })
+            .collect();
+
+        let layout = if inputs.is_empty() && parameters.is_none() {
+            layout
+        } else {
+            self.repository.update_layout(inputs, parameters).await?
+        };
+
+        let mut report = BootstrapReport {
+            nodes: desired
+                .into_iter()
+                .zip(command.nodes)
+                .zip(connections.into_iter().zip(roles))
+                .map(|((config, node), (connection, role))| BootstrapNodeReport {
+                    node_id: config.node_id,
+                    address: node.address,
+                    connection,
+                    role,
+                })
+                .collect(),
+            parameters_staged,
+            preview: vec![],
+            applied_version: None,
+            layout_version: layout.version,
+        };
+
+        let has_staged = !layout.staged_role_changes.is_empty() || layout.staged_parameters.is_some();
+        if !connected || !has_staged {
+            return Ok(report);
+        }
+
+        // 3. Garage 無法計算分區分配時預覽即回傳錯誤，暫存變更保留供修正後重試
+        let preview = self.repository.preview_layout_changes().await?;
+        report.preview = preview.message;
+
+        // 4. 以暫存的下一個版本套用
+        let applied = self.repository.apply_layout(layout.version + 1).await?;
+        report.applied_version = Some(applied.layout.version);
+        report.layout_version = applied.layout.version;
+        Ok(report)
+    }
+}
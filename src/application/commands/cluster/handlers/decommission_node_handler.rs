@@ -0,0 +1,219 @@
+//! Decommission node command handler
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing::warn;
+use crate::application::commands::cluster::DecommissionNodeCommand;
+use crate::domain::aggregates::ClusterAggregate;
+use crate::domain::entities::{DecommissionPhase, DecommissionProgress};
+use crate::domain::errors::DomainError;
+use crate::domain::entities::ApplyLayoutResult;
+use crate::domain::repositories::{ClusterRepository, NodeRepository, UpdateLayoutInput};
+
+/// 各階段在整體進度中的起點（%）
+const SYNC_START: u8 = 30;
+const RESYNC_START: u8 = 80;
+
+/// Handler for decommissioning a storage node
+///
+/// 流程：檢查副本數 → 暫存移除 → 預覽 → 套用 → 等待 update tracker 同步 → 等待 resync 佇列清空
+///
+/// resync 佇列是整個叢集的總數，可能因其他原因無法歸零，最多等待 `resync_timeout`
+pub struct DecommissionNodeHandler {
+    cluster_repository: Arc<dyn ClusterRepository>,
+    node_repository: Arc<dyn NodeRepository>,
+    replication_factor: usize,
+    poll_interval: Duration,
+    resync_timeout: Duration,
+}
+
+impl DecommissionNodeHandler {
+    pub fn new(
+        cluster_repository: Arc<dyn ClusterRepository>,
+        node_repository: Arc<dyn NodeRepository>,
+        replication_factor: usize,
+        poll_interval: Duration,
+        resync_timeout: Duration,
+    ) -> Self {
+        Self {
+            cluster_repository,
+            node_repository,
+            replication_factor,
+            poll_interval,
+            resync_timeout,
+        }
+    }
+
+    /// 執行下線流程，每次階段或進度變化都送到 `progress`
+    ///
+    /// 接收端關閉（client 斷線）時停止等待並回傳錯誤；已套用的布局不會回復，Garage 會繼續搬移資料
+    pub async fn handle(
+        &self,
+        command: DecommissionNodeCommand,
+        progress: mpsc::Sender<DecommissionProgress>,
+    ) -> Result<(), DomainError> {
+        let mut reporter = ProgressReporter { sender: progress, layout_version: None };
+        let node_id = command.node_id;
+
+        reporter
+            .send(DecommissionPhase::Validating, 0, format!("Checking whether node {} can be removed", node_id))
+            .await?;
+        let layout = self.cluster_repository.get_layout().await?;
+        ClusterAggregate::check_node_removal(&layout, &node_id, self.replication_factor)?;
+
+        reporter
+            .send(DecommissionPhase::StagingRemoval, 10, format!("Staging removal of node {}", node_id))
+            .await?;
+        self.cluster_repository
+            .update_layout(
+                vec![UpdateLayoutInput {
+                    node_id: node_id.clone(),
+                    zone: None,
+                    capacity: None,
+                    tags: None,
+                    remove: true,
+                }],
+                None,
+            )
+            .await?;
+
+        // 套用前失敗時撤回暫存的移除，避免之後的 ApplyLayout 意外移除節點
+        // （check_node_removal 已確認沒有其他暫存變更）
+        let applied = match self.preview_and_apply(layout.version + 1, &reporter).await {
+            Ok(applied) => applied,
+            Err(e) => {
+                if let Err(revert_error) = self.cluster_repository.revert_layout().await {
+                    warn!("Failed to revert staged removal of node {}: {}", node_id, revert_error);
+                }
+                return Err(e);
+            }
+        };
+        reporter.layout_version = Some(applied.layout.version);
+
+        self.wait_for_layout_sync(applied.layout.version, &reporter).await?;
+        let message = match self.wait_for_resync(&reporter).await? {
+            0 => format!("Node {} has been decommissioned", node_id),
+            queued => format!(
+                "Node {} has been removed from the layout, {} block(s) still waiting for resync after {}s; Garage keeps resyncing in the background",
+                node_id,
+                queued,
+                self.resync_timeout.as_secs()
+            ),
+        };
+
+        reporter.send(DecommissionPhase::Completed, 100, message).await
+    }
+
+    async fn preview_and_apply(&self, version: i64, reporter: &ProgressReporter) -> Result<ApplyLayoutResult, DomainError> {
+        let preview = self.cluster_repository.preview_layout_changes().await?;
+        reporter
+            .send(DecommissionPhase::Previewing, 20, preview.message.join("\n"))
+            .await?;
+
+        reporter
+            .send(DecommissionPhase::Applying, 25, format!("Applying layout version {}", version))
+            .await?;
+        self.cluster_repository.apply_layout(version).await
+    }
+
+    /// 等待所有節點的 update tracker 同步到新版本，之後舊布局不再被需要
+    async fn wait_for_layout_sync(&self, version: i64, reporter: &ProgressReporter) -> Result<(), DomainError> {
+        loop {
+            let history = self.cluster_repository.get_layout_history().await?;
+            let total = history.update_trackers.len();
+            let mut lagging: Vec<&str> = history
+                .update_trackers
+                .iter()
+                .filter(|(_, tracker)| tracker.sync < version)
+                .map(|(id, _)| id.as_str())
+                .collect();
+            lagging.sort_unstable();
+
+            let synced = total - lagging.len();
+            let percent = SYNC_START + scale(synced, total, RESYNC_START - SYNC_START);
+            if lagging.is_empty() {
+                return reporter
+                    .send(DecommissionPhase::SyncingLayout, RESYNC_START, format!("All {} node(s) synced to layout version {}", total, version))
+                    .await;
+            }
+
+            reporter
+                .send(
+                    DecommissionPhase::SyncingLayout,
+                    percent,
+                    format!(
+                        "{}/{} node(s) synced to layout version {}, waiting for: {} (use SkipDeadNodes for nodes that are down)",
+                        synced,
+                        total,
+                        version,
+                        lagging.join(", ")
+                    ),
+                )
+                .await?;
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// 等待所有節點的 resync 佇列清空，回傳逾時時仍在佇列中的 block 數
+    async fn wait_for_resync(&self, reporter: &ProgressReporter) -> Result<u64, DomainError> {
+        let deadline = Instant::now() + self.resync_timeout;
+        let mut initial = None;
+        loop {
+            let statistics = self.node_repository.get_statistics("*").await?;
+            let queued: u64 = statistics
+                .success
+                .values()
+                .filter_map(|s| s.blocks.resync_queue_length)
+                .sum();
+            let initial = *initial.get_or_insert(queued);
+
+            if queued == 0 {
+                reporter
+                    .send(DecommissionPhase::Resyncing, 99, "Resync queues are empty".to_string())
+                    .await?;
+                return Ok(0);
+            }
+            if Instant::now() >= deadline {
+                return Ok(queued);
+            }
+
+            let done = initial.saturating_sub(queued) as usize;
+            let percent = RESYNC_START + scale(done, initial as usize, 99 - RESYNC_START);
+            reporter
+                .send(DecommissionPhase::Resyncing, percent, format!("{} block(s) waiting for resync", queued))
+                .await?;
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// `done / total` 換算成 `0..=span`
+fn scale(done: usize, total: usize, span: u8) -> u8 {
+    if total == 0 {
+        return span;
+    }
+    (done.min(total) * span as usize / total) as u8
+}
+
+/// 帶上目前布局版本送出進度
+struct ProgressReporter {
+    sender: mpsc::Sender<DecommissionProgress>,
+    layout_version: Option<i64>,
+}
+
+impl ProgressReporter {
+    async fn send(&self, phase: DecommissionPhase, percent: u8, message: String) -> Result<(), DomainError> {
+        let progress = DecommissionProgress {
+            phase,
+            percent,
+            message,
+            layout_version: self.layout_version,
+        };
+        self.sender
+            .send(progress)
+            .await
+            .map_err(|_| DomainError::InternalError("Progress receiver closed, stopped watching decommission".to_string()))
+    }
+}
@@ -5,9 +5,15 @@ mod update_layout_handler;
 mod apply_layout_handler;
 mod revert_layout_handler;
 mod skip_dead_nodes_handler;
+mod decommission_node_handler;
+mod bootstrap_cluster_handler;
+mod observe_cluster_handler;
 
 pub use connect_nodes_handler::*;
 pub use update_layout_handler::*;
 pub use apply_layout_handler::*;
 pub use revert_layout_handler::*;
 pub use skip_dead_nodes_handler::*;
+pub use decommission_node_handler::*;
+pub use bootstrap_cluster_handler::*;
+pub use observe_cluster_handler::*;
@@ -0,0 +1,51 @@
+//! Observe cluster command handler
+
+use std::sync::{Arc, Mutex};
+use crate::application::commands::cluster::ObserveClusterCommand;
+use crate::domain::aggregates::ClusterAggregate;
+use crate::domain::entities::ClusterSnapshot;
+use crate::domain::events::EventBus;
+use crate::domain::repositories::ClusterRepository;
+
+/// Handler for the background cluster poller
+pub struct ObserveClusterHandler {
+    repository: Arc<dyn ClusterRepository>,
+    event_bus: Arc<dyn EventBus>,
+    /// 上一次的快照，第一次輪詢只建立基準
+    last: Mutex<Option<ClusterSnapshot>>,
+}
+
+impl ObserveClusterHandler {
+    pub fn new(repository: Arc<dyn ClusterRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// 輪詢失敗不回傳錯誤，而是回傳帶有 `poll_error` 的快照，讓訂閱者得知無法連線
+    pub async fn handle(&self, command: ObserveClusterCommand) -> ClusterSnapshot {
+        // 1. 取得健康狀態與節點狀態
+        let result = tokio::try_join!(self.repository.get_health(), self.repository.get_status());
+
+        // 2. 與上一次快照比對
+        let (snapshot, events) = {
+            let mut last = self.last.lock().expect("cluster snapshot poisoned");
+            let snapshot = match result {
+                Ok((health, status)) => ClusterSnapshot::new(health, status, command.now()),
+                Err(e) => ClusterSnapshot::unreachable(last.as_ref(), e.to_string(), command.now()),
+            };
+            let events = ClusterAggregate::watch_events(last.as_ref(), &snapshot);
+            *last = Some(snapshot.clone());
+            (snapshot, events)
+        };
+
+        // 3. 發布事件
+        for event in events {
+            self.event_bus.publish_cluster(event).await;
+        }
+
+        snapshot
+    }
+}
@@ -2,21 +2,62 @@
 
 use std::sync::Arc;
 use crate::application::commands::cluster::RevertLayoutCommand;
-use crate::domain::entities::ClusterLayout;
+use crate::application::commands::confirmation::ConfirmationGuard;
+use crate::domain::entities::{ClusterLayout, Confirmable, DestructiveOperation, ImpactSummary};
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ClusterRepository;
+use crate::domain::repositories::{ClusterRepository, ConfirmationTokenRepository};
 
 /// Handler for reverting staged layout changes
 pub struct RevertLayoutHandler {
     repository: Arc<dyn ClusterRepository>,
+    confirmation: ConfirmationGuard,
 }
 
 impl RevertLayoutHandler {
-    pub fn new(repository: Arc<dyn ClusterRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn ClusterRepository>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            confirmation: ConfirmationGuard::new(confirmations),
+        }
     }
 
-    pub async fn handle(&self, _command: RevertLayoutCommand) -> Result<ClusterLayout, DomainError> {
-        self.repository.revert_layout().await
+    pub async fn handle(&self, command: RevertLayoutCommand) -> Result<Confirmable<ClusterLayout>, DomainError> {
+        // Token 綁定目前的 layout 版本，版本變動後需重新確認
+        let layout = self.repository.get_layout().await?;
+        let operation = DestructiveOperation::new("RevertLayout", [format!("version={}", layout.version)]);
+
+        if let Some(required) = self
+            .confirmation
+            .check(&operation, command.confirmation_token.as_deref(), || async { Ok(impact(&layout)) })
+            .await?
+        {
+            return Ok(Confirmable::Required(required));
+        }
+
+        self.repository.revert_layout().await.map(Confirmable::Completed)
+    }
+}
+
+/// 將被捨棄的暫存變更
+fn impact(layout: &ClusterLayout) -> ImpactSummary {
+    let mut details: Vec<String> = layout
+        .staged_role_changes
+        .iter()
+        .map(|c| format!("staged change for node {}", c.id))
+        .collect();
+    if layout.staged_parameters.is_some() {
+        details.push("staged layout parameters".to_string());
     }
+
+    ImpactSummary::new(
+        format!(
+            "Reverting discards {} staged change(s) on layout version {}",
+            details.len(),
+            layout.version
+        ),
+        details,
+    )
 }
@@ -2,21 +2,80 @@
 
 use std::sync::Arc;
 use crate::application::commands::cluster::SkipDeadNodesCommand;
-use crate::domain::entities::SkipDeadNodesResult;
+use crate::application::commands::confirmation::ConfirmationGuard;
+use crate::domain::entities::{Confirmable, DestructiveOperation, ImpactSummary, SkipDeadNodesResult};
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ClusterRepository;
+use crate::domain::repositories::{ClusterRepository, ConfirmationTokenRepository};
 
 /// Handler for skipping dead nodes in layout updates
 pub struct SkipDeadNodesHandler {
     repository: Arc<dyn ClusterRepository>,
+    confirmation: ConfirmationGuard,
 }
 
 impl SkipDeadNodesHandler {
-    pub fn new(repository: Arc<dyn ClusterRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn ClusterRepository>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            confirmation: ConfirmationGuard::new(confirmations),
+        }
     }
 
-    pub async fn handle(&self, command: SkipDeadNodesCommand) -> Result<SkipDeadNodesResult, DomainError> {
-        self.repository.skip_dead_nodes(command.version, command.allow_missing_data).await
+    pub async fn handle(&self, command: SkipDeadNodesCommand) -> Result<Confirmable<SkipDeadNodesResult>, DomainError> {
+        // 只有允許資料遺失時才需要確認
+        if command.allow_missing_data {
+            let operation = DestructiveOperation::new(
+                "SkipDeadNodes",
+                [format!("version={}", command.version), "allow_missing_data".to_string()],
+            );
+            if let Some(required) = self
+                .confirmation
+                .check(&operation, command.confirmation_token.as_deref(), || self.impact(command.version))
+                .await?
+            {
+                return Ok(Confirmable::Required(required));
+            }
+        }
+
+        self.repository
+            .skip_dead_nodes(command.version, command.allow_missing_data)
+            .await
+            .map(Confirmable::Completed)
+    }
+
+    /// 離線節點與 partition 健康狀態
+    async fn impact(&self, version: i64) -> Result<ImpactSummary, DomainError> {
+        let status = self.repository.get_status().await?;
+        let health = self.repository.get_health().await?;
+
+        let details = status
+            .nodes
+            .iter()
+            .filter(|n| !n.is_up && n.role.is_some())
+            .map(|n| {
+                format!(
+                    "dead node {} ({}), last seen {}",
+                    n.id,
+                    n.hostname.as_deref().unwrap_or("unknown host"),
+                    n.last_seen_secs_ago
+                        .map(|s| format!("{}s ago", s))
+                        .unwrap_or_else(|| "never".to_string())
+                )
+            })
+            .collect();
+
+        Ok(ImpactSummary::new(
+            format!(
+                "Skipping dead nodes for layout version {} with missing data allowed: {} of {} partitions have quorum, {} fully healthy; data only held by dead nodes will be lost",
+                version,
+                health.partitions_quorum,
+                health.partitions,
+                health.partitions_all_ok
+            ),
+            details,
+        ))
     }
 }
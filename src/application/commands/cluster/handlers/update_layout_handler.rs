@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 use crate::application::commands::cluster::UpdateLayoutCommand;
+use crate::domain::aggregates::ClusterAggregate;
 use crate::domain::entities::ClusterLayout;
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::{ClusterRepository, UpdateLayoutInput};
@@ -17,15 +18,27 @@ impl UpdateLayoutHandler {
     }
 
     pub async fn handle(&self, command: UpdateLayoutCommand) -> Result<ClusterLayout, DomainError> {
+        if let Some(parameters) = &command.parameters {
+            ClusterAggregate::validate_parameters(parameters)?;
+        }
+
+        // Garage 不檢查暫存變更的版本，先讀取目前版本比對；
+        // 讀取與寫入之間仍有極短的競爭空間
+        if let Some(expected) = command.expected_version {
+            let layout = self.repository.get_layout().await?;
+            ClusterAggregate::new(layout.version).ensure_layout_version(expected)?;
+        }
+
         let roles: Vec<UpdateLayoutInput> = command.role_changes.into_iter().map(|rc| {
             UpdateLayoutInput {
                 node_id: rc.node_id,
                 zone: rc.zone,
                 capacity: rc.capacity.map(|c| c as u64),
                 tags: rc.tags,
+                remove: rc.remove,
             }
         }).collect();
         
-        self.repository.update_layout(roles).await
+        self.repository.update_layout(roles, command.parameters).await
     }
 }
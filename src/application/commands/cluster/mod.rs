@@ -7,6 +7,9 @@ mod update_layout;
 mod apply_layout;
 mod revert_layout;
 mod skip_dead_nodes;
+mod decommission_node;
+mod bootstrap_cluster;
+mod observe_cluster;
 
 pub mod handlers;
 
@@ -15,3 +18,6 @@ pub use update_layout::*;
 pub use apply_layout::*;
 pub use revert_layout::*;
 pub use skip_dead_nodes::*;
+pub use decommission_node::*;
+pub use bootstrap_cluster::*;
+pub use observe_cluster::*;
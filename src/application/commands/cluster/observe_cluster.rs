@@ -0,0 +1,27 @@
+//! Observe cluster command
+
+use chrono::{DateTime, Utc};
+
+/// Command to take one snapshot of the cluster state
+///
+/// 由背景輪詢定期送出，與上一次的快照比對後發布狀態變化事件
+#[derive(Debug, Clone)]
+pub struct ObserveClusterCommand {
+    now: DateTime<Utc>,
+}
+
+impl ObserveClusterCommand {
+    pub fn new() -> Self {
+        Self { now: Utc::now() }
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+impl Default for ObserveClusterCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
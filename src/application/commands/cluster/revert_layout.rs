@@ -2,10 +2,18 @@
 
 /// Command to revert staged layout changes
 #[derive(Debug, Clone, Default)]
-pub struct RevertLayoutCommand;
+pub struct RevertLayoutCommand {
+    /// 第一次呼叫取得的確認 token
+    pub confirmation_token: Option<String>,
+}
 
 impl RevertLayoutCommand {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    pub fn with_confirmation_token(mut self, token: Option<String>) -> Self {
+        self.confirmation_token = token;
+        self
     }
 }
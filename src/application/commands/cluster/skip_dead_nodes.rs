@@ -7,10 +7,17 @@ pub struct SkipDeadNodesCommand {
     pub version: i64,
     /// Allow missing data (skip dead nodes even if data may be lost)
     pub allow_missing_data: bool,
+    /// 第一次呼叫取得的確認 token（僅 `allow_missing_data` 時需要）
+    pub confirmation_token: Option<String>,
 }
 
 impl SkipDeadNodesCommand {
     pub fn new(version: i64, allow_missing_data: bool) -> Self {
-        Self { version, allow_missing_data }
+        Self { version, allow_missing_data, confirmation_token: None }
+    }
+
+    pub fn with_confirmation_token(mut self, token: Option<String>) -> Self {
+        self.confirmation_token = token;
+        self
     }
 }
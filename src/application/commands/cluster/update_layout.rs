@@ -1,10 +1,16 @@
 //! Update layout command
 
+use crate::domain::entities::LayoutParameters;
+
 /// Command to update cluster layout
 #[derive(Debug, Clone)]
 pub struct UpdateLayoutCommand {
     /// Layout role changes
     pub role_changes: Vec<LayoutRoleChange>,
+    /// Layout parameters to stage (zone redundancy)
+    pub parameters: Option<LayoutParameters>,
+    /// Current layout version the caller based the changes on
+    pub expected_version: Option<i64>,
 }
 
 /// Layout role change
@@ -24,6 +30,20 @@ pub struct LayoutRoleChange {
 
 impl UpdateLayoutCommand {
     pub fn new(role_changes: Vec<LayoutRoleChange>) -> Self {
-        Self { role_changes }
+        Self {
+            role_changes,
+            parameters: None,
+            expected_version: None,
+        }
+    }
+
+    pub fn with_parameters(mut self, parameters: Option<LayoutParameters>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    pub fn with_expected_version(mut self, expected_version: Option<i64>) -> Self {
+        self.expected_version = expected_version;
+        self
     }
 }
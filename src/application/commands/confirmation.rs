@@ -0,0 +1,66 @@
+//! Two-step confirmation for destructive commands
+//!
+//! 各 handler 共用的確認流程：
+//! 1. 未帶 token → 計算影響範圍、簽發 token，不執行
+//! 2. 帶 token → 驗證並消耗 token 後執行
+
+use std::future::Future;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::domain::entities::{ConfirmationRequired, DestructiveOperation, ImpactSummary};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ConfirmationTokenRepository;
+use crate::shared::{get_caller, get_trace_id};
+
+/// 破壞性操作的確認關卡
+pub struct ConfirmationGuard {
+    repository: Arc<dyn ConfirmationTokenRepository>,
+}
+
+impl ConfirmationGuard {
+    pub fn new(repository: Arc<dyn ConfirmationTokenRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// 回傳 `None` 表示可以執行；否則回傳待確認的影響範圍與 token
+    ///
+    /// `impact` 只在需要簽發 token 時才會呼叫
+    pub async fn check<F, Fut>(
+        &self,
+        operation: &DestructiveOperation,
+        token: Option<&str>,
+        impact: F,
+    ) -> Result<Option<ConfirmationRequired>, DomainError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<ImpactSummary, DomainError>>,
+    {
+        if !self.repository.enabled() {
+            return Ok(None);
+        }
+
+        let caller = get_caller();
+        match token.filter(|t| !t.is_empty()) {
+            Some(token) => {
+                self.repository.consume(operation, token, caller.as_deref()).await?;
+                info!(
+                    trace_id = %get_trace_id(),
+                    action = %operation.action(),
+                    subject = %operation.subject(),
+                    "Destructive operation confirmed"
+                );
+                Ok(None)
+            }
+            None => {
+                let impact = impact().await?;
+                let issued = self.repository.issue(operation, caller.as_deref()).await?;
+                Ok(Some(ConfirmationRequired {
+                    token: issued.token,
+                    expires_at: issued.expires_at,
+                    impact,
+                }))
+            }
+        }
+    }
+}
@@ -2,6 +2,9 @@
 //! 
 //! Each command has its own module with handler and command struct
 
+// Two-step confirmation shared by destructive commands
+pub mod confirmation;
+
 // Bucket commands
 pub mod bucket;
 pub use bucket::*;
@@ -9,6 +12,9 @@ pub use bucket::*;
 // Access Key commands
 pub mod access_key;
 
+// Admin Token commands
+pub mod admin_token;
+
 // Cluster commands
 pub mod cluster;
 
@@ -23,3 +29,6 @@ pub mod worker;
 
 // Object commands (S3 operations)
 pub mod object;
+
+// Schedule commands (scheduled maintenance jobs)
+pub mod schedule;
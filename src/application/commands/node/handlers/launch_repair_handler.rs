@@ -1,22 +1,69 @@
 //! Launch repair command handler
 
 use std::sync::Arc;
+use futures::future::join_all;
 use crate::application::commands::node::LaunchRepairCommand;
-use crate::domain::entities::MultiNodeResponse;
+use crate::domain::entities::{MultiNodeResponse, RepairType};
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::NodeRepository;
+use crate::domain::repositories::{NodeRepository, WorkerRepository};
+
+/// Worker 變數：scrub 每處理一個 block 後等待的倍數
+const SCRUB_TRANQUILITY_VARIABLE: &str = "scrub-tranquility";
 
 /// Handler for launching repair operations
 pub struct LaunchRepairHandler {
     repository: Arc<dyn NodeRepository>,
+    worker_repository: Arc<dyn WorkerRepository>,
 }
 
 impl LaunchRepairHandler {
-    pub fn new(repository: Arc<dyn NodeRepository>) -> Self {
-        Self { repository }
+    pub fn new(repository: Arc<dyn NodeRepository>, worker_repository: Arc<dyn WorkerRepository>) -> Self {
+        Self { repository, worker_repository }
     }
 
     pub async fn handle(&self, command: LaunchRepairCommand) -> Result<MultiNodeResponse<()>, DomainError> {
-        self.repository.launch_repair(&command.node, &command.repair_type).await
+        let Some(tranquility) = command.scrub_tranquility else {
+            return self.repository.launch_repair(&command.node, command.repair_type).await;
+        };
+
+        if !matches!(command.repair_type, RepairType::Scrub(_)) {
+            return Err(DomainError::ValidationError(format!(
+                "scrub_tranquility only applies to scrub, not {}",
+                command.repair_type
+            )));
+        }
+
+        // 先調整 tranquility，設定失敗的節點不執行命令
+        let tuned = self
+            .worker_repository
+            .set_variable(&command.node, SCRUB_TRANQUILITY_VARIABLE.to_string(), tranquility.to_string())
+            .await?;
+
+        if tuned.error.is_empty() {
+            return self.repository.launch_repair(&command.node, command.repair_type).await;
+        }
+
+        // 部分節點設定失敗時，只在設定成功的節點逐一執行
+        let launches = tuned.success.keys().map(|node_id| self.repository.launch_repair(node_id, command.repair_type));
+        let mut response = MultiNodeResponse {
+            success: Default::default(),
+            error: tuned
+                .error
+                .into_iter()
+                .map(|(node_id, error)| (node_id, format!("Failed to set {}: {}", SCRUB_TRANQUILITY_VARIABLE, error)))
+                .collect(),
+        };
+        for (node_id, result) in tuned.success.keys().zip(join_all(launches).await) {
+            match result {
+                Ok(launched) => {
+                    response.success.extend(launched.success);
+                    response.error.extend(launched.error);
+                }
+                Err(e) => {
+                    response.error.insert(node_id.clone(), e.to_string());
+                }
+            }
+        }
+        Ok(response)
     }
 }
@@ -1,16 +1,24 @@
 //! Launch repair command
 
+use crate::domain::entities::RepairType;
+
 /// Command to launch a repair operation
 #[derive(Debug, Clone)]
 pub struct LaunchRepairCommand {
     /// Target node (or "*" for all nodes)
     pub node: String,
-    /// Repair type (e.g., "tables", "blocks", "versions", "block_refs", "block_rc", "scrub")
-    pub repair_type: String,
+    pub repair_type: RepairType,
+    /// 執行 scrub 命令前設定的 `scrub-tranquility`（僅 scrub 可用）
+    pub scrub_tranquility: Option<u32>,
 }
 
 impl LaunchRepairCommand {
-    pub fn new(node: String, repair_type: String) -> Self {
-        Self { node, repair_type }
+    pub fn new(node: String, repair_type: RepairType) -> Self {
+        Self { node, repair_type, scrub_tranquility: None }
+    }
+
+    pub fn with_scrub_tranquility(mut self, tranquility: Option<u32>) -> Self {
+        self.scrub_tranquility = tranquility;
+        self
     }
 }
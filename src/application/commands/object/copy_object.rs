@@ -1,6 +1,7 @@
 //! Copy Object Command
 
 use crate::domain::errors::DomainError;
+use crate::domain::value_objects::S3Credentials;
 
 /// Command to copy an object from one location to another
 #[derive(Debug, Clone)]
@@ -9,6 +10,8 @@ pub struct CopyObjectCommand {
     source_key: String,
     dest_bucket: String,
     dest_key: String,
+    /// 呼叫者指定的 Access Key，未指定時使用預設 S3 credentials
+    credentials: Option<S3Credentials>,
 }
 
 impl CopyObjectCommand {
@@ -24,6 +27,7 @@ impl CopyObjectCommand {
             source_key,
             dest_bucket,
             dest_key,
+            credentials: None,
         };
         command.validate()?;
         Ok(command)
@@ -54,6 +58,17 @@ impl CopyObjectCommand {
         Ok(())
     }
 
+    /// 以指定的 Access Key 執行
+    pub fn with_credentials(mut self, credentials: Option<S3Credentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Get the caller's S3 credentials
+    pub fn credentials(&self) -> Option<&S3Credentials> {
+        self.credentials.as_ref()
+    }
+
     /// Get the source bucket name
     pub fn source_bucket(&self) -> &str {
         &self.source_bucket
@@ -1,18 +1,21 @@
 //! Delete Object Command
 
 use crate::domain::errors::DomainError;
+use crate::domain::value_objects::S3Credentials;
 
 /// Command to delete a single object from a bucket
 #[derive(Debug, Clone)]
 pub struct DeleteObjectCommand {
     bucket: String,
     key: String,
+    /// 呼叫者指定的 Access Key，未指定時使用預設 S3 credentials
+    credentials: Option<S3Credentials>,
 }
 
 impl DeleteObjectCommand {
     /// Create a new DeleteObjectCommand
     pub fn new(bucket: String, key: String) -> Result<Self, DomainError> {
-        let command = Self { bucket, key };
+        let command = Self { bucket, key, credentials: None };
         command.validate()?;
         Ok(command)
     }
@@ -32,6 +35,17 @@ impl DeleteObjectCommand {
         Ok(())
     }
 
+    /// 以指定的 Access Key 執行
+    pub fn with_credentials(mut self, credentials: Option<S3Credentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Get the caller's S3 credentials
+    pub fn credentials(&self) -> Option<&S3Credentials> {
+        self.credentials.as_ref()
+    }
+
     /// Get the bucket name
     pub fn bucket(&self) -> &str {
         &self.bucket
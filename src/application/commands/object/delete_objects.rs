@@ -1,18 +1,23 @@
 //! Delete Objects Command (Batch)
 
 use crate::domain::errors::DomainError;
+use crate::domain::value_objects::S3Credentials;
 
 /// Command to delete multiple objects from a bucket
 #[derive(Debug, Clone)]
 pub struct DeleteObjectsCommand {
     bucket: String,
     keys: Vec<String>,
+    /// 呼叫者指定的 Access Key，未指定時使用預設 S3 credentials
+    credentials: Option<S3Credentials>,
+    /// 第一次呼叫取得的確認 token（僅遞迴刪除資料夾時需要）
+    confirmation_token: Option<String>,
 }
 
 impl DeleteObjectsCommand {
     /// Create a new DeleteObjectsCommand
     pub fn new(bucket: String, keys: Vec<String>) -> Result<Self, DomainError> {
-        let command = Self { bucket, keys };
+        let command = Self { bucket, keys, credentials: None, confirmation_token: None };
         command.validate()?;
         Ok(command)
     }
@@ -40,6 +45,26 @@ impl DeleteObjectsCommand {
         Ok(())
     }
 
+    /// 以指定的 Access Key 執行
+    pub fn with_credentials(mut self, credentials: Option<S3Credentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    pub fn with_confirmation_token(mut self, token: Option<String>) -> Self {
+        self.confirmation_token = token;
+        self
+    }
+
+    pub fn confirmation_token(&self) -> Option<&str> {
+        self.confirmation_token.as_deref()
+    }
+
+    /// Get the caller's S3 credentials
+    pub fn credentials(&self) -> Option<&S3Credentials> {
+        self.credentials.as_ref()
+    }
+
     /// Get the bucket name
     pub fn bucket(&self) -> &str {
         &self.bucket
@@ -6,23 +6,24 @@ use tracing::info;
 use crate::application::commands::object::CopyObjectCommand;
 use crate::domain::entities::CopyObjectResult;
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ObjectRepository;
+use crate::domain::repositories::ObjectRepositoryProvider;
 use crate::shared::get_trace_id;
 
 /// Handler for CopyObjectCommand
 pub struct CopyObjectHandler {
-    repository: Arc<dyn ObjectRepository>,
+    repositories: Arc<dyn ObjectRepositoryProvider>,
 }
 
 impl CopyObjectHandler {
     /// Create a new handler
-    pub fn new(repository: Arc<dyn ObjectRepository>) -> Self {
-        Self { repository }
+    pub fn new(repositories: Arc<dyn ObjectRepositoryProvider>) -> Self {
+        Self { repositories }
     }
 
     /// Handle the command
     pub async fn handle(&self, command: CopyObjectCommand) -> Result<CopyObjectResult, DomainError> {
         let trace_id = get_trace_id();
+        let repository = self.repositories.resolve(command.credentials()).await?;
 
         info!(
             trace_id = %trace_id,
@@ -33,7 +34,7 @@ impl CopyObjectHandler {
             "Copying object"
         );
 
-        let result = self.repository
+        let result = repository
             .copy(
                 command.source_bucket(),
                 command.source_key(),
@@ -5,23 +5,24 @@ use tracing::info;
 
 use crate::application::commands::object::DeleteObjectCommand;
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ObjectRepository;
+use crate::domain::repositories::ObjectRepositoryProvider;
 use crate::shared::get_trace_id;
 
 /// Handler for DeleteObjectCommand
 pub struct DeleteObjectHandler {
-    repository: Arc<dyn ObjectRepository>,
+    repositories: Arc<dyn ObjectRepositoryProvider>,
 }
 
 impl DeleteObjectHandler {
     /// Create a new handler
-    pub fn new(repository: Arc<dyn ObjectRepository>) -> Self {
-        Self { repository }
+    pub fn new(repositories: Arc<dyn ObjectRepositoryProvider>) -> Self {
+        Self { repositories }
     }
 
     /// Handle the command
     pub async fn handle(&self, command: DeleteObjectCommand) -> Result<(), DomainError> {
         let trace_id = get_trace_id();
+        let repository = self.repositories.resolve(command.credentials()).await?;
 
         info!(
             trace_id = %trace_id,
@@ -30,7 +31,7 @@ impl DeleteObjectHandler {
             "Deleting object"
         );
 
-        self.repository
+        repository
             .delete(command.bucket(), command.key())
             .await?;
 
@@ -3,32 +3,50 @@
 use std::sync::Arc;
 use tracing::info;
 
+use crate::application::commands::confirmation::ConfirmationGuard;
 use crate::application::commands::object::DeleteObjectsCommand;
-use crate::domain::entities::{DeleteObjectsResult, DeleteObjectError};
+use crate::domain::entities::{
+    Confirmable, DeleteObjectsResult, DeleteObjectError, DestructiveOperation, ImpactSummary,
+};
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ObjectRepository;
-use crate::shared::get_trace_id;
+use crate::domain::repositories::{ConfirmationTokenRepository, ObjectRepository, ObjectRepositoryProvider};
+use crate::shared::{format_bytes, get_trace_id};
+
+/// 計算影響範圍時每次列出的物件數
+const IMPACT_PAGE_SIZE: i32 = 1000;
+/// 計算影響範圍時所有資料夾合計最多列出的頁數，超過時摘要標示為下限
+const IMPACT_MAX_PAGES: usize = 10;
 
 /// Handler for DeleteObjectsCommand
 pub struct DeleteObjectsHandler {
-    repository: Arc<dyn ObjectRepository>,
+    repositories: Arc<dyn ObjectRepositoryProvider>,
+    confirmation: ConfirmationGuard,
 }
 
 impl DeleteObjectsHandler {
     /// Create a new handler
-    pub fn new(repository: Arc<dyn ObjectRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repositories: Arc<dyn ObjectRepositoryProvider>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+    ) -> Self {
+        Self {
+            repositories,
+            confirmation: ConfirmationGuard::new(confirmations),
+        }
     }
 
     /// Handle the command
     /// 
-    /// 對於以 `/` 結尾的 key（資料夾），會遞迴刪除該資料夾下的所有物件
+    /// 對於以 `/` 結尾的 key（資料夾），會遞迴刪除該資料夾下的所有物件，
+    /// 遞迴刪除需要二次確認
     pub async fn handle(
         &self,
         command: DeleteObjectsCommand,
-    ) -> Result<DeleteObjectsResult, DomainError> {
+    ) -> Result<Confirmable<DeleteObjectsResult>, DomainError> {
         let trace_id = get_trace_id();
+        let repository = self.repositories.resolve(command.credentials()).await?;
         let bucket = command.bucket().to_string();
+        let token = command.confirmation_token().map(str::to_string);
         let keys = command.into_keys();
 
         // 分離資料夾（以 / 結尾）和普通物件
@@ -36,6 +54,20 @@ impl DeleteObjectsHandler {
             .into_iter()
             .partition(|key| key.ends_with('/'));
 
+        if !folder_keys.is_empty() {
+            let operation = DestructiveOperation::new(
+                "DeleteObject",
+                std::iter::once(format!("bucket={}", bucket)).chain(folder_keys.iter().cloned()),
+            );
+            if let Some(required) = self
+                .confirmation
+                .check(&operation, token.as_deref(), || impact(repository.as_ref(), &bucket, &folder_keys))
+                .await?
+            {
+                return Ok(Confirmable::Required(required));
+            }
+        }
+
         let mut all_deleted: Vec<String> = Vec::new();
         let mut all_errors: Vec<DeleteObjectError> = Vec::new();
 
@@ -48,8 +80,7 @@ impl DeleteObjectsHandler {
                 "Recursively deleting folder"
             );
 
-            let result = self
-                .repository
+            let result = repository
                 .delete_recursive(&bucket, &folder_key)
                 .await?;
 
@@ -66,8 +97,7 @@ impl DeleteObjectsHandler {
                 "Batch deleting objects"
             );
 
-            let result = self
-                .repository
+            let result = repository
                 .delete_batch(&bucket, object_keys)
                 .await?;
 
@@ -83,9 +113,63 @@ impl DeleteObjectsHandler {
             "Delete operation completed"
         );
 
-        Ok(DeleteObjectsResult {
+        Ok(Confirmable::Completed(DeleteObjectsResult {
             deleted: all_deleted,
             errors: all_errors,
-        })
+        }))
     }
 }
+
+/// 統計各資料夾下將被刪除的物件數與容量
+async fn impact(
+    repository: &dyn ObjectRepository,
+    bucket: &str,
+    folder_keys: &[String],
+) -> Result<ImpactSummary, DomainError> {
+    let mut total_objects = 0usize;
+    let mut total_bytes = 0u64;
+    let mut pages = 0usize;
+    let mut partial = false;
+    let mut details = Vec::with_capacity(folder_keys.len());
+
+    for folder in folder_keys {
+        let mut objects = 0usize;
+        let mut bytes = 0u64;
+        let mut continuation_token: Option<String> = None;
+        let mut complete = false;
+
+        while pages < IMPACT_MAX_PAGES {
+            let page = repository
+                .list(bucket, Some(folder), continuation_token.as_deref(), Some(IMPACT_PAGE_SIZE), None)
+                .await?;
+            pages += 1;
+            objects += page.objects.len();
+            bytes += page.objects.iter().map(|o| o.size.max(0) as u64).sum::<u64>();
+
+            match page.next_continuation_token.filter(|_| page.is_truncated) {
+                Some(next) => continuation_token = Some(next),
+                None => {
+                    complete = true;
+                    break;
+                }
+            }
+        }
+
+        let at_least = if complete { "" } else { "at least " };
+        partial |= !complete;
+        details.push(format!("{} contains {}{} objects / {}", folder, at_least, objects, format_bytes(bytes)));
+        total_objects += objects;
+        total_bytes += bytes;
+    }
+
+    Ok(ImpactSummary::new(
+        format!(
+            "Recursive delete in bucket {} removes {}{} objects / {}",
+            bucket,
+            if partial { "at least " } else { "" },
+            total_objects,
+            format_bytes(total_bytes)
+        ),
+        details,
+    ))
+}
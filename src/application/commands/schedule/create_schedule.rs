@@ -0,0 +1,18 @@
+//! Create schedule command
+
+use crate::domain::entities::ScheduleAction;
+
+/// Command to create a scheduled maintenance job
+#[derive(Debug, Clone)]
+pub struct CreateScheduleCommand {
+    pub name: String,
+    pub cron: String,
+    pub action: ScheduleAction,
+    pub enabled: bool,
+}
+
+impl CreateScheduleCommand {
+    pub fn new(name: String, cron: String, action: ScheduleAction, enabled: bool) -> Self {
+        Self { name, cron, action, enabled }
+    }
+}
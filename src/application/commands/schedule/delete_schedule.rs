@@ -0,0 +1,13 @@
+//! Delete schedule command
+
+/// Command to delete a scheduled maintenance job
+#[derive(Debug, Clone)]
+pub struct DeleteScheduleCommand {
+    pub id: String,
+}
+
+impl DeleteScheduleCommand {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,41 @@
+//! Create schedule command handler
+
+use std::sync::Arc;
+use chrono::Utc;
+use crate::application::commands::schedule::CreateScheduleCommand;
+use crate::domain::entities::{CronExpression, Schedule};
+use crate::domain::errors::DomainError;
+use crate::domain::events::{EventBus, NodeEvent, NodeScheduleCreatedEvent};
+use crate::domain::repositories::ScheduleRepository;
+use crate::shared::generate_trace_id;
+
+/// Handler for creating schedules
+pub struct CreateScheduleHandler {
+    repository: Arc<dyn ScheduleRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CreateScheduleHandler {
+    pub fn new(repository: Arc<dyn ScheduleRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: CreateScheduleCommand) -> Result<Schedule, DomainError> {
+        let schedule = Schedule::new(
+            generate_trace_id(),
+            command.name,
+            CronExpression::parse(&command.cron)?,
+            command.action,
+            command.enabled,
+            Utc::now(),
+        )?;
+
+        self.repository.save(&schedule).await?;
+
+        self.event_bus
+            .publish_node(NodeEvent::ScheduleCreated(NodeScheduleCreatedEvent::new(&schedule)))
+            .await;
+
+        Ok(schedule)
+    }
+}
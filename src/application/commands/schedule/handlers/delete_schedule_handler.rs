@@ -0,0 +1,31 @@
+//! Delete schedule command handler
+
+use std::sync::Arc;
+use crate::application::commands::schedule::DeleteScheduleCommand;
+use crate::domain::errors::DomainError;
+use crate::domain::events::{EventBus, NodeEvent, NodeScheduleDeletedEvent};
+use crate::domain::repositories::ScheduleRepository;
+
+/// Handler for deleting schedules
+pub struct DeleteScheduleHandler {
+    repository: Arc<dyn ScheduleRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DeleteScheduleHandler {
+    pub fn new(repository: Arc<dyn ScheduleRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: DeleteScheduleCommand) -> Result<(), DomainError> {
+        // 先讀取名稱供稽核紀錄使用
+        let schedule = self.repository.get(&command.id).await?;
+        self.repository.delete(&command.id).await?;
+
+        self.event_bus
+            .publish_node(NodeEvent::ScheduleDeleted(NodeScheduleDeletedEvent::new(&schedule)))
+            .await;
+
+        Ok(())
+    }
+}
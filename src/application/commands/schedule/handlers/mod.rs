@@ -0,0 +1,13 @@
+//! Schedule command handlers
+
+mod create_schedule_handler;
+mod update_schedule_handler;
+mod delete_schedule_handler;
+mod run_schedule_handler;
+mod run_due_schedules_handler;
+
+pub use create_schedule_handler::*;
+pub use update_schedule_handler::*;
+pub use delete_schedule_handler::*;
+pub use run_schedule_handler::*;
+pub use run_due_schedules_handler::*;
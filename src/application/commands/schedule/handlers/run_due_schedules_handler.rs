@@ -0,0 +1,51 @@
+//! Run due schedules command handler
+
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use tracing::error;
+use crate::application::commands::schedule::{RunDueSchedulesCommand, RunScheduleCommand};
+use crate::application::commands::schedule::handlers::RunScheduleHandler;
+use crate::domain::entities::Schedule;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ScheduleRepository;
+
+/// Result of one scheduler pass
+#[derive(Debug, Clone, Default)]
+pub struct DueSchedulesReport {
+    /// 本次執行的排程（含執行結果）
+    pub ran: Vec<Schedule>,
+    /// 所有啟用排程中最早的下一次觸發時間
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+/// Handler for the background scheduler tick
+pub struct RunDueSchedulesHandler {
+    repository: Arc<dyn ScheduleRepository>,
+    run_schedule_handler: Arc<RunScheduleHandler>,
+}
+
+impl RunDueSchedulesHandler {
+    pub fn new(repository: Arc<dyn ScheduleRepository>, run_schedule_handler: Arc<RunScheduleHandler>) -> Self {
+        Self { repository, run_schedule_handler }
+    }
+
+    pub async fn handle(&self, command: RunDueSchedulesCommand) -> Result<DueSchedulesReport, DomainError> {
+        let schedules = self.repository.list().await?;
+
+        let mut report = DueSchedulesReport::default();
+        for schedule in schedules.iter().filter(|s| s.is_due(command.since, command.now)) {
+            match self
+                .run_schedule_handler
+                .handle(RunScheduleCommand::cron(schedule.id.clone()).with_paused(command.paused))
+                .await
+            {
+                Ok(ran) => report.ran.push(ran),
+                // 排程在執行前被刪除，或寫入 last_run 失敗
+                Err(e) => error!("Failed to run schedule {}: {}", schedule.id, e),
+            }
+        }
+
+        report.next_run_at = schedules.iter().filter_map(|s| s.next_run_at(command.now)).min();
+        Ok(report)
+    }
+}
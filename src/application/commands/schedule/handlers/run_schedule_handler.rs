@@ -0,0 +1,137 @@
+//! Run schedule command handler
+
+use std::sync::Arc;
+use chrono::Utc;
+use crate::application::commands::block::RetryBlockResyncCommand;
+use crate::application::commands::block::handlers::RetryBlockResyncHandler;
+use crate::application::commands::bucket::CleanupIncompleteUploadsCommand;
+use crate::application::commands::bucket::handlers::CleanupIncompleteUploadsHandler;
+use crate::application::commands::node::{CreateMetadataSnapshotCommand, LaunchRepairCommand};
+use crate::application::commands::node::handlers::{CreateMetadataSnapshotHandler, LaunchRepairHandler};
+use crate::application::commands::schedule::RunScheduleCommand;
+use crate::domain::entities::{MultiNodeResponse, Schedule, ScheduleAction, ScheduleRun};
+use crate::domain::errors::DomainError;
+use crate::domain::events::{EventBus, NodeEvent, NodeScheduledJobRanEvent};
+use crate::domain::repositories::ScheduleRepository;
+
+/// 執行結果：(summary, error)
+type Outcome = (String, Option<String>);
+
+/// Handler for running a schedule's action once
+///
+/// 執行結果寫回排程的 `last_run` 並發出 `NodeEvent::ScheduledJobRan`；
+/// 操作本身失敗不視為 handler 錯誤
+pub struct RunScheduleHandler {
+    repository: Arc<dyn ScheduleRepository>,
+    create_metadata_snapshot_handler: Arc<CreateMetadataSnapshotHandler>,
+    launch_repair_handler: Arc<LaunchRepairHandler>,
+    cleanup_incomplete_uploads_handler: Arc<CleanupIncompleteUploadsHandler>,
+    retry_block_resync_handler: Arc<RetryBlockResyncHandler>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl RunScheduleHandler {
+    pub fn new(
+        repository: Arc<dyn ScheduleRepository>,
+        create_metadata_snapshot_handler: Arc<CreateMetadataSnapshotHandler>,
+        launch_repair_handler: Arc<LaunchRepairHandler>,
+        cleanup_incomplete_uploads_handler: Arc<CleanupIncompleteUploadsHandler>,
+        retry_block_resync_handler: Arc<RetryBlockResyncHandler>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            create_metadata_snapshot_handler,
+            launch_repair_handler,
+            cleanup_incomplete_uploads_handler,
+            retry_block_resync_handler,
+            event_bus,
+        }
+    }
+
+    pub async fn handle(&self, command: RunScheduleCommand) -> Result<Schedule, DomainError> {
+        let schedule = self.repository.get(&command.id).await?;
+
+        let started_at = Utc::now();
+        let (summary, error) = if command.paused {
+            ("Skipped".to_string(), Some("read-only mode is enabled".to_string()))
+        } else {
+            match self.execute(&schedule.action).await {
+                Ok(outcome) => outcome,
+                Err(e) => ("Failed".to_string(), Some(e.to_string())),
+            }
+        };
+        let run = ScheduleRun {
+            trigger: command.trigger,
+            started_at,
+            finished_at: Utc::now(),
+            success: error.is_none(),
+            summary,
+            error,
+        };
+
+        self.event_bus
+            .publish_node(NodeEvent::ScheduledJobRan(NodeScheduledJobRanEvent::new(&schedule, &run)))
+            .await;
+
+        self.repository.record_run(&schedule.id, &run).await
+    }
+
+    async fn execute(&self, action: &ScheduleAction) -> Result<Outcome, DomainError> {
+        match action.clone() {
+            ScheduleAction::CreateMetadataSnapshot { node } => {
+                let response = self
+                    .create_metadata_snapshot_handler
+                    .handle(CreateMetadataSnapshotCommand::new(node))
+                    .await?;
+                Ok(multi_node_outcome(&response))
+            }
+            ScheduleAction::LaunchRepair { node, repair_type, scrub_tranquility } => {
+                let response = self
+                    .launch_repair_handler
+                    .handle(LaunchRepairCommand::new(node, repair_type).with_scrub_tranquility(scrub_tranquility))
+                    .await?;
+                Ok(multi_node_outcome(&response))
+            }
+            ScheduleAction::CleanupIncompleteUploads { bucket_id, older_than_secs } => {
+                let report = self
+                    .cleanup_incomplete_uploads_handler
+                    .handle(CleanupIncompleteUploadsCommand::new(bucket_id, older_than_secs))
+                    .await?;
+                let summary = format!(
+                    "{} uploads deleted in {}/{} buckets",
+                    report.total_deleted(),
+                    report.uploads_deleted.len(),
+                    report.uploads_deleted.len() + report.errors.len()
+                );
+                Ok((summary, join_errors(report.errors.into_iter())))
+            }
+            ScheduleAction::RetryBlockResync { node, block_hashes } => {
+                let command = match block_hashes {
+                    Some(hashes) => RetryBlockResyncCommand::new(node, hashes),
+                    None => RetryBlockResyncCommand::all(node),
+                };
+                let response = self.retry_block_resync_handler.handle(command).await?;
+                Ok(multi_node_outcome(&response))
+            }
+        }
+    }
+}
+
+/// 任一節點失敗即視為失敗
+fn multi_node_outcome<T>(response: &MultiNodeResponse<T>) -> Outcome {
+    let total = response.success.len() + response.error.len();
+    let summary = format!("{}/{} nodes succeeded", response.success.len(), total);
+    let errors = response.error.iter().map(|(node, e)| (node.clone(), e.clone()));
+    (summary, join_errors(errors))
+}
+
+/// `id: error; id: error`，依 ID 排序
+fn join_errors(errors: impl Iterator<Item = (String, String)>) -> Option<String> {
+    let mut errors: Vec<String> = errors.map(|(id, e)| format!("{}: {}", id, e)).collect();
+    if errors.is_empty() {
+        return None;
+    }
+    errors.sort();
+    Some(errors.join("; "))
+}
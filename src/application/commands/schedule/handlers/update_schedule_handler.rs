@@ -0,0 +1,52 @@
+//! Update schedule command handler
+
+use std::sync::Arc;
+use chrono::Utc;
+use crate::application::commands::schedule::UpdateScheduleCommand;
+use crate::domain::entities::{CronExpression, Schedule};
+use crate::domain::errors::DomainError;
+use crate::domain::events::{EventBus, NodeEvent, NodeScheduleUpdatedEvent};
+use crate::domain::repositories::ScheduleRepository;
+
+/// Handler for updating schedules
+pub struct UpdateScheduleHandler {
+    repository: Arc<dyn ScheduleRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl UpdateScheduleHandler {
+    pub fn new(repository: Arc<dyn ScheduleRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, command: UpdateScheduleCommand) -> Result<Schedule, DomainError> {
+        let mut schedule = self.repository.get(&command.id).await?;
+        if !command.has_changes() {
+            return Ok(schedule);
+        }
+
+        if let Some(name) = command.name {
+            Schedule::validate_name(&name)?;
+            schedule.name = name;
+        }
+        if let Some(cron) = command.cron {
+            schedule.cron = CronExpression::parse(&cron)?;
+        }
+        if let Some(action) = command.action {
+            action.validate()?;
+            schedule.action = action;
+        }
+        if let Some(enabled) = command.enabled {
+            schedule.enabled = enabled;
+        }
+        schedule.updated_at = Utc::now();
+
+        self.repository.save(&schedule).await?;
+
+        self.event_bus
+            .publish_node(NodeEvent::ScheduleUpdated(NodeScheduleUpdatedEvent::new(&schedule)))
+            .await;
+
+        Ok(schedule)
+    }
+}
@@ -0,0 +1,17 @@
+//! Schedule commands
+//!
+//! Commands for managing and running scheduled maintenance jobs
+
+mod create_schedule;
+mod update_schedule;
+mod delete_schedule;
+mod run_schedule;
+mod run_due_schedules;
+
+pub mod handlers;
+
+pub use create_schedule::*;
+pub use update_schedule::*;
+pub use delete_schedule::*;
+pub use run_schedule::*;
+pub use run_due_schedules::*;
@@ -0,0 +1,23 @@
+//! Run due schedules command
+
+use chrono::{DateTime, Utc};
+
+/// Command to run every schedule that fired in `(since, now]`
+#[derive(Debug, Clone)]
+pub struct RunDueSchedulesCommand {
+    pub since: DateTime<Utc>,
+    pub now: DateTime<Utc>,
+    /// 唯讀模式下到期的排程只記錄為略過
+    pub paused: bool,
+}
+
+impl RunDueSchedulesCommand {
+    pub fn new(since: DateTime<Utc>, now: DateTime<Utc>) -> Self {
+        Self { since, now, paused: false }
+    }
+
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+}
@@ -0,0 +1,29 @@
+//! Run schedule command
+
+use crate::domain::entities::ScheduleTrigger;
+
+/// Command to run a scheduled job once
+#[derive(Debug, Clone)]
+pub struct RunScheduleCommand {
+    pub id: String,
+    pub trigger: ScheduleTrigger,
+    /// 唯讀模式下不執行操作，只記錄一次略過的執行
+    pub paused: bool,
+}
+
+impl RunScheduleCommand {
+    /// 透過 API 立即執行，不論排程是否啟用
+    pub fn manual(id: String) -> Self {
+        Self { id, trigger: ScheduleTrigger::Manual, paused: false }
+    }
+
+    /// 由 scheduler 依 cron 觸發
+    pub fn cron(id: String) -> Self {
+        Self { id, trigger: ScheduleTrigger::Cron, paused: false }
+    }
+
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+}
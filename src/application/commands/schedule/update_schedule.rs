@@ -0,0 +1,46 @@
+//! Update schedule command
+
+use crate::domain::entities::ScheduleAction;
+
+/// Command to update a scheduled maintenance job
+///
+/// 未指定的欄位保持不變
+#[derive(Debug, Clone)]
+pub struct UpdateScheduleCommand {
+    pub id: String,
+    pub name: Option<String>,
+    pub cron: Option<String>,
+    pub action: Option<ScheduleAction>,
+    pub enabled: Option<bool>,
+}
+
+impl UpdateScheduleCommand {
+    pub fn new(id: String) -> Self {
+        Self { id, name: None, cron: None, action: None, enabled: None }
+    }
+
+    pub fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub fn with_cron(mut self, cron: Option<String>) -> Self {
+        self.cron = cron;
+        self
+    }
+
+    pub fn with_action(mut self, action: Option<ScheduleAction>) -> Self {
+        self.action = action;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: Option<bool>) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// 檢查是否有任何變更
+    pub fn has_changes(&self) -> bool {
+        self.name.is_some() || self.cron.is_some() || self.action.is_some() || self.enabled.is_some()
+    }
+}
@@ -0,0 +1,17 @@
+//! Export access keys query
+
+use crate::domain::entities::KeyTransferFormat;
+
+/// Query to export every access key with its bucket grants
+#[derive(Debug, Clone, Default)]
+pub struct ExportKeysQuery {
+    pub format: KeyTransferFormat,
+    /// 是否包含 secret（不包含時匯出檔無法再匯入）
+    pub include_secret: bool,
+}
+
+impl ExportKeysQuery {
+    pub fn new(format: KeyTransferFormat, include_secret: bool) -> Self {
+        Self { format, include_secret }
+    }
+}
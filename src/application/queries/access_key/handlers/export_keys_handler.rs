@@ -0,0 +1,77 @@
+//! Export access keys query handler
+
+use std::sync::Arc;
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use crate::application::queries::access_key::ExportKeysQuery;
+use crate::domain::entities::{AccessKey, KeyTransferRecord};
+use crate::domain::errors::DomainError;
+use crate::domain::events::{AccessKeyEvent, AccessKeySecretRevealedEvent, EventBus};
+use crate::domain::repositories::AccessKeyQueryRepository;
+use crate::shared::get_caller;
+
+/// 同時向 Garage 查詢的 Key 數量上限
+const EXPORT_CONCURRENCY: usize = 8;
+
+/// Handler for exporting all access keys
+pub struct ExportKeysHandler {
+    repository: Arc<dyn AccessKeyQueryRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl ExportKeysHandler {
+    pub fn new(repository: Arc<dyn AccessKeyQueryRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    /// 依 ID 排序逐筆送出匯出資料，編碼由呼叫端依 `query.format` 處理
+    ///
+    /// 不會一次載入所有 Key；接收端關閉（client 中斷）時停止查詢。
+    /// 回傳已送出的筆數
+    pub async fn handle(
+        &self,
+        query: ExportKeysQuery,
+        records: mpsc::Sender<KeyTransferRecord>,
+    ) -> Result<usize, DomainError> {
+        let mut ids: Vec<String> = self.repository.list().await?
+            .into_iter()
+            .map(|k| k.id)
+            .collect();
+        ids.sort();
+
+        let include_secret = query.include_secret;
+        let mut keys = futures::stream::iter(ids)
+            .map(|id| self.load(id, include_secret))
+            .buffered(EXPORT_CONCURRENCY);
+
+        let mut total = 0;
+        while let Some(key) = keys.next().await {
+            let key = key?;
+
+            // 每個匯出的 secret 都記錄一次讀取事件
+            if include_secret {
+                let event = AccessKeyEvent::SecretRevealed(AccessKeySecretRevealedEvent::new(
+                    key.id.clone(),
+                    get_caller(),
+                ));
+                self.event_bus.publish_access_key(event).await;
+            }
+
+            let record = KeyTransferRecord::from_access_key(key, include_secret);
+            if records.send(record).await.is_err() {
+                break;
+            }
+            total += 1;
+        }
+
+        Ok(total)
+    }
+
+    async fn load(&self, id: String, include_secret: bool) -> Result<AccessKey, DomainError> {
+        let mut key = self.repository.find_by_id(&id).await?;
+        if include_secret {
+            key.secret_access_key = Some(self.repository.find_secret(&id).await?);
+        }
+        Ok(key)
+    }
+}
@@ -31,20 +31,8 @@ impl ListKeysHandler {
         let total = filtered.len();
         let paginated = paginate(&filtered, query.page as usize, query.page_size as usize);
 
-        let paginated_id: Vec<String> = paginated.iter().map(|b| b.id.clone()).collect();
-
-        let task: Vec<_> = paginated_id.iter().map(|id| self.repository.find_by_id(id.as_str())).collect();
-
-        let detail = futures::future::try_join_all(task).await?;
-
-        let data = detail.into_iter().map(|d| AccessKeyListItem {
-            id: d.id,
-            name: d.name,
-            created: d.created,
-            expiration: d.expiration,
-            secret_access_key: d.secret_access_key,
-            expired: d.expired,
-        }).collect();
+        // 列表不含 secret，直接由列表資料轉換，不需逐筆查詢詳細資訊
+        let data = paginated.iter().map(AccessKeyListItem::from_response).collect();
 
         Ok((data, total))
     }
@@ -1,7 +1,11 @@
 //! Access Key query handlers
 
+mod export_keys_handler;
 mod list_keys_handler;
 mod read_key_handler;
+mod reveal_key_secret_handler;
 
+pub use export_keys_handler::*;
 pub use list_keys_handler::*;
 pub use read_key_handler::*;
+pub use reveal_key_secret_handler::*;
@@ -0,0 +1,36 @@
+//! Reveal access key secret query handler
+
+use std::sync::Arc;
+use crate::application::queries::access_key::RevealKeySecretQuery;
+use crate::domain::errors::DomainError;
+use crate::domain::events::{AccessKeyEvent, AccessKeySecretRevealedEvent, EventBus};
+use crate::domain::repositories::AccessKeyQueryRepository;
+use crate::shared::get_caller;
+
+/// Handler for revealing an access key secret
+///
+/// 每次讀取 secret 都會發布 `SecretRevealed` 事件供稽核
+pub struct RevealKeySecretHandler {
+    repository: Arc<dyn AccessKeyQueryRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl RevealKeySecretHandler {
+    pub fn new(repository: Arc<dyn AccessKeyQueryRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { repository, event_bus }
+    }
+
+    pub async fn handle(&self, query: RevealKeySecretQuery) -> Result<String, DomainError> {
+        query.validate()?;
+
+        let secret = self.repository.find_secret(&query.id).await?;
+
+        let event = AccessKeyEvent::SecretRevealed(AccessKeySecretRevealedEvent::new(
+            query.id,
+            get_caller(),
+        ));
+        self.event_bus.publish_access_key(event).await;
+
+        Ok(secret)
+    }
+}
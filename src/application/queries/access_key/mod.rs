@@ -2,10 +2,14 @@
 //!
 //! Queries for reading access key information
 
+mod export_keys;
 mod list_keys;
 mod read_key;
+mod reveal_key_secret;
 
 pub mod handlers;
 
+pub use export_keys::*;
 pub use list_keys::*;
 pub use read_key::*;
+pub use reveal_key_secret::*;
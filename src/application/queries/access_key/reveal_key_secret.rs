@@ -0,0 +1,21 @@
+//! Reveal access key secret query
+
+use crate::domain::aggregates::AccessKeyAggregate;
+use crate::domain::errors::DomainError;
+
+/// Query to read the secret of an access key
+#[derive(Debug, Clone)]
+pub struct RevealKeySecretQuery {
+    /// The access key ID
+    pub id: String,
+}
+
+impl RevealKeySecretQuery {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AccessKeyAggregate::validate_id(&self.id)
+    }
+}
@@ -0,0 +1,18 @@
+//! Get current admin token query
+
+use crate::domain::aggregates::AdminTokenAggregate;
+
+/// Query to get the admin token of the current caller
+///
+/// `caller` 為 auth middleware 驗證後放入 request extensions 的 token；
+/// 未啟用認證時為 `None`，此時沒有可回傳的呼叫者身分
+#[derive(Debug, Clone, Default)]
+pub struct GetCurrentAdminTokenQuery {
+    pub caller: Option<AdminTokenAggregate>,
+}
+
+impl GetCurrentAdminTokenQuery {
+    pub fn new(caller: Option<AdminTokenAggregate>) -> Self {
+        Self { caller }
+    }
+}
@@ -0,0 +1,70 @@
+//! Get current admin token query handler
+
+use crate::application::queries::admin_token::GetCurrentAdminTokenQuery;
+use crate::domain::entities::AdminTokenInfo;
+use crate::domain::errors::DomainError;
+
+/// Handler for getting the admin token of the current caller
+///
+/// 直接回傳 auth middleware 已解析的 token，不再查詢 Garage：
+/// 以 server 自身的 `GARAGE_API_KEY` 查詢只會得到 server 的身分
+#[derive(Default)]
+pub struct GetCurrentAdminTokenHandler;
+
+impl GetCurrentAdminTokenHandler {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub async fn handle(&self, query: GetCurrentAdminTokenQuery) -> Result<AdminTokenInfo, DomainError> {
+        let token = query.caller.ok_or_else(|| {
+            DomainError::Unauthenticated("No authenticated admin token for this request".to_string())
+        })?;
+
+        Ok(AdminTokenInfo {
+            id: token.id().to_string(),
+            name: token.name().map(str::to_string),
+            created: None,
+            expiration: token.expiration().map(str::to_string),
+            expired: token.is_expired(),
+            scope: token.scope().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::aggregates::AdminTokenAggregate;
+
+    #[tokio::test]
+    async fn test_returns_caller_token() {
+        let caller = AdminTokenAggregate::reconstitute(
+            "token-id".to_string(),
+            Some("ops".to_string()),
+            vec!["read:buckets".to_string()],
+            false,
+            Some("2030-01-01T00:00:00Z".to_string()),
+        );
+
+        let info = GetCurrentAdminTokenHandler::new()
+            .handle(GetCurrentAdminTokenQuery::new(Some(caller)))
+            .await
+            .unwrap();
+
+        assert_eq!(info.id, "token-id");
+        assert_eq!(info.name.as_deref(), Some("ops"));
+        assert_eq!(info.scope, vec!["read:buckets".to_string()]);
+        assert_eq!(info.expiration.as_deref(), Some("2030-01-01T00:00:00Z"));
+        assert!(!info.expired);
+    }
+
+    #[tokio::test]
+    async fn test_missing_caller_is_unauthenticated() {
+        let result = GetCurrentAdminTokenHandler::new()
+            .handle(GetCurrentAdminTokenQuery::new(None))
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Unauthenticated(_))));
+    }
+}
@@ -0,0 +1,33 @@
+//! List admin tokens query handler
+
+use std::sync::Arc;
+use crate::application::queries::admin_token::ListAdminTokensQuery;
+use crate::domain::entities::AdminTokenListItem;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::AdminTokenRepository;
+use crate::shared::paginate;
+
+/// Handler for listing admin tokens
+pub struct ListAdminTokensHandler {
+    repository: Arc<dyn AdminTokenRepository>,
+}
+
+impl ListAdminTokensHandler {
+    pub fn new(repository: Arc<dyn AdminTokenRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, query: ListAdminTokensQuery) -> Result<(Vec<AdminTokenListItem>, usize), DomainError> {
+        let rows = self.repository.list().await?;
+
+        let filtered: Vec<_> = rows
+            .into_iter()
+            .filter(|item| query.matches(item))
+            .collect();
+
+        let total = filtered.len();
+        let data = paginate(&filtered, query.page as usize, query.page_size as usize);
+
+        Ok((data, total))
+    }
+}
@@ -0,0 +1,9 @@
+//! Admin Token query handlers
+
+mod list_admin_tokens_handler;
+mod read_admin_token_handler;
+mod get_current_admin_token_handler;
+
+pub use list_admin_tokens_handler::*;
+pub use read_admin_token_handler::*;
+pub use get_current_admin_token_handler::*;
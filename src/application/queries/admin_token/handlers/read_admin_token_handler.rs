@@ -0,0 +1,22 @@
+//! Read admin token query handler
+
+use std::sync::Arc;
+use crate::application::queries::admin_token::ReadAdminTokenQuery;
+use crate::domain::entities::AdminTokenInfo;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::AdminTokenRepository;
+
+/// Handler for getting an admin token by ID
+pub struct ReadAdminTokenHandler {
+    repository: Arc<dyn AdminTokenRepository>,
+}
+
+impl ReadAdminTokenHandler {
+    pub fn new(repository: Arc<dyn AdminTokenRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, query: ReadAdminTokenQuery) -> Result<AdminTokenInfo, DomainError> {
+        self.repository.get(&query.id).await
+    }
+}
@@ -0,0 +1,41 @@
+//! List admin tokens query
+
+use crate::domain::entities::AdminTokenListItem;
+
+/// Query to list admin tokens
+#[derive(Debug, Clone, Default)]
+pub struct ListAdminTokensQuery {
+    // 分頁
+    pub page: i32,
+    pub page_size: i32,
+
+    // 過濾條件
+    /// 名稱包含（模糊搜尋，不分大小寫）
+    pub name: Option<String>,
+}
+
+impl ListAdminTokensQuery {
+    pub fn new(page: i32, page_size: i32) -> Self {
+        Self {
+            page,
+            page_size,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name.filter(|n| !n.is_empty());
+        self
+    }
+
+    /// 檢查 token 是否符合過濾條件
+    pub fn matches(&self, item: &AdminTokenListItem) -> bool {
+        match &self.name {
+            Some(name) => item
+                .name
+                .as_deref()
+                .is_some_and(|n| n.to_lowercase().contains(&name.to_lowercase())),
+            None => true,
+        }
+    }
+}
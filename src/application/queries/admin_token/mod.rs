@@ -0,0 +1,13 @@
+//! Admin Token queries
+//!
+//! Queries for reading Garage admin token information
+
+mod list_admin_tokens;
+mod read_admin_token;
+mod get_current_admin_token;
+
+pub mod handlers;
+
+pub use list_admin_tokens::*;
+pub use read_admin_token::*;
+pub use get_current_admin_token::*;
@@ -0,0 +1,14 @@
+//! Read admin token query
+
+/// Query to get an admin token by ID
+#[derive(Debug, Clone)]
+pub struct ReadAdminTokenQuery {
+    /// The admin token ID
+    pub id: String,
+}
+
+impl ReadAdminTokenQuery {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,28 @@
+//! List audit events query handler
+
+use std::sync::Arc;
+use crate::application::queries::audit::ListAuditEventsQuery;
+use crate::domain::entities::AuditRecord;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::AuditRepository;
+use crate::shared::paginate;
+
+/// Handler for listing audit records (newest first)
+pub struct ListAuditEventsHandler {
+    repository: Arc<dyn AuditRepository>,
+}
+
+impl ListAuditEventsHandler {
+    pub fn new(repository: Arc<dyn AuditRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, query: ListAuditEventsQuery) -> Result<(Vec<AuditRecord>, usize), DomainError> {
+        let records = self.repository.query(&query.filter()).await?;
+
+        let total = records.len();
+        let data = paginate(&records, query.page as usize, query.page_size as usize);
+
+        Ok((data, total))
+    }
+}
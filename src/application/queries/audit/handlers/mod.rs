@@ -0,0 +1,5 @@
+//! Audit query handlers
+
+mod list_audit_events_handler;
+
+pub use list_audit_events_handler::*;
@@ -0,0 +1,90 @@
+//! List audit events query
+
+use chrono::{DateTime, Utc};
+use crate::domain::entities::AuditFilter;
+use crate::shared::parse_datetime;
+
+/// Query to list audit trail records
+#[derive(Debug, Clone, Default)]
+pub struct ListAuditEventsQuery {
+    // 分頁
+    pub page: i32,
+    pub page_size: i32,
+
+    // 過濾條件
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub resource_id: Option<String>,
+    /// 完整事件類型（`Bucket.KeyDenied`）或 Aggregate（`Bucket`）
+    pub event_type: Option<String>,
+    /// Admin Token ID
+    pub actor: Option<String>,
+}
+
+impl ListAuditEventsQuery {
+    pub fn new(page: i32, page_size: i32) -> Self {
+        Self {
+            page,
+            page_size,
+            ..Default::default()
+        }
+    }
+
+    /// 從 gRPC 請求建立 Query
+    pub fn from_grpc_request(
+        page: i32,
+        page_size: i32,
+        start: Option<String>,
+        end: Option<String>,
+        resource_id: Option<String>,
+        event_type: Option<String>,
+        actor: Option<String>,
+    ) -> Self {
+        Self::new(page, page_size)
+            .with_time_range(
+                start.and_then(|s| parse_datetime(&s)),
+                end.and_then(|s| parse_datetime(&s)),
+            )
+            .with_resource_id(resource_id)
+            .with_event_type(event_type)
+            .with_actor(actor)
+    }
+
+    // ============ Builder Methods ============
+
+    pub fn with_time_range(
+        mut self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>
+    ) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn with_resource_id(mut self, resource_id: Option<String>) -> Self {
+        self.resource_id = resource_id.filter(|s| !s.is_empty());
+        self
+    }
+
+    pub fn with_event_type(mut self, event_type: Option<String>) -> Self {
+        self.event_type = event_type.filter(|s| !s.is_empty());
+        self
+    }
+
+    pub fn with_actor(mut self, actor: Option<String>) -> Self {
+        self.actor = actor.filter(|s| !s.is_empty());
+        self
+    }
+
+    /// 轉換為 Repository 查詢條件
+    pub fn filter(&self) -> AuditFilter {
+        AuditFilter {
+            start: self.start,
+            end: self.end,
+            resource_id: self.resource_id.clone(),
+            event_type: self.event_type.clone(),
+            actor: self.actor.clone(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Audit queries
+//!
+//! Queries for reading the audit trail
+
+mod list_audit_events;
+
+pub mod handlers;
+
+pub use list_audit_events::*;
@@ -0,0 +1,14 @@
+//! Get block error report query
+
+/// Query to build a block error triage report
+#[derive(Debug, Clone)]
+pub struct GetBlockErrorReportQuery {
+    /// Target node (or "*" for all nodes)
+    pub node: String,
+}
+
+impl GetBlockErrorReportQuery {
+    pub fn new(node: String) -> Self {
+        Self { node }
+    }
+}
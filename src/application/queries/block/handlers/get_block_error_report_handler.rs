@@ -0,0 +1,68 @@
+//! Get block error report query handler
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use futures::StreamExt;
+use crate::application::queries::block::GetBlockErrorReportQuery;
+use crate::domain::entities::{BlockErrorReport, BlockInfo};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{BlockRepository, BucketRepository};
+
+/// 同時查詢區塊資訊的上限，錯誤區塊可能有數千個
+const LOOKUP_CONCURRENCY: usize = 8;
+
+/// Handler for building the block error triage report
+pub struct GetBlockErrorReportHandler {
+    block_repository: Arc<dyn BlockRepository>,
+    bucket_repository: Arc<dyn BucketRepository>,
+}
+
+impl GetBlockErrorReportHandler {
+    pub fn new(block_repository: Arc<dyn BlockRepository>, bucket_repository: Arc<dyn BucketRepository>) -> Self {
+        Self { block_repository, bucket_repository }
+    }
+
+    pub async fn handle(&self, query: GetBlockErrorReportQuery) -> Result<BlockErrorReport, DomainError> {
+        let errors = self.block_repository.list_errors(&query.node).await?;
+        let by_hash = BlockErrorReport::collect_errors(&errors);
+
+        let lookups: Vec<(String, BTreeMap<String, String>)> =
+            by_hash.iter().map(|(hash, nodes)| (hash.clone(), nodes.clone())).collect();
+        let infos: HashMap<String, Result<BlockInfo, String>> = futures::stream::iter(lookups)
+            .map(|(hash, nodes)| async move {
+                let info = self.lookup(&hash, &nodes).await;
+                (hash, info)
+            })
+            .buffer_unordered(LOOKUP_CONCURRENCY)
+            .collect()
+            .await;
+
+        // 沒有錯誤區塊時不需要 bucket 清單
+        let buckets = if by_hash.is_empty() {
+            vec![]
+        } else {
+            self.bucket_repository.list().await?
+        };
+
+        Ok(BlockErrorReport::build(by_hash, infos, errors.error, &buckets))
+    }
+
+    /// 依序向回報錯誤的節點查詢區塊資訊，直到其中一個成功
+    async fn lookup(&self, block_hash: &str, nodes: &BTreeMap<String, String>) -> Result<BlockInfo, String> {
+        let mut last_error = "No node reported this block".to_string();
+        for node in nodes.keys() {
+            match self.block_repository.get_info(node, block_hash).await {
+                Ok(mut response) => {
+                    if let Some(info) = response.success.remove(node) {
+                        return Ok(info);
+                    }
+                    if let Some(error) = response.error.remove(node) {
+                        last_error = error;
+                    }
+                }
+                Err(e) => last_error = e.to_string(),
+            }
+        }
+        Err(last_error)
+    }
+}
@@ -1,7 +1,9 @@
 //! Block query handlers
 
 mod get_block_info_handler;
+mod get_block_error_report_handler;
 mod list_block_errors_handler;
 
 pub use get_block_info_handler::*;
+pub use get_block_error_report_handler::*;
 pub use list_block_errors_handler::*;
@@ -3,9 +3,11 @@
 //! Queries for reading block information
 
 mod get_block_info;
+mod get_block_error_report;
 mod list_block_errors;
 
 pub mod handlers;
 
 pub use get_block_info::*;
+pub use get_block_error_report::*;
 pub use list_block_errors::*;
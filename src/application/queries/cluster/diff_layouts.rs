@@ -0,0 +1,17 @@
+//! Diff layouts query
+
+/// Query to compare two cluster layouts
+///
+/// 未指定 `from_version` 時使用目前布局，未指定 `to_version` 時使用暫存布局；
+/// 兩者皆未指定即為目前布局與暫存變更的比較
+#[derive(Debug, Clone, Default)]
+pub struct DiffLayoutsQuery {
+    pub from_version: Option<i64>,
+    pub to_version: Option<i64>,
+}
+
+impl DiffLayoutsQuery {
+    pub fn new(from_version: Option<i64>, to_version: Option<i64>) -> Self {
+        Self { from_version, to_version }
+    }
+}
@@ -0,0 +1,11 @@
+//! Get cluster statistics query
+
+/// Query to get cluster-wide storage statistics
+#[derive(Debug, Clone, Default)]
+pub struct GetClusterStatisticsQuery;
+
+impl GetClusterStatisticsQuery {
+    pub fn new() -> Self {
+        Self
+    }
+}
@@ -0,0 +1,49 @@
+//! Diff layouts query handler
+
+use std::sync::Arc;
+use crate::application::queries::cluster::DiffLayoutsQuery;
+use crate::domain::entities::{ClusterLayout, LayoutDiff, LayoutState};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ClusterRepository;
+
+/// Handler for comparing two cluster layouts
+pub struct DiffLayoutsHandler {
+    repository: Arc<dyn ClusterRepository>,
+}
+
+impl DiffLayoutsHandler {
+    pub fn new(repository: Arc<dyn ClusterRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, query: DiffLayoutsQuery) -> Result<LayoutDiff, DomainError> {
+        let layout = self.repository.get_layout().await?;
+
+        let from = match query.from_version {
+            Some(version) => self.version_state(&layout, version).await?,
+            None => layout.current_state(),
+        };
+        let to = match query.to_version {
+            Some(version) => self.version_state(&layout, version).await?,
+            None => layout.staged_state(),
+        };
+
+        Ok(LayoutDiff::between(&from, &to))
+    }
+
+    /// 目前版本直接使用目前布局（含參數），其他版本從布局歷史取得
+    async fn version_state(&self, layout: &ClusterLayout, version: i64) -> Result<LayoutState, DomainError> {
+        if version == layout.version {
+            return Ok(layout.current_state());
+        }
+
+        self.repository
+            .get_layout_history()
+            .await?
+            .versions
+            .iter()
+            .find(|v| v.version == version)
+            .map(|v| v.state())
+            .ok_or(DomainError::LayoutVersionNotFound(version))
+    }
+}
@@ -0,0 +1,22 @@
+//! Get cluster statistics query handler
+
+use std::sync::Arc;
+use crate::application::queries::cluster::GetClusterStatisticsQuery;
+use crate::domain::entities::ClusterStatistics;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ClusterRepository;
+
+/// Handler for getting cluster statistics
+pub struct GetClusterStatisticsHandler {
+    repository: Arc<dyn ClusterRepository>,
+}
+
+impl GetClusterStatisticsHandler {
+    pub fn new(repository: Arc<dyn ClusterRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, _query: GetClusterStatisticsQuery) -> Result<ClusterStatistics, DomainError> {
+        self.repository.get_statistics().await
+    }
+}
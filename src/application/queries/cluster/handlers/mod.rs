@@ -2,12 +2,18 @@
 
 mod get_cluster_status_handler;
 mod get_cluster_health_handler;
+mod get_cluster_statistics_handler;
 mod get_cluster_layout_handler;
 mod get_layout_history_handler;
 mod preview_layout_changes_handler;
+mod diff_layouts_handler;
+mod simulate_layout_handler;
 
 pub use get_cluster_status_handler::*;
 pub use get_cluster_health_handler::*;
+pub use get_cluster_statistics_handler::*;
 pub use get_cluster_layout_handler::*;
 pub use get_layout_history_handler::*;
 pub use preview_layout_changes_handler::*;
+pub use diff_layouts_handler::*;
+pub use simulate_layout_handler::*;
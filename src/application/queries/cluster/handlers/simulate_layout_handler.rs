@@ -0,0 +1,72 @@
+//! Simulate layout query handler
+
+use std::sync::Arc;
+use crate::application::queries::cluster::SimulateLayoutQuery;
+use crate::domain::entities::{ClusterLayout, LayoutSimulation, LayoutSimulationReport};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ClusterRepository;
+
+/// 單次模擬允許的角色數上限
+const MAX_ROLES: usize = 1000;
+
+/// Handler for the local layout what-if simulator
+pub struct SimulateLayoutHandler {
+    repository: Arc<dyn ClusterRepository>,
+    replication_factor: usize,
+}
+
+impl SimulateLayoutHandler {
+    pub fn new(repository: Arc<dyn ClusterRepository>, replication_factor: usize) -> Self {
+        Self { repository, replication_factor }
+    }
+
+    pub async fn handle(&self, query: SimulateLayoutQuery) -> Result<LayoutSimulationReport, DomainError> {
+        if query.roles.len() > MAX_ROLES {
+            return Err(DomainError::ValidationError(format!(
+                "At most {} roles can be simulated, got {}",
+                MAX_ROLES,
+                query.roles.len()
+            )));
+        }
+
+        let layout = self.repository.get_layout().await?;
+        let default_replication_factor = self.replication_factor;
+
+        // 分配演算法為 CPU 密集運算，移出 async runtime 執行
+        tokio::task::spawn_blocking(move || simulate(&layout, &query, default_replication_factor))
+            .await
+            .map_err(|e| DomainError::InternalError(format!("Layout simulation failed: {}", e)))?
+    }
+}
+
+fn simulate(
+    layout: &ClusterLayout,
+    query: &SimulateLayoutQuery,
+    default_replication_factor: usize,
+) -> Result<LayoutSimulationReport, DomainError> {
+    let current_redundancy = layout.parameters.as_ref().and_then(|p| p.zone_redundancy.as_ref());
+    let replication_factor = query.replication_factor.unwrap_or(default_replication_factor);
+
+    // 1. 以相同演算法模擬目前布局，作為搬移數量的比較基準
+    let current = LayoutSimulation::run(&layout.roles, default_replication_factor, current_redundancy, None).ok();
+
+    // 2. 模擬新布局，盡量保留目前的分配
+    let simulation = LayoutSimulation::run(
+        &query.roles,
+        replication_factor,
+        query.zone_redundancy.as_ref().or(current_redundancy),
+        current.as_ref().map(|c| &c.assignment),
+    )?;
+
+    let (partitions_moved, replicas_moved) = current
+        .as_ref()
+        .map(|c| c.assignment.moves_to(&simulation.assignment))
+        .unwrap_or_default();
+
+    Ok(LayoutSimulationReport {
+        current_usable_capacity: current.map(|c| c.usable_capacity),
+        partitions_moved,
+        replicas_moved,
+        simulation,
+    })
+}
@@ -4,14 +4,20 @@
 
 mod get_cluster_status;
 mod get_cluster_health;
+mod get_cluster_statistics;
 mod get_cluster_layout;
 mod get_layout_history;
 mod preview_layout_changes;
+mod diff_layouts;
+mod simulate_layout;
 
 pub mod handlers;
 
 pub use get_cluster_status::*;
 pub use get_cluster_health::*;
+pub use get_cluster_statistics::*;
 pub use get_cluster_layout::*;
 pub use get_layout_history::*;
 pub use preview_layout_changes::*;
+pub use diff_layouts::*;
+pub use simulate_layout::*;
@@ -0,0 +1,32 @@
+//! Simulate layout query
+
+use crate::domain::entities::{LayoutRole, ZoneRedundancy};
+
+/// Query to simulate partition assignment for a hypothetical layout
+///
+/// 只在後端計算，不會暫存或套用任何變更
+#[derive(Debug, Clone)]
+pub struct SimulateLayoutQuery {
+    /// 模擬後的完整角色清單，容量為空的節點視為 gateway
+    pub roles: Vec<LayoutRole>,
+    /// 未指定時使用 `GARAGE_REPLICATION_FACTOR`
+    pub replication_factor: Option<usize>,
+    /// 未指定時使用目前布局的設定
+    pub zone_redundancy: Option<ZoneRedundancy>,
+}
+
+impl SimulateLayoutQuery {
+    pub fn new(roles: Vec<LayoutRole>) -> Self {
+        Self { roles, replication_factor: None, zone_redundancy: None }
+    }
+
+    pub fn with_replication_factor(mut self, replication_factor: Option<usize>) -> Self {
+        self.replication_factor = replication_factor;
+        self
+    }
+
+    pub fn with_zone_redundancy(mut self, zone_redundancy: Option<ZoneRedundancy>) -> Self {
+        self.zone_redundancy = zone_redundancy;
+        self
+    }
+}
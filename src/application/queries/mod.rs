@@ -9,6 +9,9 @@ pub use bucket::*;
 // Access Key queries
 pub mod access_key;
 
+// Admin Token queries
+pub mod admin_token;
+
 // Cluster queries
 pub mod cluster;
 
@@ -23,3 +26,9 @@ pub mod worker;
 
 // Object queries (S3 operations)
 pub mod object;
+
+// Audit queries
+pub mod audit;
+
+// Schedule queries
+pub mod schedule;
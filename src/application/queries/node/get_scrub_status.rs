@@ -0,0 +1,14 @@
+//! Get scrub status query
+
+/// Query to get the scrub worker progress on each node
+#[derive(Debug, Clone)]
+pub struct GetScrubStatusQuery {
+    /// Target node (or "*" for all nodes)
+    pub node: String,
+}
+
+impl GetScrubStatusQuery {
+    pub fn new(node: String) -> Self {
+        Self { node }
+    }
+}
@@ -2,21 +2,31 @@
 
 use std::sync::Arc;
 use crate::application::queries::node::GetNodeStatisticsQuery;
-use crate::domain::entities::{MultiNodeResponse, NodeStatistics};
+use crate::domain::entities::{MultiNodeResponse, NodeStatistics, NodeStatisticsTotals};
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::NodeRepository;
 
 /// Handler for getting node statistics
 pub struct GetNodeStatisticsHandler {
     repository: Arc<dyn NodeRepository>,
+    replication_factor: usize,
 }
 
 impl GetNodeStatisticsHandler {
-    pub fn new(repository: Arc<dyn NodeRepository>) -> Self {
-        Self { repository }
+    pub fn new(repository: Arc<dyn NodeRepository>, replication_factor: usize) -> Self {
+        Self { repository, replication_factor }
     }
 
-    pub async fn handle(&self, query: GetNodeStatisticsQuery) -> Result<MultiNodeResponse<NodeStatistics>, DomainError> {
-        self.repository.get_statistics(&query.node).await
+    /// 回傳各節點統計與成功節點的加總
+    pub async fn handle(
+        &self,
+        query: GetNodeStatisticsQuery,
+    ) -> Result<(MultiNodeResponse<NodeStatistics>, NodeStatisticsTotals), DomainError> {
+        let response = self.repository.get_statistics(&query.node).await?;
+        // 只有查詢所有節點時，加總的項目數才會包含每個副本
+        let replication_factor = if query.node == "*" { self.replication_factor } else { 1 };
+        let mut totals = NodeStatisticsTotals::aggregate(response.success.values(), replication_factor);
+        totals.partial |= !response.error.is_empty();
+        Ok((response, totals))
     }
 }
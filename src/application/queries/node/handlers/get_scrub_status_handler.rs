@@ -0,0 +1,38 @@
+//! Get scrub status query handler
+
+use std::sync::Arc;
+use crate::application::queries::node::GetScrubStatusQuery;
+use crate::domain::entities::{MultiNodeResponse, ScrubStatus};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::WorkerRepository;
+
+/// Handler for getting scrub progress from the worker list
+pub struct GetScrubStatusHandler {
+    worker_repository: Arc<dyn WorkerRepository>,
+}
+
+impl GetScrubStatusHandler {
+    pub fn new(worker_repository: Arc<dyn WorkerRepository>) -> Self {
+        Self { worker_repository }
+    }
+
+    pub async fn handle(&self, query: GetScrubStatusQuery) -> Result<MultiNodeResponse<ScrubStatus>, DomainError> {
+        let workers = self.worker_repository.list(&query.node, false, false).await?;
+
+        let mut response = MultiNodeResponse {
+            success: Default::default(),
+            error: workers.error,
+        };
+        for (node_id, workers) in workers.success {
+            match workers.iter().find(|w| w.name == ScrubStatus::WORKER_NAME) {
+                Some(worker) => {
+                    response.success.insert(node_id, ScrubStatus::from_worker(worker));
+                }
+                None => {
+                    response.error.insert(node_id, "Scrub worker not found".to_string());
+                }
+            }
+        }
+        Ok(response)
+    }
+}
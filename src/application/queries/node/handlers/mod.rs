@@ -2,6 +2,8 @@
 
 mod get_node_info_handler;
 mod get_node_statistics_handler;
+mod get_scrub_status_handler;
 
 pub use get_node_info_handler::*;
 pub use get_node_statistics_handler::*;
+pub use get_scrub_status_handler::*;
@@ -4,8 +4,10 @@
 
 mod get_node_info;
 mod get_node_statistics;
+mod get_scrub_status;
 
 pub mod handlers;
 
 pub use get_node_info::*;
 pub use get_node_statistics::*;
+pub use get_scrub_status::*;
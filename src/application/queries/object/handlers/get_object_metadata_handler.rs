@@ -6,18 +6,18 @@ use tracing::info;
 use crate::application::queries::object::GetObjectMetadataQuery;
 use crate::domain::entities::ObjectMetadata;
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ObjectRepository;
+use crate::domain::repositories::ObjectRepositoryProvider;
 use crate::shared::get_trace_id;
 
 /// Handler for GetObjectMetadataQuery
 pub struct GetObjectMetadataHandler {
-    repository: Arc<dyn ObjectRepository>,
+    repositories: Arc<dyn ObjectRepositoryProvider>,
 }
 
 impl GetObjectMetadataHandler {
     /// Create a new handler
-    pub fn new(repository: Arc<dyn ObjectRepository>) -> Self {
-        Self { repository }
+    pub fn new(repositories: Arc<dyn ObjectRepositoryProvider>) -> Self {
+        Self { repositories }
     }
 
     /// Handle the query
@@ -26,6 +26,7 @@ impl GetObjectMetadataHandler {
         query: GetObjectMetadataQuery,
     ) -> Result<ObjectMetadata, DomainError> {
         let trace_id = get_trace_id();
+        let repository = self.repositories.resolve(query.credentials()).await?;
 
         info!(
             trace_id = %trace_id,
@@ -34,8 +35,7 @@ impl GetObjectMetadataHandler {
             "Getting object metadata"
         );
 
-        let metadata = self
-            .repository
+        let metadata = repository
             .get_metadata(query.bucket(), query.key())
             .await?;
 
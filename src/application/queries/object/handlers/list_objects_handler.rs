@@ -6,22 +6,32 @@ use tracing::info;
 use crate::application::queries::object::ListObjectsQuery;
 use crate::domain::entities::ListObjectsResult;
 use crate::domain::errors::DomainError;
-use crate::domain::repositories::ObjectRepository;
+use crate::domain::repositories::{ObjectRepository, ObjectRepositoryProvider};
 use crate::shared::get_trace_id;
 
 /// Handler for ListObjectsQuery
 pub struct ListObjectsHandler {
-    repository: Arc<dyn ObjectRepository>,
+    repositories: Arc<dyn ObjectRepositoryProvider>,
 }
 
 impl ListObjectsHandler {
     /// Create a new handler
-    pub fn new(repository: Arc<dyn ObjectRepository>) -> Self {
-        Self { repository }
+    pub fn new(repositories: Arc<dyn ObjectRepositoryProvider>) -> Self {
+        Self { repositories }
     }
 
     /// Handle the query
     pub async fn handle(&self, query: ListObjectsQuery) -> Result<ListObjectsResult, DomainError> {
+        let repository = self.repositories.resolve(query.credentials()).await?;
+        self.handle_with(repository.as_ref(), query).await
+    }
+
+    /// 使用呼叫者已取得的 repository 執行，避免同一個請求重複解析 credentials
+    pub async fn handle_with(
+        &self,
+        repository: &dyn ObjectRepository,
+        query: ListObjectsQuery,
+    ) -> Result<ListObjectsResult, DomainError> {
         let trace_id = get_trace_id();
 
         info!(
@@ -34,8 +44,7 @@ impl ListObjectsHandler {
             "Listing objects"
         );
 
-        let result = self
-            .repository
+        let result = repository
             .list(
                 query.bucket(),
                 query.prefix(),
@@ -1,6 +1,7 @@
 //! List Objects Query
 
 use crate::domain::errors::DomainError;
+use crate::domain::value_objects::S3Credentials;
 
 /// Query to list objects in a bucket
 #[derive(Debug, Clone)]
@@ -10,6 +11,8 @@ pub struct ListObjectsQuery {
     continuation_token: Option<String>,
     max_keys: Option<i32>,
     delimiter: Option<String>,
+    /// 呼叫者指定的 Access Key，未指定時使用預設 S3 credentials
+    credentials: Option<S3Credentials>,
 }
 
 impl ListObjectsQuery {
@@ -27,6 +30,7 @@ impl ListObjectsQuery {
             continuation_token,
             max_keys,
             delimiter,
+            credentials: None,
         };
         query.validate()?;
         Ok(query)
@@ -49,6 +53,17 @@ impl ListObjectsQuery {
         Ok(())
     }
 
+    /// 以指定的 Access Key 執行
+    pub fn with_credentials(mut self, credentials: Option<S3Credentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Get the caller's S3 credentials
+    pub fn credentials(&self) -> Option<&S3Credentials> {
+        self.credentials.as_ref()
+    }
+
     /// Get the bucket name
     pub fn bucket(&self) -> &str {
         &self.bucket
@@ -0,0 +1,13 @@
+//! Get schedule query
+
+/// Query to get a scheduled maintenance job by ID
+#[derive(Debug, Clone)]
+pub struct GetScheduleQuery {
+    pub id: String,
+}
+
+impl GetScheduleQuery {
+    pub fn new(id: String) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,22 @@
+//! Get schedule query handler
+
+use std::sync::Arc;
+use crate::application::queries::schedule::GetScheduleQuery;
+use crate::domain::entities::Schedule;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ScheduleRepository;
+
+/// Handler for getting a schedule by ID
+pub struct GetScheduleHandler {
+    repository: Arc<dyn ScheduleRepository>,
+}
+
+impl GetScheduleHandler {
+    pub fn new(repository: Arc<dyn ScheduleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, query: GetScheduleQuery) -> Result<Schedule, DomainError> {
+        self.repository.get(&query.id).await
+    }
+}
@@ -0,0 +1,26 @@
+//! List schedules query handler
+
+use std::sync::Arc;
+use crate::application::queries::schedule::ListSchedulesQuery;
+use crate::domain::entities::Schedule;
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ScheduleRepository;
+
+/// Handler for listing schedules
+pub struct ListSchedulesHandler {
+    repository: Arc<dyn ScheduleRepository>,
+}
+
+impl ListSchedulesHandler {
+    pub fn new(repository: Arc<dyn ScheduleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn handle(&self, query: ListSchedulesQuery) -> Result<Vec<Schedule>, DomainError> {
+        let mut schedules = self.repository.list().await?;
+        if query.enabled_only {
+            schedules.retain(|s| s.enabled);
+        }
+        Ok(schedules)
+    }
+}
@@ -0,0 +1,7 @@
+//! Schedule query handlers
+
+mod list_schedules_handler;
+mod get_schedule_handler;
+
+pub use list_schedules_handler::*;
+pub use get_schedule_handler::*;
@@ -0,0 +1,14 @@
+//! List schedules query
+
+/// Query to list all scheduled maintenance jobs
+#[derive(Debug, Clone, Default)]
+pub struct ListSchedulesQuery {
+    /// 只列出啟用中的排程
+    pub enabled_only: bool,
+}
+
+impl ListSchedulesQuery {
+    pub fn new(enabled_only: bool) -> Self {
+        Self { enabled_only }
+    }
+}
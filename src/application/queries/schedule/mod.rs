@@ -0,0 +1,11 @@
+//! Schedule queries
+//!
+//! Queries for reading scheduled maintenance jobs
+
+mod list_schedules;
+mod get_schedule;
+
+pub mod handlers;
+
+pub use list_schedules::*;
+pub use get_schedule::*;
@@ -2,10 +2,13 @@
 //!
 //! 聚合根，負責管理 Admin Token 的完整生命週期和業務規則
 
+use chrono::{DateTime, Utc};
+
 use crate::domain::errors::DomainError;
 use crate::domain::events::{
     AdminTokenCreatedEvent, AdminTokenDeletedEvent, AdminTokenEvent, AdminTokenUpdatedEvent,
 };
+use crate::shared::UpdateField;
 
 /// Admin Token Aggregate Root
 ///
@@ -71,8 +74,49 @@ impl AdminTokenAggregate {
         }
     }
 
-    /// 驗證 scope 格式
-    fn validate_scope(scope: &[String]) -> Result<(), DomainError> {
+    /// 驗證名稱（公開供 Command 層使用）
+    pub fn validate_name(name: &str) -> Result<(), DomainError> {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Admin token name cannot be empty".to_string()
+            ));
+        }
+        if trimmed.len() > 255 {
+            return Err(DomainError::ValidationError(
+                "Admin token name cannot exceed 255 characters".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 驗證過期時間必須是未來（公開供 Command 層使用）
+    pub fn validate_expiration_future(expiration: Option<DateTime<Utc>>) -> Result<(), DomainError> {
+        if let Some(exp) = expiration {
+            if exp < Utc::now() {
+                return Err(DomainError::ValidationError(
+                    "Expiration time must be in the future".to_string()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 驗證 ID 非空
+    pub fn validate_id(id: &str) -> Result<(), DomainError> {
+        if id.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Admin token ID cannot be empty".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 驗證 scope 格式（公開供 Command 層使用）
+    pub fn validate_scope(scope: &[String]) -> Result<(), DomainError> {
+        if scope.is_empty() {
+            return Err(DomainError::ValidationError("Scope cannot be empty".to_string()));
+        }
         for s in scope {
             if s.is_empty() {
                 return Err(DomainError::ValidationError("Scope cannot contain empty strings".to_string()));
@@ -100,6 +144,39 @@ impl AdminTokenAggregate {
         Ok(AdminTokenEvent::Updated(AdminTokenUpdatedEvent::new(self.id.clone(), self.name.clone())))
     }
 
+    /// 應用更新（使用 UpdateField 語義），回傳要發布的事件
+    ///
+    /// 名稱與 scope 各自產生一個事件；只有其他變更（例如過期時間）時產生一個事件
+    pub fn apply_update(
+        &mut self,
+        name: &UpdateField<String>,
+        expiration: &UpdateField<DateTime<Utc>>,
+        scope: &UpdateField<Vec<String>>,
+    ) -> Result<Vec<AdminTokenEvent>, DomainError> {
+        let mut events = Vec::new();
+        // Clear 對於 name 不合理，忽略
+        if let UpdateField::Set(name) = name {
+            events.push(self.update_name(Some(name.clone()))?);
+        }
+        if let UpdateField::Set(scope) = scope {
+            events.push(self.update_scope(scope.clone())?);
+        }
+
+        match expiration {
+            UpdateField::NoChange => {}
+            UpdateField::Clear => self.expiration = None,
+            UpdateField::Set(exp) => self.expiration = Some(exp.to_rfc3339()),
+        }
+
+        if events.is_empty() && (name.has_change() || expiration.has_change() || scope.has_change()) {
+            events.push(AdminTokenEvent::Updated(AdminTokenUpdatedEvent::new(
+                self.id.clone(),
+                self.name.clone(),
+            )));
+        }
+        Ok(events)
+    }
+
     /// 刪除 Admin Token
     pub fn delete(self) -> Result<AdminTokenEvent, DomainError> {
         Ok(AdminTokenEvent::Deleted(AdminTokenDeletedEvent::new(self.id)))
@@ -131,4 +208,68 @@ impl AdminTokenAggregate {
     pub fn is_expired(&self) -> bool {
         self.expired
     }
+
+    pub fn expiration(&self) -> Option<&str> {
+        self.expiration.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token() -> AdminTokenAggregate {
+        AdminTokenAggregate::reconstitute(
+            "token-id".to_string(),
+            Some("old".to_string()),
+            vec![AdminTokenScope::READ_BUCKETS.to_string()],
+            false,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_name_and_scope_change_produce_both_events() {
+        let mut token = token();
+        let events = token
+            .apply_update(
+                &UpdateField::Set("new".to_string()),
+                &UpdateField::NoChange,
+                &UpdateField::Set(vec![AdminTokenScope::WRITE_BUCKETS.to_string()]),
+            )
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(token.name(), Some("new"));
+        assert_eq!(token.scope(), [AdminTokenScope::WRITE_BUCKETS.to_string()]);
+    }
+
+    #[test]
+    fn test_expiration_only_change_produces_one_event() {
+        let mut token = token();
+        let expiration = Utc::now() + chrono::Duration::days(1);
+        let events = token
+            .apply_update(&UpdateField::NoChange, &UpdateField::Set(expiration), &UpdateField::NoChange)
+            .unwrap();
+
+        assert!(matches!(
+            &events[..],
+            [AdminTokenEvent::Updated(e)] if e.token_id == "token-id"
+        ));
+        assert_eq!(token.expiration(), Some(expiration.to_rfc3339().as_str()));
+    }
+
+    #[test]
+    fn test_no_changes_produce_no_events() {
+        let events = token()
+            .apply_update(&UpdateField::NoChange, &UpdateField::NoChange, &UpdateField::NoChange)
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_scope_is_rejected() {
+        let result = token().apply_update(&UpdateField::NoChange, &UpdateField::NoChange, &UpdateField::Set(vec![]));
+        assert!(result.is_err());
+    }
 }
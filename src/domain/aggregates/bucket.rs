@@ -238,11 +238,20 @@ impl BucketAggregate {
         &self.keys
     }
 
+    pub fn objects(&self) -> u64 {
+        self.objects
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
     // ============ For Repository (重建 Aggregate) ============
 
     /// 從持久化資料重建 Aggregate（不驗證）
     /// 
     /// 這個方法用於從資料庫載入已驗證的資料，跳過驗證以提高性能
+    #[allow(clippy::too_many_arguments)]
     pub fn reconstitute(
         id: String,
         global_aliases: Vec<String>,
@@ -2,12 +2,21 @@
 //!
 //! 聚合根，負責管理 Cluster 的布局和節點操作
 
+use std::collections::HashSet;
+
+use crate::domain::entities::{
+    BootstrapRoleOutcome, ClusterLayout, ClusterSnapshot, LayoutParameters, RoleChangeType, ZoneRedundancy,
+};
 use crate::domain::errors::DomainError;
 use crate::domain::events::{
-    ClusterDeadNodesSkippedEvent, ClusterEvent, ClusterLayoutAppliedEvent,
-    ClusterLayoutRevertedEvent, ClusterLayoutUpdatedEvent, ClusterNodesConnectedEvent,
+    ClusterDeadNodesSkippedEvent, ClusterEvent, ClusterHealthDegradedEvent, ClusterHealthRecoveredEvent,
+    ClusterLayoutAppliedEvent, ClusterLayoutRevertedEvent, ClusterLayoutUpdatedEvent, ClusterNodeCameUpEvent,
+    ClusterNodeWentDownEvent, ClusterNodesConnectedEvent, ClusterReachableEvent, ClusterUnreachableEvent,
 };
 
+/// Garage 回報的健康狀態，其餘為 `degraded` 或 `unavailable`
+const HEALTHY_STATUS: &str = "healthy";
+
 /// 節點角色配置
 #[derive(Debug, Clone)]
 pub struct NodeRoleConfig {
@@ -83,6 +92,161 @@ impl ClusterAggregate {
         Ok(())
     }
 
+    /// 檢查布局參數
+    ///
+    /// # 業務規則
+    /// - 固定的 zone 冗餘數至少為 1
+    pub fn validate_parameters(parameters: &LayoutParameters) -> Result<(), DomainError> {
+        match parameters.zone_redundancy {
+            Some(ZoneRedundancy::Value(n)) if n < 1 => Err(DomainError::ValidationError(format!(
+                "Zone redundancy must be at least 1, got {}",
+                n
+            ))),
+            Some(ZoneRedundancy::Maximum { maximum: false }) => Err(DomainError::ValidationError(
+                "Zone redundancy must be a number or maximum".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// 確認目前布局版本與呼叫者讀取時相同（樂觀並行控制）
+    ///
+    /// # 業務規則
+    /// - 其他管理者已變更布局時拒絕，避免覆蓋對方的變更
+    pub fn ensure_layout_version(&self, expected: i64) -> Result<(), DomainError> {
+        if self.layout_version != expected {
+            return Err(DomainError::LayoutVersionMismatch {
+                expected,
+                actual: self.layout_version,
+            });
+        }
+        Ok(())
+    }
+
+    /// 確認要套用的版本就是目前暫存的下一個版本
+    pub fn ensure_staged_version(&self, version: i64) -> Result<(), DomainError> {
+        let staged = self.layout_version + 1;
+        if version != staged {
+            return Err(DomainError::LayoutVersionMismatch {
+                expected: version,
+                actual: staged,
+            });
+        }
+        Ok(())
+    }
+
+    /// 檢查節點是否可以安全移出布局
+    ///
+    /// # 業務規則
+    /// - 節點必須在目前布局中
+    /// - 不得有其他暫存變更，避免一併套用他人的變更
+    /// - 剩餘儲存節點數至少為副本數
+    /// - 剩餘 zone 數至少為 zone 冗餘數（`maximum` 時為移除前可達到的冗餘數）
+    pub fn check_node_removal(
+        layout: &ClusterLayout,
+        node_id: &str,
+        replication_factor: usize,
+    ) -> Result<(), DomainError> {
+        if !layout.roles.iter().any(|r| r.id == node_id) {
+            return Err(DomainError::NodeNotFound(node_id.to_string()));
+        }
+        if layout.staged_role_changes.iter().any(|c| c.id != node_id) || layout.staged_parameters.is_some() {
+            return Err(DomainError::ClusterPreconditionFailed(
+                "Layout has other staged changes, apply or revert them first".to_string(),
+            ));
+        }
+
+        // Gateway 節點（無 capacity）不儲存資料
+        let storage: Vec<_> = layout.roles.iter().filter(|r| r.capacity.is_some()).collect();
+        if !storage.iter().any(|r| r.id == node_id) {
+            return Ok(());
+        }
+        let zones = |exclude: Option<&str>| -> HashSet<&str> {
+            storage
+                .iter()
+                .filter(|r| Some(r.id.as_str()) != exclude)
+                .map(|r| r.zone.as_str())
+                .collect()
+        };
+
+        let remaining_nodes = storage.len() - 1;
+        if remaining_nodes < replication_factor {
+            return Err(DomainError::ClusterPreconditionFailed(format!(
+                "Removing {} leaves {} storage node(s), replication factor is {}",
+                node_id, remaining_nodes, replication_factor
+            )));
+        }
+
+        let required_zones = match layout.parameters.as_ref().and_then(|p| p.zone_redundancy.as_ref()) {
+            Some(ZoneRedundancy::Value(n)) => (*n).max(1) as usize,
+            _ => zones(None).len().min(replication_factor),
+        };
+        let remaining_zones = zones(Some(node_id)).len();
+        if remaining_zones < required_zones {
+            return Err(DomainError::ClusterPreconditionFailed(format!(
+                "Removing {} leaves {} zone(s), {} required to keep zone redundancy",
+                node_id, remaining_zones, required_zones
+            )));
+        }
+        Ok(())
+    }
+
+    /// 比對期望的節點角色與目前布局，決定哪些角色需要暫存
+    ///
+    /// 回傳值與 `desired` 順序一致；重複執行時已符合的角色不會再次暫存
+    ///
+    /// # 業務規則
+    /// - 同一節點不得重複出現
+    /// - 不得有與期望無關的暫存變更，避免一併套用他人的變更
+    pub fn plan_roles(
+        layout: &ClusterLayout,
+        desired: &[NodeRoleConfig],
+    ) -> Result<Vec<BootstrapRoleOutcome>, DomainError> {
+        let mut seen = HashSet::new();
+        for config in desired {
+            if config.node_id.is_empty() {
+                return Err(DomainError::ValidationError("Node ID cannot be empty".to_string()));
+            }
+            if !seen.insert(config.node_id.as_str()) {
+                return Err(DomainError::ValidationError(format!("Duplicate node {}", config.node_id)));
+            }
+        }
+
+        let zone = |c: &NodeRoleConfig| c.zone.clone().unwrap_or_default();
+        let capacity = |c: &NodeRoleConfig| c.capacity.map(|v| v as i64);
+        let tags = |c: &NodeRoleConfig| c.tags.clone().unwrap_or_default();
+
+        let mut outcomes = Vec::with_capacity(desired.len());
+        for config in desired {
+            let staged = layout.staged_role_changes.iter().find(|s| s.id == config.node_id);
+            let current = layout.roles.iter().find(|r| r.id == config.node_id);
+
+            let outcome = match staged.map(|s| &s.change) {
+                Some(RoleChangeType::Assign { zone: z, capacity: c, tags: t })
+                    if *z == zone(config) && *c == capacity(config) && *t == tags(config) =>
+                {
+                    BootstrapRoleOutcome::AlreadyStaged
+                }
+                Some(_) => BootstrapRoleOutcome::Staged,
+                None => match current {
+                    Some(r) if r.zone == zone(config) && r.capacity == capacity(config) && r.tags == tags(config) => {
+                        BootstrapRoleOutcome::Unchanged
+                    }
+                    _ => BootstrapRoleOutcome::Staged,
+                },
+            };
+            outcomes.push(outcome);
+        }
+
+        if let Some(other) = layout.staged_role_changes.iter().find(|s| !seen.contains(s.id.as_str())) {
+            return Err(DomainError::ClusterPreconditionFailed(format!(
+                "Layout has a staged change for node {} that is not part of this request, apply or revert it first",
+                other.id
+            )));
+        }
+        Ok(outcomes)
+    }
+
     /// 更新布局
     pub fn update_layout(&mut self, new_version: i64) -> Result<ClusterEvent, DomainError> {
         self.layout_version = new_version;
@@ -122,6 +286,67 @@ impl ClusterAggregate {
         )))
     }
 
+    /// 比對兩次觀察到的集群狀態，產生對應的領域事件
+    ///
+    /// # 業務規則
+    /// - 新出現的節點不視為上線，只有 `is_up` 由 false 轉為 true 才算
+    /// - 從非 healthy 轉為其他非 healthy 狀態仍視為降級（例如 degraded → unavailable）
+    /// - 第一次輪詢只建立基準，但失敗時仍發布無法連線事件
+    /// - 輪詢失敗期間狀態沿用先前的快照，只在轉為失敗時發布一次事件
+    /// - 布局版本變更不另外發布事件，本服務的操作已有 LayoutApplied / LayoutReverted
+    pub fn watch_events(previous: Option<&ClusterSnapshot>, current: &ClusterSnapshot) -> Vec<ClusterEvent> {
+        let Some(previous) = previous else {
+            return current
+                .poll_error
+                .iter()
+                .map(|error| ClusterEvent::Unreachable(ClusterUnreachableEvent::new(error.clone())))
+                .collect();
+        };
+
+        let mut events = Vec::new();
+        match (&previous.poll_error, &current.poll_error) {
+            (None, Some(error)) => {
+                return vec![ClusterEvent::Unreachable(ClusterUnreachableEvent::new(error.clone()))];
+            }
+            (Some(_), Some(_)) => return events,
+            (Some(error), None) => {
+                events.push(ClusterEvent::Reachable(ClusterReachableEvent::new(error.clone())));
+            }
+            (None, None) => {}
+        }
+
+        for node in &current.nodes {
+            let Some(old) = previous.nodes.iter().find(|n| n.id == node.id) else {
+                continue;
+            };
+            match (old.is_up, node.is_up) {
+                (true, false) => events.push(ClusterEvent::NodeWentDown(ClusterNodeWentDownEvent::new(
+                    node.id.clone(),
+                    node.hostname.clone(),
+                ))),
+                (false, true) => events.push(ClusterEvent::NodeCameUp(ClusterNodeCameUpEvent::new(
+                    node.id.clone(),
+                    node.hostname.clone(),
+                ))),
+                _ => {}
+            }
+        }
+
+        let (before, after) = (&previous.health.status, &current.health.status);
+        if before != after {
+            if after == HEALTHY_STATUS {
+                events.push(ClusterEvent::HealthRecovered(ClusterHealthRecoveredEvent::new(before.clone())));
+            } else {
+                events.push(ClusterEvent::HealthDegraded(ClusterHealthDegradedEvent::new(
+                    before.clone(),
+                    &current.health,
+                )));
+            }
+        }
+
+        events
+    }
+
     // ============ Getters ============
 
     pub fn layout_version(&self) -> i64 {
@@ -136,3 +361,219 @@ impl ClusterAggregate {
         &self.staged_changes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{ClusterHealth, LayoutRole, StagedRoleChange, WatchedNode};
+
+    #[test]
+    fn test_layout_version_checks() {
+        let cluster = ClusterAggregate::new(4);
+
+        assert!(cluster.ensure_layout_version(4).is_ok());
+        assert!(matches!(
+            cluster.ensure_layout_version(3),
+            Err(DomainError::LayoutVersionMismatch { expected: 3, actual: 4 })
+        ));
+
+        assert!(cluster.ensure_staged_version(5).is_ok());
+        assert!(matches!(
+            cluster.ensure_staged_version(4),
+            Err(DomainError::LayoutVersionMismatch { expected: 4, actual: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_validate_zone_redundancy() {
+        let parameters = |zone_redundancy| LayoutParameters { zone_redundancy: Some(zone_redundancy) };
+
+        assert!(ClusterAggregate::validate_parameters(&parameters(ZoneRedundancy::Value(2))).is_ok());
+        assert!(ClusterAggregate::validate_parameters(&parameters(ZoneRedundancy::Maximum { maximum: true })).is_ok());
+        assert!(ClusterAggregate::validate_parameters(&parameters(ZoneRedundancy::Value(0))).is_err());
+        assert!(ClusterAggregate::validate_parameters(&LayoutParameters { zone_redundancy: None }).is_ok());
+    }
+
+    fn layout(roles: &[(&str, &str, Option<i64>)], zone_redundancy: Option<ZoneRedundancy>) -> ClusterLayout {
+        ClusterLayout {
+            version: 1,
+            partition_size: 0,
+            roles: roles
+                .iter()
+                .map(|(id, zone, capacity)| LayoutRole {
+                    id: id.to_string(),
+                    zone: zone.to_string(),
+                    capacity: *capacity,
+                    tags: vec![],
+                })
+                .collect(),
+            staged_role_changes: vec![],
+            parameters: Some(LayoutParameters { zone_redundancy }),
+            staged_parameters: None,
+        }
+    }
+
+    #[test]
+    fn test_check_node_removal() {
+        const TB: Option<i64> = Some(1_000_000_000_000);
+        let three_zones = [("a", "dc1", TB), ("b", "dc2", TB), ("c", "dc3", TB), ("d", "dc3", TB), ("gw", "dc1", None)];
+
+        // 同 zone 還有其他節點
+        let l = layout(&three_zones, Some(ZoneRedundancy::Maximum { maximum: true }));
+        assert!(ClusterAggregate::check_node_removal(&l, "d", 3).is_ok());
+        assert!(ClusterAggregate::check_node_removal(&l, "gw", 3).is_ok());
+        // 該 zone 唯一的節點
+        assert!(matches!(
+            ClusterAggregate::check_node_removal(&l, "a", 3),
+            Err(DomainError::ClusterPreconditionFailed(_))
+        ));
+        // 固定冗餘數 2 時允許
+        let l = layout(&three_zones, Some(ZoneRedundancy::Value(2)));
+        assert!(ClusterAggregate::check_node_removal(&l, "a", 3).is_ok());
+
+        // 節點數不足副本數
+        let single_zone = [("a", "dc1", TB), ("b", "dc1", TB), ("c", "dc1", TB)];
+        let l = layout(&single_zone, Some(ZoneRedundancy::Maximum { maximum: true }));
+        assert!(ClusterAggregate::check_node_removal(&l, "a", 2).is_ok());
+        assert!(ClusterAggregate::check_node_removal(&l, "a", 3).is_err());
+        assert!(matches!(
+            ClusterAggregate::check_node_removal(&l, "x", 1),
+            Err(DomainError::NodeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_plan_roles_is_idempotent() {
+        let mut l = layout(&[("a", "dc1", Some(100))], None);
+        l.staged_role_changes.push(StagedRoleChange {
+            id: "b".to_string(),
+            change: RoleChangeType::Assign { zone: "dc2".to_string(), capacity: Some(100), tags: vec![] },
+        });
+        let config = |id: &str, zone: &str| NodeRoleConfig {
+            node_id: id.to_string(),
+            zone: Some(zone.to_string()),
+            capacity: Some(100),
+            tags: None,
+        };
+
+        let outcomes = ClusterAggregate::plan_roles(&l, &[config("a", "dc1"), config("b", "dc2"), config("c", "dc3")]).unwrap();
+        assert_eq!(
+            outcomes,
+            vec![BootstrapRoleOutcome::Unchanged, BootstrapRoleOutcome::AlreadyStaged, BootstrapRoleOutcome::Staged]
+        );
+
+        // 角色不同時重新暫存
+        let outcomes = ClusterAggregate::plan_roles(&l, &[config("a", "dc9"), config("b", "dc2")]).unwrap();
+        assert_eq!(outcomes, vec![BootstrapRoleOutcome::Staged, BootstrapRoleOutcome::AlreadyStaged]);
+
+        // 與請求無關的暫存變更
+        assert!(matches!(
+            ClusterAggregate::plan_roles(&l, &[config("a", "dc1")]),
+            Err(DomainError::ClusterPreconditionFailed(_))
+        ));
+        assert!(ClusterAggregate::plan_roles(&l, &[config("b", "dc2"), config("b", "dc2")]).is_err());
+    }
+
+    fn snapshot(status: &str, nodes: &[(&str, bool)], layout_version: i64) -> ClusterSnapshot {
+        ClusterSnapshot {
+            health: ClusterHealth {
+                status: status.to_string(),
+                known_nodes: nodes.len() as i32,
+                connected_nodes: nodes.iter().filter(|(_, up)| *up).count() as i32,
+                storage_nodes: nodes.len() as i32,
+                storage_nodes_up: nodes.iter().filter(|(_, up)| *up).count() as i32,
+                partitions: 256,
+                partitions_quorum: 256,
+                partitions_all_ok: 256,
+            },
+            nodes: nodes
+                .iter()
+                .map(|(id, is_up)| WatchedNode {
+                    id: id.to_string(),
+                    hostname: None,
+                    addr: None,
+                    is_up: *is_up,
+                    last_seen_secs_ago: None,
+                })
+                .collect(),
+            layout_version,
+            observed_at: chrono::Utc::now(),
+            poll_error: None,
+        }
+    }
+
+    #[test]
+    fn test_watch_events() {
+        let before = snapshot("healthy", &[("a", true), ("b", true)], 3);
+
+        let after = snapshot("degraded", &[("a", true), ("b", false), ("c", true)], 4);
+        let events = ClusterAggregate::watch_events(Some(&before), &after);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], ClusterEvent::NodeWentDown(e) if e.node_id == "b"));
+        assert!(matches!(&events[1], ClusterEvent::HealthDegraded(e) if e.previous_status == "healthy" && e.status == "degraded"));
+
+        let events = ClusterAggregate::watch_events(Some(&after), &before);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], ClusterEvent::NodeCameUp(e) if e.node_id == "b"));
+        assert!(matches!(&events[1], ClusterEvent::HealthRecovered(_)));
+
+        // 最後一次看到的時間變動不算狀態改變
+        let mut later = before.clone();
+        later.nodes[0].last_seen_secs_ago = Some(12);
+        assert!(ClusterAggregate::watch_events(Some(&before), &later).is_empty());
+        assert!(ClusterAggregate::watch_events(None, &before).is_empty());
+        assert!(before.diff(&later).is_empty());
+    }
+
+    #[test]
+    fn test_watch_events_unreachable() {
+        let now = chrono::Utc::now();
+        let before = snapshot("healthy", &[("a", true)], 3);
+
+        let failed = ClusterSnapshot::unreachable(Some(&before), "timeout".to_string(), now);
+        assert_eq!(failed.nodes, before.nodes);
+        let events = ClusterAggregate::watch_events(Some(&before), &failed);
+        assert!(matches!(&events[..], [ClusterEvent::Unreachable(e)] if e.error == "timeout"));
+
+        // 持續失敗不重複發布
+        let still_failed = ClusterSnapshot::unreachable(Some(&failed), "refused".to_string(), now);
+        assert!(ClusterAggregate::watch_events(Some(&failed), &still_failed).is_empty());
+        let changes = failed.diff(&still_failed);
+        assert_eq!(changes.reachable, None);
+        assert_eq!(changes.poll_error.as_deref(), Some("refused"));
+
+        // 恢復後與失敗前的狀態比較
+        let after = snapshot("healthy", &[("a", false)], 3);
+        let events = ClusterAggregate::watch_events(Some(&still_failed), &after);
+        assert!(matches!(&events[0], ClusterEvent::Reachable(e) if e.previous_error == "refused"));
+        assert!(matches!(&events[1], ClusterEvent::NodeWentDown(e) if e.node_id == "a"));
+        assert_eq!(still_failed.diff(&after).reachable, Some(true));
+
+        // 第一次輪詢就失敗
+        let initial = ClusterSnapshot::unreachable(None, "timeout".to_string(), now);
+        assert_eq!(initial.health.status, "unavailable");
+        assert_eq!(initial.full().reachable, Some(false));
+        let events = ClusterAggregate::watch_events(None, &initial);
+        assert!(matches!(&events[..], [ClusterEvent::Unreachable(_)]));
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let before = snapshot("healthy", &[("a", true), ("b", true)], 3);
+        let mut after = snapshot("healthy", &[("b", false), ("c", true)], 3);
+
+        let changes = before.diff(&after);
+        assert_eq!(changes.health.map(|h| h.storage_nodes_up), Some(1));
+        after.health = before.health.clone();
+
+        let changes = before.diff(&after);
+        assert_eq!(changes.health, None);
+        assert_eq!(changes.layout_version, None);
+        assert_eq!(changes.nodes.iter().map(|n| n.id.as_str()).collect::<Vec<_>>(), vec!["b", "c"]);
+        assert_eq!(changes.removed_nodes, vec!["a".to_string()]);
+
+        let full = after.full();
+        assert_eq!(full.nodes.len(), 2);
+        assert_eq!(full.layout_version, Some(3));
+    }
+}
@@ -6,6 +6,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use crate::domain::aggregates::AccessKeyAggregate;
+use crate::domain::entities::garage::KeyListItemResponse;
+use crate::shared::parse_datetime;
 
 // ============ List Item Read Model ============
 
@@ -18,7 +20,6 @@ pub struct AccessKeyListItem {
     pub created: DateTime<Utc>,
     pub expiration: Option<DateTime<Utc>>,
     pub expired: bool,
-    pub secret_access_key: String,
 }
 
 impl AccessKeyListItem {
@@ -29,7 +30,17 @@ impl AccessKeyListItem {
             created: aggregate.created(),
             expiration: aggregate.expiration(),
             expired: aggregate.is_expired(),
-            secret_access_key: aggregate.secret_access_key().to_string(),
+        }
+    }
+
+    pub fn from_response(response: &KeyListItemResponse) -> Self {
+        let expiration = response.expiration.as_deref().and_then(parse_datetime);
+        AccessKeyListItem {
+            id: response.id.clone(),
+            name: response.name.clone(),
+            created: parse_datetime(&response.created).expect("Invalid created datetime"),
+            expiration,
+            expired: Self::compute_expired(expiration),
         }
     }
 
@@ -47,7 +58,8 @@ impl AccessKeyListItem {
 pub struct AccessKey {
     pub id: String,
     pub name: String,
-    pub secret_access_key: String,
+    /// 預設不查詢 secret，只有 Create / Import 的結果會帶值
+    pub secret_access_key: Option<String>,
     pub created: DateTime<Utc>,
     pub expiration: Option<DateTime<Utc>>,
     pub expired: bool,
@@ -61,7 +73,8 @@ impl AccessKey {
         AccessKey {
             id: aggregate.id().to_string(),
             name: aggregate.name().to_string(),
-            secret_access_key: aggregate.secret_access_key().to_string(),
+            secret_access_key: Some(aggregate.secret_access_key().to_string())
+                .filter(|s| !s.is_empty()),
             created: aggregate.created(),
             expiration: aggregate.expiration(),
             expired: aggregate.is_expired(),
@@ -0,0 +1,201 @@
+//! Audit trail entities
+//!
+//! 每個 DomainEvent 轉成一筆 AuditRecord 永久保存，用於事後追查
+//! （例如「上週二是誰把 Key X 從 Bucket Y 移除」）
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domain::events::{DomainEvent, EventMetadata};
+use crate::shared::redact_value;
+
+/// 一筆稽核紀錄
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub occurred_at: DateTime<Utc>,
+    /// 事件類型，格式為 `<Aggregate>.<Event>`，例如 `Bucket.KeyDenied`
+    pub event_type: String,
+    /// 事件涉及的資源 ID（Bucket、Access Key、Node 等）
+    #[serde(default)]
+    pub resource_ids: Vec<String>,
+    /// 呼叫者（Admin Token ID），背景任務發出的事件為 None
+    pub actor: Option<String>,
+    pub peer_subject: Option<String>,
+    pub trace_id: Option<String>,
+    /// 事件內容（已遮蔽敏感欄位）
+    #[serde(default)]
+    pub details: Value,
+}
+
+impl AuditRecord {
+    pub fn from_event(event: &DomainEvent, metadata: &EventMetadata) -> Self {
+        let (event_type, mut details) = match serde_json::to_value(event) {
+            Ok(value) => flatten_event(value),
+            Err(_) => ("Unknown".to_string(), Value::Null),
+        };
+        redact_value(&mut details);
+
+        Self {
+            occurred_at: metadata.published_at,
+            event_type,
+            resource_ids: resource_ids(&details),
+            actor: metadata.caller.clone(),
+            peer_subject: metadata.peer_subject.clone(),
+            trace_id: metadata.trace_id.clone(),
+            details,
+        }
+    }
+}
+
+/// `{"Bucket": {"KeyDenied": {...}}}` → (`Bucket.KeyDenied`, `{...}`)
+fn flatten_event(value: Value) -> (String, Value) {
+    let Some((aggregate, inner)) = single_entry(value) else {
+        return ("Unknown".to_string(), Value::Null);
+    };
+    match inner {
+        Value::String(variant) => (format!("{}.{}", aggregate, variant), Value::Null),
+        inner => match single_entry(inner) {
+            Some((variant, details)) => (format!("{}.{}", aggregate, variant), details),
+            None => (aggregate, Value::Null),
+        },
+    }
+}
+
+fn single_entry(value: Value) -> Option<(String, Value)> {
+    match value {
+        Value::Object(map) if map.len() == 1 => map.into_iter().next(),
+        _ => None,
+    }
+}
+
+/// 取出名稱為 `id` 或以 `_id` 結尾的欄位值
+fn resource_ids(details: &Value) -> Vec<String> {
+    let Value::Object(map) = details else {
+        return vec![];
+    };
+
+    let mut ids: Vec<String> = map
+        .iter()
+        .filter(|(k, _)| *k == "id" || k.ends_with("_id"))
+        .filter_map(|(_, v)| v.as_str().map(str::to_string))
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// 稽核紀錄查詢條件
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    /// 符合任一 resource_ids
+    pub resource_id: Option<String>,
+    /// 完整事件類型（`Bucket.KeyDenied`）或 Aggregate 前綴（`Bucket`），不分大小寫
+    pub event_type: Option<String>,
+    pub actor: Option<String>,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        if self.start.is_some_and(|start| record.occurred_at < start) {
+            return false;
+        }
+        if self.end.is_some_and(|end| record.occurred_at > end) {
+            return false;
+        }
+        if let Some(id) = &self.resource_id {
+            if !record.resource_ids.iter().any(|r| r == id) {
+                return false;
+            }
+        }
+        if let Some(event_type) = &self.event_type {
+            if !event_type_matches(&record.event_type, event_type) {
+                return false;
+            }
+        }
+        if let Some(actor) = &self.actor {
+            if record.actor.as_deref() != Some(actor.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn event_type_matches(event_type: &str, pattern: &str) -> bool {
+    let event_type = event_type.to_lowercase();
+    let pattern = pattern.to_lowercase();
+    event_type == pattern
+        || event_type
+            .strip_prefix(&pattern)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::events::{AccessKeyEvent, AccessKeyRotatedEvent, BucketEvent, BucketKeyDeniedEvent};
+
+    fn metadata(caller: Option<&str>) -> EventMetadata {
+        EventMetadata {
+            trace_id: Some("trace-1".to_string()),
+            caller: caller.map(str::to_string),
+            peer_subject: None,
+            published_at: Utc::now(),
+        }
+    }
+
+    fn key_denied() -> DomainEvent {
+        DomainEvent::Bucket(BucketEvent::KeyDenied(BucketKeyDeniedEvent::new(
+            "bucket-1".to_string(),
+            "GK1".to_string(),
+            true,
+            true,
+            false,
+        )))
+    }
+
+    #[test]
+    fn test_record_from_event() {
+        let record = AuditRecord::from_event(&key_denied(), &metadata(Some("token-1")));
+
+        assert_eq!(record.event_type, "Bucket.KeyDenied");
+        assert_eq!(record.resource_ids, vec!["GK1", "bucket-1"]);
+        assert_eq!(record.actor.as_deref(), Some("token-1"));
+        assert_eq!(record.trace_id.as_deref(), Some("trace-1"));
+        assert_eq!(record.details["read"], Value::Bool(true));
+    }
+
+    #[test]
+    fn test_record_collects_all_ids() {
+        let event = DomainEvent::AccessKey(AccessKeyEvent::Rotated(AccessKeyRotatedEvent::new(
+            "GK-old".to_string(),
+            "GK-new".to_string(),
+            None,
+        )));
+        let record = AuditRecord::from_event(&event, &metadata(None));
+
+        assert_eq!(record.event_type, "AccessKey.Rotated");
+        assert_eq!(record.resource_ids, vec!["GK-new", "GK-old"]);
+        assert_eq!(record.actor, None);
+    }
+
+    #[test]
+    fn test_filter() {
+        let record = AuditRecord::from_event(&key_denied(), &metadata(Some("token-1")));
+
+        assert!(AuditFilter::default().matches(&record));
+        assert!(AuditFilter { event_type: Some("bucket".to_string()), ..Default::default() }.matches(&record));
+        assert!(AuditFilter { event_type: Some("Bucket.KeyDenied".to_string()), ..Default::default() }.matches(&record));
+        assert!(!AuditFilter { event_type: Some("Buck".to_string()), ..Default::default() }.matches(&record));
+        assert!(AuditFilter { resource_id: Some("GK1".to_string()), ..Default::default() }.matches(&record));
+        assert!(!AuditFilter { actor: Some("token-2".to_string()), ..Default::default() }.matches(&record));
+        assert!(!AuditFilter {
+            start: Some(record.occurred_at + chrono::Duration::seconds(1)),
+            ..Default::default()
+        }
+        .matches(&record));
+    }
+}
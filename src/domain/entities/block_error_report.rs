@@ -0,0 +1,324 @@
+//! Block error triage report
+//!
+//! 將各節點回報的區塊錯誤去重，並依 `GetBlockInfo` 的引用對應到 bucket 與 object key，
+//! 以判斷哪些檔案受損，再決定要 resync 還是 purge。
+
+use std::collections::{BTreeMap, HashMap};
+use crate::domain::entities::{BlockError, BlockInfo, GarageBucketInfo, GarageLocalAlias, MultiNodeResponse};
+
+/// 去重後的錯誤區塊
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamagedBlock {
+    pub block_hash: String,
+    /// 回報此區塊錯誤的節點 → 錯誤訊息
+    pub node_errors: BTreeMap<String, String>,
+    pub size: Option<i64>,
+    pub refcount: Option<i64>,
+    /// 所有回報節點的 GetBlockInfo 都失敗時的最後一個錯誤
+    pub lookup_error: Option<String>,
+}
+
+impl DamagedBlock {
+    /// 區塊資訊已取得且沒有任何 object / upload 引用，可直接 purge
+    pub fn is_unreferenced(&self) -> bool {
+        self.lookup_error.is_none() && self.refcount == Some(0)
+    }
+}
+
+/// Object 或 upload 中指向錯誤區塊的位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamagedBlockRef {
+    pub block_hash: String,
+    pub block_offset: i64,
+    /// 僅 multipart upload 有
+    pub part_number: Option<i32>,
+}
+
+/// 受損的 object 版本
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamagedObject {
+    pub key: String,
+    pub version_uuid: String,
+    /// 版本已刪除（只剩舊版本引用此區塊）
+    pub deleted: bool,
+    pub blocks: Vec<DamagedBlockRef>,
+}
+
+/// 受損的未完成 multipart upload
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamagedUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub blocks: Vec<DamagedBlockRef>,
+}
+
+/// 單一 bucket 內受影響的 object 與 upload
+#[derive(Debug, Clone)]
+pub struct BucketBlockErrors {
+    pub bucket_id: String,
+    /// Bucket 已不存在時為空
+    pub global_aliases: Vec<String>,
+    pub local_aliases: Vec<GarageLocalAlias>,
+    pub objects: Vec<DamagedObject>,
+    pub uploads: Vec<DamagedUpload>,
+}
+
+/// 區塊錯誤分析報告
+#[derive(Debug, Clone, Default)]
+pub struct BlockErrorReport {
+    /// 依 hash 排序
+    pub blocks: Vec<DamagedBlock>,
+    /// 依第一個 global alias（沒有則用 bucket ID）排序
+    pub buckets: Vec<BucketBlockErrors>,
+    /// 無法列出區塊錯誤的節點
+    pub node_errors: BTreeMap<String, String>,
+}
+
+impl BlockErrorReport {
+    /// 依 hash 去重各節點的錯誤：hash → (節點 → 錯誤訊息)
+    pub fn collect_errors(errors: &MultiNodeResponse<Vec<BlockError>>) -> BTreeMap<String, BTreeMap<String, String>> {
+        let mut by_hash: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        for (node_id, node_errors) in &errors.success {
+            for e in node_errors {
+                by_hash
+                    .entry(e.block_hash.clone())
+                    .or_default()
+                    .insert(node_id.clone(), e.error.clone());
+            }
+        }
+        by_hash
+    }
+
+    /// `infos` 為每個 hash 的 GetBlockInfo 結果，`buckets` 用於解析 alias
+    pub fn build(
+        errors: BTreeMap<String, BTreeMap<String, String>>,
+        mut infos: HashMap<String, Result<BlockInfo, String>>,
+        node_errors: HashMap<String, String>,
+        buckets: &[GarageBucketInfo],
+    ) -> Self {
+        let mut blocks = Vec::with_capacity(errors.len());
+        let mut objects: BTreeMap<String, BTreeMap<(String, String), DamagedObject>> = BTreeMap::new();
+        let mut uploads: BTreeMap<String, BTreeMap<(String, String), DamagedUpload>> = BTreeMap::new();
+
+        for (block_hash, block_node_errors) in errors {
+            let info = infos
+                .remove(&block_hash)
+                .unwrap_or_else(|| Err("Block info not requested".to_string()));
+
+            let info = match info {
+                Ok(info) => info,
+                Err(lookup_error) => {
+                    blocks.push(DamagedBlock {
+                        block_hash,
+                        node_errors: block_node_errors,
+                        size: None,
+                        refcount: None,
+                        lookup_error: Some(lookup_error),
+                    });
+                    continue;
+                }
+            };
+
+            for v in info.versions {
+                objects
+                    .entry(v.bucket_id)
+                    .or_default()
+                    .entry((v.key.clone(), v.version_uuid.clone()))
+                    .or_insert_with(|| DamagedObject {
+                        key: v.key,
+                        version_uuid: v.version_uuid,
+                        deleted: v.deleted,
+                        blocks: vec![],
+                    })
+                    .blocks
+                    .push(DamagedBlockRef {
+                        block_hash: block_hash.clone(),
+                        block_offset: v.block_offset,
+                        part_number: None,
+                    });
+            }
+
+            for u in info.uploads {
+                uploads
+                    .entry(u.bucket_id)
+                    .or_default()
+                    .entry((u.key.clone(), u.upload_id.clone()))
+                    .or_insert_with(|| DamagedUpload {
+                        key: u.key,
+                        upload_id: u.upload_id,
+                        blocks: vec![],
+                    })
+                    .blocks
+                    .push(DamagedBlockRef {
+                        block_hash: block_hash.clone(),
+                        block_offset: u.block_offset,
+                        part_number: Some(u.part_number),
+                    });
+            }
+
+            blocks.push(DamagedBlock {
+                block_hash,
+                node_errors: block_node_errors,
+                size: Some(info.size),
+                refcount: Some(info.refcount),
+                lookup_error: None,
+            });
+        }
+
+        let bucket_info: HashMap<&str, &GarageBucketInfo> = buckets.iter().map(|b| (b.id.as_str(), b)).collect();
+        let mut bucket_ids: Vec<String> = objects.keys().chain(uploads.keys()).cloned().collect();
+        bucket_ids.sort();
+        bucket_ids.dedup();
+
+        let mut buckets: Vec<BucketBlockErrors> = bucket_ids
+            .into_iter()
+            .map(|bucket_id| {
+                let info = bucket_info.get(bucket_id.as_str());
+                BucketBlockErrors {
+                    global_aliases: info.map(|b| b.global_aliases.clone()).unwrap_or_default(),
+                    local_aliases: info.map(|b| b.local_aliases.clone()).unwrap_or_default(),
+                    objects: objects.remove(&bucket_id).map(|o| sorted_refs(o.into_values())).unwrap_or_default(),
+                    uploads: uploads.remove(&bucket_id).map(|u| sorted_refs(u.into_values())).unwrap_or_default(),
+                    bucket_id,
+                }
+            })
+            .collect();
+        buckets.sort_by(|a, b| {
+            let name = |x: &BucketBlockErrors| x.global_aliases.first().cloned().unwrap_or_else(|| x.bucket_id.clone());
+            name(a).cmp(&name(b))
+        });
+
+        Self {
+            blocks,
+            buckets,
+            node_errors: node_errors.into_iter().collect(),
+        }
+    }
+}
+
+/// 每個 object / upload 內依 offset 排列錯誤區塊
+fn sorted_refs<T: HasBlockRefs>(items: impl Iterator<Item = T>) -> Vec<T> {
+    items
+        .map(|mut item| {
+            item.blocks_mut().sort_by_key(|b| (b.part_number, b.block_offset));
+            item
+        })
+        .collect()
+}
+
+trait HasBlockRefs {
+    fn blocks_mut(&mut self) -> &mut Vec<DamagedBlockRef>;
+}
+
+impl HasBlockRefs for DamagedObject {
+    fn blocks_mut(&mut self) -> &mut Vec<DamagedBlockRef> {
+        &mut self.blocks
+    }
+}
+
+impl HasBlockRefs for DamagedUpload {
+    fn blocks_mut(&mut self) -> &mut Vec<DamagedBlockRef> {
+        &mut self.blocks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{BlockUploadRef, BlockVersionRef};
+
+    fn block_error(hash: &str, error: &str) -> BlockError {
+        BlockError { block_hash: hash.to_string(), error: error.to_string() }
+    }
+
+    fn version_ref(bucket: &str, key: &str, offset: i64) -> BlockVersionRef {
+        BlockVersionRef {
+            bucket_id: bucket.to_string(),
+            key: key.to_string(),
+            version_uuid: format!("{}-v1", key),
+            deleted: false,
+            block_offset: offset,
+        }
+    }
+
+    fn info(hash: &str, versions: Vec<BlockVersionRef>, uploads: Vec<BlockUploadRef>) -> BlockInfo {
+        BlockInfo {
+            block_hash: hash.to_string(),
+            size: 1024,
+            refcount: (versions.len() + uploads.len()) as i64,
+            versions,
+            uploads,
+        }
+    }
+
+    #[test]
+    fn test_collect_errors_deduplicates() {
+        let errors = MultiNodeResponse {
+            success: HashMap::from([
+                ("n1".to_string(), vec![block_error("aa", "timeout"), block_error("bb", "io")]),
+                ("n2".to_string(), vec![block_error("aa", "refused")]),
+            ]),
+            error: HashMap::new(),
+        };
+
+        let by_hash = BlockErrorReport::collect_errors(&errors);
+        assert_eq!(by_hash.len(), 2);
+        assert_eq!(by_hash["aa"].len(), 2);
+        assert_eq!(by_hash["aa"]["n2"], "refused");
+    }
+
+    #[test]
+    fn test_build_groups_by_bucket() {
+        let errors = MultiNodeResponse {
+            success: HashMap::from([(
+                "n1".to_string(),
+                vec![block_error("aa", "io"), block_error("bb", "io"), block_error("cc", "io"), block_error("dd", "io")],
+            )]),
+            error: HashMap::new(),
+        };
+        let by_hash = BlockErrorReport::collect_errors(&errors);
+
+        let upload = BlockUploadRef {
+            bucket_id: "b2".to_string(),
+            key: "big.iso".to_string(),
+            upload_id: "u1".to_string(),
+            part_number: 3,
+            block_offset: 0,
+        };
+        let infos = HashMap::from([
+            // 同一個 object 的兩個區塊
+            ("aa".to_string(), Ok(info("aa", vec![version_ref("b1", "photo.jpg", 1024)], vec![]))),
+            ("bb".to_string(), Ok(info("bb", vec![version_ref("b1", "photo.jpg", 0)], vec![upload]))),
+            ("cc".to_string(), Ok(info("cc", vec![], vec![]))),
+            ("dd".to_string(), Err("node unreachable".to_string())),
+        ]);
+        let buckets = vec![
+            GarageBucketInfo { id: "b1".to_string(), global_aliases: vec!["photos".to_string()], local_aliases: vec![] },
+            GarageBucketInfo { id: "b2".to_string(), global_aliases: vec!["archive".to_string()], local_aliases: vec![] },
+        ];
+
+        let report = BlockErrorReport::build(
+            by_hash,
+            infos,
+            HashMap::from([("n2".to_string(), "timeout".to_string())]),
+            &buckets,
+        );
+
+        assert_eq!(report.blocks.len(), 4);
+        assert!(report.blocks[2].is_unreferenced());
+        assert_eq!(report.blocks[3].lookup_error.as_deref(), Some("node unreachable"));
+        assert!(!report.blocks[3].is_unreferenced());
+        assert_eq!(report.node_errors["n2"], "timeout");
+
+        // 依 alias 排序：archive、photos
+        assert_eq!(report.buckets.len(), 2);
+        assert_eq!(report.buckets[0].bucket_id, "b2");
+        assert_eq!(report.buckets[0].uploads[0].blocks[0].part_number, Some(3));
+
+        let photos = &report.buckets[1];
+        assert_eq!(photos.objects.len(), 1);
+        let offsets: Vec<i64> = photos.objects[0].blocks.iter().map(|b| b.block_offset).collect();
+        assert_eq!(offsets, vec![0, 1024]);
+        assert_eq!(photos.objects[0].blocks[0].block_hash, "bb");
+    }
+}
@@ -75,6 +75,7 @@ impl Bucket {
 }
 
 impl BucketDetail {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         global_aliases: Vec<String>,
@@ -100,4 +101,36 @@ impl BucketDetail {
             created,
         }
     }
+
+    /// 是否有 `access_key_id` 以外的 Key 擁有 owner 權限
+    pub fn has_other_owner(&self, access_key_id: &str) -> bool {
+        self.keys
+            .iter()
+            .any(|k| k.access_key_id != access_key_id && k.permissions.owner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(keys: &[(&str, bool)]) -> BucketDetail {
+        let keys = keys
+            .iter()
+            .map(|(id, owner)| BucketKey {
+                access_key_id: id.to_string(),
+                name: String::new(),
+                permissions: BucketKeyPermissions { read: true, write: true, owner: *owner },
+                bucket_local_aliases: vec![],
+            })
+            .collect();
+        BucketDetail::new("b1".to_string(), vec![], vec![], false, None, keys, Quotas::default(), 0, 0, String::new())
+    }
+
+    #[test]
+    fn test_has_other_owner() {
+        assert!(!detail(&[("GK1", true)]).has_other_owner("GK1"));
+        assert!(!detail(&[("GK1", true), ("GK2", false)]).has_other_owner("GK1"));
+        assert!(detail(&[("GK1", true), ("GK2", true)]).has_other_owner("GK1"));
+    }
 }
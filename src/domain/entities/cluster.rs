@@ -1,5 +1,6 @@
 //! Cluster entities
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -45,7 +46,7 @@ pub struct PartitionInfo {
 }
 
 /// 集群健康狀態
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClusterHealth {
     pub status: String,
@@ -59,12 +60,84 @@ pub struct ClusterHealth {
 }
 
 /// 集群統計資訊
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 由 Garage 的 freeform 文字解析而來，無法解析的部分保持空值，原始文字保留於 `freeform`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClusterStatistics {
+    pub nodes: Vec<NodeStorageStatistics>,
+    pub tables: Vec<TableStatistics>,
+    pub totals: StorageTotals,
     pub freeform: String,
 }
 
+/// 單一儲存節點的容量與使用量
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStorageStatistics {
+    pub id: String,
+    pub hostname: Option<String>,
+    pub zone: Option<String>,
+    /// Layout 中設定的容量（bytes）
+    pub capacity: Option<u64>,
+    /// 分配到的 partition 數量
+    pub partitions: Option<u64>,
+    pub data: Option<DiskUsage>,
+    pub metadata: Option<DiskUsage>,
+}
+
+/// 磁碟可用空間（bytes）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskUsage {
+    pub available: u64,
+    pub total: u64,
+}
+
+impl DiskUsage {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.available)
+    }
+}
+
+impl std::ops::Add for DiskUsage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            available: self.available + other.available,
+            total: self.total + other.total,
+        }
+    }
+}
+
+/// Metadata table 統計
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStatistics {
+    pub name: String,
+    /// Garage 未計算時（顯示為 `NC`）為 None
+    pub items: Option<u64>,
+    pub merkle_items: Option<u64>,
+    pub merkle_todo: Option<u64>,
+    pub insert_queue: Option<u64>,
+    pub gc_todo: Option<u64>,
+}
+
+/// 所有儲存節點的加總
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageTotals {
+    pub capacity: u64,
+    pub data: DiskUsage,
+    pub metadata: DiskUsage,
+    /// Garage 估算的集群可用資料空間
+    pub estimated_data_available: Option<u64>,
+    pub estimated_metadata_available: Option<u64>,
+    /// 部分節點缺少資訊時，估算值只是上限
+    pub estimate_is_upper_bound: bool,
+}
+
 /// 連接節點結果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -119,14 +192,14 @@ pub enum RoleChangeType {
 }
 
 /// 布局參數
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LayoutParameters {
     pub zone_redundancy: Option<ZoneRedundancy>,
 }
 
 /// Zone 冗餘設定
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ZoneRedundancy {
     Value(i32),
@@ -175,3 +248,229 @@ pub struct SkipDeadNodesResult {
     pub ack_updated: Vec<String>,
     pub sync_updated: Vec<String>,
 }
+
+/// 節點下線流程的階段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecommissionPhase {
+    /// 檢查剩餘節點與 zone 是否足以維持副本數
+    Validating,
+    /// 暫存移除節點角色
+    StagingRemoval,
+    /// 預覽新布局的分區分配
+    Previewing,
+    /// 套用新布局
+    Applying,
+    /// 等待所有節點的 update tracker 同步到新布局
+    SyncingLayout,
+    /// 等待 block resync 佇列清空
+    Resyncing,
+    Completed,
+}
+
+/// 節點下線進度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecommissionProgress {
+    pub phase: DecommissionPhase,
+    /// 整體進度 0-100
+    pub percent: u8,
+    pub message: String,
+    /// 移除節點後的布局版本（套用後才有）
+    pub layout_version: Option<i64>,
+}
+
+/// 初始化集群時節點連線的結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BootstrapConnectOutcome {
+    /// 節點已在集群中且在線，未重新連線
+    AlreadyConnected,
+    Connected,
+    Failed { error: String },
+}
+
+/// 初始化集群時節點角色的結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BootstrapRoleOutcome {
+    /// 目前布局的角色已符合
+    Unchanged,
+    /// 先前已暫存相同的變更
+    AlreadyStaged,
+    Staged,
+    /// 因連線失敗未暫存
+    Skipped,
+}
+
+/// 單一節點的初始化結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BootstrapNodeReport {
+    pub node_id: String,
+    pub address: String,
+    pub connection: BootstrapConnectOutcome,
+    pub role: BootstrapRoleOutcome,
+}
+
+/// 初始化集群的彙整報告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BootstrapReport {
+    pub nodes: Vec<BootstrapNodeReport>,
+    /// 是否暫存了新的布局參數
+    pub parameters_staged: bool,
+    /// Garage 預覽新布局的訊息
+    pub preview: Vec<String>,
+    /// 本次套用的布局版本，沒有變更需要套用時為 None
+    pub applied_version: Option<i64>,
+    /// 完成後的目前布局版本
+    pub layout_version: i64,
+}
+
+/// 監看中的節點狀態
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchedNode {
+    pub id: String,
+    pub hostname: Option<String>,
+    pub addr: Option<String>,
+    pub is_up: bool,
+    pub last_seen_secs_ago: Option<i64>,
+}
+
+impl WatchedNode {
+    /// 比較狀態是否相同；`last_seen_secs_ago` 每次輪詢都會變動，不列入比較
+    fn same_state(&self, other: &WatchedNode) -> bool {
+        self.id == other.id && self.hostname == other.hostname && self.addr == other.addr && self.is_up == other.is_up
+    }
+}
+
+/// 某一時間點的集群狀態，由背景輪詢產生
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterSnapshot {
+    pub health: ClusterHealth,
+    /// 依節點 ID 排序
+    pub nodes: Vec<WatchedNode>,
+    pub layout_version: i64,
+    pub observed_at: DateTime<Utc>,
+    /// 最近一次輪詢失敗的原因；此時其餘欄位為最後一次成功輪詢的結果
+    pub poll_error: Option<String>,
+}
+
+/// 兩個快照之間的差異，未變更的部分為空
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterChanges {
+    pub health: Option<ClusterHealth>,
+    /// 新增或狀態改變的節點
+    pub nodes: Vec<WatchedNode>,
+    pub removed_nodes: Vec<String>,
+    pub layout_version: Option<i64>,
+    /// 可連線狀態改變時設定
+    pub reachable: Option<bool>,
+    /// 輪詢失敗且原因改變時設定
+    pub poll_error: Option<String>,
+}
+
+impl ClusterSnapshot {
+    pub fn new(health: ClusterHealth, status: ClusterStatus, observed_at: DateTime<Utc>) -> Self {
+        let mut nodes: Vec<WatchedNode> = status
+            .nodes
+            .into_iter()
+            .map(|n| WatchedNode {
+                id: n.id,
+                hostname: n.hostname,
+                addr: n.addr,
+                is_up: n.is_up,
+                last_seen_secs_ago: n.last_seen_secs_ago,
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Self {
+            health,
+            nodes,
+            layout_version: status.layout_version,
+            observed_at,
+            poll_error: None,
+        }
+    }
+
+    /// 輪詢失敗時的快照
+    ///
+    /// 沿用上一次的狀態；尚未成功輪詢過時以 `unavailable` 且沒有節點表示
+    pub fn unreachable(previous: Option<&ClusterSnapshot>, error: String, observed_at: DateTime<Utc>) -> Self {
+        let mut snapshot = previous.cloned().unwrap_or_else(|| Self {
+            health: ClusterHealth {
+                status: "unavailable".to_string(),
+                known_nodes: 0,
+                connected_nodes: 0,
+                storage_nodes: 0,
+                storage_nodes_up: 0,
+                partitions: 0,
+                partitions_quorum: 0,
+                partitions_all_ok: 0,
+            },
+            nodes: vec![],
+            layout_version: 0,
+            observed_at,
+            poll_error: None,
+        });
+        snapshot.observed_at = observed_at;
+        snapshot.poll_error = Some(error);
+        snapshot
+    }
+
+    pub fn is_reachable(&self) -> bool {
+        self.poll_error.is_none()
+    }
+
+    /// 以完整狀態表示的變更，用於新訂閱者的第一則訊息
+    pub fn full(&self) -> ClusterChanges {
+        ClusterChanges {
+            health: Some(self.health.clone()),
+            nodes: self.nodes.clone(),
+            removed_nodes: vec![],
+            layout_version: Some(self.layout_version),
+            reachable: Some(self.is_reachable()),
+            poll_error: self.poll_error.clone(),
+        }
+    }
+
+    /// 從 `self` 到 `newer` 的差異
+    pub fn diff(&self, newer: &ClusterSnapshot) -> ClusterChanges {
+        let find = |nodes: &[WatchedNode], id: &str| nodes.iter().find(|n| n.id == id).cloned();
+
+        ClusterChanges {
+            health: (self.health != newer.health).then(|| newer.health.clone()),
+            nodes: newer
+                .nodes
+                .iter()
+                .filter(|n| !find(&self.nodes, &n.id).is_some_and(|old| old.same_state(n)))
+                .cloned()
+                .collect(),
+            removed_nodes: self
+                .nodes
+                .iter()
+                .filter(|n| find(&newer.nodes, &n.id).is_none())
+                .map(|n| n.id.clone())
+                .collect(),
+            layout_version: (self.layout_version != newer.layout_version).then_some(newer.layout_version),
+            reachable: (self.is_reachable() != newer.is_reachable()).then_some(newer.is_reachable()),
+            poll_error: newer.poll_error.clone().filter(|_| self.poll_error != newer.poll_error),
+        }
+    }
+}
+
+impl ClusterChanges {
+    pub fn is_empty(&self) -> bool {
+        self.health.is_none()
+            && self.nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.layout_version.is_none()
+            && self.reachable.is_none()
+            && self.poll_error.is_none()
+    }
+}
@@ -0,0 +1,114 @@
+//! Confirmation of destructive operations
+//!
+//! 破壞性操作採兩階段呼叫：第一次回傳影響範圍與短效的確認 token，
+//! 第二次帶上 token 才會真正執行。Token 綁定操作類型與操作對象，
+//! 不能拿來確認其他操作。
+
+use chrono::{DateTime, Utc};
+
+/// 需要二次確認的操作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DestructiveOperation {
+    /// RPC 名稱，例如 `DeleteBucket`
+    action: String,
+    /// 正規化後的操作對象：排序後每項以 `<位元組長度>:<內容>` 串接。
+    /// Object key、alias 可能含有任何字元，加上長度才不會讓不同的對象組合得到相同結果
+    subject: String,
+}
+
+impl DestructiveOperation {
+    /// 操作對象的順序不影響比對
+    pub fn new(action: impl Into<String>, subject: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut parts: Vec<String> = subject.into_iter().map(Into::into).collect();
+        parts.sort();
+        parts.dedup();
+
+        Self {
+            action: action.into(),
+            subject: parts.iter().map(|p| format!("{}:{}", p.len(), p)).collect(),
+        }
+    }
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+}
+
+/// 操作影響範圍
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImpactSummary {
+    /// 一行摘要，例如 `2 buckets with 12345 objects / 40.0 GB will be deleted`
+    pub summary: String,
+    /// 逐項說明
+    pub details: Vec<String>,
+}
+
+impl ImpactSummary {
+    pub fn new(summary: impl Into<String>, details: Vec<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            details,
+        }
+    }
+}
+
+/// 已簽發的確認 token
+#[derive(Debug, Clone)]
+pub struct ConfirmationToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 第一次呼叫的結果：尚未執行，需帶 token 再次呼叫
+#[derive(Debug, Clone)]
+pub struct ConfirmationRequired {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+    pub impact: ImpactSummary,
+}
+
+/// 需確認操作的執行結果
+#[derive(Debug, Clone)]
+pub enum Confirmable<T> {
+    /// 尚未執行
+    Required(ConfirmationRequired),
+    /// 已確認並執行
+    Completed(T),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subject_ignores_order_and_duplicates() {
+        let a = DestructiveOperation::new("DeleteBucket", ["b2", "b1", "b2"]);
+        let b = DestructiveOperation::new("DeleteBucket", ["b1", "b2"]);
+
+        assert_eq!(a, b);
+        assert_eq!(a.subject(), "2:b12:b2");
+    }
+
+    #[test]
+    fn test_subject_parts_cannot_collide() {
+        // 含有分隔字元的 key 不能與拆開的多個 key 相同
+        let single = DestructiveOperation::new("DeleteObject", ["bucket=b", "a/,c/"]);
+        let split = DestructiveOperation::new("DeleteObject", ["bucket=b", "a/", "c/"]);
+        assert_ne!(single.subject(), split.subject());
+        assert_ne!(
+            DestructiveOperation::new("DeleteObject", ["ab", "c"]).subject(),
+            DestructiveOperation::new("DeleteObject", ["a", "bc"]).subject()
+        );
+    }
+
+    #[test]
+    fn test_action_is_part_of_identity() {
+        let a = DestructiveOperation::new("DeleteBucket", ["b1"]);
+        let b = DestructiveOperation::new("PurgeBlocks", ["b1"]);
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,288 @@
+//! Garage freeform text helpers
+//!
+//! Garage 的統計只以給人看的文字回傳，這裡提供叢集與節點統計共用的區段、表格與數值解析。
+//! 表格由 Garage 以固定欄寬對齊，欄位值本身可能含空白（例如 `100.0 GB`），
+//! 因此依表頭的欄位位置切割，而不是以空白分隔。
+
+use super::cluster::{DiskUsage, NodeStorageStatistics, TableStatistics};
+use super::node::BlockManagerStatistics;
+
+// ============ Sections ============
+
+/// 以未縮排、`:` 結尾的行為標題的區段
+pub(crate) struct Section<'a> {
+    pub title: &'a str,
+    pub lines: Vec<&'a str>,
+}
+
+pub(crate) fn sections(text: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section<'_>> = Vec::new();
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indented = line.starts_with(char::is_whitespace);
+        match line.trim_end().strip_suffix(':') {
+            Some(title) if !indented => sections.push(Section { title, lines: Vec::new() }),
+            _ if indented => {
+                if let Some(section) = sections.last_mut() {
+                    section.lines.push(line);
+                }
+            }
+            // 區段之外的說明文字
+            _ => {}
+        }
+    }
+    sections
+}
+
+/// 未縮排的 `key: value` 行，例如 `Database engine: LMDB (using Heed crate)`
+pub(crate) fn header_fields(text: &str) -> impl Iterator<Item = (&str, &str)> {
+    text.lines()
+        .filter(|line| !line.starts_with(char::is_whitespace))
+        .filter_map(|line| line.split_once(": "))
+        .map(|(key, value)| (key.trim(), value.trim()))
+}
+
+// ============ Tables ============
+
+/// 依表頭欄位位置切割的表格
+pub(crate) struct Table {
+    headers: Vec<(usize, String)>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// 第一行為表頭
+    pub fn parse(lines: &[&str]) -> Option<Self> {
+        let (header, rows) = lines.split_first()?;
+        let headers = header_columns(header);
+        if headers.is_empty() {
+            return None;
+        }
+
+        let rows = rows.iter().map(|row| slice_row(row, &headers)).collect();
+        Some(Self { headers, rows })
+    }
+
+    /// 欄位索引（不分大小寫）
+    pub fn column(&self, names: &[&str]) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|(_, h)| names.iter().any(|n| h.eq_ignore_ascii_case(n)))
+    }
+}
+
+/// 表頭中每個欄位名稱及其起始位置（以字元計）
+fn header_columns(header: &str) -> Vec<(usize, String)> {
+    let chars: Vec<char> = header.chars().collect();
+    let mut columns = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        // 欄位名稱內允許單一空白，兩個以上空白或 tab 才是欄位分隔
+        while i < chars.len()
+            && !(chars[i] == '\t' || (chars[i] == ' ' && chars.get(i + 1).is_none_or(|c| c.is_whitespace())))
+        {
+            i += 1;
+        }
+        columns.push((start, chars[start..i].iter().collect()));
+    }
+    columns
+}
+
+fn slice_row(row: &str, headers: &[(usize, String)]) -> Vec<String> {
+    // Garage 未對齊（以 tab 分隔）時直接切割
+    if row.contains('\t') {
+        return row.split('\t').map(|c| c.trim().to_string()).collect();
+    }
+
+    let chars: Vec<char> = row.chars().collect();
+    headers
+        .iter()
+        .enumerate()
+        .map(|(i, (start, _))| {
+            let end = headers.get(i + 1).map_or(chars.len(), |(next, _)| *next).min(chars.len());
+            let start = (*start).min(end);
+            chars[start..end].iter().collect::<String>().trim().to_string()
+        })
+        .collect()
+}
+
+fn cell(row: &[String], column: Option<usize>) -> Option<&str> {
+    let value = row.get(column?)?.as_str();
+    (!value.is_empty() && value != "?").then_some(value)
+}
+
+// ============ Storage Nodes ============
+
+pub(crate) fn parse_storage_nodes(lines: &[&str]) -> Vec<NodeStorageStatistics> {
+    let Some(table) = Table::parse(lines) else {
+        return vec![];
+    };
+    let id = table.column(&["ID"]);
+    let hostname = table.column(&["Hostname"]);
+    let zone = table.column(&["Zone"]);
+    let capacity = table.column(&["Capacity"]);
+    let partitions = table.column(&["Part.", "Partitions"]);
+    let data = table.column(&["DataAvail"]);
+    let metadata = table.column(&["MetaAvail"]);
+
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            Some(NodeStorageStatistics {
+                id: cell(row, id)?.to_string(),
+                hostname: cell(row, hostname).map(str::to_string),
+                zone: cell(row, zone).map(str::to_string),
+                capacity: cell(row, capacity).and_then(parse_bytes),
+                partitions: cell(row, partitions).and_then(|p| p.parse().ok()),
+                data: cell(row, data).and_then(parse_disk_usage),
+                metadata: cell(row, metadata).and_then(parse_disk_usage),
+            })
+        })
+        .collect()
+}
+
+// ============ Table Stats ============
+
+/// 解析 `Table stats:` 區段
+pub(crate) fn parse_table_stats(lines: &[&str]) -> Vec<TableStatistics> {
+    let Some(table) = Table::parse(lines) else {
+        return vec![];
+    };
+    let name = table.column(&["Table"]);
+    let items = table.column(&["Items"]);
+    let merkle_items = table.column(&["MklItems"]);
+    let merkle_todo = table.column(&["MklTodo"]);
+    let insert_queue = table.column(&["InsQueue"]);
+    let gc_todo = table.column(&["GcTodo"]);
+    let number = |row: &[String], column| cell(row, column).and_then(|v| v.parse().ok());
+
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            Some(TableStatistics {
+                name: cell(row, name)?.to_string(),
+                items: number(row, items),
+                merkle_items: number(row, merkle_items),
+                merkle_todo: number(row, merkle_todo),
+                insert_queue: number(row, insert_queue),
+                gc_todo: number(row, gc_todo),
+            })
+        })
+        .collect()
+}
+
+// ============ Block Manager ============
+
+/// `  number of RC entries:  4098 (~= number of blocks)` 等
+///
+/// 舊版格式為 `number of RC entries (~= number of blocks): 4098`，兩者皆以 key 前綴判斷
+pub(crate) fn parse_block_manager(lines: &[&str]) -> BlockManagerStatistics {
+    let mut blocks = BlockManagerStatistics::default();
+
+    for line in lines {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let digits: String = value.trim().chars().take_while(char::is_ascii_digit).collect();
+        let value = digits.parse().ok();
+
+        if key.starts_with("number of rc entries") {
+            blocks.rc_entries = value;
+        } else if key.starts_with("resync queue length") {
+            blocks.resync_queue_length = value;
+        } else if key.starts_with("blocks with resync errors") {
+            blocks.resync_errors = value;
+        }
+    }
+    blocks
+}
+
+// ============ Values ============
+
+/// `87.3 GB/100.0 GB (87.3%)` → available / total
+fn parse_disk_usage(value: &str) -> Option<DiskUsage> {
+    let value = value.split_once('(').map_or(value, |(v, _)| v);
+    let (available, total) = value.split_once('/')?;
+    Some(DiskUsage {
+        available: parse_bytes(available)?,
+        total: parse_bytes(total)?,
+    })
+}
+
+/// 解析 Garage（bytesize）輸出的大小，例如 `100.0 GB`、`1.5 TiB`、`512 B`
+pub(crate) fn parse_bytes(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: f64 = match unit.trim() {
+        "" | "B" => 1.0,
+        "kB" | "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        "PB" => 1e15,
+        "EB" => 1e18,
+        "KiB" => 1024f64,
+        "MiB" => 1024f64.powi(2),
+        "GiB" => 1024f64.powi(3),
+        "TiB" => 1024f64.powi(4),
+        "PiB" => 1024f64.powi(5),
+        "EiB" => 1024f64.powi(6),
+        _ => return None,
+    };
+    Some((number * multiplier).round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table_stats() {
+        let lines = [
+            "  Table             Items  MklItems  MklTodo  InsQueue  GcTodo",
+            "  bucket_v2         3      4         0        0         0",
+            "  object            1532   1790      2        0         17",
+        ];
+        let tables = parse_table_stats(&lines);
+
+        assert_eq!(tables.len(), 2);
+        assert_eq!(
+            tables[1],
+            TableStatistics {
+                name: "object".to_string(),
+                items: Some(1532),
+                merkle_items: Some(1790),
+                merkle_todo: Some(2),
+                insert_queue: Some(0),
+                gc_todo: Some(17),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        assert_eq!(parse_bytes("512 B"), Some(512));
+        assert_eq!(parse_bytes("1.5 kB"), Some(1500));
+        assert_eq!(parse_bytes("2.0 GiB"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_bytes("0"), Some(0));
+        assert_eq!(parse_bytes("gateway"), None);
+        assert_eq!(parse_bytes("12 parsecs"), None);
+    }
+}
@@ -74,7 +74,9 @@ pub struct KeyListItemResponse {
 pub struct KeyInfoResponse {
     pub access_key_id: String,
     pub name: String,
-    pub secret_access_key: String,
+    /// 只有帶 `showSecretKey=true` 查詢或 Create / Import 時才會回傳
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
     pub created: String,
     pub expiration: Option<String>,
     #[serde(default)]
@@ -0,0 +1,251 @@
+//! Access Key expiration tracking
+//!
+//! 背景到期檢查的判定邏輯：依提前通知時間產生「即將到期」與「已到期」通知，
+//! 每個門檻只通知一次。狀態只保存在記憶體，重新啟動後會重新通知一次。
+
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::entities::AccessKeyListItem;
+
+/// 到期通知
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpirationNotice {
+    /// 距離到期時間已少於 `lead_time`
+    ExpiringSoon {
+        id: String,
+        name: String,
+        expiration: DateTime<Utc>,
+        lead_time: Duration,
+    },
+    Expired {
+        id: String,
+        name: String,
+        expiration: DateTime<Utc>,
+    },
+}
+
+/// 到期處理策略
+#[derive(Debug, Clone)]
+pub struct KeyExpirationPolicy {
+    /// 提前通知時間（由大到小）
+    lead_times: Vec<Duration>,
+    /// 到期超過此時間後自動刪除，None 表示不刪除
+    purge_after: Option<Duration>,
+}
+
+impl KeyExpirationPolicy {
+    /// 非正值的提前通知時間會被忽略
+    pub fn new(lead_times: Vec<Duration>, purge_after: Option<Duration>) -> Self {
+        let mut lead_times: Vec<Duration> = lead_times
+            .into_iter()
+            .filter(|d| *d > Duration::zero())
+            .collect();
+        lead_times.sort_by(|a, b| b.cmp(a));
+        lead_times.dedup();
+
+        Self { lead_times, purge_after }
+    }
+
+    pub fn lead_times(&self) -> &[Duration] {
+        &self.lead_times
+    }
+
+    pub fn purge_after(&self) -> Option<Duration> {
+        self.purge_after
+    }
+
+    /// 已到期且超過寬限期，可自動刪除
+    pub fn should_purge(&self, expiration: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        self.purge_after
+            .and_then(|grace| expiration.checked_add_signed(grace))
+            .is_some_and(|deadline| now >= deadline)
+    }
+
+    /// 已跨過的提前通知門檻數量
+    fn stage(&self, remaining: Duration) -> usize {
+        self.lead_times.iter().filter(|lead| remaining <= **lead).count()
+    }
+}
+
+/// 單一 Key 已發出的通知
+#[derive(Debug, Clone, Copy)]
+struct NotifiedState {
+    expiration: DateTime<Utc>,
+    stage: usize,
+    expired: bool,
+}
+
+/// 追蹤每個 Key 已發出的通知，避免重複
+#[derive(Debug)]
+pub struct KeyExpirationTracker {
+    policy: KeyExpirationPolicy,
+    notified: HashMap<String, NotifiedState>,
+}
+
+impl KeyExpirationTracker {
+    pub fn new(policy: KeyExpirationPolicy) -> Self {
+        Self {
+            policy,
+            notified: HashMap::new(),
+        }
+    }
+
+    pub fn policy(&self) -> &KeyExpirationPolicy {
+        &self.policy
+    }
+
+    /// 依目前的 Key 列表產生新的通知
+    ///
+    /// 到期時間被修改的 Key 會重新計算；同時跨過多個門檻時只通知最小的那個
+    pub fn evaluate(&mut self, keys: &[AccessKeyListItem], now: DateTime<Utc>) -> Vec<ExpirationNotice> {
+        let mut notices = Vec::new();
+        let mut seen = HashSet::new();
+
+        for key in keys {
+            let Some(expiration) = key.expiration else {
+                continue;
+            };
+            seen.insert(key.id.as_str());
+
+            let state = self
+                .notified
+                .entry(key.id.clone())
+                .and_modify(|s| {
+                    if s.expiration != expiration {
+                        *s = NotifiedState { expiration, stage: 0, expired: false };
+                    }
+                })
+                .or_insert(NotifiedState { expiration, stage: 0, expired: false });
+
+            if now >= expiration {
+                if !state.expired {
+                    state.expired = true;
+                    notices.push(ExpirationNotice::Expired {
+                        id: key.id.clone(),
+                        name: key.name.clone(),
+                        expiration,
+                    });
+                }
+                continue;
+            }
+
+            let stage = self.policy.stage(expiration - now);
+            if stage > state.stage {
+                state.stage = stage;
+                notices.push(ExpirationNotice::ExpiringSoon {
+                    id: key.id.clone(),
+                    name: key.name.clone(),
+                    expiration,
+                    lead_time: self.policy.lead_times[stage - 1],
+                });
+            }
+        }
+
+        // 已刪除或取消到期時間的 Key 不再追蹤
+        self.notified.retain(|id, _| seen.contains(id.as_str()));
+        notices
+    }
+
+    /// 回傳可自動刪除的 Key ID
+    pub fn purgeable<'a>(&self, keys: &'a [AccessKeyListItem], now: DateTime<Utc>) -> Vec<&'a str> {
+        keys.iter()
+            .filter(|k| k.expiration.is_some_and(|e| self.policy.should_purge(e, now)))
+            .map(|k| k.id.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(id: &str, expiration: Option<DateTime<Utc>>) -> AccessKeyListItem {
+        AccessKeyListItem {
+            id: id.to_string(),
+            name: format!("{}-name", id),
+            created: Utc::now() - Duration::days(30),
+            expiration,
+            expired: false,
+        }
+    }
+
+    fn tracker(purge_after: Option<Duration>) -> KeyExpirationTracker {
+        KeyExpirationTracker::new(KeyExpirationPolicy::new(
+            vec![Duration::days(1), Duration::days(7), Duration::zero()],
+            purge_after,
+        ))
+    }
+
+    #[test]
+    fn test_policy_sorts_lead_times() {
+        let policy = KeyExpirationPolicy::new(
+            vec![Duration::hours(1), Duration::days(7), Duration::hours(1), Duration::seconds(-5)],
+            None,
+        );
+        assert_eq!(policy.lead_times(), &[Duration::days(7), Duration::hours(1)]);
+    }
+
+    #[test]
+    fn test_expiring_soon_once_per_threshold() {
+        let now = Utc::now();
+        let mut tracker = tracker(None);
+        let keys = vec![key("GK1", Some(now + Duration::days(3))), key("GK2", None)];
+
+        let notices = tracker.evaluate(&keys, now);
+        assert_eq!(notices.len(), 1);
+        assert!(matches!(
+            &notices[0],
+            ExpirationNotice::ExpiringSoon { id, lead_time, .. } if id == "GK1" && *lead_time == Duration::days(7)
+        ));
+        assert!(tracker.evaluate(&keys, now + Duration::hours(1)).is_empty());
+
+        let notices = tracker.evaluate(&keys, now + Duration::days(2) + Duration::hours(1));
+        assert!(matches!(
+            &notices[0],
+            ExpirationNotice::ExpiringSoon { lead_time, .. } if *lead_time == Duration::days(1)
+        ));
+    }
+
+    #[test]
+    fn test_skipped_thresholds_notify_smallest() {
+        let now = Utc::now();
+        let mut tracker = tracker(None);
+        let keys = vec![key("GK1", Some(now + Duration::hours(2)))];
+
+        let notices = tracker.evaluate(&keys, now);
+        assert_eq!(notices.len(), 1);
+        assert!(matches!(
+            &notices[0],
+            ExpirationNotice::ExpiringSoon { lead_time, .. } if *lead_time == Duration::days(1)
+        ));
+    }
+
+    #[test]
+    fn test_expired_once_and_reset_on_change() {
+        let now = Utc::now();
+        let mut tracker = tracker(None);
+        let mut keys = vec![key("GK1", Some(now - Duration::minutes(1)))];
+
+        assert!(matches!(tracker.evaluate(&keys, now)[..], [ExpirationNotice::Expired { .. }]));
+        assert!(tracker.evaluate(&keys, now).is_empty());
+
+        // 延長到期時間後重新追蹤
+        keys[0].expiration = Some(now + Duration::days(10));
+        assert!(tracker.evaluate(&keys, now).is_empty());
+        keys[0].expiration = Some(now - Duration::seconds(1));
+        assert_eq!(tracker.evaluate(&keys, now).len(), 1);
+    }
+
+    #[test]
+    fn test_purgeable_after_grace() {
+        let now = Utc::now();
+        let keys = vec![
+            key("GK1", Some(now - Duration::days(3))),
+            key("GK2", Some(now - Duration::hours(1))),
+            key("GK3", None),
+        ];
+
+        assert_eq!(tracker(Some(Duration::days(1))).purgeable(&keys, now), vec!["GK1"]);
+        assert!(tracker(None).purgeable(&keys, now).is_empty());
+    }
+}
@@ -0,0 +1,482 @@
+//! Access Key export / import file (Read Model)
+//!
+//! 批次匯出、匯入 Access Key 使用的檔案格式，支援 JSON 與 CSV
+//!
+//! - JSON：`KeyTransferRecord` 陣列，每個 Key 一筆
+//! - CSV：每個 (Key, Bucket) 授權一列，Key 欄位重複；沒有授權的 Key 只有一列且 Bucket 欄位留空。
+//!   Alias 以 `;` 分隔
+
+use serde::{Deserialize, Serialize};
+use crate::domain::entities::AccessKey;
+use crate::domain::errors::DomainError;
+
+/// 匯出 / 匯入檔案格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyTransferFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+/// 單一 Access Key 的匯出資料
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyTransferRecord {
+    pub access_key_id: String,
+    #[serde(default)]
+    pub name: String,
+    /// 匯出時未要求 secret 則為 None（此時檔案無法再匯入）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_access_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<String>,
+    #[serde(default)]
+    pub allow_create_bucket: bool,
+    #[serde(default)]
+    pub buckets: Vec<KeyTransferBucket>,
+}
+
+/// Key 對單一 Bucket 的授權
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyTransferBucket {
+    pub bucket_id: String,
+    #[serde(default)]
+    pub global_aliases: Vec<String>,
+    #[serde(default)]
+    pub local_aliases: Vec<String>,
+    #[serde(default)]
+    pub read: bool,
+    #[serde(default)]
+    pub write: bool,
+    #[serde(default)]
+    pub owner: bool,
+}
+
+/// 解析後的一筆資料，保留來源列號（從 1 開始）以便回報
+#[derive(Debug, Clone)]
+pub struct KeyTransferRow {
+    pub row: usize,
+    pub record: Result<KeyTransferRecord, String>,
+}
+
+impl KeyTransferRecord {
+    pub fn from_access_key(key: AccessKey, include_secret: bool) -> Self {
+        Self {
+            created: Some(key.created_string()),
+            expiration: key.expiration_string(),
+            access_key_id: key.id,
+            name: key.name,
+            secret_access_key: key.secret_access_key.filter(|_| include_secret),
+            allow_create_bucket: key.permissions.create_bucket,
+            buckets: key.buckets.into_iter().map(|b| KeyTransferBucket {
+                bucket_id: b.id,
+                global_aliases: b.global_aliases,
+                local_aliases: b.local_aliases,
+                read: b.permissions.read,
+                write: b.permissions.write,
+                owner: b.permissions.owner,
+            }).collect(),
+        }
+    }
+}
+
+// ============ Encoding ============
+
+const CSV_COLUMNS: [&str; 12] = [
+    "access_key_id",
+    "name",
+    "secret_access_key",
+    "created",
+    "expiration",
+    "allow_create_bucket",
+    "bucket_id",
+    "global_aliases",
+    "local_aliases",
+    "read",
+    "write",
+    "owner",
+];
+
+/// 逐筆輸出匯出檔，用於 streaming
+///
+/// 依序呼叫 `begin`、`record`（每筆 Key 一次）、`finish`，串接起來即為完整檔案
+#[derive(Debug)]
+pub struct KeyTransferEncoder {
+    format: KeyTransferFormat,
+    count: usize,
+}
+
+impl KeyTransferEncoder {
+    pub fn new(format: KeyTransferFormat) -> Self {
+        Self { format, count: 0 }
+    }
+
+    pub fn begin(&self) -> String {
+        match self.format {
+            KeyTransferFormat::Json => "[".to_string(),
+            KeyTransferFormat::Csv => csv_line(CSV_COLUMNS.iter().copied()),
+        }
+    }
+
+    pub fn record(&mut self, record: &KeyTransferRecord) -> String {
+        self.count += 1;
+        match self.format {
+            KeyTransferFormat::Json => {
+                let separator = if self.count == 1 { "\n  " } else { ",\n  " };
+                let json = serde_json::to_string(record).unwrap_or_else(|_| "{}".to_string());
+                format!("{}{}", separator, json)
+            }
+            KeyTransferFormat::Csv => encode_csv_record(record),
+        }
+    }
+
+    pub fn finish(&self) -> String {
+        match self.format {
+            KeyTransferFormat::Json if self.count > 0 => "\n]\n".to_string(),
+            KeyTransferFormat::Json => "]\n".to_string(),
+            KeyTransferFormat::Csv => String::new(),
+        }
+    }
+}
+
+fn encode_csv_record(record: &KeyTransferRecord) -> String {
+    let key_fields = [
+        record.access_key_id.clone(),
+        record.name.clone(),
+        record.secret_access_key.clone().unwrap_or_default(),
+        record.created.clone().unwrap_or_default(),
+        record.expiration.clone().unwrap_or_default(),
+        record.allow_create_bucket.to_string(),
+    ];
+
+    if record.buckets.is_empty() {
+        let empty = std::iter::repeat_n(String::new(), 6);
+        return csv_line(key_fields.iter().cloned().chain(empty));
+    }
+
+    record.buckets.iter().map(|b| {
+        let bucket_fields = [
+            b.bucket_id.clone(),
+            b.global_aliases.join(";"),
+            b.local_aliases.join(";"),
+            b.read.to_string(),
+            b.write.to_string(),
+            b.owner.to_string(),
+        ];
+        csv_line(key_fields.iter().cloned().chain(bucket_fields))
+    }).collect()
+}
+
+fn csv_line<I, S>(fields: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut line = fields
+        .into_iter()
+        .map(|f| csv_escape(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// ============ Decoding ============
+
+impl KeyTransferFormat {
+    /// 解析匯入檔
+    ///
+    /// 整份檔案無法解析時回傳錯誤；單筆資料的錯誤保留在 `KeyTransferRow::record`
+    pub fn decode(&self, content: &str) -> Result<Vec<KeyTransferRow>, DomainError> {
+        match self {
+            KeyTransferFormat::Json => decode_json(content),
+            KeyTransferFormat::Csv => decode_csv(content),
+        }
+    }
+}
+
+fn decode_json(content: &str) -> Result<Vec<KeyTransferRow>, DomainError> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(content)
+        .map_err(|e| DomainError::ValidationError(format!("Invalid JSON key file: {}", e)))?;
+
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| KeyTransferRow {
+            row: i + 1,
+            record: serde_json::from_value(v).map_err(|e| e.to_string()),
+        })
+        .collect())
+}
+
+fn decode_csv(content: &str) -> Result<Vec<KeyTransferRow>, DomainError> {
+    let mut lines = parse_csv(content)
+        .map_err(|e| DomainError::ValidationError(format!("Invalid CSV key file: {}", e)))?
+        .into_iter();
+
+    let (_, header) = lines.next().ok_or_else(|| {
+        DomainError::ValidationError("CSV key file is empty".to_string())
+    })?;
+    let columns: Vec<Option<usize>> = CSV_COLUMNS
+        .iter()
+        .map(|c| header.iter().position(|h| h.trim() == *c))
+        .collect();
+    if columns[0].is_none() {
+        return Err(DomainError::ValidationError(
+            "CSV key file is missing the access_key_id column".to_string(),
+        ));
+    }
+
+    // 連續且 access_key_id 相同的列合併為同一個 Key
+    let mut rows: Vec<KeyTransferRow> = Vec::new();
+    for (line_no, fields) in lines {
+        if fields.iter().all(|f| f.is_empty()) {
+            continue;
+        }
+        let get = |i: usize| column(&fields, &columns, i);
+
+        let bucket = match parse_csv_bucket(&fields, &columns) {
+            Ok(bucket) => bucket,
+            Err(e) => {
+                rows.push(KeyTransferRow { row: line_no, record: Err(e) });
+                continue;
+            }
+        };
+
+        if let Some(KeyTransferRow { record: Ok(prev), .. }) = rows.last_mut() {
+            if prev.access_key_id == get(0) {
+                prev.buckets.extend(bucket);
+                continue;
+            }
+        }
+
+        let record = parse_csv_bool(get(5), "allow_create_bucket").map(|allow_create_bucket| {
+            KeyTransferRecord {
+                access_key_id: get(0).to_string(),
+                name: get(1).to_string(),
+                secret_access_key: non_empty(get(2)),
+                created: non_empty(get(3)),
+                expiration: non_empty(get(4)),
+                allow_create_bucket,
+                buckets: bucket.into_iter().collect(),
+            }
+        });
+        rows.push(KeyTransferRow { row: line_no, record });
+    }
+
+    Ok(rows)
+}
+
+/// 取得指定欄位的值，欄位不存在時為空字串
+fn column<'a>(fields: &'a [String], columns: &[Option<usize>], i: usize) -> &'a str {
+    columns[i].and_then(|c| fields.get(c)).map(|s| s.as_str()).unwrap_or("")
+}
+
+fn parse_csv_bucket(fields: &[String], columns: &[Option<usize>]) -> Result<Option<KeyTransferBucket>, String> {
+    let get = |i: usize| column(fields, columns, i);
+    let bucket_id = get(6);
+    if bucket_id.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(KeyTransferBucket {
+        bucket_id: bucket_id.to_string(),
+        global_aliases: split_aliases(get(7)),
+        local_aliases: split_aliases(get(8)),
+        read: parse_csv_bool(get(9), "read")?,
+        write: parse_csv_bool(get(10), "write")?,
+        owner: parse_csv_bool(get(11), "owner")?,
+    }))
+}
+
+fn parse_csv_bool(value: &str, column: &str) -> Result<bool, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "" | "false" | "0" => Ok(false),
+        "true" | "1" => Ok(true),
+        other => Err(format!("Invalid boolean '{}' in column {}", other, column)),
+    }
+}
+
+fn split_aliases(value: &str) -> Vec<String> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// RFC 4180 CSV 解析，回傳 (起始行號, 欄位)
+fn parse_csv(content: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut fields)));
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(format!("unterminated quoted field starting at line {}", record_line));
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<KeyTransferRecord> {
+        vec![
+            KeyTransferRecord {
+                access_key_id: "GK31c2f218a2e44f485b94239e".to_string(),
+                name: "ci, \"nightly\"".to_string(),
+                secret_access_key: Some("b892c0665f0ada8a4755dae98baa3b133590e11dae3bcc1f9d769d67f16c3835".to_string()),
+                created: Some("2026-01-01T00:00:00+00:00".to_string()),
+                expiration: None,
+                allow_create_bucket: true,
+                buckets: vec![
+                    KeyTransferBucket {
+                        bucket_id: "b1".to_string(),
+                        global_aliases: vec!["assets".to_string(), "static".to_string()],
+                        local_aliases: vec![],
+                        read: true,
+                        write: true,
+                        owner: false,
+                    },
+                    KeyTransferBucket {
+                        bucket_id: "b2".to_string(),
+                        global_aliases: vec![],
+                        local_aliases: vec!["scratch".to_string()],
+                        read: true,
+                        write: false,
+                        owner: true,
+                    },
+                ],
+            },
+            KeyTransferRecord {
+                access_key_id: "GK0000000000000000000000aa".to_string(),
+                name: "no-grants".to_string(),
+                secret_access_key: None,
+                created: None,
+                expiration: Some("2030-01-01T00:00:00+00:00".to_string()),
+                allow_create_bucket: false,
+                buckets: vec![],
+            },
+        ]
+    }
+
+    fn encode(format: KeyTransferFormat, records: &[KeyTransferRecord]) -> String {
+        let mut encoder = KeyTransferEncoder::new(format);
+        let mut out = encoder.begin();
+        for r in records {
+            out.push_str(&encoder.record(r));
+        }
+        out.push_str(&encoder.finish());
+        out
+    }
+
+    fn records(rows: Vec<KeyTransferRow>) -> Vec<KeyTransferRecord> {
+        rows.into_iter().map(|r| r.record.unwrap()).collect()
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let content = encode(KeyTransferFormat::Json, &sample());
+        let rows = KeyTransferFormat::Json.decode(&content).unwrap();
+        assert_eq!(rows[1].row, 2);
+        assert_eq!(records(rows), sample());
+    }
+
+    #[test]
+    fn test_json_empty() {
+        let content = encode(KeyTransferFormat::Json, &[]);
+        assert!(KeyTransferFormat::Json.decode(&content).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let content = encode(KeyTransferFormat::Csv, &sample());
+        assert_eq!(content.lines().count(), 4);
+
+        let rows = KeyTransferFormat::Csv.decode(&content).unwrap();
+        assert_eq!(rows[0].row, 2);
+        assert_eq!(rows[1].row, 4);
+        assert_eq!(records(rows), sample());
+    }
+
+    #[test]
+    fn test_csv_row_errors_are_kept() {
+        let content = "access_key_id,bucket_id,read\nGK1,b1,yes\nGK2,,\n";
+        let rows = KeyTransferFormat::Csv.decode(content).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].row, 2);
+        assert!(rows[0].record.is_err());
+        assert_eq!(rows[1].record.as_ref().unwrap().access_key_id, "GK2");
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert!(KeyTransferFormat::Json.decode("{").is_err());
+        assert!(KeyTransferFormat::Csv.decode("").is_err());
+        assert!(KeyTransferFormat::Csv.decode("name\nfoo\n").is_err());
+        assert!(KeyTransferFormat::Csv.decode("access_key_id\n\"GK1\n").is_err());
+    }
+
+    #[test]
+    fn test_export_without_secret() {
+        let mut record = sample().remove(0);
+        record.secret_access_key = None;
+        let content = encode(KeyTransferFormat::Json, &[record]);
+        assert!(!content.contains("secret_access_key"));
+    }
+}
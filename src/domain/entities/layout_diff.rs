@@ -0,0 +1,315 @@
+//! Cluster layout diff
+//!
+//! 比較兩個布局狀態（歷史版本、目前布局或暫存布局），產生可呈現為變更紀錄的差異。
+//! Garage 的布局歷史不含布局參數，歷史版本的 zone redundancy 視為未知，不列入比較。
+
+use std::collections::BTreeMap;
+use serde::Serialize;
+use crate::domain::entities::{
+    ClusterLayout, LayoutParameters, LayoutRole, LayoutVersion, RoleChangeType, ZoneRedundancy,
+};
+
+/// 用於比較的布局狀態
+#[derive(Debug, Clone)]
+pub struct LayoutState {
+    /// 暫存布局尚未套用，沒有版本號
+    pub version: Option<i64>,
+    pub roles: Vec<LayoutRole>,
+    /// `None` 表示未知
+    pub parameters: Option<LayoutParameters>,
+}
+
+/// 節點角色的變更類型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum LayoutRoleDiffKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// 單一節點的角色差異
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutRoleDiff {
+    pub node_id: String,
+    pub kind: LayoutRoleDiffKind,
+    pub before: Option<LayoutRole>,
+    pub after: Option<LayoutRole>,
+    pub zone_changed: bool,
+    pub capacity_changed: bool,
+    pub tags_added: Vec<String>,
+    pub tags_removed: Vec<String>,
+}
+
+/// Zone redundancy 的變更
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneRedundancyChange {
+    pub before: Option<ZoneRedundancy>,
+    pub after: Option<ZoneRedundancy>,
+}
+
+/// 單一 zone 的節點數與容量（bytes），gateway 節點不計入容量
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneCapacity {
+    pub zone: String,
+    pub nodes_before: usize,
+    pub nodes_after: usize,
+    pub capacity_before: u64,
+    pub capacity_after: u64,
+}
+
+/// 兩個布局狀態之間的差異
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutDiff {
+    pub from_version: Option<i64>,
+    pub to_version: Option<i64>,
+    /// 依節點 ID 排序
+    pub roles: Vec<LayoutRoleDiff>,
+    /// 任一方參數未知或未變更時為 `None`
+    pub zone_redundancy: Option<ZoneRedundancyChange>,
+    /// 依 zone 名稱排序，包含兩邊出現過的所有 zone
+    pub zones: Vec<ZoneCapacity>,
+}
+
+impl ClusterLayout {
+    /// 目前生效的布局
+    pub fn current_state(&self) -> LayoutState {
+        LayoutState {
+            version: Some(self.version),
+            roles: self.roles.clone(),
+            parameters: Some(self.parameters.clone().unwrap_or(LayoutParameters { zone_redundancy: None })),
+        }
+    }
+
+    /// 套用暫存變更後的布局
+    pub fn staged_state(&self) -> LayoutState {
+        let mut roles: BTreeMap<&str, LayoutRole> = self.roles.iter().map(|r| (r.id.as_str(), r.clone())).collect();
+        for staged in &self.staged_role_changes {
+            match &staged.change {
+                RoleChangeType::Remove { .. } => {
+                    roles.remove(staged.id.as_str());
+                }
+                RoleChangeType::Assign { zone, capacity, tags } => {
+                    roles.insert(
+                        staged.id.as_str(),
+                        LayoutRole {
+                            id: staged.id.clone(),
+                            zone: zone.clone(),
+                            capacity: *capacity,
+                            tags: tags.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        LayoutState {
+            version: None,
+            roles: roles.into_values().collect(),
+            parameters: self.staged_parameters.clone().or_else(|| self.current_state().parameters),
+        }
+    }
+}
+
+impl LayoutVersion {
+    pub fn state(&self) -> LayoutState {
+        LayoutState {
+            version: Some(self.version),
+            roles: self.roles.clone(),
+            parameters: None,
+        }
+    }
+}
+
+impl LayoutDiff {
+    pub fn between(from: &LayoutState, to: &LayoutState) -> Self {
+        let before: BTreeMap<&str, &LayoutRole> = from.roles.iter().map(|r| (r.id.as_str(), r)).collect();
+        let after: BTreeMap<&str, &LayoutRole> = to.roles.iter().map(|r| (r.id.as_str(), r)).collect();
+
+        let mut ids: Vec<&str> = before.keys().chain(after.keys()).copied().collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        let roles = ids
+            .into_iter()
+            .filter_map(|id| role_diff(id, before.get(id).copied(), after.get(id).copied()))
+            .collect();
+
+        let zone_redundancy = match (&from.parameters, &to.parameters) {
+            (Some(b), Some(a)) if b.zone_redundancy != a.zone_redundancy => Some(ZoneRedundancyChange {
+                before: b.zone_redundancy.clone(),
+                after: a.zone_redundancy.clone(),
+            }),
+            _ => None,
+        };
+
+        Self {
+            from_version: from.version,
+            to_version: to.version,
+            roles,
+            zone_redundancy,
+            zones: zone_capacities(&from.roles, &to.roles),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roles.is_empty() && self.zone_redundancy.is_none()
+    }
+}
+
+fn role_diff(id: &str, before: Option<&LayoutRole>, after: Option<&LayoutRole>) -> Option<LayoutRoleDiff> {
+    let kind = match (before, after) {
+        (None, Some(_)) => LayoutRoleDiffKind::Added,
+        (Some(_), None) => LayoutRoleDiffKind::Removed,
+        (Some(b), Some(a)) if b.zone == a.zone && b.capacity == a.capacity && sorted(&b.tags) == sorted(&a.tags) => {
+            return None;
+        }
+        (Some(_), Some(_)) => LayoutRoleDiffKind::Modified,
+        (None, None) => return None,
+    };
+
+    let tags = |role: Option<&LayoutRole>| role.map(|r| r.tags.clone()).unwrap_or_default();
+    let (tags_before, tags_after) = (tags(before), tags(after));
+
+    Some(LayoutRoleDiff {
+        node_id: id.to_string(),
+        kind,
+        zone_changed: before.map(|r| &r.zone) != after.map(|r| &r.zone),
+        capacity_changed: before.map(|r| r.capacity) != after.map(|r| r.capacity),
+        tags_added: tags_after.iter().filter(|t| !tags_before.contains(t)).cloned().collect(),
+        tags_removed: tags_before.iter().filter(|t| !tags_after.contains(t)).cloned().collect(),
+        before: before.cloned(),
+        after: after.cloned(),
+    })
+}
+
+fn sorted(tags: &[String]) -> Vec<&String> {
+    let mut tags: Vec<&String> = tags.iter().collect();
+    tags.sort_unstable();
+    tags
+}
+
+fn zone_capacities(before: &[LayoutRole], after: &[LayoutRole]) -> Vec<ZoneCapacity> {
+    let mut zones: BTreeMap<&str, ZoneCapacity> = BTreeMap::new();
+    let capacity = |role: &LayoutRole| role.capacity.unwrap_or(0).max(0) as u64;
+
+    for role in before {
+        let zone = zones.entry(role.zone.as_str()).or_insert_with(|| ZoneCapacity::empty(&role.zone));
+        zone.nodes_before += 1;
+        zone.capacity_before += capacity(role);
+    }
+    for role in after {
+        let zone = zones.entry(role.zone.as_str()).or_insert_with(|| ZoneCapacity::empty(&role.zone));
+        zone.nodes_after += 1;
+        zone.capacity_after += capacity(role);
+    }
+    zones.into_values().collect()
+}
+
+impl ZoneCapacity {
+    fn empty(zone: &str) -> Self {
+        Self {
+            zone: zone.to_string(),
+            nodes_before: 0,
+            nodes_after: 0,
+            capacity_before: 0,
+            capacity_after: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::StagedRoleChange;
+
+    fn role(id: &str, zone: &str, capacity: Option<i64>, tags: &[&str]) -> LayoutRole {
+        LayoutRole {
+            id: id.to_string(),
+            zone: zone.to_string(),
+            capacity,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_diff_between_versions() {
+        let from = LayoutVersion {
+            version: 3,
+            partition_size: 0,
+            roles: vec![
+                role("a", "dc1", Some(100), &["ssd"]),
+                role("b", "dc1", Some(100), &[]),
+                role("c", "dc2", Some(100), &["x", "y"]),
+            ],
+        };
+        let to = LayoutVersion {
+            version: 4,
+            partition_size: 0,
+            roles: vec![
+                role("a", "dc1", Some(200), &["ssd"]),
+                role("c", "dc2", Some(100), &["y", "x"]),
+                role("d", "dc3", None, &["gw"]),
+            ],
+        };
+
+        let diff = LayoutDiff::between(&from.state(), &to.state());
+        assert_eq!((diff.from_version, diff.to_version), (Some(3), Some(4)));
+        assert_eq!(
+            diff.roles.iter().map(|r| (r.node_id.as_str(), r.kind)).collect::<Vec<_>>(),
+            vec![
+                ("a", LayoutRoleDiffKind::Modified),
+                ("b", LayoutRoleDiffKind::Removed),
+                ("d", LayoutRoleDiffKind::Added),
+            ]
+        );
+        assert!(diff.roles[0].capacity_changed && !diff.roles[0].zone_changed);
+        assert_eq!(diff.roles[2].tags_added, vec!["gw".to_string()]);
+        // 歷史版本沒有參數
+        assert_eq!(diff.zone_redundancy, None);
+
+        assert_eq!(
+            diff.zones,
+            vec![
+                ZoneCapacity { zone: "dc1".into(), nodes_before: 2, nodes_after: 1, capacity_before: 200, capacity_after: 200 },
+                ZoneCapacity { zone: "dc2".into(), nodes_before: 1, nodes_after: 1, capacity_before: 100, capacity_after: 100 },
+                ZoneCapacity { zone: "dc3".into(), nodes_before: 0, nodes_after: 1, capacity_before: 0, capacity_after: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_current_against_staged() {
+        let layout = ClusterLayout {
+            version: 5,
+            partition_size: 0,
+            roles: vec![role("a", "dc1", Some(100), &[]), role("b", "dc2", Some(100), &[])],
+            staged_role_changes: vec![
+                StagedRoleChange { id: "a".into(), change: RoleChangeType::Assign { zone: "dc3".into(), capacity: Some(100), tags: vec![] } },
+                StagedRoleChange { id: "b".into(), change: RoleChangeType::Remove { remove: true } },
+            ],
+            parameters: Some(LayoutParameters { zone_redundancy: Some(ZoneRedundancy::Maximum { maximum: true }) }),
+            staged_parameters: Some(LayoutParameters { zone_redundancy: Some(ZoneRedundancy::Value(2)) }),
+        };
+
+        let diff = LayoutDiff::between(&layout.current_state(), &layout.staged_state());
+        assert_eq!(diff.to_version, None);
+        assert_eq!(diff.roles.len(), 2);
+        assert!(diff.roles[0].zone_changed && diff.roles[0].kind == LayoutRoleDiffKind::Modified);
+        assert_eq!(diff.roles[1].kind, LayoutRoleDiffKind::Removed);
+        assert_eq!(
+            diff.zone_redundancy,
+            Some(ZoneRedundancyChange {
+                before: Some(ZoneRedundancy::Maximum { maximum: true }),
+                after: Some(ZoneRedundancy::Value(2)),
+            })
+        );
+
+        // 沒有暫存變更時兩者相同
+        let applied = ClusterLayout { staged_role_changes: vec![], staged_parameters: None, ..layout };
+        assert!(LayoutDiff::between(&applied.current_state(), &applied.staged_state()).is_empty());
+    }
+}
@@ -0,0 +1,494 @@
+//! Local layout simulation
+//!
+//! 在後端重現 Garage 的 partition 分配：以 max-flow 找出最大的 partition 大小，
+//! 再以 min-cost flow 盡量保留原本的分配，用於 what-if 試算，不會暫存任何變更。
+//! Admin API 不提供實際的 partition 分配，搬移數量是與「目前布局的模擬結果」比較的估計值。
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use serde::Serialize;
+use crate::domain::entities::{LayoutRole, ZoneRedundancy};
+use crate::domain::errors::DomainError;
+
+/// Garage 固定將資料切為 256 個 partition
+pub const NB_PARTITIONS: usize = 256;
+
+/// 每個 partition 分配到的節點 ID（已排序）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartitionAssignment(Vec<Vec<String>>);
+
+/// 模擬結果中的單一節點
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedNode {
+    pub id: String,
+    pub zone: String,
+    /// gateway 節點為 `None`
+    pub capacity: Option<u64>,
+    pub partitions: usize,
+    /// partition 數 × partition 大小（bytes）
+    pub used_capacity: u64,
+}
+
+/// 一次模擬的結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutSimulation {
+    pub replication_factor: usize,
+    /// 實際採用的 zone redundancy（`maximum` 已換算為 zone 數）
+    pub zone_redundancy: usize,
+    /// 每個 partition 的大小（bytes）
+    pub partition_size: u64,
+    /// 可使用的容量，即 partition 大小 × partition 數
+    pub usable_capacity: u64,
+    /// 依節點 ID 排序
+    pub nodes: Vec<SimulatedNode>,
+    #[serde(skip)]
+    pub assignment: PartitionAssignment,
+}
+
+/// 模擬結果與目前布局的比較
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutSimulationReport {
+    pub simulation: LayoutSimulation,
+    /// 目前布局無法分配（例如尚未指派任何儲存節點）時為 `None`
+    pub current_usable_capacity: Option<u64>,
+    /// 至少有一個副本換到其他節點的 partition 數
+    pub partitions_moved: usize,
+    /// 換到其他節點的副本總數
+    pub replicas_moved: usize,
+}
+
+impl PartitionAssignment {
+    pub fn partitions(&self) -> &[Vec<String>] {
+        &self.0
+    }
+
+    /// 與另一個分配相比，(有變動的 partition 數, 需要搬移的副本數)
+    pub fn moves_to(&self, other: &PartitionAssignment) -> (usize, usize) {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(before, after)| after.iter().filter(|n| !before.contains(n)).count())
+            .filter(|moved| *moved > 0)
+            .fold((0, 0), |(partitions, replicas), moved| (partitions + 1, replicas + moved))
+    }
+}
+
+impl LayoutSimulation {
+    /// 計算 `roles` 的 partition 分配
+    ///
+    /// `previous` 為原本的分配，會在不降低 partition 大小的前提下盡量保留
+    ///
+    /// # 業務規則
+    /// - 容量為空或 0 的節點視為 gateway，不分配 partition
+    /// - 儲存節點數必須不少於 replication factor
+    /// - 每個 partition 至少分散到 zone redundancy 個 zone，未指定時為 `maximum`
+    pub fn run(
+        roles: &[LayoutRole],
+        replication_factor: usize,
+        zone_redundancy: Option<&ZoneRedundancy>,
+        previous: Option<&PartitionAssignment>,
+    ) -> Result<Self, DomainError> {
+        let mut seen = HashSet::new();
+        if let Some(role) = roles.iter().find(|r| !seen.insert(r.id.as_str())) {
+            return Err(DomainError::ValidationError(format!("Duplicate node {}", role.id)));
+        }
+
+        let mut storage: Vec<(&LayoutRole, u64)> = roles
+            .iter()
+            .filter_map(|r| r.capacity.filter(|c| *c > 0).map(|c| (r, c as u64)))
+            .collect();
+        storage.sort_by(|a, b| a.0.id.cmp(&b.0.id));
+
+        if replication_factor == 0 {
+            return Err(DomainError::ValidationError("Replication factor must be at least 1".to_string()));
+        }
+        if storage.len() < replication_factor {
+            return Err(DomainError::ValidationError(format!(
+                "Replication factor {} needs at least {} storage nodes, got {}",
+                replication_factor,
+                replication_factor,
+                storage.len()
+            )));
+        }
+
+        let zones: Vec<&str> = storage
+            .iter()
+            .map(|(r, _)| r.zone.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let zone_redundancy = match zone_redundancy {
+            None | Some(ZoneRedundancy::Maximum { .. }) => zones.len().min(replication_factor),
+            Some(ZoneRedundancy::Value(v)) if *v < 1 || *v as usize > replication_factor => {
+                return Err(DomainError::ValidationError(format!(
+                    "Zone redundancy must be between 1 and the replication factor ({}), got {}",
+                    replication_factor, v
+                )));
+            }
+            Some(ZoneRedundancy::Value(v)) => *v as usize,
+        };
+        if zone_redundancy > zones.len() {
+            return Err(DomainError::ValidationError(format!(
+                "Zone redundancy {} needs at least {} zones with storage nodes, got {}",
+                zone_redundancy,
+                zone_redundancy,
+                zones.len()
+            )));
+        }
+
+        let problem = AssignmentProblem {
+            nodes: storage
+                .iter()
+                .map(|(r, c)| (zones.iter().position(|z| *z == r.zone).unwrap_or(0), *c))
+                .collect(),
+            zones: zones.len(),
+            replication_factor,
+            zone_redundancy,
+        };
+
+        // 1. 二分搜尋可行的最大 partition 大小
+        let total: u64 = storage.iter().map(|(_, c)| c).sum();
+        let mut low = 1;
+        let mut high = total / (replication_factor * NB_PARTITIONS) as u64 + 1;
+        if !problem.feasible(low) {
+            return Err(DomainError::ValidationError(
+                "Not enough capacity to place every partition replica".to_string(),
+            ));
+        }
+        while low + 1 < high {
+            let mid = low + (high - low) / 2;
+            if problem.feasible(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let partition_size = low;
+
+        // 2. 在此大小下盡量保留原本的分配
+        let ids: Vec<&str> = storage.iter().map(|(r, _)| r.id.as_str()).collect();
+        let kept = |p: usize, n: usize| previous.is_some_and(|prev| prev.0.get(p).is_some_and(|ns| ns.iter().any(|id| id == ids[n])));
+        let assigned = problem.assign(partition_size, kept);
+
+        let assignment = PartitionAssignment(
+            assigned
+                .iter()
+                .map(|nodes| {
+                    let mut ids: Vec<String> = nodes.iter().map(|n| ids[*n].to_string()).collect();
+                    ids.sort();
+                    ids
+                })
+                .collect(),
+        );
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for id in assignment.0.iter().flatten() {
+            *counts.entry(id.as_str()).or_default() += 1;
+        }
+
+        let mut nodes: Vec<SimulatedNode> = roles
+            .iter()
+            .map(|r| {
+                let partitions = counts.get(r.id.as_str()).copied().unwrap_or(0);
+                SimulatedNode {
+                    id: r.id.clone(),
+                    zone: r.zone.clone(),
+                    capacity: r.capacity.filter(|c| *c > 0).map(|c| c as u64),
+                    partitions,
+                    used_capacity: partitions as u64 * partition_size,
+                }
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(Self {
+            replication_factor,
+            zone_redundancy,
+            partition_size,
+            usable_capacity: partition_size * NB_PARTITIONS as u64,
+            nodes,
+            assignment,
+        })
+    }
+}
+
+/// 與 Garage 相同的流量網路：
+/// Source → Pup(p)（zone_redundancy）/ Pdown(p)（rf - zone_redundancy）→ PZ(p, z) → N(n) → Sink，
+/// Pup(p) → PZ(p, z) 容量為 1 以確保分散到足夠的 zone，N(n) → Sink 容量為 ⌊節點容量 / partition 大小⌋
+struct AssignmentProblem {
+    /// (zone index, 容量)
+    nodes: Vec<(usize, u64)>,
+    zones: usize,
+    replication_factor: usize,
+    zone_redundancy: usize,
+}
+
+impl AssignmentProblem {
+    fn feasible(&self, partition_size: u64) -> bool {
+        let (mut graph, _) = self.graph(partition_size, |_, _| false);
+        graph.max_flow(SOURCE, SINK) == (self.replication_factor * NB_PARTITIONS) as i64
+    }
+
+    /// 回傳每個 partition 分配到的節點 index；`kept(p, n)` 為 true 的分配成本較低
+    fn assign(&self, partition_size: u64, kept: impl Fn(usize, usize) -> bool) -> Vec<Vec<usize>> {
+        let (mut graph, placements) = self.graph(partition_size, kept);
+        graph.min_cost_max_flow(SOURCE, SINK);
+
+        let mut assigned = vec![Vec::new(); NB_PARTITIONS];
+        for (p, n, edge) in placements {
+            if graph.edges[edge].cap == 0 {
+                assigned[p].push(n);
+            }
+        }
+        assigned
+    }
+
+    /// 建立流量網路，同時回傳 PZ(p, z) → N(n) 的邊 (partition, node, edge)
+    fn graph(&self, partition_size: u64, kept: impl Fn(usize, usize) -> bool) -> (FlowGraph, Vec<(usize, usize, usize)>) {
+        let rf = self.replication_factor as i64;
+        let zr = self.zone_redundancy as i64;
+        let pup = |p: usize| 2 + p;
+        let pdown = |p: usize| 2 + NB_PARTITIONS + p;
+        let pz = |p: usize, z: usize| 2 + 2 * NB_PARTITIONS + p * self.zones + z;
+        let node = |n: usize| 2 + (2 + self.zones) * NB_PARTITIONS + n;
+
+        let mut graph = FlowGraph::new(node(self.nodes.len()));
+        let mut placements = Vec::with_capacity(NB_PARTITIONS * self.nodes.len());
+        for p in 0..NB_PARTITIONS {
+            graph.add_edge(SOURCE, pup(p), zr, 0);
+            graph.add_edge(SOURCE, pdown(p), rf - zr, 0);
+            for z in 0..self.zones {
+                graph.add_edge(pup(p), pz(p, z), 1, 0);
+                graph.add_edge(pdown(p), pz(p, z), rf - zr, 0);
+            }
+            for (n, (z, _)) in self.nodes.iter().enumerate() {
+                let cost = if kept(p, n) { -1 } else { 0 };
+                placements.push((p, n, graph.add_edge(pz(p, *z), node(n), 1, cost)));
+            }
+        }
+        for (n, (_, capacity)) in self.nodes.iter().enumerate() {
+            let slots = (capacity / partition_size).min(NB_PARTITIONS as u64) as i64;
+            graph.add_edge(node(n), SINK, slots, 0);
+        }
+        (graph, placements)
+    }
+}
+
+const SOURCE: usize = 0;
+const SINK: usize = 1;
+
+struct FlowEdge {
+    to: usize,
+    cap: i64,
+    cost: i64,
+}
+
+/// 殘量網路，邊 `e` 的反向邊為 `e ^ 1`
+struct FlowGraph {
+    adjacency: Vec<Vec<usize>>,
+    edges: Vec<FlowEdge>,
+}
+
+impl FlowGraph {
+    fn new(vertices: usize) -> Self {
+        Self {
+            adjacency: vec![Vec::new(); vertices],
+            edges: Vec::new(),
+        }
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, cap: i64, cost: i64) -> usize {
+        let id = self.edges.len();
+        self.edges.push(FlowEdge { to, cap, cost });
+        self.edges.push(FlowEdge { to: from, cap: 0, cost: -cost });
+        self.adjacency[from].push(id);
+        self.adjacency[to].push(id + 1);
+        id
+    }
+
+    /// Dinic
+    fn max_flow(&mut self, source: usize, sink: usize) -> i64 {
+        let mut total = 0;
+        loop {
+            let mut level = vec![-1i32; self.adjacency.len()];
+            level[source] = 0;
+            let mut queue = VecDeque::from([source]);
+            while let Some(v) = queue.pop_front() {
+                for &e in &self.adjacency[v] {
+                    let edge = &self.edges[e];
+                    if edge.cap > 0 && level[edge.to] < 0 {
+                        level[edge.to] = level[v] + 1;
+                        queue.push_back(edge.to);
+                    }
+                }
+            }
+            if level[sink] < 0 {
+                return total;
+            }
+
+            let mut next = vec![0; self.adjacency.len()];
+            loop {
+                let pushed = self.augment(source, sink, i64::MAX, &level, &mut next);
+                if pushed == 0 {
+                    break;
+                }
+                total += pushed;
+            }
+        }
+    }
+
+    fn augment(&mut self, v: usize, sink: usize, limit: i64, level: &[i32], next: &mut [usize]) -> i64 {
+        if v == sink {
+            return limit;
+        }
+        while next[v] < self.adjacency[v].len() {
+            let e = self.adjacency[v][next[v]];
+            let (to, cap) = (self.edges[e].to, self.edges[e].cap);
+            if cap > 0 && level[to] == level[v] + 1 {
+                let pushed = self.augment(to, sink, limit.min(cap), level, next);
+                if pushed > 0 {
+                    self.edges[e].cap -= pushed;
+                    self.edges[e ^ 1].cap += pushed;
+                    return pushed;
+                }
+            }
+            next[v] += 1;
+        }
+        0
+    }
+
+    /// 以最短路徑（SPFA）逐次增廣，得到成本最低的最大流
+    fn min_cost_max_flow(&mut self, source: usize, sink: usize) -> i64 {
+        let vertices = self.adjacency.len();
+        let mut total = 0;
+        loop {
+            let mut dist = vec![i64::MAX; vertices];
+            let mut via = vec![usize::MAX; vertices];
+            let mut queued = vec![false; vertices];
+            let mut queue = VecDeque::from([source]);
+            dist[source] = 0;
+            while let Some(v) = queue.pop_front() {
+                queued[v] = false;
+                for &e in &self.adjacency[v] {
+                    let edge = &self.edges[e];
+                    if edge.cap > 0 && dist[v] + edge.cost < dist[edge.to] {
+                        dist[edge.to] = dist[v] + edge.cost;
+                        via[edge.to] = e;
+                        if !queued[edge.to] {
+                            queued[edge.to] = true;
+                            queue.push_back(edge.to);
+                        }
+                    }
+                }
+            }
+            if dist[sink] == i64::MAX {
+                return total;
+            }
+
+            let mut pushed = i64::MAX;
+            let mut v = sink;
+            while v != source {
+                let e = via[v];
+                pushed = pushed.min(self.edges[e].cap);
+                v = self.edges[e ^ 1].to;
+            }
+            let mut v = sink;
+            while v != source {
+                let e = via[v];
+                self.edges[e].cap -= pushed;
+                self.edges[e ^ 1].cap += pushed;
+                v = self.edges[e ^ 1].to;
+            }
+            total += pushed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: i64 = 1_000_000_000;
+
+    fn role(id: &str, zone: &str, capacity: Option<i64>) -> LayoutRole {
+        LayoutRole {
+            id: id.to_string(),
+            zone: zone.to_string(),
+            capacity,
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn test_simulation_spreads_replicas_across_zones() {
+        let roles = vec![
+            role("a", "dc1", Some(100 * GB)),
+            role("b", "dc2", Some(100 * GB)),
+            role("c", "dc3", Some(100 * GB)),
+            role("gw", "dc1", None),
+        ];
+
+        let simulation = LayoutSimulation::run(&roles, 3, None, None).unwrap();
+        assert_eq!(simulation.zone_redundancy, 3);
+        assert_eq!(simulation.partition_size, (100 * GB) as u64 / NB_PARTITIONS as u64);
+        assert_eq!(simulation.usable_capacity, simulation.partition_size * NB_PARTITIONS as u64);
+        assert_eq!(
+            simulation.nodes.iter().map(|n| (n.id.as_str(), n.partitions)).collect::<Vec<_>>(),
+            vec![("a", 256), ("b", 256), ("c", 256), ("gw", 0)]
+        );
+        assert!(simulation.assignment.partitions().iter().all(|p| p.len() == 3));
+    }
+
+    #[test]
+    fn test_simulation_is_limited_by_smallest_zone() {
+        // dc2 只有一個節點，每個 partition 都必須在 dc2 放一份副本
+        let roles = vec![
+            role("a", "dc1", Some(100 * GB)),
+            role("b", "dc1", Some(100 * GB)),
+            role("c", "dc2", Some(50 * GB)),
+        ];
+
+        let simulation = LayoutSimulation::run(&roles, 2, Some(&ZoneRedundancy::Maximum { maximum: true }), None).unwrap();
+        assert_eq!(simulation.partition_size, (50 * GB) as u64 / NB_PARTITIONS as u64);
+        assert_eq!(simulation.nodes[2].partitions, 256);
+
+        // 只要求一個 zone 時可以使用 dc1 的全部容量
+        let relaxed = LayoutSimulation::run(&roles, 2, Some(&ZoneRedundancy::Value(1)), None).unwrap();
+        assert!(relaxed.partition_size > simulation.partition_size);
+    }
+
+    #[test]
+    fn test_simulation_keeps_previous_assignment() {
+        let roles = vec![
+            role("a", "dc1", Some(100 * GB)),
+            role("b", "dc2", Some(100 * GB)),
+            role("c", "dc3", Some(100 * GB)),
+        ];
+        let current = LayoutSimulation::run(&roles, 2, None, None).unwrap();
+
+        // 相同布局不會搬移
+        let same = LayoutSimulation::run(&roles, 2, None, Some(&current.assignment)).unwrap();
+        assert_eq!(current.assignment.moves_to(&same.assignment), (0, 0));
+
+        // 新增節點只搬移必要的副本
+        let mut grown = roles.clone();
+        grown.push(role("d", "dc4", Some(100 * GB)));
+        let scenario = LayoutSimulation::run(&grown, 2, None, Some(&current.assignment)).unwrap();
+        let (partitions, replicas) = current.assignment.moves_to(&scenario.assignment);
+        assert_eq!(replicas, scenario.nodes[3].partitions);
+        assert!(partitions <= replicas && replicas <= NB_PARTITIONS);
+    }
+
+    #[test]
+    fn test_simulation_rejects_impossible_layouts() {
+        let roles = vec![role("a", "dc1", Some(GB)), role("b", "dc1", Some(GB))];
+
+        assert!(LayoutSimulation::run(&roles, 3, None, None).is_err());
+        assert!(LayoutSimulation::run(&roles, 2, Some(&ZoneRedundancy::Value(2)), None).is_err());
+        assert!(LayoutSimulation::run(&roles, 2, Some(&ZoneRedundancy::Value(3)), None).is_err());
+        assert!(LayoutSimulation::run(&[role("a", "dc1", Some(GB)), role("a", "dc2", Some(GB))], 1, None, None).is_err());
+        assert!(LayoutSimulation::run(&roles, 2, None, None).is_ok());
+    }
+}
@@ -4,20 +4,37 @@
 
 pub mod bucket;
 pub mod access_key;
+pub mod key_transfer;
+pub mod key_expiration;
+pub mod audit;
+pub mod confirmation;
 pub mod admin_token;
 pub mod cluster;
+pub mod freeform;
+pub mod layout_diff;
+pub mod layout_simulation;
+pub mod block_error_report;
 pub mod node;
 pub mod block;
 pub mod worker;
 pub mod object;
+pub mod schedule;
 pub mod garage;
 
 pub use bucket::*;
 pub use access_key::*;
+pub use key_transfer::*;
+pub use key_expiration::*;
+pub use audit::*;
+pub use confirmation::*;
 pub use admin_token::*;
 pub use cluster::*;
+pub use layout_diff::*;
+pub use layout_simulation::*;
+pub use block_error_report::*;
 pub use node::*;
 pub use block::*;
 pub use worker::*;
 pub use object::*;
+pub use schedule::*;
 pub use garage::*;
\ No newline at end of file
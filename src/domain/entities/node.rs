@@ -2,6 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+
+use super::cluster::{DiskUsage, TableStatistics};
+use super::freeform::{header_fields, parse_block_manager, parse_storage_nodes, parse_table_stats, sections};
+use super::worker::WorkerInfo;
 
 /// 多節點響應
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,14 +35,171 @@ pub struct NodeInfo {
 }
 
 /// 節點統計資訊
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// 由 Garage 的 freeform 文字解析而來，無法解析的部分保持空值，原始文字保留於 `freeform`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeStatistics {
+    pub garage_version: Option<String>,
+    pub garage_features: Vec<String>,
+    pub rust_version: Option<String>,
+    pub db_engine: Option<String>,
+    pub tables: Vec<TableStatistics>,
+    pub blocks: BlockManagerStatistics,
+    pub data_disk: Option<DiskUsage>,
+    pub metadata_disk: Option<DiskUsage>,
     pub freeform: String,
 }
 
-/// 修復操作類型
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl NodeStatistics {
+    /// 解析 `GetNodeStatistics` 單一節點的輸出
+    ///
+    /// `node_id` 用於從 `Storage nodes:` 區段（舊版 Garage 才有）找出該節點的磁碟使用量
+    pub fn parse(node_id: &str, freeform: String) -> Self {
+        let mut stats = Self::default();
+
+        for (key, value) in header_fields(&freeform) {
+            match key.to_ascii_lowercase().as_str() {
+                "garage version" => {
+                    // `v2.0.0 [features: k2v, lmdb]`
+                    let (version, features) = match value.split_once('[') {
+                        Some((version, rest)) => (version.trim(), rest.trim_end_matches(']')),
+                        None => (value, ""),
+                    };
+                    stats.garage_version = Some(version.to_string());
+                    stats.garage_features = features
+                        .trim_start_matches("features:")
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|f| !f.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                }
+                "rust compiler version" => stats.rust_version = Some(value.to_string()),
+                "database engine" => stats.db_engine = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        for section in sections(&freeform) {
+            let title = section.title.to_ascii_lowercase();
+            if title.starts_with("table stats") {
+                stats.tables = parse_table_stats(&section.lines);
+            } else if title.starts_with("block manager stats") {
+                stats.blocks = parse_block_manager(&section.lines);
+            } else if title.starts_with("storage nodes") {
+                let node = parse_storage_nodes(&section.lines)
+                    .into_iter()
+                    .find(|n| node_id.starts_with(&n.id) || n.id.starts_with(node_id));
+                if let Some(node) = node {
+                    stats.data_disk = node.data;
+                    stats.metadata_disk = node.metadata;
+                }
+            }
+        }
+
+        stats.freeform = freeform;
+        stats
+    }
+}
+
+/// Block manager 統計
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockManagerStatistics {
+    /// Reference count 項目數（約等於 block 數）
+    pub rc_entries: Option<u64>,
+    pub resync_queue_length: Option<u64>,
+    pub resync_errors: Option<u64>,
+}
+
+/// 多節點統計的加總
+///
+/// 佇列長度與磁碟用量直接加總；table 項目數與 RC entries 在每個副本上都會計算一次，
+/// 因此除以 replication factor，作為叢集中不重複項目數的估計
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStatisticsTotals {
+    pub node_count: usize,
+    /// 依 table 名稱加總，順序為第一次出現的順序
+    pub tables: Vec<TableStatistics>,
+    pub blocks: BlockManagerStatistics,
+    pub data_disk: DiskUsage,
+    pub metadata_disk: DiskUsage,
+    /// 部分節點缺少某些數值（例如 Garage v1 的 NC），或有節點查詢失敗
+    pub partial: bool,
+}
+
+impl NodeStatisticsTotals {
+    /// 加總各節點的統計；任一節點有值即計入並標記 `partial`，全部節點皆無值時保持 None
+    ///
+    /// 只查詢單一節點時 `replication_factor` 傳 1，回傳該節點本身的數值
+    pub fn aggregate<'a>(nodes: impl IntoIterator<Item = &'a NodeStatistics>, replication_factor: usize) -> Self {
+        let nodes: Vec<&NodeStatistics> = nodes.into_iter().collect();
+        let replication_factor = replication_factor.max(1) as u64;
+        let mut partial = false;
+        let mut sum = |values: Vec<Option<u64>>| -> Option<u64> {
+            let present: Vec<u64> = values.iter().flatten().copied().collect();
+            if present.is_empty() {
+                return None;
+            }
+            partial |= present.len() < values.len();
+            Some(present.iter().sum())
+        };
+        let replicated = |total: Option<u64>| total.map(|v| v / replication_factor);
+
+        let mut names: Vec<&str> = Vec::new();
+        for table in nodes.iter().flat_map(|n| &n.tables) {
+            if !names.contains(&table.name.as_str()) {
+                names.push(&table.name);
+            }
+        }
+        let tables = names
+            .into_iter()
+            .map(|name| {
+                let per_node: Vec<Option<&TableStatistics>> =
+                    nodes.iter().map(|n| n.tables.iter().find(|t| t.name == name)).collect();
+                let field = |get: fn(&TableStatistics) -> Option<u64>| -> Vec<Option<u64>> {
+                    per_node.iter().map(|t| t.and_then(get)).collect()
+                };
+                TableStatistics {
+                    name: name.to_string(),
+                    items: replicated(sum(field(|t| t.items))),
+                    merkle_items: replicated(sum(field(|t| t.merkle_items))),
+                    merkle_todo: sum(field(|t| t.merkle_todo)),
+                    insert_queue: sum(field(|t| t.insert_queue)),
+                    gc_todo: sum(field(|t| t.gc_todo)),
+                }
+            })
+            .collect();
+
+        let blocks = BlockManagerStatistics {
+            rc_entries: replicated(sum(nodes.iter().map(|n| n.blocks.rc_entries).collect())),
+            resync_queue_length: sum(nodes.iter().map(|n| n.blocks.resync_queue_length).collect()),
+            resync_errors: sum(nodes.iter().map(|n| n.blocks.resync_errors).collect()),
+        };
+
+        let mut disk = |usage: fn(&NodeStatistics) -> Option<DiskUsage>| {
+            let present: Vec<DiskUsage> = nodes.iter().filter_map(|n| usage(n)).collect();
+            partial |= !present.is_empty() && present.len() < nodes.len();
+            present.into_iter().fold(DiskUsage::default(), |acc, u| acc + u)
+        };
+        let data_disk = disk(|n| n.data_disk);
+        let metadata_disk = disk(|n| n.metadata_disk);
+
+        Self {
+            node_count: nodes.len(),
+            tables,
+            blocks,
+            data_disk,
+            metadata_disk,
+            partial,
+        }
+    }
+}
+
+/// 修復操作類型，對應 Garage `LaunchRepairOperation` 的 `repairType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum RepairType {
     Tables,
@@ -46,13 +208,313 @@ pub enum RepairType {
     MultipartUploads,
     BlockRefs,
     BlockRc,
-    RebalancePartitions,
+    Rebalance,
+    Aliases,
+    ClearResyncQueue,
     Scrub(ScrubCommand),
 }
 
 /// Scrub 命令
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScrubCommand {
+    Start,
+    Pause,
+    Resume,
+    Cancel,
+}
+
+impl fmt::Display for RepairType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Tables => "tables",
+            Self::Blocks => "blocks",
+            Self::Versions => "versions",
+            Self::MultipartUploads => "multipartUploads",
+            Self::BlockRefs => "blockRefs",
+            Self::BlockRc => "blockRc",
+            Self::Rebalance => "rebalance",
+            Self::Aliases => "aliases",
+            Self::ClearResyncQueue => "clearResyncQueue",
+            Self::Scrub(ScrubCommand::Start) => "scrub:start",
+            Self::Scrub(ScrubCommand::Pause) => "scrub:pause",
+            Self::Scrub(ScrubCommand::Resume) => "scrub:resume",
+            Self::Scrub(ScrubCommand::Cancel) => "scrub:cancel",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Scrub worker 的執行狀態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScrubState {
+    Running,
+    Paused,
+    /// 已完成或尚未開始，等待下一次排程
+    Idle,
+}
+
+/// 單一節點的 scrub 進度
+///
+/// 由 scrub worker 的 `progress`、`persistentErrors` 與 freeform 文字解析而來，無法解析的部分保持空值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ScrubCommand {
-    pub command: String,
+pub struct ScrubStatus {
+    pub worker_id: i64,
+    pub state: ScrubState,
+    /// 0-100，執行中或暫停時有值
+    pub progress_percent: Option<f64>,
+    pub tranquility: Option<i64>,
+    pub corruptions_detected: Option<u64>,
+    pub paused_until: Option<String>,
+    pub last_completed_at: Option<String>,
+    pub next_run_at: Option<String>,
+    pub errors: i64,
+    pub last_error: Option<String>,
+}
+
+impl ScrubStatus {
+    /// Garage 中 scrub worker 的名稱
+    pub const WORKER_NAME: &'static str = "Block scrub worker";
+
+    pub fn from_worker(worker: &WorkerInfo) -> Self {
+        let lines: Vec<&str> = worker.freeform.as_deref().unwrap_or_default().lines().map(str::trim).collect();
+        let field = |prefix: &str| {
+            lines
+                .iter()
+                .find_map(|line| line.strip_prefix(prefix))
+                .map(|value| value.trim().to_string())
+        };
+
+        let progress_percent = worker
+            .progress
+            .as_deref()
+            .and_then(|p| p.trim().trim_end_matches('%').parse::<f64>().ok());
+        // Garage 暫停時同時回報 progress 與「Scrub paused, resumes at …」
+        let paused_until = field("Scrub paused, resumes at");
+        let state = if paused_until.is_some() {
+            ScrubState::Paused
+        } else if progress_percent.is_some() {
+            ScrubState::Running
+        } else {
+            ScrubState::Idle
+        };
+
+        Self {
+            worker_id: worker.id,
+            state,
+            progress_percent,
+            tranquility: worker.tranquility,
+            corruptions_detected: worker.persistent_errors,
+            paused_until,
+            last_completed_at: field("Last scrub completed at"),
+            next_run_at: field("Next scrub scheduled for"),
+            errors: worker.errors,
+            last_error: worker.last_error.as_ref().map(|e| e.message.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str, items: Option<u64>, merkle_todo: u64) -> TableStatistics {
+        TableStatistics {
+            name: name.to_string(),
+            items,
+            merkle_todo: Some(merkle_todo),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_aggregate_sums_per_table_and_disk() {
+        let a = NodeStatistics {
+            tables: vec![table("object", Some(30), 1), table("version", Some(12), 0)],
+            blocks: BlockManagerStatistics { rc_entries: Some(90), resync_queue_length: Some(2), resync_errors: None },
+            data_disk: Some(DiskUsage { available: 40, total: 100 }),
+            ..Default::default()
+        };
+        let b = NodeStatistics {
+            tables: vec![table("object", Some(30), 3), table("version", Some(12), 0)],
+            blocks: BlockManagerStatistics { rc_entries: Some(60), resync_queue_length: Some(0), resync_errors: None },
+            data_disk: Some(DiskUsage { available: 10, total: 100 }),
+            ..Default::default()
+        };
+
+        let totals = NodeStatisticsTotals::aggregate([&a, &b], 2);
+
+        assert_eq!(totals.node_count, 2);
+        assert_eq!(totals.tables.len(), 2);
+        // 複製的項目數除以 replication factor，佇列長度直接加總
+        assert_eq!(totals.tables[0].items, Some(30));
+        assert_eq!(totals.tables[0].merkle_todo, Some(4));
+        assert_eq!(totals.blocks.rc_entries, Some(75));
+        assert_eq!(totals.blocks.resync_queue_length, Some(2));
+        assert_eq!(totals.blocks.resync_errors, None);
+        assert_eq!(totals.data_disk, DiskUsage { available: 50, total: 200 });
+        assert_eq!(totals.metadata_disk, DiskUsage::default());
+        assert!(!totals.partial);
+    }
+
+    #[test]
+    fn test_aggregate_flags_missing_values_as_partial() {
+        let a = NodeStatistics {
+            tables: vec![table("object", Some(10), 1)],
+            data_disk: Some(DiskUsage { available: 40, total: 100 }),
+            ..Default::default()
+        };
+        let b = NodeStatistics {
+            tables: vec![table("object", None, 3)],
+            ..Default::default()
+        };
+
+        let totals = NodeStatisticsTotals::aggregate([&a, &b], 1);
+
+        assert_eq!(totals.tables[0].items, Some(10));
+        assert_eq!(totals.data_disk, DiskUsage { available: 40, total: 100 });
+        assert!(totals.partial);
+
+        let single = NodeStatisticsTotals::aggregate([&a], 1);
+        assert!(!single.partial);
+    }
+
+    fn scrub_worker(progress: Option<&str>, persistent_errors: Option<u64>, freeform: &[&str]) -> WorkerInfo {
+        WorkerInfo {
+            id: 7,
+            name: ScrubStatus::WORKER_NAME.to_string(),
+            state: "busy".to_string(),
+            progress: progress.map(str::to_string),
+            errors: 0,
+            consecutive_errors: 0,
+            last_error: None,
+            tranquility: Some(4),
+            persistent_errors,
+            freeform: Some(freeform.join("\n")),
+        }
+    }
+
+    #[test]
+    fn test_scrub_status_from_worker() {
+        let running = ScrubStatus::from_worker(&scrub_worker(Some("42.50%"), Some(0), &[]));
+        assert_eq!(running.state, ScrubState::Running);
+        assert_eq!(running.progress_percent, Some(42.5));
+        assert_eq!(running.corruptions_detected, Some(0));
+        assert_eq!(running.tranquility, Some(4));
+
+        let paused = ScrubStatus::from_worker(&scrub_worker(
+            Some("42.50%"),
+            Some(0),
+            &["Scrub paused, resumes at 2026-10-02T00:00:00.000Z"],
+        ));
+        assert_eq!(paused.state, ScrubState::Paused);
+        assert_eq!(paused.progress_percent, Some(42.5));
+        assert_eq!(paused.paused_until.as_deref(), Some("2026-10-02T00:00:00.000Z"));
+
+        let idle = ScrubStatus::from_worker(&scrub_worker(
+            None,
+            Some(3),
+            &[
+                "Last scrub completed at 2026-09-01T00:00:00.000Z",
+                "Next scrub scheduled for 2026-10-20T00:00:00.000Z",
+            ],
+        ));
+        assert_eq!(idle.state, ScrubState::Idle);
+        assert_eq!(idle.corruptions_detected, Some(3));
+        assert_eq!(idle.last_completed_at.as_deref(), Some("2026-09-01T00:00:00.000Z"));
+        assert_eq!(idle.next_run_at.as_deref(), Some("2026-10-20T00:00:00.000Z"));
+    }
+
+    /// Garage v2 `GetNodeStatistics`
+    const NODE_V2: &str = "\
+Garage version: v2.0.0 [features: k2v, lmdb, sqlite, metrics, bundled-libs]
+Rust compiler version: 1.82.0
+
+Database engine: LMDB (using Heed crate)
+
+Table stats:
+  Table         Items  MklItems  MklTodo  InsQueue  GcTodo
+  admin_token   2      2         0        0         0
+  bucket_v2     3      4         0        0         0
+  object        1532   1790      2        0         17
+  version       1540   1801      0        0         0
+  block_ref     4120   4800      5        1         3
+
+Block manager stats:
+  number of RC entries:       4098 (~= number of blocks)
+  resync queue length:        12
+  blocks with resync errors:  1
+";
+
+    /// Garage v1 `garage stats`：未計算的值顯示為 NC，並附上 storage nodes 區段
+    const NODE_V1: &str = "\
+Garage version: v1.0.1 [features: k2v, lmdb, sqlite]
+Rust compiler version: 1.77.2
+
+Database engine: LMDB (using Heed crate)
+
+Table stats:
+  Table      Items  MklItems  MklTodo  GcTodo
+  bucket_v2  1      1         0        0
+  object     NC     12        0        0
+
+Block manager stats:
+  number of RC entries (~= number of blocks): NC
+  resync queue length: 0
+  blocks with resync errors: 0
+
+If values are missing above (marked as NC), consider adding the --detailed flag (this will be slow).
+
+Storage nodes:
+  ID                Hostname  Zone  Capacity  Part.  DataAvail                MetaAvail
+  563e1ac825ee3323  garage-a  dc1   1.0 TB    256    512.0 GB/1.0 TB (51.2%)  20.0 GB/25.0 GB (80.0%)
+";
+
+    #[test]
+    fn test_parse_node_statistics_v2() {
+        let stats = NodeStatistics::parse("563e1ac825ee3323aa", NODE_V2.to_string());
+
+        assert_eq!(stats.garage_version.as_deref(), Some("v2.0.0"));
+        assert_eq!(stats.garage_features, vec!["k2v", "lmdb", "sqlite", "metrics", "bundled-libs"]);
+        assert_eq!(stats.rust_version.as_deref(), Some("1.82.0"));
+        assert_eq!(stats.db_engine.as_deref(), Some("LMDB (using Heed crate)"));
+        assert_eq!(stats.tables.len(), 5);
+        assert_eq!(stats.tables[4].name, "block_ref");
+        assert_eq!(stats.tables[4].insert_queue, Some(1));
+        assert_eq!(
+            stats.blocks,
+            BlockManagerStatistics { rc_entries: Some(4098), resync_queue_length: Some(12), resync_errors: Some(1) }
+        );
+        assert_eq!(stats.data_disk, None);
+        assert_eq!(stats.freeform, NODE_V2);
+    }
+
+    #[test]
+    fn test_parse_node_statistics_v1() {
+        let stats = NodeStatistics::parse("563e1ac825ee3323aa", NODE_V1.to_string());
+
+        assert_eq!(stats.tables[1].name, "object");
+        assert_eq!(stats.tables[1].items, None);
+        assert_eq!(stats.tables[1].merkle_items, Some(12));
+        assert_eq!(stats.tables[1].insert_queue, None);
+        assert_eq!(stats.blocks.rc_entries, None);
+        assert_eq!(stats.blocks.resync_queue_length, Some(0));
+        assert_eq!(stats.data_disk, Some(DiskUsage { available: 512_000_000_000, total: 1_000_000_000_000 }));
+        assert_eq!(stats.metadata_disk, Some(DiskUsage { available: 20_000_000_000, total: 25_000_000_000 }));
+
+        let other = NodeStatistics::parse("86f0f26ae4afbd59", NODE_V1.to_string());
+        assert_eq!(other.data_disk, None);
+    }
+
+    #[test]
+    fn test_repair_type_serializes_like_garage() {
+        assert_eq!(serde_json::to_value(RepairType::BlockRc).unwrap(), serde_json::json!("blockRc"));
+        assert_eq!(
+            serde_json::to_value(RepairType::Scrub(ScrubCommand::Pause)).unwrap(),
+            serde_json::json!({ "scrub": "pause" })
+        );
+        assert_eq!(RepairType::Scrub(ScrubCommand::Cancel).to_string(), "scrub:cancel");
+    }
 }
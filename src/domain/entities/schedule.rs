@@ -0,0 +1,358 @@
+//! Scheduled maintenance jobs
+//!
+//! 以 cron 表示式定期執行維運操作（metadata snapshot、repair、清理未完成上傳、重試 resync）。
+//! cron 採標準五欄格式（分 時 日 月 週），一律以 UTC 計算，另接受 `@daily`、`@monthly` 等別名。
+
+use std::fmt;
+use chrono::{DateTime, SubsecRound, Utc};
+use croner::Cron;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::domain::entities::RepairType;
+use crate::domain::errors::DomainError;
+
+const MAX_NAME_LENGTH: usize = 128;
+
+// ============ Cron Expression ============
+
+/// 已驗證的 cron 表示式，序列化為原始字串
+#[derive(Debug, Clone)]
+pub struct CronExpression {
+    source: String,
+    cron: Cron,
+}
+
+impl CronExpression {
+    pub fn parse(expression: &str) -> Result<Self, DomainError> {
+        let source = expression.trim().to_string();
+        let cron = Cron::new(&source).parse().map_err(|e| {
+            DomainError::ValidationError(format!("Invalid cron expression '{}': {}", source, e))
+        })?;
+        Ok(Self { source, cron })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// `after` 之後（不含）的下一次觸發時間
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.cron
+            .find_next_occurrence(&after.trunc_subsecs(0), false)
+            .ok()
+    }
+}
+
+impl PartialEq for CronExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl fmt::Display for CronExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Serialize for CronExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for CronExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Self::parse(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+// ============ Action ============
+
+/// 排程執行的操作
+///
+/// `node` 為節點 ID 或 `*`（所有節點）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ScheduleAction {
+    CreateMetadataSnapshot {
+        node: String,
+    },
+    LaunchRepair {
+        node: String,
+        repair_type: RepairType,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scrub_tranquility: Option<u32>,
+    },
+    /// 清理超過 `older_than_secs` 的未完成 multipart upload，`bucket_id` 未指定時處理所有 bucket
+    CleanupIncompleteUploads {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bucket_id: Option<String>,
+        older_than_secs: u64,
+    },
+    /// `block_hashes` 未指定時重試所有 resync 錯誤
+    RetryBlockResync {
+        node: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block_hashes: Option<Vec<String>>,
+    },
+}
+
+impl ScheduleAction {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if let Some(node) = self.node() {
+            if node.trim().is_empty() {
+                return Err(DomainError::ValidationError("node is required".to_string()));
+            }
+        }
+
+        match self {
+            Self::LaunchRepair { repair_type, scrub_tranquility: Some(_), .. }
+                if !matches!(repair_type, RepairType::Scrub(_)) =>
+            {
+                Err(DomainError::ValidationError(format!(
+                    "scrub_tranquility only applies to scrub, not {}",
+                    repair_type
+                )))
+            }
+            Self::CleanupIncompleteUploads { bucket_id: Some(id), .. } if id.trim().is_empty() => {
+                Err(DomainError::ValidationError("bucket_id must not be empty".to_string()))
+            }
+            Self::CleanupIncompleteUploads { older_than_secs: 0, .. } => {
+                Err(DomainError::ValidationError("older_than_secs must be greater than 0".to_string()))
+            }
+            Self::RetryBlockResync { block_hashes: Some(hashes), .. } if hashes.is_empty() => {
+                Err(DomainError::ValidationError("block_hashes must not be empty".to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 對應的 RPC 名稱
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CreateMetadataSnapshot { .. } => "CreateMetadataSnapshot",
+            Self::LaunchRepair { .. } => "LaunchRepair",
+            Self::CleanupIncompleteUploads { .. } => "CleanupIncompleteUploads",
+            Self::RetryBlockResync { .. } => "RetryBlockResync",
+        }
+    }
+
+    /// 目標節點，CleanupIncompleteUploads 作用於整個叢集
+    pub fn node(&self) -> Option<&str> {
+        match self {
+            Self::CreateMetadataSnapshot { node }
+            | Self::LaunchRepair { node, .. }
+            | Self::RetryBlockResync { node, .. } => Some(node),
+            Self::CleanupIncompleteUploads { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ScheduleAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LaunchRepair { repair_type, .. } => write!(f, "{}({})", self.name(), repair_type),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+// ============ Schedule ============
+
+/// 觸發方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScheduleTrigger {
+    /// 依 cron 表示式自動觸發
+    Cron,
+    /// 透過 RunSchedule 手動觸發
+    Manual,
+}
+
+/// 單次執行結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleRun {
+    pub trigger: ScheduleTrigger,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub success: bool,
+    /// 例如「3/3 nodes succeeded」、「12 uploads deleted」
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 排程
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    pub id: String,
+    pub name: String,
+    pub cron: CronExpression,
+    pub action: ScheduleAction,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_run: Option<ScheduleRun>,
+}
+
+impl Schedule {
+    pub fn new(
+        id: String,
+        name: String,
+        cron: CronExpression,
+        action: ScheduleAction,
+        enabled: bool,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Self::validate_name(&name)?;
+        action.validate()?;
+
+        Ok(Self {
+            id,
+            name,
+            cron,
+            action,
+            enabled,
+            created_at: now,
+            updated_at: now,
+            last_run: None,
+        })
+    }
+
+    pub fn validate_name(name: &str) -> Result<(), DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::ValidationError("Schedule name is required".to_string()));
+        }
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Schedule name must be at most {} characters",
+                MAX_NAME_LENGTH
+            )));
+        }
+        Ok(())
+    }
+
+    /// 下一次觸發時間，停用時為 None
+    pub fn next_run_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if !self.enabled {
+            return None;
+        }
+        self.cron.next_after(now)
+    }
+
+    /// `since`（不含）到 `now`（含）之間是否有觸發時間
+    ///
+    /// 排程在這段期間內被修改時，從修改時間起算，避免補跑修改前的觸發
+    pub fn is_due(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        self.enabled
+            && self
+                .cron
+                .next_after(since.max(self.updated_at))
+                .is_some_and(|next| next <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::ScrubCommand;
+    use chrono::TimeZone;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap()
+    }
+
+    fn monthly_scrub(now: DateTime<Utc>) -> Schedule {
+        Schedule::new(
+            "s1".to_string(),
+            "Monthly scrub".to_string(),
+            CronExpression::parse("0 3 1 * *").unwrap(),
+            ScheduleAction::LaunchRepair {
+                node: "*".to_string(),
+                repair_type: RepairType::Scrub(ScrubCommand::Start),
+                scrub_tranquility: Some(2),
+            },
+            true,
+            now,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cron_expression() {
+        let daily = CronExpression::parse("@daily").unwrap();
+        assert_eq!(daily.next_after(at(2024, 1, 31, 12, 0, 0)), Some(at(2024, 2, 1, 0, 0, 0)));
+
+        let every_15 = CronExpression::parse(" */15 * * * * ").unwrap();
+        assert_eq!(every_15.as_str(), "*/15 * * * *");
+        // 剛好在觸發時間上時取下一次
+        assert_eq!(every_15.next_after(at(2024, 1, 1, 10, 15, 0)), Some(at(2024, 1, 1, 10, 30, 0)));
+
+        assert!(CronExpression::parse("").is_err());
+        assert!(CronExpression::parse("61 * * * *").is_err());
+        // 不接受秒欄位
+        assert!(CronExpression::parse("0 0 3 * * *").is_err());
+    }
+
+    #[test]
+    fn test_schedule_due_and_next_run() {
+        let created = at(2024, 1, 15, 0, 0, 0);
+        let mut schedule = monthly_scrub(created);
+
+        assert_eq!(schedule.next_run_at(created), Some(at(2024, 2, 1, 3, 0, 0)));
+        assert!(!schedule.is_due(at(2024, 2, 1, 2, 59, 0), at(2024, 2, 1, 2, 59, 30)));
+        assert!(schedule.is_due(at(2024, 2, 1, 2, 59, 50), at(2024, 2, 1, 3, 0, 0)));
+        // 上一輪已檢查過觸發時間
+        assert!(!schedule.is_due(at(2024, 2, 1, 3, 0, 0), at(2024, 2, 1, 3, 0, 10)));
+
+        // 修改後不補跑修改前的觸發
+        schedule.updated_at = at(2024, 2, 1, 3, 0, 5);
+        assert!(!schedule.is_due(at(2024, 2, 1, 2, 59, 50), at(2024, 2, 1, 3, 0, 10)));
+
+        schedule.enabled = false;
+        assert_eq!(schedule.next_run_at(created), None);
+        assert!(!schedule.is_due(at(2024, 1, 31, 0, 0, 0), at(2024, 3, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_action_validation() {
+        let snapshot = ScheduleAction::CreateMetadataSnapshot { node: " ".to_string() };
+        assert!(snapshot.validate().is_err());
+
+        let repair = ScheduleAction::LaunchRepair {
+            node: "*".to_string(),
+            repair_type: RepairType::Tables,
+            scrub_tranquility: Some(2),
+        };
+        assert!(repair.validate().is_err());
+
+        let cleanup = ScheduleAction::CleanupIncompleteUploads { bucket_id: None, older_than_secs: 0 };
+        assert!(cleanup.validate().is_err());
+
+        let resync = ScheduleAction::RetryBlockResync { node: "*".to_string(), block_hashes: Some(vec![]) };
+        assert!(resync.validate().is_err());
+
+        let resync = ScheduleAction::RetryBlockResync { node: "*".to_string(), block_hashes: None };
+        assert!(resync.validate().is_ok());
+    }
+
+    #[test]
+    fn test_schedule_roundtrip() {
+        let schedule = monthly_scrub(at(2024, 1, 15, 0, 0, 0));
+        let json = serde_json::to_value(&schedule).unwrap();
+
+        assert_eq!(json["cron"], "0 3 1 * *");
+        assert_eq!(json["action"]["type"], "launchRepair");
+        assert_eq!(json["action"]["scrubTranquility"], 2);
+
+        let parsed: Schedule = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, schedule);
+
+        let invalid = serde_json::json!({ "cron": "not a cron" });
+        assert!(serde_json::from_value::<CronExpression>(invalid["cron"].clone()).is_err());
+    }
+}
@@ -15,6 +15,9 @@ pub struct WorkerInfo {
     pub consecutive_errors: i64,
     pub last_error: Option<WorkerError>,
     pub tranquility: Option<i64>,
+    /// 持續存在的錯誤數，scrub worker 為偵測到的損毀 block 數
+    pub persistent_errors: Option<u64>,
+    /// 多行時以換行分隔
     pub freeform: Option<String>,
 }
 
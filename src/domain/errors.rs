@@ -36,6 +36,15 @@ pub enum DomainError {
     #[error("Admin token not found: {0}")]
     AdminTokenNotFound(String),
     
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+    
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("Confirmation required: {0}")]
+    InvalidConfirmation(String),
+    
     // ============ Cluster Errors ============
     
     #[error("Cluster operation failed: {0}")]
@@ -44,6 +53,12 @@ pub enum DomainError {
     #[error("Layout version mismatch: expected {expected}, got {actual}")]
     LayoutVersionMismatch { expected: i64, actual: i64 },
     
+    #[error("Cluster precondition failed: {0}")]
+    ClusterPreconditionFailed(String),
+    
+    #[error("Layout version not found: {0}")]
+    LayoutVersionNotFound(i64),
+    
     // ============ Node Errors ============
     
     #[error("Node not found: {0}")]
@@ -53,6 +68,11 @@ pub enum DomainError {
     
     #[error("Object not found: {0}")]
     ObjectNotFound(String),
+
+    // ============ Schedule Errors ============
+
+    #[error("Schedule not found: {0}")]
+    ScheduleNotFound(String),
     
     // ============ Infrastructure Errors ============
     
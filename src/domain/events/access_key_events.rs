@@ -1,35 +1,78 @@
 //! Access Key domain events
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
 
 /// Events related to access key lifecycle
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum AccessKeyEvent {
     Created(AccessKeyCreatedEvent),
     Updated(AccessKeyUpdatedEvent),
     Deleted(AccessKeyDeletedEvent),
+    Rotated(AccessKeyRotatedEvent),
+    /// 距離到期時間少於設定的提前通知時間（由背景到期檢查發出）
+    ExpiringSoon(AccessKeyExpiringSoonEvent),
+    Expired(AccessKeyExpiredEvent),
+    /// Secret 被讀取（RevealKeySecret 或含 secret 的匯出），供稽核使用
+    SecretRevealed(AccessKeySecretRevealedEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AccessKeyCreatedEvent {
     pub id: String,
     pub name: String,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AccessKeyUpdatedEvent {
     pub id: String,
     pub name: String,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AccessKeyDeletedEvent {
     pub id: String,
     pub deleted_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct AccessKeyRotatedEvent {
+    pub old_id: String,
+    pub new_id: String,
+    /// 舊 Key 的寬限期到期時間，未設定寬限期時為 None
+    pub old_key_expiration: Option<DateTime<Utc>>,
+    pub rotated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccessKeyExpiringSoonEvent {
+    pub id: String,
+    pub name: String,
+    pub expiration: DateTime<Utc>,
+    /// 觸發此通知的提前通知時間
+    #[serde(rename = "lead_time_secs", serialize_with = "serialize_seconds")]
+    pub lead_time: Duration,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccessKeyExpiredEvent {
+    pub id: String,
+    pub name: String,
+    pub expiration: DateTime<Utc>,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccessKeySecretRevealedEvent {
+    pub id: String,
+    /// 呼叫者（Admin Token ID），未啟用認證時為 None
+    pub revealed_by: Option<String>,
+    pub revealed_at: DateTime<Utc>,
+}
+
 impl AccessKeyCreatedEvent {
     pub fn new(id: String, name: String) -> Self {
         Self {
@@ -57,4 +100,52 @@ impl AccessKeyDeletedEvent {
             deleted_at: Utc::now(),
         }
     }
-}
\ No newline at end of file
+}
+
+impl AccessKeyRotatedEvent {
+    pub fn new(old_id: String, new_id: String, old_key_expiration: Option<DateTime<Utc>>) -> Self {
+        Self {
+            old_id,
+            new_id,
+            old_key_expiration,
+            rotated_at: Utc::now(),
+        }
+    }
+}
+
+impl AccessKeyExpiringSoonEvent {
+    pub fn new(id: String, name: String, expiration: DateTime<Utc>, lead_time: Duration) -> Self {
+        Self {
+            id,
+            name,
+            expiration,
+            lead_time,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl AccessKeyExpiredEvent {
+    pub fn new(id: String, name: String, expiration: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            name,
+            expiration,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl AccessKeySecretRevealedEvent {
+    pub fn new(id: String, revealed_by: Option<String>) -> Self {
+        Self {
+            id,
+            revealed_by,
+            revealed_at: Utc::now(),
+        }
+    }
+}
+
+fn serialize_seconds<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(duration.num_seconds())
+}
@@ -1,16 +1,17 @@
 //! Admin Token domain events
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 /// Events related to admin token lifecycle
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum AdminTokenEvent {
     Created(AdminTokenCreatedEvent),
     Updated(AdminTokenUpdatedEvent),
     Deleted(AdminTokenDeletedEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AdminTokenCreatedEvent {
     pub token_id: String,
     pub name: Option<String>,
@@ -18,14 +19,14 @@ pub struct AdminTokenCreatedEvent {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AdminTokenUpdatedEvent {
     pub token_id: String,
     pub name: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AdminTokenDeletedEvent {
     pub token_id: String,
     pub deleted_at: DateTime<Utc>,
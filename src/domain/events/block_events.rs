@@ -1,15 +1,16 @@
 //! Block domain events
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 /// Events related to block operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum BlockEvent {
     Purged(BlocksPurgedEvent),
     ResyncRetried(BlockResyncRetriedEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BlocksPurgedEvent {
     pub node_id: String,
     pub blocks_purged: i32,
@@ -18,7 +19,7 @@ pub struct BlocksPurgedEvent {
     pub purged_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BlockResyncRetriedEvent {
     pub node_id: String,
     pub blocks_retried: i32,
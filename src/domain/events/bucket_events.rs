@@ -1,9 +1,10 @@
 //! Bucket domain events
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 /// Events related to bucket lifecycle
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum BucketEvent {
     Created(BucketCreatedEvent),
     Updated(BucketUpdatedEvent),
@@ -14,20 +15,20 @@ pub enum BucketEvent {
     KeyDenied(BucketKeyDeniedEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketCreatedEvent {
     pub bucket_id: String,
     pub global_alias: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketUpdatedEvent {
     pub bucket_id: String,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketDeletedEvent {
     pub bucket_id: String,
     pub deleted_at: DateTime<Utc>,
@@ -61,14 +62,14 @@ impl BucketDeletedEvent {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketAliasAddedEvent {
     pub bucket_id: String,
     pub alias: String,
     pub added_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketAliasRemovedEvent {
     pub bucket_id: String,
     pub alias: String,
@@ -95,7 +96,7 @@ impl BucketAliasRemovedEvent {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketKeyAllowedEvent {
     pub bucket_id: String,
     pub access_key_id: String,
@@ -105,7 +106,7 @@ pub struct BucketKeyAllowedEvent {
     pub allowed_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BucketKeyDeniedEvent {
     pub bucket_id: String,
     pub access_key_id: String,
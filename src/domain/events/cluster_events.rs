@@ -1,50 +1,105 @@
 //! Cluster domain events
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::entities::ClusterHealth;
 
 /// Events related to cluster lifecycle
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ClusterEvent {
     NodesConnected(ClusterNodesConnectedEvent),
     LayoutUpdated(ClusterLayoutUpdatedEvent),
     LayoutApplied(ClusterLayoutAppliedEvent),
     LayoutReverted(ClusterLayoutRevertedEvent),
     DeadNodesSkipped(ClusterDeadNodesSkippedEvent),
+    NodeWentDown(ClusterNodeWentDownEvent),
+    NodeCameUp(ClusterNodeCameUpEvent),
+    HealthDegraded(ClusterHealthDegradedEvent),
+    HealthRecovered(ClusterHealthRecoveredEvent),
+    Unreachable(ClusterUnreachableEvent),
+    Reachable(ClusterReachableEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClusterNodesConnectedEvent {
     pub node_addresses: Vec<String>,
     pub successful_count: usize,
     pub connected_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClusterLayoutUpdatedEvent {
     pub version: i64,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClusterLayoutAppliedEvent {
     pub version: i64,
     pub message: Vec<String>,
     pub applied_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClusterLayoutRevertedEvent {
     pub version: i64,
     pub reverted_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ClusterDeadNodesSkippedEvent {
     pub version: i64,
     pub allow_missing_data: bool,
     pub skipped_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterNodeWentDownEvent {
+    pub node_id: String,
+    pub hostname: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterNodeCameUpEvent {
+    pub node_id: String,
+    pub hostname: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterHealthDegradedEvent {
+    pub previous_status: String,
+    pub status: String,
+    pub storage_nodes_up: i32,
+    pub storage_nodes: i32,
+    pub partitions_all_ok: i32,
+    pub partitions: i32,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterHealthRecoveredEvent {
+    pub previous_status: String,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// 背景輪詢無法取得集群狀態
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterUnreachableEvent {
+    pub error: String,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// 背景輪詢在失敗後恢復
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterReachableEvent {
+    /// 恢復前最後一次失敗的原因
+    pub previous_error: String,
+    pub detected_at: DateTime<Utc>,
+}
+
 impl ClusterNodesConnectedEvent {
     pub fn new(node_addresses: Vec<String>, successful_count: usize) -> Self {
         Self {
@@ -92,3 +147,64 @@ impl ClusterDeadNodesSkippedEvent {
         }
     }
 }
+
+impl ClusterNodeWentDownEvent {
+    pub fn new(node_id: String, hostname: Option<String>) -> Self {
+        Self {
+            node_id,
+            hostname,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl ClusterNodeCameUpEvent {
+    pub fn new(node_id: String, hostname: Option<String>) -> Self {
+        Self {
+            node_id,
+            hostname,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl ClusterHealthDegradedEvent {
+    pub fn new(previous_status: String, health: &ClusterHealth) -> Self {
+        Self {
+            previous_status,
+            status: health.status.clone(),
+            storage_nodes_up: health.storage_nodes_up,
+            storage_nodes: health.storage_nodes,
+            partitions_all_ok: health.partitions_all_ok,
+            partitions: health.partitions,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl ClusterHealthRecoveredEvent {
+    pub fn new(previous_status: String) -> Self {
+        Self {
+            previous_status,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl ClusterUnreachableEvent {
+    pub fn new(error: String) -> Self {
+        Self {
+            error,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+impl ClusterReachableEvent {
+    pub fn new(previous_error: String) -> Self {
+        Self {
+            previous_error,
+            detected_at: Utc::now(),
+        }
+    }
+}
@@ -1,14 +1,17 @@
 //! Event Bus for domain events
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use tokio::sync::mpsc;
 use super::{
     AccessKeyEvent, AdminTokenEvent, BlockEvent, BucketEvent, 
-    ClusterEvent, NodeEvent, WorkerEvent,
+    ClusterEvent, NodeEvent, SystemEvent, WorkerEvent,
 };
+use crate::shared::current_context;
 
 /// 統一的領域事件類型
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum DomainEvent {
     Bucket(BucketEvent),
     AccessKey(AccessKeyEvent),
@@ -17,6 +20,41 @@ pub enum DomainEvent {
     Node(NodeEvent),
     Block(BlockEvent),
     Worker(WorkerEvent),
+    System(SystemEvent),
+}
+
+/// 事件發生時的請求資訊
+///
+/// 事件由背景的 EventProcessor 處理，無法再讀取請求的 task-local context，
+/// 因此在 publish 時擷取
+#[derive(Debug, Clone)]
+pub struct EventMetadata {
+    pub trace_id: Option<String>,
+    /// 已通過認證的呼叫者（Admin Token ID），背景任務發出的事件為 None
+    pub caller: Option<String>,
+    /// mTLS client 憑證的 subject
+    pub peer_subject: Option<String>,
+    pub published_at: DateTime<Utc>,
+}
+
+impl EventMetadata {
+    /// 從目前的請求 context 擷取
+    pub fn capture() -> Self {
+        let context = current_context();
+        Self {
+            trace_id: context.as_ref().map(|c| c.trace_id.clone()),
+            caller: context.as_ref().and_then(|c| c.caller.clone()),
+            peer_subject: context.and_then(|c| c.peer_subject),
+            published_at: Utc::now(),
+        }
+    }
+}
+
+/// 事件與其發生時的請求資訊
+#[derive(Debug, Clone)]
+pub struct EventEnvelope {
+    pub event: DomainEvent,
+    pub metadata: EventMetadata,
 }
 
 /// Event Bus trait for publishing domain events
@@ -59,16 +97,21 @@ pub trait EventBus: Send + Sync {
     async fn publish_worker(&self, event: WorkerEvent) {
         self.publish(DomainEvent::Worker(event)).await;
     }
+    
+    /// Publish a system event (convenience method)
+    async fn publish_system(&self, event: SystemEvent) {
+        self.publish(DomainEvent::System(event)).await;
+    }
 }
 
 /// Channel-based Event Bus implementation
 pub struct ChannelEventBus {
-    sender: mpsc::UnboundedSender<DomainEvent>,
+    sender: mpsc::UnboundedSender<EventEnvelope>,
 }
 
 impl ChannelEventBus {
     /// Create a new EventBus with an unbounded channel
-    pub fn new() -> (Self, mpsc::UnboundedReceiver<DomainEvent>) {
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<EventEnvelope>) {
         let (sender, receiver) = mpsc::unbounded_channel();
         (Self { sender }, receiver)
     }
@@ -85,6 +128,9 @@ impl EventBus for ChannelEventBus {
     async fn publish(&self, event: DomainEvent) {
         // Using unbounded channel, send should not fail unless receiver is dropped
         // In production, you might want to log errors or use a dead letter queue
-        let _ = self.sender.send(event);
+        let _ = self.sender.send(EventEnvelope {
+            event,
+            metadata: EventMetadata::capture(),
+        });
     }
 }
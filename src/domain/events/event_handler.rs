@@ -1,16 +1,19 @@
 //! Event Handler trait and implementations
 
+use std::sync::Arc;
 use async_trait::async_trait;
 use super::{
     DomainEvent, BucketEvent, AccessKeyEvent, AdminTokenEvent,
-    ClusterEvent, NodeEvent, BlockEvent, WorkerEvent,
+    ClusterEvent, NodeEvent, BlockEvent, WorkerEvent, SystemEvent, EventEnvelope, EventMetadata,
 };
+use crate::domain::entities::AuditRecord;
+use crate::domain::repositories::AuditRepository;
 
 /// Trait for handling domain events
 #[async_trait]
 pub trait EventHandler: Send + Sync {
     /// Handle a domain event
-    async fn handle(&self, event: &DomainEvent);
+    async fn handle(&self, event: &DomainEvent, metadata: &EventMetadata);
 }
 
 /// Logging event handler - logs all events
@@ -18,7 +21,7 @@ pub struct LoggingEventHandler;
 
 #[async_trait]
 impl EventHandler for LoggingEventHandler {
-    async fn handle(&self, event: &DomainEvent) {
+    async fn handle(&self, event: &DomainEvent, _metadata: &EventMetadata) {
         match event {
             DomainEvent::Bucket(e) => Self::handle_bucket_event(e),
             DomainEvent::AccessKey(e) => Self::handle_access_key_event(e),
@@ -27,6 +30,7 @@ impl EventHandler for LoggingEventHandler {
             DomainEvent::Node(e) => Self::handle_node_event(e),
             DomainEvent::Block(e) => Self::handle_block_event(e),
             DomainEvent::Worker(e) => Self::handle_worker_event(e),
+            DomainEvent::System(e) => Self::handle_system_event(e),
         }
     }
 }
@@ -122,6 +126,41 @@ impl LoggingEventHandler {
                     e.deleted_at
                 );
             }
+            AccessKeyEvent::Rotated(e) => {
+                tracing::info!(
+                    "[INFO] Access Key rotated | old_key_id: {} | new_key_id: {} | old_key_expiration: {:?} | rotated_at: {}",
+                    e.old_id,
+                    e.new_id,
+                    e.old_key_expiration,
+                    e.rotated_at
+                );
+            }
+            AccessKeyEvent::ExpiringSoon(e) => {
+                tracing::warn!(
+                    "[WARN] Access Key expiring soon | key_id: {} | name: {:?} | expiration: {} | lead_time_secs: {}",
+                    e.id,
+                    e.name,
+                    e.expiration,
+                    e.lead_time.num_seconds()
+                );
+            }
+            AccessKeyEvent::Expired(e) => {
+                tracing::warn!(
+                    "[WARN] Access Key expired | key_id: {} | name: {:?} | expiration: {} | detected_at: {}",
+                    e.id,
+                    e.name,
+                    e.expiration,
+                    e.detected_at
+                );
+            }
+            AccessKeyEvent::SecretRevealed(e) => {
+                tracing::warn!(
+                    "[WARN] Access Key secret revealed | key_id: {} | revealed_by: {:?} | revealed_at: {}",
+                    e.id,
+                    e.revealed_by,
+                    e.revealed_at
+                );
+            }
         }
     }
 
@@ -194,6 +233,55 @@ impl LoggingEventHandler {
                     e.skipped_at
                 );
             }
+            ClusterEvent::NodeWentDown(e) => {
+                tracing::warn!(
+                    "[WARN] Cluster node went down | node_id: {} | hostname: {:?} | detected_at: {}",
+                    e.node_id,
+                    e.hostname,
+                    e.detected_at
+                );
+            }
+            ClusterEvent::NodeCameUp(e) => {
+                tracing::info!(
+                    "[INFO] Cluster node came up | node_id: {} | hostname: {:?} | detected_at: {}",
+                    e.node_id,
+                    e.hostname,
+                    e.detected_at
+                );
+            }
+            ClusterEvent::HealthDegraded(e) => {
+                tracing::warn!(
+                    "[WARN] Cluster health degraded | status: {} -> {} | storage_nodes_up: {}/{} | partitions_all_ok: {}/{} | detected_at: {}",
+                    e.previous_status,
+                    e.status,
+                    e.storage_nodes_up,
+                    e.storage_nodes,
+                    e.partitions_all_ok,
+                    e.partitions,
+                    e.detected_at
+                );
+            }
+            ClusterEvent::HealthRecovered(e) => {
+                tracing::info!(
+                    "[INFO] Cluster health recovered | previous_status: {} | detected_at: {}",
+                    e.previous_status,
+                    e.detected_at
+                );
+            }
+            ClusterEvent::Unreachable(e) => {
+                tracing::warn!(
+                    "[WARN] Cluster unreachable | error: {} | detected_at: {}",
+                    e.error,
+                    e.detected_at
+                );
+            }
+            ClusterEvent::Reachable(e) => {
+                tracing::info!(
+                    "[INFO] Cluster reachable again | previous_error: {} | detected_at: {}",
+                    e.previous_error,
+                    e.detected_at
+                );
+            }
         }
     }
 
@@ -214,6 +302,60 @@ impl LoggingEventHandler {
                     e.launched_at
                 );
             }
+            NodeEvent::ScheduledJobRan(e) if e.success => {
+                tracing::info!(
+                    "[INFO] Scheduled job ran | schedule_id: {} | name: {} | action: {} | node_id: {:?} | manual: {} | summary: {} | ran_at: {}",
+                    e.schedule_id,
+                    e.schedule_name,
+                    e.action,
+                    e.node_id,
+                    e.manual,
+                    e.summary,
+                    e.ran_at
+                );
+            }
+            NodeEvent::ScheduledJobRan(e) => {
+                tracing::warn!(
+                    "[WARN] Scheduled job failed | schedule_id: {} | name: {} | action: {} | node_id: {:?} | manual: {} | error: {:?} | ran_at: {}",
+                    e.schedule_id,
+                    e.schedule_name,
+                    e.action,
+                    e.node_id,
+                    e.manual,
+                    e.error,
+                    e.ran_at
+                );
+            }
+            NodeEvent::ScheduleCreated(e) => {
+                tracing::info!(
+                    "[INFO] Schedule created | schedule_id: {} | name: {} | cron: {} | action: {} | enabled: {} | created_at: {}",
+                    e.schedule_id,
+                    e.schedule_name,
+                    e.cron,
+                    e.action,
+                    e.enabled,
+                    e.created_at
+                );
+            }
+            NodeEvent::ScheduleUpdated(e) => {
+                tracing::info!(
+                    "[INFO] Schedule updated | schedule_id: {} | name: {} | cron: {} | action: {} | enabled: {} | updated_at: {}",
+                    e.schedule_id,
+                    e.schedule_name,
+                    e.cron,
+                    e.action,
+                    e.enabled,
+                    e.updated_at
+                );
+            }
+            NodeEvent::ScheduleDeleted(e) => {
+                tracing::info!(
+                    "[INFO] Schedule deleted | schedule_id: {} | name: {} | deleted_at: {}",
+                    e.schedule_id,
+                    e.schedule_name,
+                    e.deleted_at
+                );
+            }
         }
     }
 
@@ -254,6 +396,44 @@ impl LoggingEventHandler {
             }
         }
     }
+
+    fn handle_system_event(event: &SystemEvent) {
+        match event {
+            SystemEvent::ReadOnlyModeChanged(e) => {
+                tracing::warn!(
+                    "[WARN] Read-only mode changed | enabled: {} | changed_at: {}",
+                    e.enabled,
+                    e.changed_at
+                );
+            }
+        }
+    }
+}
+
+/// Audit event handler - persists every event to the audit trail
+pub struct AuditEventHandler {
+    repository: Arc<dyn AuditRepository>,
+}
+
+impl AuditEventHandler {
+    pub fn new(repository: Arc<dyn AuditRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl EventHandler for AuditEventHandler {
+    async fn handle(&self, event: &DomainEvent, metadata: &EventMetadata) {
+        let record = AuditRecord::from_event(event, metadata);
+        if let Err(e) = self.repository.append(&record).await {
+            tracing::error!(
+                "[ERROR] Failed to write audit record | event_type: {} | trace_id: {:?} | error: {}",
+                record.event_type,
+                record.trace_id,
+                e
+            );
+        }
+    }
 }
 
 /// Event processor - processes events from a channel with multiple handlers
@@ -268,13 +448,13 @@ impl EventProcessor {
     }
     
     /// Process events from a receiver
-    pub async fn run(self, mut receiver: tokio::sync::mpsc::UnboundedReceiver<DomainEvent>) {
-        while let Some(event) = receiver.recv().await {
+    pub async fn run(self, mut receiver: tokio::sync::mpsc::UnboundedReceiver<EventEnvelope>) {
+        while let Some(EventEnvelope { event, metadata }) = receiver.recv().await {
             // Process event with all handlers concurrently
             let futures: Vec<_> = self
                 .handlers
                 .iter()
-                .map(|handler| handler.handle(&event))
+                .map(|handler| handler.handle(&event, &metadata))
                 .collect();
             
             // Wait for all handlers to complete
@@ -11,6 +11,7 @@ pub mod cluster_events;
 mod event_bus;
 mod event_handler;
 pub mod node_events;
+pub mod system_events;
 pub mod worker_events;
 
 pub use access_key_events::*;
@@ -21,4 +22,5 @@ pub use cluster_events::*;
 pub use event_bus::*;
 pub use event_handler::*;
 pub use node_events::*;
+pub use system_events::*;
 pub use worker_events::*;
@@ -1,27 +1,75 @@
 //! Node domain events
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::entities::{Schedule, ScheduleRun, ScheduleTrigger};
 
 /// Events related to node operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum NodeEvent {
     MetadataSnapshotCreated(NodeMetadataSnapshotCreatedEvent),
     RepairLaunched(NodeRepairLaunchedEvent),
+    ScheduledJobRan(NodeScheduledJobRanEvent),
+    ScheduleCreated(NodeScheduleCreatedEvent),
+    ScheduleUpdated(NodeScheduleUpdatedEvent),
+    ScheduleDeleted(NodeScheduleDeletedEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NodeMetadataSnapshotCreatedEvent {
     pub node_id: String,
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NodeRepairLaunchedEvent {
     pub node_id: String,
     pub repair_type: String,
     pub launched_at: DateTime<Utc>,
 }
 
+/// 排程執行完畢（不論成功與否）
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeScheduledJobRanEvent {
+    pub schedule_id: String,
+    pub schedule_name: String,
+    pub action: String,
+    /// 節點 ID 或 `*`，CleanupIncompleteUploads 為 None
+    pub node_id: Option<String>,
+    pub manual: bool,
+    pub success: bool,
+    pub summary: String,
+    pub error: Option<String>,
+    pub ran_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeScheduleCreatedEvent {
+    pub schedule_id: String,
+    pub schedule_name: String,
+    pub cron: String,
+    pub action: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeScheduleUpdatedEvent {
+    pub schedule_id: String,
+    pub schedule_name: String,
+    pub cron: String,
+    pub action: String,
+    pub enabled: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeScheduleDeletedEvent {
+    pub schedule_id: String,
+    pub schedule_name: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
 impl NodeMetadataSnapshotCreatedEvent {
     pub fn new(node_id: String) -> Self {
         Self {
@@ -40,3 +88,55 @@ impl NodeRepairLaunchedEvent {
         }
     }
 }
+
+impl NodeScheduledJobRanEvent {
+    pub fn new(schedule: &Schedule, run: &ScheduleRun) -> Self {
+        Self {
+            schedule_id: schedule.id.clone(),
+            schedule_name: schedule.name.clone(),
+            action: schedule.action.to_string(),
+            node_id: schedule.action.node().map(str::to_string),
+            manual: run.trigger == ScheduleTrigger::Manual,
+            success: run.success,
+            summary: run.summary.clone(),
+            error: run.error.clone(),
+            ran_at: run.started_at,
+        }
+    }
+}
+
+impl NodeScheduleCreatedEvent {
+    pub fn new(schedule: &Schedule) -> Self {
+        Self {
+            schedule_id: schedule.id.clone(),
+            schedule_name: schedule.name.clone(),
+            cron: schedule.cron.to_string(),
+            action: schedule.action.to_string(),
+            enabled: schedule.enabled,
+            created_at: schedule.created_at,
+        }
+    }
+}
+
+impl NodeScheduleUpdatedEvent {
+    pub fn new(schedule: &Schedule) -> Self {
+        Self {
+            schedule_id: schedule.id.clone(),
+            schedule_name: schedule.name.clone(),
+            cron: schedule.cron.to_string(),
+            action: schedule.action.to_string(),
+            enabled: schedule.enabled,
+            updated_at: schedule.updated_at,
+        }
+    }
+}
+
+impl NodeScheduleDeletedEvent {
+    pub fn new(schedule: &Schedule) -> Self {
+        Self {
+            schedule_id: schedule.id.clone(),
+            schedule_name: schedule.name.clone(),
+            deleted_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! System domain events
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Events related to server-wide runtime settings
+#[derive(Debug, Clone, Serialize)]
+pub enum SystemEvent {
+    ReadOnlyModeChanged(ReadOnlyModeChangedEvent),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadOnlyModeChangedEvent {
+    pub enabled: bool,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl ReadOnlyModeChangedEvent {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            changed_at: Utc::now(),
+        }
+    }
+}
@@ -1,14 +1,15 @@
 //! Worker domain events
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 /// Events related to worker operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum WorkerEvent {
     VariableSet(WorkerVariableSetEvent),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WorkerVariableSetEvent {
     pub node_id: String,
     pub variable: String,
@@ -19,6 +19,9 @@ pub trait AccessKeyCommandRepository: Send + Sync {
     /// 創建 Access Key
     async fn create(&self, aggregate: &AccessKeyAggregate) -> Result<AccessKeyAggregate, DomainError>;
     
+    /// 匯入既有的 Access Key（ID 與 secret 由 Aggregate 提供）
+    async fn import(&self, aggregate: &AccessKeyAggregate) -> Result<AccessKeyAggregate, DomainError>;
+    
     /// 保存 Access Key（用於更新，接收 Aggregate 而非 Command）
     async fn save(&self, aggregate: &AccessKeyAggregate) -> Result<AccessKeyAggregate, DomainError>;
     
@@ -35,7 +38,10 @@ pub trait AccessKeyQueryRepository: Send + Sync {
     /// 列出所有 Access Keys（返回 Read Model）
     async fn list(&self) -> Result<Vec<KeyListItemResponse>, DomainError>;
     
-    /// 獲取 Access Key 詳細資訊（返回 Read Model）
+    /// 獲取 Access Key 詳細資訊（返回 Read Model，不含 secret）
     async fn find_by_id(&self, id: &str) -> Result<AccessKey, DomainError>;
+    
+    /// 獲取 Secret Access Key（僅供 RevealKeySecret / 匯出使用）
+    async fn find_secret(&self, id: &str) -> Result<String, DomainError>;
 }
 
@@ -19,6 +19,8 @@ pub struct CreateAdminTokenInput {
 pub struct UpdateAdminTokenInput {
     pub name: Option<String>,
     pub expiration: Option<String>,
+    /// 移除過期時間
+    pub never_expires: Option<bool>,
     pub scope: Option<Vec<String>>,
 }
 
@@ -0,0 +1,17 @@
+//! Audit repository interface
+
+use async_trait::async_trait;
+use crate::domain::entities::{AuditFilter, AuditRecord};
+use crate::domain::errors::DomainError;
+
+/// Audit trail storage
+///
+/// 只允許新增與查詢，紀錄寫入後不可修改
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+    /// 寫入一筆紀錄，回傳前須確保已落地
+    async fn append(&self, record: &AuditRecord) -> Result<(), DomainError>;
+
+    /// 查詢符合條件的紀錄，依時間由新到舊排序
+    async fn query(&self, filter: &AuditFilter) -> Result<Vec<AuditRecord>, DomainError>;
+}
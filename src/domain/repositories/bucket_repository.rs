@@ -87,5 +87,10 @@ pub trait BucketRepository: Send + Sync {
         write: bool,
         owner: bool,
     ) -> Result<BucketDetail, DomainError>;
+
+    // ============ Maintenance 操作 ============
+
+    /// Delete incomplete multipart uploads older than `older_than_secs`, returns the number deleted
+    async fn cleanup_incomplete_uploads(&self, bucket_id: &str, older_than_secs: u64) -> Result<i64, DomainError>;
 }
 
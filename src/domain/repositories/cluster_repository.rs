@@ -5,7 +5,7 @@
 use async_trait::async_trait;
 use crate::domain::entities::{
     ApplyLayoutResult, ClusterHealth, ClusterLayout, ClusterLayoutHistory,
-    ClusterStatistics, ClusterStatus, ConnectNodeResult, SkipDeadNodesResult,
+    ClusterStatistics, ClusterStatus, ConnectNodeResult, LayoutParameters, SkipDeadNodesResult,
 };
 use crate::domain::errors::DomainError;
 
@@ -16,6 +16,8 @@ pub struct UpdateLayoutInput {
     pub zone: Option<String>,
     pub capacity: Option<u64>,
     pub tags: Option<Vec<String>>,
+    /// 將節點移出布局（忽略其他欄位）
+    pub remove: bool,
 }
 
 /// Cluster Repository trait
@@ -44,7 +46,13 @@ pub trait ClusterRepository: Send + Sync {
     async fn get_layout(&self) -> Result<ClusterLayout, DomainError>;
     
     /// 更新集群布局
-    async fn update_layout(&self, roles: Vec<UpdateLayoutInput>) -> Result<ClusterLayout, DomainError>;
+    ///
+    /// `parameters` 為 `None` 時不變更布局參數
+    async fn update_layout(
+        &self,
+        roles: Vec<UpdateLayoutInput>,
+        parameters: Option<LayoutParameters>,
+    ) -> Result<ClusterLayout, DomainError>;
     
     /// 應用集群布局
     async fn apply_layout(&self, version: i64) -> Result<ApplyLayoutResult, DomainError>;
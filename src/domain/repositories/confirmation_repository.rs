@@ -0,0 +1,29 @@
+//! Confirmation token repository interface
+
+use async_trait::async_trait;
+use crate::domain::entities::{ConfirmationToken, DestructiveOperation};
+use crate::domain::errors::DomainError;
+
+/// 簽發與驗證破壞性操作的確認 token
+#[async_trait]
+pub trait ConfirmationTokenRepository: Send + Sync {
+    /// 是否要求二次確認；停用時破壞性操作直接執行
+    fn enabled(&self) -> bool;
+
+    /// 為操作簽發 token，`caller` 為第一次呼叫者
+    async fn issue(
+        &self,
+        operation: &DestructiveOperation,
+        caller: Option<&str>,
+    ) -> Result<ConfirmationToken, DomainError>;
+
+    /// 驗證並消耗 token，每個 token 只能使用一次
+    ///
+    /// Token 無效、過期、不屬於此操作，或違反確認者限制時回傳錯誤
+    async fn consume(
+        &self,
+        operation: &DestructiveOperation,
+        token: &str,
+        caller: Option<&str>,
+    ) -> Result<(), DomainError>;
+}
@@ -3,7 +3,7 @@
 //! Domain 層的 Repository 抽象介面
 
 use async_trait::async_trait;
-use crate::domain::entities::{MultiNodeResponse, NodeInfo, NodeStatistics};
+use crate::domain::entities::{MultiNodeResponse, NodeInfo, NodeStatistics, RepairType};
 use crate::domain::errors::DomainError;
 
 /// Node Repository trait
@@ -22,5 +22,5 @@ pub trait NodeRepository: Send + Sync {
     async fn create_metadata_snapshot(&self, node: &str) -> Result<MultiNodeResponse<()>, DomainError>;
     
     /// 啟動修復操作
-    async fn launch_repair(&self, node: &str, repair_type: &str) -> Result<MultiNodeResponse<()>, DomainError>;
+    async fn launch_repair(&self, node: &str, repair_type: RepairType) -> Result<MultiNodeResponse<()>, DomainError>;
 }
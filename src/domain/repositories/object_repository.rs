@@ -2,6 +2,7 @@
 //!
 //! Abstract interface for S3 object operations with streaming support
 
+use std::sync::Arc;
 use async_trait::async_trait;
 use aws_sdk_s3::primitives::ByteStream;
 use tokio::sync::mpsc;
@@ -10,6 +11,7 @@ use crate::domain::entities::{
     CopyObjectResult, DeleteObjectsResult, DownloadMetadata, ListObjectsResult, ObjectMetadata, UploadResult,
 };
 use crate::domain::errors::DomainError;
+use crate::domain::value_objects::S3Credentials;
 use crate::infrastructure::s3::UploadProgress;
 
 /// Download result containing metadata and body stream
@@ -119,3 +121,14 @@ pub trait ObjectRepository: Send + Sync {
         expires_in_seconds: u64,
     ) -> Result<String, DomainError>;
 }
+
+/// 依呼叫者的 S3 credentials 取得對應的 ObjectRepository
+///
+/// 未提供 credentials 時使用預設（`S3_ACCESS_KEY_ID`）的 repository
+#[async_trait]
+pub trait ObjectRepositoryProvider: Send + Sync {
+    async fn resolve(
+        &self,
+        credentials: Option<&S3Credentials>,
+    ) -> Result<Arc<dyn ObjectRepository>, DomainError>;
+}
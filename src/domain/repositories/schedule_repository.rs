@@ -0,0 +1,24 @@
+//! Schedule repository interface
+
+use async_trait::async_trait;
+use crate::domain::entities::{Schedule, ScheduleRun};
+use crate::domain::errors::DomainError;
+
+/// Scheduled maintenance job storage
+#[async_trait]
+pub trait ScheduleRepository: Send + Sync {
+    /// 列出所有排程，依建立時間排序
+    async fn list(&self) -> Result<Vec<Schedule>, DomainError>;
+
+    /// 找不到時回傳 `ScheduleNotFound`
+    async fn get(&self, id: &str) -> Result<Schedule, DomainError>;
+
+    /// 新增或取代整個排程
+    async fn save(&self, schedule: &Schedule) -> Result<(), DomainError>;
+
+    /// 找不到時回傳 `ScheduleNotFound`
+    async fn delete(&self, id: &str) -> Result<(), DomainError>;
+
+    /// 只更新 `last_run`，不覆寫執行期間對排程的其他修改
+    async fn record_run(&self, id: &str, run: &ScheduleRun) -> Result<Schedule, DomainError>;
+}
@@ -5,6 +5,8 @@
 
 mod alias;
 mod quotas;
+mod s3_credentials;
 
 pub use alias::{GlobalAlias, LocalAlias};
 pub use quotas::Quotas;
+pub use s3_credentials::S3Credentials;
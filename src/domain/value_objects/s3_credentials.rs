@@ -0,0 +1,82 @@
+//! Value Objects - S3 credentials
+
+use std::fmt;
+use crate::domain::aggregates::AccessKeyAggregate;
+use crate::domain::errors::DomainError;
+use crate::shared::REDACTED;
+
+/// S3 Credentials Value Object
+///
+/// Object 操作使用的 Access Key；未提供 secret 時由後端透過 Admin API 查詢。
+/// `Debug` 會遮蔽 secret
+#[derive(Clone, PartialEq, Eq)]
+pub struct S3Credentials {
+    access_key_id: String,
+    secret_access_key: Option<String>,
+}
+
+impl S3Credentials {
+    /// 創建新的 S3Credentials，會進行驗證
+    pub fn new(access_key_id: String, secret_access_key: Option<String>) -> Result<Self, DomainError> {
+        let secret_access_key = secret_access_key.filter(|s| !s.is_empty());
+
+        AccessKeyAggregate::validate_access_key_id(&access_key_id)?;
+        if let Some(secret) = &secret_access_key {
+            AccessKeyAggregate::validate_secret_access_key(secret)?;
+        }
+
+        Ok(Self {
+            access_key_id,
+            secret_access_key,
+        })
+    }
+
+    pub fn access_key_id(&self) -> &str {
+        &self.access_key_id
+    }
+
+    pub fn secret_access_key(&self) -> Option<&str> {
+        self.secret_access_key.as_deref()
+    }
+}
+
+impl fmt::Debug for S3Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &self.secret_access_key.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_ID: &str = "GK31c2f218a2e44f485b94239e";
+    const SECRET: &str = "b892c0665f0ada8a4755dae98baa3b133590e11dae3bcc1f9d769d67f16c3835";
+
+    #[test]
+    fn test_valid_credentials() {
+        let creds = S3Credentials::new(KEY_ID.to_string(), Some(SECRET.to_string())).unwrap();
+        assert_eq!(creds.access_key_id(), KEY_ID);
+        assert_eq!(creds.secret_access_key(), Some(SECRET));
+
+        let creds = S3Credentials::new(KEY_ID.to_string(), Some(String::new())).unwrap();
+        assert_eq!(creds.secret_access_key(), None);
+    }
+
+    #[test]
+    fn test_invalid_credentials() {
+        assert!(S3Credentials::new("bad".to_string(), None).is_err());
+        assert!(S3Credentials::new(KEY_ID.to_string(), Some("short".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let creds = S3Credentials::new(KEY_ID.to_string(), Some(SECRET.to_string())).unwrap();
+        let debug = format!("{:?}", creds);
+        assert!(debug.contains(KEY_ID));
+        assert!(!debug.contains(SECRET));
+    }
+}
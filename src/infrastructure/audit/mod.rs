@@ -0,0 +1,7 @@
+//! Audit trail storage
+//!
+//! DomainEvent 的永久稽核紀錄
+
+mod ndjson_repository;
+
+pub use ndjson_repository::NdjsonAuditRepository;
@@ -0,0 +1,272 @@
+//! NDJSON audit repository
+//!
+//! 稽核紀錄以 NDJSON 寫入本機目錄，每天（UTC）一個 segment：
+//! `audit-YYYY-MM-DD.ndjson`。每筆寫入後 `fsync`，查詢時依時間範圍只讀取相關的 segment。
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate};
+use tracing::{info, warn};
+
+use crate::domain::entities::{AuditFilter, AuditRecord};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::AuditRepository;
+
+const SEGMENT_PREFIX: &str = "audit-";
+const SEGMENT_SUFFIX: &str = ".ndjson";
+
+/// 目前寫入中的 segment
+struct Segment {
+    date: NaiveDate,
+    file: File,
+}
+
+struct Inner {
+    dir: PathBuf,
+    retention_days: Option<u32>,
+    current: Mutex<Option<Segment>>,
+}
+
+/// Audit repository backed by daily NDJSON segments
+pub struct NdjsonAuditRepository {
+    inner: Arc<Inner>,
+}
+
+impl NdjsonAuditRepository {
+    /// 建立目錄；`retention_days` 設定時，換日會刪除超過保存天數的 segment
+    pub fn new(dir: impl Into<PathBuf>, retention_days: Option<u32>) -> Result<Self, DomainError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| io_error(&dir, e))?;
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                dir,
+                retention_days,
+                current: Mutex::new(None),
+            }),
+        })
+    }
+}
+
+impl Inner {
+    fn segment_path(&self, date: NaiveDate) -> PathBuf {
+        self.dir.join(format!("{}{}{}", SEGMENT_PREFIX, date.format("%Y-%m-%d"), SEGMENT_SUFFIX))
+    }
+
+    fn append(&self, date: NaiveDate, line: &[u8]) -> Result<(), DomainError> {
+        let mut current = self.current.lock().expect("audit segment poisoned");
+
+        if current.as_ref().is_none_or(|s| s.date != date) {
+            let path = self.segment_path(date);
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|e| io_error(&path, e))?;
+            *current = Some(Segment { date, file });
+            self.remove_expired(date);
+        }
+
+        let segment = current.as_mut().expect("segment opened above");
+        let path = self.segment_path(date);
+        segment.file.write_all(line).map_err(|e| io_error(&path, e))?;
+        segment.file.sync_data().map_err(|e| io_error(&path, e))
+    }
+
+    /// 刪除超過保存天數的 segment
+    fn remove_expired(&self, today: NaiveDate) {
+        let Some(days) = self.retention_days else {
+            return;
+        };
+        let cutoff = today - Duration::days(days as i64);
+
+        for (date, path) in self.segments() {
+            if date < cutoff {
+                match fs::remove_file(&path) {
+                    Ok(()) => info!(path = %path.display(), "Removed expired audit segment"),
+                    Err(e) => warn!(path = %path.display(), error = %e, "Failed to remove audit segment"),
+                }
+            }
+        }
+    }
+
+    /// 列出所有 segment 及其日期
+    fn segments(&self) -> Vec<(NaiveDate, PathBuf)> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return vec![];
+        };
+
+        entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let date = segment_date(name.to_str()?)?;
+                Some((date, entry.path()))
+            })
+            .collect()
+    }
+
+    fn query(&self, filter: &AuditFilter) -> Result<Vec<AuditRecord>, DomainError> {
+        let start = filter.start.map(|t| t.date_naive());
+        let end = filter.end.map(|t| t.date_naive());
+
+        let mut records = Vec::new();
+        for (date, path) in self.segments() {
+            if start.is_some_and(|s| date < s) || end.is_some_and(|e| date > e) {
+                continue;
+            }
+
+            let file = File::open(&path).map_err(|e| io_error(&path, e))?;
+            for (n, line) in BufReader::new(file).lines().enumerate() {
+                let line = line.map_err(|e| io_error(&path, e))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<AuditRecord>(&line) {
+                    Ok(record) if filter.matches(&record) => records.push(record),
+                    Ok(_) => {}
+                    // 寫入中斷可能留下不完整的最後一行，略過即可
+                    Err(e) => warn!(path = %path.display(), line = n + 1, error = %e, "Skipping malformed audit record"),
+                }
+            }
+        }
+
+        records.sort_by_key(|r| std::cmp::Reverse(r.occurred_at));
+        Ok(records)
+    }
+}
+
+#[async_trait]
+impl AuditRepository for NdjsonAuditRepository {
+    async fn append(&self, record: &AuditRecord) -> Result<(), DomainError> {
+        let mut line = serde_json::to_vec(record)
+            .map_err(|e| DomainError::InternalError(format!("Failed to serialize audit record: {}", e)))?;
+        line.push(b'\n');
+
+        let date = record.occurred_at.date_naive();
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || inner.append(date, &line))
+            .await
+            .map_err(|e| DomainError::InternalError(e.to_string()))?
+    }
+
+    async fn query(&self, filter: &AuditFilter) -> Result<Vec<AuditRecord>, DomainError> {
+        let filter = filter.clone();
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || inner.query(&filter))
+            .await
+            .map_err(|e| DomainError::InternalError(e.to_string()))?
+    }
+}
+
+/// `audit-2024-01-31.ndjson` → 2024-01-31
+fn segment_date(file_name: &str) -> Option<NaiveDate> {
+    let date = file_name.strip_prefix(SEGMENT_PREFIX)?.strip_suffix(SEGMENT_SUFFIX)?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!("Audit store {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn today() -> NaiveDate {
+        Utc::now().date_naive()
+    }
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "garage-ui-audit-{}-{}",
+                name,
+                crate::shared::generate_trace_id()
+            ));
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn record(event_type: &str, resource: &str, actor: Option<&str>, days_ago: i64) -> AuditRecord {
+        AuditRecord {
+            occurred_at: Utc::now() - Duration::days(days_ago),
+            event_type: event_type.to_string(),
+            resource_ids: vec![resource.to_string()],
+            actor: actor.map(str::to_string),
+            peer_subject: None,
+            trace_id: None,
+            details: json!({ "id": resource }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_append_and_query() {
+        let dir = TempDir::new("query");
+        let repo = NdjsonAuditRepository::new(&dir.0, None).unwrap();
+
+        repo.append(&record("Bucket.KeyDenied", "bucket-1", Some("token-1"), 3)).await.unwrap();
+        repo.append(&record("AccessKey.Deleted", "GK1", Some("token-2"), 1)).await.unwrap();
+        repo.append(&record("Bucket.Deleted", "bucket-1", None, 0)).await.unwrap();
+
+        let all = repo.query(&AuditFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].event_type, "Bucket.Deleted");
+
+        let filter = AuditFilter { resource_id: Some("bucket-1".to_string()), ..Default::default() };
+        assert_eq!(repo.query(&filter).await.unwrap().len(), 2);
+
+        let filter = AuditFilter { start: Some(Utc::now() - Duration::days(2)), ..Default::default() };
+        assert_eq!(repo.query(&filter).await.unwrap().len(), 2);
+
+        let filter = AuditFilter { actor: Some("token-1".to_string()), ..Default::default() };
+        assert_eq!(repo.query(&filter).await.unwrap()[0].event_type, "Bucket.KeyDenied");
+    }
+
+    #[tokio::test]
+    async fn test_skips_malformed_lines() {
+        let dir = TempDir::new("malformed");
+        let repo = NdjsonAuditRepository::new(&dir.0, None).unwrap();
+        repo.append(&record("Bucket.Deleted", "bucket-1", None, 0)).await.unwrap();
+
+        let path = repo.inner.segment_path(today());
+        let mut file = OpenOptions::new().append(true).open(path).unwrap();
+        file.write_all(b"{\"occurred_at\":").unwrap();
+
+        assert_eq!(repo.query(&AuditFilter::default()).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retention_removes_old_segments() {
+        let dir = TempDir::new("retention");
+        let repo = NdjsonAuditRepository::new(&dir.0, Some(7)).unwrap();
+
+        let old = repo.inner.segment_path(today() - Duration::days(30));
+        fs::write(&old, b"").unwrap();
+        repo.append(&record("Bucket.Deleted", "bucket-1", None, 0)).await.unwrap();
+
+        assert!(!old.exists());
+        assert_eq!(repo.inner.segments().len(), 1);
+    }
+
+    #[test]
+    fn test_segment_date() {
+        assert_eq!(segment_date("audit-2024-01-31.ndjson"), NaiveDate::from_ymd_opt(2024, 1, 31));
+        assert_eq!(segment_date("audit-2024-01-31.log"), None);
+        assert_eq!(segment_date("other.ndjson"), None);
+    }
+}
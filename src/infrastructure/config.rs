@@ -1,19 +1,38 @@
 //! Application configuration
 
+use std::collections::HashMap;
 use std::env;
+use std::fmt;
+
+use crate::shared::REDACTED;
 
 /// Application configuration loaded from environment variables
-#[derive(Debug, Clone)]
+///
+/// `Debug` 會遮蔽 Garage Admin Token 與 S3 secret
+#[derive(Clone)]
 pub struct AppConfig {
     pub garage_api_url: String,
     pub garage_api_key: String,
+    /// 同時進行中的 Garage Admin API 呼叫上限，0 表示不限制
+    pub garage_api_max_concurrency: usize,
     pub grpc_server_addr: String,
     pub log_dir: String,
     pub s3_config: S3Config,
+    pub auth_config: AuthConfig,
+    pub rate_limit_config: RateLimitConfig,
+    /// 啟動時是否為唯讀模式（執行期間可透過 SystemService 切換）
+    pub read_only: bool,
+    /// 未設定 GRPC_TLS_CERT 時為 None（plaintext）
+    pub tls_config: Option<TlsConfig>,
+    pub key_expiration_config: KeyExpirationConfig,
+    pub audit_config: AuditConfig,
+    pub confirmation_config: ConfirmationConfig,
+    pub cluster_config: ClusterConfig,
+    pub schedule_config: ScheduleConfig,
 }
 
 /// S3 configuration for Garage S3-compatible API
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct S3Config {
     /// S3 endpoint URL (e.g., http://localhost:3900)
     pub endpoint_url: String,
@@ -23,6 +42,184 @@ pub struct S3Config {
     pub access_key_id: String,
     /// S3 Secret Access Key
     pub secret_access_key: String,
+    /// 依呼叫者 Access Key 快取的 S3 client 數量上限
+    pub client_pool_size: usize,
+    /// S3 client 閒置多久後移除（秒），0 表示不快取
+    pub client_pool_ttl_secs: u64,
+}
+
+impl fmt::Debug for AppConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppConfig")
+            .field("garage_api_url", &self.garage_api_url)
+            .field("garage_api_key", &REDACTED)
+            .field("garage_api_max_concurrency", &self.garage_api_max_concurrency)
+            .field("grpc_server_addr", &self.grpc_server_addr)
+            .field("log_dir", &self.log_dir)
+            .field("s3_config", &self.s3_config)
+            .field("auth_config", &self.auth_config)
+            .field("rate_limit_config", &self.rate_limit_config)
+            .field("read_only", &self.read_only)
+            .field("tls_config", &self.tls_config)
+            .field("key_expiration_config", &self.key_expiration_config)
+            .field("audit_config", &self.audit_config)
+            .field("confirmation_config", &self.confirmation_config)
+            .field("cluster_config", &self.cluster_config)
+            .field("schedule_config", &self.schedule_config)
+            .finish()
+    }
+}
+
+impl fmt::Debug for S3Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Config")
+            .field("endpoint_url", &self.endpoint_url)
+            .field("region", &self.region)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &REDACTED)
+            .field("client_pool_size", &self.client_pool_size)
+            .field("client_pool_ttl_secs", &self.client_pool_ttl_secs)
+            .finish()
+    }
+}
+
+/// gRPC authentication configuration
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    /// 是否要求呼叫者提供 Garage Admin Token（預設啟用）
+    pub enabled: bool,
+    /// Token 解析結果的快取秒數，0 表示不快取
+    pub cache_ttl_secs: u64,
+}
+
+/// gRPC rate limiting configuration
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// 每個 client 對每個 method 的預設限制
+    pub default_limit: RateLimit,
+    /// 個別 method 的限制，key 為 `package.Service/Method`
+    pub method_limits: HashMap<String, RateLimit>,
+    /// 以 token 或連線來源區分 client
+    pub key: RateLimitKey,
+    /// 同時處理中的 gRPC 請求上限（不含 server streaming），0 表示不限制
+    pub max_concurrent_requests: usize,
+    /// 同時開啟的 server streaming RPC 上限，0 表示不限制
+    pub max_concurrent_streams: usize,
+    /// 每個來源 IP 驗證失敗的次數限制
+    pub auth_failure_limit: RateLimit,
+}
+
+/// Token bucket 參數
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// 每秒補充的請求數，0 表示不限制
+    pub requests_per_sec: f64,
+    /// 可累積的最大請求數
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// 解析 `rps:burst`，省略 burst 時等於 rps
+    fn parse(value: &str) -> Option<Self> {
+        let (rps, burst) = match value.split_once(':') {
+            Some((rps, burst)) => (rps.trim().parse::<f64>().ok()?, burst.trim().parse().ok()?),
+            None => {
+                let rps = value.trim().parse::<f64>().ok()?;
+                (rps, rps.ceil() as u32)
+            }
+        };
+        (rps.is_finite() && rps >= 0.0).then_some(Self { requests_per_sec: rps, burst: burst.max(1) })
+    }
+}
+
+/// 區分 client 的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKey {
+    /// 驗證過的 admin token ID，未啟用認證時改用連線來源 IP
+    Token,
+    /// 連線來源 IP
+    Peer,
+}
+
+/// gRPC server TLS configuration
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    /// Server certificate chain (PEM)
+    pub cert_path: String,
+    /// Server private key (PEM)
+    pub key_path: String,
+    /// Client CA bundle (PEM)，設定後啟用 mTLS
+    pub client_ca_path: Option<String>,
+    /// 檢查憑證檔案變更的間隔秒數，0 表示不自動重新載入
+    pub reload_interval_secs: u64,
+}
+
+/// Background access key expiration check configuration
+#[derive(Debug, Clone)]
+pub struct KeyExpirationConfig {
+    /// 檢查間隔秒數，0 表示停用
+    pub interval_secs: u64,
+    /// 到期前多少秒發出即將到期通知，可設定多個
+    pub warn_lead_secs: Vec<u64>,
+    /// 到期超過此秒數後自動刪除，未設定則不刪除
+    pub purge_after_secs: Option<u64>,
+}
+
+/// Audit trail storage configuration
+#[derive(Debug, Clone)]
+pub struct AuditConfig {
+    /// NDJSON segment 存放目錄
+    pub dir: String,
+    /// 保存天數，未設定則永久保存
+    pub retention_days: Option<u32>,
+}
+
+/// Destructive operation confirmation configuration
+///
+/// `Debug` 會遮蔽簽章金鑰
+#[derive(Clone)]
+pub struct ConfirmationConfig {
+    /// 是否要求破壞性操作二次確認（預設關閉）
+    pub enabled: bool,
+    /// 確認 token 有效秒數
+    pub ttl_secs: u64,
+    /// HMAC 簽章金鑰，未設定時啟動時隨機產生（重啟後舊 token 失效）
+    pub secret: Option<String>,
+    /// 確認者必須與第一次呼叫者不同（四眼原則）
+    pub require_different_caller: bool,
+}
+
+impl fmt::Debug for ConfirmationConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfirmationConfig")
+            .field("enabled", &self.enabled)
+            .field("ttl_secs", &self.ttl_secs)
+            .field("secret", &self.secret.as_ref().map(|_| REDACTED))
+            .field("require_different_caller", &self.require_different_caller)
+            .finish()
+    }
+}
+
+/// Cluster management configuration
+#[derive(Debug, Clone)]
+pub struct ClusterConfig {
+    /// Garage 的 `replication_factor`，Admin API 不提供此值，需與 garage.toml 一致
+    pub replication_factor: usize,
+    /// 節點下線時輪詢 layout history 與 resync 佇列的間隔秒數
+    pub decommission_poll_interval_secs: u64,
+    /// 節點下線時等待 resync 佇列清空的最長秒數，逾時後不再等待（Garage 仍會繼續 resync）
+    pub decommission_resync_timeout_secs: u64,
+    /// WatchCluster 背景輪詢的間隔秒數，0 表示停用
+    pub watch_interval_secs: u64,
+}
+
+/// Scheduled maintenance job configuration
+#[derive(Debug, Clone)]
+pub struct ScheduleConfig {
+    /// 排程設定的 JSON 檔案路徑
+    pub file: String,
+    /// 是否依 cron 自動執行；停用時仍可透過 ScheduleService 管理與手動執行
+    pub enabled: bool,
 }
 
 impl AppConfig {
@@ -36,6 +233,11 @@ impl AppConfig {
         let garage_api_key = env::var("GARAGE_API_KEY")
             .map_err(|_| ConfigError::MissingEnvVar("GARAGE_API_KEY".to_string()))?;
         
+        let garage_api_max_concurrency = env::var("GARAGE_API_MAX_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(16);
+
         let grpc_server_addr = env::var("GRPC_SERVER_ADDR")
             .unwrap_or_else(|_| "0.0.0.0:50051".to_string());
 
@@ -59,14 +261,195 @@ impl AppConfig {
             region: s3_region,
             access_key_id: s3_access_key_id,
             secret_access_key: s3_secret_access_key,
+            client_pool_size: env::var("S3_CLIENT_POOL_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(64),
+            client_pool_ttl_secs: env::var("S3_CLIENT_POOL_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(600),
+        };
+
+        // gRPC Auth Configuration
+        let auth_enabled = env::var("GRPC_AUTH_ENABLED")
+            .map(|v| !matches!(v.to_lowercase().as_str(), "false" | "0" | "no"))
+            .unwrap_or(true);
+
+        let auth_cache_ttl_secs = env::var("GRPC_AUTH_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30);
+
+        let auth_config = AuthConfig {
+            enabled: auth_enabled,
+            cache_ttl_secs: auth_cache_ttl_secs,
+        };
+
+        // gRPC Rate Limit Configuration
+        let default_limit = RateLimit {
+            requests_per_sec: env::var("GRPC_RATE_LIMIT_RPS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v: &f64| v.is_finite() && *v >= 0.0)
+                .unwrap_or(20.0),
+            burst: env::var("GRPC_RATE_LIMIT_BURST")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(40u32)
+                .max(1),
+        };
+
+        // 格式：`bucket.BucketService/ReadBucket=5:10,object.ObjectService/UploadObject=1`
+        let method_limits = env::var("GRPC_RATE_LIMIT_METHODS")
+            .map(|v| {
+                v.split(',')
+                    .filter_map(|entry| {
+                        let (method, limit) = entry.split_once('=')?;
+                        let method = method.trim().trim_start_matches('/');
+                        Some((format!("/{}", method), RateLimit::parse(limit)?))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let rate_limit_config = RateLimitConfig {
+            default_limit,
+            method_limits,
+            key: match env::var("GRPC_RATE_LIMIT_KEY").map(|v| v.to_lowercase()).as_deref() {
+                Ok("peer") => RateLimitKey::Peer,
+                _ => RateLimitKey::Token,
+            },
+            max_concurrent_requests: env::var("GRPC_MAX_CONCURRENT_REQUESTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(256),
+            max_concurrent_streams: env::var("GRPC_MAX_CONCURRENT_STREAMS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(64),
+            auth_failure_limit: env::var("GRPC_AUTH_FAILURE_LIMIT")
+                .ok()
+                .and_then(|v| RateLimit::parse(&v))
+                .unwrap_or(RateLimit { requests_per_sec: 0.5, burst: 20 }),
+        };
+
+        let read_only = env::var("READ_ONLY_MODE")
+            .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"))
+            .unwrap_or(false);
+
+        // gRPC TLS Configuration
+        let tls_config = match env::var("GRPC_TLS_CERT") {
+            Ok(cert_path) => {
+                let key_path = env::var("GRPC_TLS_KEY")
+                    .map_err(|_| ConfigError::MissingEnvVar("GRPC_TLS_KEY".to_string()))?;
+                let client_ca_path = env::var("GRPC_TLS_CLIENT_CA")
+                    .ok()
+                    .filter(|v| !v.is_empty());
+                let reload_interval_secs = env::var("GRPC_TLS_RELOAD_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(60);
+
+                Some(TlsConfig {
+                    cert_path,
+                    key_path,
+                    client_ca_path,
+                    reload_interval_secs,
+                })
+            }
+            Err(_) => None,
+        };
+
+        // Access Key Expiration Configuration
+        let key_expiration_config = KeyExpirationConfig {
+            interval_secs: env::var("KEY_EXPIRATION_CHECK_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            // 預設到期前 7 天與 1 天各通知一次
+            warn_lead_secs: env::var("KEY_EXPIRATION_WARN_LEAD_SECS")
+                .map(|v| v.split(',').filter_map(|s| s.trim().parse().ok()).collect())
+                .unwrap_or_else(|_| vec![7 * 86400, 86400]),
+            purge_after_secs: env::var("KEY_EXPIRATION_PURGE_AFTER_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+        };
+
+        // Audit Trail Configuration
+        let audit_config = AuditConfig {
+            dir: env::var("AUDIT_LOG_DIR")
+                .unwrap_or_else(|_| "./audit".to_string()),
+            retention_days: env::var("AUDIT_RETENTION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+        };
+
+        // Destructive Operation Confirmation Configuration
+        let confirmation_config = ConfirmationConfig {
+            // 預設關閉：client 需先支援 ConfirmationRequired 的兩段式流程
+            enabled: env::var("CONFIRMATION_TOKENS_ENABLED")
+                .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"))
+                .unwrap_or(false),
+            ttl_secs: env::var("CONFIRMATION_TOKEN_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(300),
+            secret: env::var("CONFIRMATION_TOKEN_SECRET")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            require_different_caller: env::var("CONFIRMATION_REQUIRE_DIFFERENT_CALLER")
+                .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1" | "yes"))
+                .unwrap_or(false),
+        };
+
+        // Cluster Management Configuration
+        let cluster_config = ClusterConfig {
+            replication_factor: env::var("GARAGE_REPLICATION_FACTOR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(3),
+            decommission_poll_interval_secs: env::var("DECOMMISSION_POLL_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(10),
+            decommission_resync_timeout_secs: env::var("DECOMMISSION_RESYNC_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            watch_interval_secs: env::var("CLUSTER_WATCH_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+        };
+
+        // Scheduled Maintenance Configuration
+        let schedule_config = ScheduleConfig {
+            file: env::var("SCHEDULE_FILE")
+                .unwrap_or_else(|_| "./schedules.json".to_string()),
+            enabled: env::var("SCHEDULER_ENABLED")
+                .map(|v| !matches!(v.to_lowercase().as_str(), "false" | "0" | "no"))
+                .unwrap_or(true),
         };
 
         Ok(Self {
             garage_api_url,
             garage_api_key,
+            garage_api_max_concurrency,
             grpc_server_addr,
             log_dir,
             s3_config,
+            auth_config,
+            rate_limit_config,
+            read_only,
+            tls_config,
+            key_expiration_config,
+            audit_config,
+            confirmation_config,
+            cluster_config,
+            schedule_config,
         })
     }
 }
@@ -0,0 +1,323 @@
+//! HMAC-signed confirmation tokens
+//!
+//! Token 格式：`base64url(claims JSON).base64url(HMAC-SHA256)`。
+//! Claims 只記錄操作對象的 SHA-256，不會把 bucket / key 名稱帶給呼叫者以外的地方。
+//! 已使用的 nonce 保存在記憶體中直到 token 過期，確保每個 token 只能使用一次。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::domain::entities::{ConfirmationToken, DestructiveOperation};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ConfirmationTokenRepository;
+use crate::infrastructure::config::ConfirmationConfig;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 隨機金鑰長度（bytes）
+const RANDOM_KEY_LEN: usize = 32;
+
+/// Token 內容
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    /// 操作類型
+    act: String,
+    /// 操作對象的 SHA-256（hex）
+    dig: String,
+    /// 第一次呼叫者
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+    /// 到期時間（Unix 秒）
+    exp: i64,
+    nonce: String,
+}
+
+/// Confirmation token repository using HMAC-SHA256 signed, single-use tokens
+pub struct HmacConfirmationTokenRepository {
+    enabled: bool,
+    key: Vec<u8>,
+    ttl: Duration,
+    require_different_caller: bool,
+    /// 已使用的 nonce → 到期時間
+    used: Mutex<HashMap<String, i64>>,
+}
+
+impl HmacConfirmationTokenRepository {
+    /// 未設定 secret 時產生隨機金鑰
+    pub fn new(config: &ConfirmationConfig) -> Result<Self, DomainError> {
+        let key = match &config.secret {
+            Some(secret) => secret.as_bytes().to_vec(),
+            None => {
+                if config.enabled {
+                    warn!("CONFIRMATION_TOKEN_SECRET is not set, confirmation tokens are only valid on this instance until restart");
+                }
+                random_bytes(RANDOM_KEY_LEN)?
+            }
+        };
+
+        let ttl = i64::try_from(config.ttl_secs)
+            .ok()
+            .and_then(Duration::try_seconds)
+            .ok_or_else(|| DomainError::ValidationError(format!(
+                "CONFIRMATION_TOKEN_TTL_SECS is out of range: {}",
+                config.ttl_secs
+            )))?;
+
+        Ok(Self {
+            enabled: config.enabled,
+            key,
+            ttl,
+            require_different_caller: config.require_different_caller,
+            used: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn mac(&self) -> HmacSha256 {
+        HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length")
+    }
+
+    fn sign(&self, claims: &Claims) -> Result<String, DomainError> {
+        let payload = serde_json::to_vec(claims)
+            .map_err(|e| DomainError::InternalError(format!("Failed to encode confirmation token: {}", e)))?;
+        let payload = URL_SAFE_NO_PAD.encode(payload);
+
+        let mut mac = self.mac();
+        mac.update(payload.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        Ok(format!("{}.{}", payload, signature))
+    }
+
+    /// 驗證簽章並解出 claims
+    fn verify(&self, token: &str) -> Result<Claims, DomainError> {
+        let (payload, signature) = token.split_once('.').ok_or_else(invalid_token)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid_token())?;
+
+        let mut mac = self.mac();
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&signature).map_err(|_| invalid_token())?;
+
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid_token())?;
+        serde_json::from_slice(&payload).map_err(|_| invalid_token())
+    }
+
+    fn issue_at(
+        &self,
+        operation: &DestructiveOperation,
+        caller: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<ConfirmationToken, DomainError> {
+        let expires_at = now
+            .checked_add_signed(self.ttl)
+            .ok_or_else(|| DomainError::InternalError("Confirmation token expiry is out of range".to_string()))?;
+        let claims = Claims {
+            act: operation.action().to_string(),
+            dig: subject_digest(operation),
+            iss: caller.map(str::to_string),
+            exp: expires_at.timestamp(),
+            nonce: hex(&random_bytes(16)?),
+        };
+
+        Ok(ConfirmationToken {
+            token: self.sign(&claims)?,
+            expires_at,
+        })
+    }
+
+    fn consume_at(
+        &self,
+        operation: &DestructiveOperation,
+        token: &str,
+        caller: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let claims = self.verify(token)?;
+
+        if claims.exp <= now.timestamp() {
+            return Err(DomainError::InvalidConfirmation(
+                "Confirmation token has expired, request a new one".to_string(),
+            ));
+        }
+        if claims.act != operation.action() || claims.dig != subject_digest(operation) {
+            return Err(DomainError::InvalidConfirmation(format!(
+                "Confirmation token was not issued for this {} request",
+                operation.action()
+            )));
+        }
+        if self.require_different_caller {
+            match caller {
+                None => {
+                    return Err(DomainError::PermissionDenied(
+                        "Confirming a destructive operation requires an authenticated caller".to_string(),
+                    ))
+                }
+                Some(caller) if claims.iss.as_deref() == Some(caller) => {
+                    return Err(DomainError::PermissionDenied(
+                        "Destructive operation must be confirmed by a different caller".to_string(),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        let mut used = self.used.lock().expect("confirmation nonces poisoned");
+        used.retain(|_, exp| *exp > now.timestamp());
+        if used.insert(claims.nonce, claims.exp).is_some() {
+            return Err(DomainError::InvalidConfirmation(
+                "Confirmation token has already been used".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ConfirmationTokenRepository for HmacConfirmationTokenRepository {
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    async fn issue(
+        &self,
+        operation: &DestructiveOperation,
+        caller: Option<&str>,
+    ) -> Result<ConfirmationToken, DomainError> {
+        self.issue_at(operation, caller, Utc::now())
+    }
+
+    async fn consume(
+        &self,
+        operation: &DestructiveOperation,
+        token: &str,
+        caller: Option<&str>,
+    ) -> Result<(), DomainError> {
+        self.consume_at(operation, token, caller, Utc::now())
+    }
+}
+
+fn subject_digest(operation: &DestructiveOperation) -> String {
+    hex(&Sha256::digest(operation.subject().as_bytes()))
+}
+
+fn random_bytes(len: usize) -> Result<Vec<u8>, DomainError> {
+    let mut buf = vec![0u8; len];
+    getrandom::fill(&mut buf)
+        .map_err(|e| DomainError::InternalError(format!("Failed to generate random bytes: {}", e)))?;
+    Ok(buf)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn invalid_token() -> DomainError {
+    DomainError::InvalidConfirmation("Invalid confirmation token".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repository(require_different_caller: bool) -> HmacConfirmationTokenRepository {
+        HmacConfirmationTokenRepository::new(&ConfirmationConfig {
+            enabled: true,
+            ttl_secs: 60,
+            secret: Some("test-secret".to_string()),
+            require_different_caller,
+        })
+        .unwrap()
+    }
+
+    fn delete_bucket(id: &str) -> DestructiveOperation {
+        DestructiveOperation::new("DeleteBucket", [id])
+    }
+
+    #[test]
+    fn test_out_of_range_ttl_is_rejected() {
+        let result = HmacConfirmationTokenRepository::new(&ConfirmationConfig {
+            enabled: true,
+            ttl_secs: u64::MAX,
+            secret: None,
+            require_different_caller: false,
+        });
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_round_trip_is_single_use() {
+        let repo = repository(false);
+        let now = Utc::now();
+        let issued = repo.issue_at(&delete_bucket("b1"), Some("alice"), now).unwrap();
+
+        assert!(repo.consume_at(&delete_bucket("b1"), &issued.token, Some("alice"), now).is_ok());
+        assert!(matches!(
+            repo.consume_at(&delete_bucket("b1"), &issued.token, Some("alice"), now),
+            Err(DomainError::InvalidConfirmation(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_tampered_and_foreign_tokens() {
+        let repo = repository(false);
+        let now = Utc::now();
+        let issued = repo.issue_at(&delete_bucket("b1"), None, now).unwrap();
+
+        let (payload, signature) = issued.token.split_once('.').unwrap();
+        let forged = format!("{}x.{}", payload, signature);
+        assert!(repo.consume_at(&delete_bucket("b1"), &forged, None, now).is_err());
+
+        let mut other = repository(false);
+        other.key = b"other-secret".to_vec();
+        assert!(other.consume_at(&delete_bucket("b1"), &issued.token, None, now).is_err());
+    }
+
+    #[test]
+    fn test_rejects_expired_token() {
+        let repo = repository(false);
+        let now = Utc::now();
+        let issued = repo.issue_at(&delete_bucket("b1"), None, now).unwrap();
+
+        let later = now + Duration::seconds(61);
+        assert!(matches!(
+            repo.consume_at(&delete_bucket("b1"), &issued.token, None, later),
+            Err(DomainError::InvalidConfirmation(_))
+        ));
+    }
+
+    #[test]
+    fn test_token_is_bound_to_operation() {
+        let repo = repository(false);
+        let now = Utc::now();
+        let issued = repo.issue_at(&delete_bucket("b1"), None, now).unwrap();
+
+        assert!(repo.consume_at(&delete_bucket("b2"), &issued.token, None, now).is_err());
+        let purge = DestructiveOperation::new("PurgeBlocks", ["b1"]);
+        assert!(repo.consume_at(&purge, &issued.token, None, now).is_err());
+    }
+
+    #[test]
+    fn test_require_different_caller() {
+        let repo = repository(true);
+        let now = Utc::now();
+        let issued = repo.issue_at(&delete_bucket("b1"), Some("alice"), now).unwrap();
+
+        assert!(matches!(
+            repo.consume_at(&delete_bucket("b1"), &issued.token, Some("alice"), now),
+            Err(DomainError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            repo.consume_at(&delete_bucket("b1"), &issued.token, None, now),
+            Err(DomainError::PermissionDenied(_))
+        ));
+        assert!(repo.consume_at(&delete_bucket("b1"), &issued.token, Some("bob"), now).is_ok());
+    }
+}
@@ -0,0 +1,7 @@
+//! Confirmation tokens
+//!
+//! 破壞性操作二次確認 token 的簽發與驗證
+
+mod hmac_token_repository;
+
+pub use hmac_token_repository::HmacConfirmationTokenRepository;
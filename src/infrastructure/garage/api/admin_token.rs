@@ -52,7 +52,8 @@ pub struct AdminTokenListItemResponse {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdminTokenInfoResponse {
-    pub id: String,
+    /// 設定檔中的 admin_token 沒有 ID，Garage 會回傳 null
+    pub id: Option<String>,
     pub name: Option<String>,
     pub created: Option<String>,
     pub expiration: Option<String>,
@@ -42,11 +42,13 @@ pub struct LayoutParametersRequest {
 }
 
 /// Zone 冗餘設定請求
+///
+/// Garage 格式為 `{"atLeast": 2}` 或 `"maximum"`
 #[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[serde(rename_all = "camelCase")]
 pub enum ZoneRedundancyRequest {
-    Value(i32),
-    Maximum { maximum: bool },
+    AtLeast(i32),
+    Maximum,
 }
 
 /// 應用布局請求
@@ -180,11 +182,13 @@ pub struct LayoutParametersResponse {
 }
 
 /// Zone 冗餘響應
+///
+/// Garage 格式為 `{"atLeast": 2}` 或 `"maximum"`
 #[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
+#[serde(rename_all = "camelCase")]
 pub enum ZoneRedundancyResponse {
-    Value(i32),
-    Maximum { maximum: bool },
+    AtLeast(i32),
+    Maximum,
 }
 
 /// 應用布局結果響應
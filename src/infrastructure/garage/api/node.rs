@@ -13,20 +13,31 @@ pub struct LaunchRepairRequest {
 }
 
 /// 修復類型請求
+///
+/// 一般修復序列化為字串（例如 `"blockRc"`），scrub 為 `{"scrub": "start"}`
 #[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[serde(rename_all = "camelCase")]
 pub enum RepairTypeRequest {
-    Simple(String),
-    Scrub {
-        scrub: ScrubCommandRequest,
-    },
+    Tables,
+    Blocks,
+    Versions,
+    MultipartUploads,
+    BlockRefs,
+    BlockRc,
+    Rebalance,
+    Aliases,
+    ClearResyncQueue,
+    Scrub(ScrubCommandRequest),
 }
 
 /// Scrub 命令請求
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ScrubCommandRequest {
-    pub command: String,
+pub enum ScrubCommandRequest {
+    Start,
+    Pause,
+    Resume,
+    Cancel,
 }
 
 // ============ Response Types ============
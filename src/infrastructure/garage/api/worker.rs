@@ -62,7 +62,26 @@ pub struct WorkerInfoResponse {
     pub consecutive_errors: i64,
     pub last_error: Option<WorkerErrorResponse>,
     pub tranquility: Option<i64>,
-    pub freeform: Option<String>,
+    pub persistent_errors: Option<u64>,
+    pub freeform: Option<Freeform>,
+}
+
+/// Worker freeform 文字：Garage v2 回傳字串陣列，舊版為單一字串
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Freeform {
+    Lines(Vec<String>),
+    Text(String),
+}
+
+impl Freeform {
+    /// 以換行合併為單一字串
+    pub fn into_text(self) -> String {
+        match self {
+            Self::Lines(lines) => lines.join("\n"),
+            Self::Text(text) => text,
+        }
+    }
 }
 
 /// Worker 錯誤響應
@@ -89,3 +108,34 @@ pub struct SetVariableResultResponse {
     pub old_value: Option<String>,
     pub new_value: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker_info(freeform: serde_json::Value) -> WorkerInfoResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": 3,
+            "name": "Block scrub worker",
+            "state": "idle",
+            "errors": 0,
+            "consecutiveErrors": 0,
+            "persistentErrors": 2,
+            "freeform": freeform,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_freeform_accepts_lines_and_text() {
+        let v2 = worker_info(serde_json::json!(["Last scrub completed at x", "Next scrub scheduled for y"]));
+        assert_eq!(v2.persistent_errors, Some(2));
+        assert_eq!(
+            v2.freeform.map(Freeform::into_text).as_deref(),
+            Some("Last scrub completed at x\nNext scrub scheduled for y")
+        );
+
+        let legacy = worker_info(serde_json::json!("Scrub paused, resumes at z"));
+        assert_eq!(legacy.freeform.map(Freeform::into_text).as_deref(), Some("Scrub paused, resumes at z"));
+    }
+}
@@ -2,10 +2,12 @@
 
 use reqwest::{Client, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tracing::{info, error};
 use crate::domain::errors::DomainError;
-use crate::shared::get_trace_id;
+use crate::shared::{get_trace_id, redact_text};
 
 // Re-export from endpoints module
 pub use super::endpoints::GarageApiEndpoint;
@@ -16,6 +18,8 @@ pub struct GarageClient {
     client: Client,
     base_url: String,
     api_key: String,
+    /// 同時進行中的 Admin API 呼叫上限，所有 clone 共用
+    concurrency: Option<Arc<Semaphore>>,
 }
 
 impl GarageClient {
@@ -30,9 +34,37 @@ impl GarageClient {
             client,
             base_url,
             api_key,
+            concurrency: None,
         }
     }
 
+    /// 限制同時進行中的 Admin API 呼叫數量，超過時排隊等待；0 表示不限制
+    ///
+    /// 限制由所有 clone（包含 `with_api_key` 建立的 client）共用，保護整個 Garage cluster
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.concurrency = (max_concurrency > 0).then(|| Arc::new(Semaphore::new(max_concurrency)));
+        self
+    }
+
+    /// 以另一組 Admin Token 建立 client（共用底層連線池）
+    ///
+    /// 用於以呼叫者自身的 token 向 Garage 查詢身分
+    pub fn with_api_key(&self, api_key: &str) -> Self {
+        Self {
+            client: self.client.clone(),
+            base_url: self.base_url.clone(),
+            api_key: api_key.to_string(),
+            concurrency: self.concurrency.clone(),
+        }
+    }
+
+    /// 取得呼叫許可，未設定上限時直接放行
+    async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let semaphore = self.concurrency.clone()?;
+        // Semaphore 不會被關閉
+        semaphore.acquire_owned().await.ok()
+    }
+
     /// Truncate response for logging (max 500 chars)
     fn truncate_response(response: &str) -> String {
         if response.len() > 500 {
@@ -44,10 +76,12 @@ impl GarageClient {
 
     /// Log API call result
     /// 格式: timestamp [api] trace_id | [method] uri | request | [status] response | duration_ms
+    ///
+    /// Request / response body 先遮蔽敏感欄位再截斷（例如 `secretAccessKey`、`secretToken`）
     fn log_api_call(&self, method: &str, uri: &str, status: u16, duration_ms: u128, request_body: Option<&str>, response_body: &str) {
         let trace_id = get_trace_id();
-        let request = Self::truncate_response(request_body.unwrap_or("{}"));
-        let response = Self::truncate_response(response_body);
+        let request = Self::truncate_response(&redact_text(request_body.unwrap_or("{}")));
+        let response = Self::truncate_response(&redact_text(response_body));
         info!(
             target: "api",
             trace_id = %trace_id,
@@ -71,7 +105,8 @@ impl GarageClient {
     /// Log API call error
     fn log_api_error(&self, method: &str, uri: &str, error: &str, duration_ms: u128, request_body: Option<&str>) {
         let trace_id = get_trace_id();
-        let request = Self::truncate_response(request_body.unwrap_or("{}"));
+        let request = Self::truncate_response(&redact_text(request_body.unwrap_or("{}")));
+        let error = redact_text(error);
         error!(
             target: "api",
             trace_id = %trace_id,
@@ -93,6 +128,7 @@ impl GarageClient {
     /// Make a GET request
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, DomainError> {
         let url = format!("{}{}", self.base_url, path);
+        let _permit = self.acquire().await;
         let start = Instant::now();
         
         let response = self
@@ -123,6 +159,7 @@ impl GarageClient {
         body: &B,
     ) -> Result<T, DomainError> {
         let url = format!("{}{}", self.base_url, path);
+        let _permit = self.acquire().await;
         let start = Instant::now();
         let request_json = serde_json::to_string(body).unwrap_or_default();
         
@@ -150,6 +187,7 @@ impl GarageClient {
     /// Make a POST request without body (for v2 API endpoints like DeleteBucket)
     pub async fn post_empty(&self, path: &str) -> Result<(), DomainError> {
         let url = format!("{}{}", self.base_url, path);
+        let _permit = self.acquire().await;
         let start = Instant::now();
         
         let response = self
@@ -182,6 +220,7 @@ impl GarageClient {
     /// Make a POST request without body but with response (for v2 API endpoints like RevertClusterLayout)
     pub async fn post_with_empty_body<T: DeserializeOwned>(&self, path: &str) -> Result<T, DomainError> {
         let url = format!("{}{}", self.base_url, path);
+        let _permit = self.acquire().await;
         let start = Instant::now();
         
         let response = self
@@ -210,6 +249,7 @@ impl GarageClient {
         body: &B,
     ) -> Result<T, DomainError> {
         let url = format!("{}{}", self.base_url, path);
+        let _permit = self.acquire().await;
         let start = Instant::now();
         let request_json = serde_json::to_string(body).unwrap_or_default();
         
@@ -237,6 +277,7 @@ impl GarageClient {
     /// Make a DELETE request
     pub async fn delete(&self, path: &str) -> Result<(), DomainError> {
         let url = format!("{}{}", self.base_url, path);
+        let _permit = self.acquire().await;
         let start = Instant::now();
         
         let response = self
@@ -279,6 +320,12 @@ impl GarageClient {
             StatusCode::NOT_FOUND => {
                 Err(DomainError::BucketNotFound("Resource not found".to_string()))
             }
+            StatusCode::UNAUTHORIZED => {
+                Err(DomainError::Unauthenticated(body.to_string()))
+            }
+            StatusCode::FORBIDDEN => {
+                Err(DomainError::PermissionDenied(body.to_string()))
+            }
             StatusCode::BAD_REQUEST => {
                 // Parse error response to identify specific errors
                 if body.contains("Local alias already exists") {
@@ -6,6 +6,7 @@ pub mod api;
 pub mod client;
 pub mod endpoints;
 pub mod repositories;
+pub mod statistics;
 
 pub use api::*;
 pub use client::*;
@@ -9,7 +9,7 @@ use crate::domain::repositories::{AccessKeyCommandRepository, AccessKeyQueryRepo
 use crate::domain::entities::{AccessKey, AccessKeyListItem, KeyPermissions, KeyBucket, BucketPermissions};
 use crate::domain::aggregates::{AccessKeyAggregate, BucketVO, BucketPermissionVO};
 use crate::domain::entities::garage::{
-    CreateKeyRequest, KeyInfoResponse, KeyUpdateResponse, KeyListItemResponse, UpdateKeyRequest, KeyPermRequest,
+    CreateKeyRequest, ImportKeyRequest, KeyInfoResponse, KeyUpdateResponse, KeyListItemResponse, UpdateKeyRequest, KeyPermRequest,
 };
 use crate::infrastructure::garage::client::GarageClient;
 use crate::infrastructure::garage::endpoints::GarageApiEndpoint;
@@ -32,8 +32,7 @@ impl GarageAccessKeyCommandRepository {
 #[async_trait]
 impl AccessKeyCommandRepository for GarageAccessKeyCommandRepository {
     async fn get(&self, id: &str) -> Result<AccessKeyAggregate, DomainError> {
-        let path = format!("{}?id={}&showSecretKey=true", 
-            GarageApiEndpoint::GetKeyInfo.path(), id);
+        let path = format!("{}?id={}", GarageApiEndpoint::GetKeyInfo.path(), id);
 
         let response: KeyInfoResponse = self.client.get(&path).await?;
         Ok(map_response_to_aggregate(response))
@@ -56,6 +55,17 @@ impl AccessKeyCommandRepository for GarageAccessKeyCommandRepository {
         Ok(map_response_to_aggregate(response))
     }
     
+    async fn import(&self, aggregate: &AccessKeyAggregate) -> Result<AccessKeyAggregate, DomainError> {
+        let request = ImportKeyRequest {
+            access_key_id: aggregate.id().to_string(),
+            secret_access_key: aggregate.secret_access_key().to_string(),
+            name: Some(aggregate.name().to_string()).filter(|n| !n.is_empty()),
+        };
+
+        let response: KeyInfoResponse = self.client.post(GarageApiEndpoint::ImportKey.path(), &request).await?;
+        Ok(map_response_to_aggregate(response))
+    }
+    
     async fn save(&self, aggregate: &AccessKeyAggregate) -> Result<AccessKeyAggregate, DomainError> {
         let path = format!("{}?id={}", GarageApiEndpoint::UpdateKey.path(), aggregate.id());
         
@@ -106,8 +116,7 @@ impl AccessKeyQueryRepository for GarageAccessKeyQueryRepository {
     }
     
     async fn find_by_id(&self, id: &str) -> Result<AccessKey, DomainError> {
-        let path = format!("{}?id={}&showSecretKey=true", 
-            GarageApiEndpoint::GetKeyInfo.path(), id);
+        let path = format!("{}?id={}", GarageApiEndpoint::GetKeyInfo.path(), id);
 
         let response: KeyInfoResponse = self.client.get(&path).await?;
         
@@ -117,7 +126,7 @@ impl AccessKeyQueryRepository for GarageAccessKeyQueryRepository {
         Ok(AccessKey {
             id: response.access_key_id,
             name: response.name,
-            secret_access_key: response.secret_access_key,
+            secret_access_key: None,
             created,
             expiration,
             expired: AccessKeyListItem::compute_expired(expiration),
@@ -136,6 +145,16 @@ impl AccessKeyQueryRepository for GarageAccessKeyQueryRepository {
             }).collect(),
         })
     }
+
+    async fn find_secret(&self, id: &str) -> Result<String, DomainError> {
+        let path = format!("{}?id={}&showSecretKey=true",
+            GarageApiEndpoint::GetKeyInfo.path(), id);
+
+        let response: KeyInfoResponse = self.client.get(&path).await?;
+        response.secret_access_key.ok_or_else(|| {
+            DomainError::GarageApiError(format!("Garage did not return the secret of key {}", id))
+        })
+    }
 }
 
 // ============ Mapping Functions ============
@@ -165,7 +184,7 @@ fn map_response_to_aggregate(response: KeyInfoResponse) -> AccessKeyAggregate {
         created,
         expiration,
         response.permissions.create_bucket,
-        response.secret_access_key,
+        response.secret_access_key.unwrap_or_default(),
     )
 }
 
@@ -81,7 +81,7 @@ impl AdminTokenRepository for GarageAdminTokenRepository {
         let request = UpdateAdminTokenRequest {
             name: input.name,
             expiration: input.expiration,
-            never_expires: None,
+            never_expires: input.never_expires,
             scope: input.scope,
         };
         let response: AdminTokenInfoResponse = self.client.post(&path, &request).await?;
@@ -96,7 +96,7 @@ impl AdminTokenRepository for GarageAdminTokenRepository {
 
 fn convert_token_info(response: AdminTokenInfoResponse) -> AdminTokenInfo {
     AdminTokenInfo {
-        id: response.id,
+        id: response.id.unwrap_or_default(),
         name: response.name,
         created: response.created,
         expiration: response.expiration,
@@ -8,7 +8,8 @@ use crate::domain::repositories::{BucketRepository, CreateBucketInput};
 use crate::domain::value_objects::{LocalAlias, Quotas};
 use crate::infrastructure::garage::client::{ GarageClient, GarageApiEndpoint };
 use crate::infrastructure::garage::api::{
-    AddGlobalAliasRequest, AddLocalAliasRequest, RemoveGlobalAliasRequest, RemoveLocalAliasRequest,
+    AddGlobalAliasRequest, AddLocalAliasRequest, CleanupIncompleteUploadsRequest,
+    CleanupIncompleteUploadsResponse, RemoveGlobalAliasRequest, RemoveLocalAliasRequest,
 };
 use crate::domain::entities::garage::{      
     CreateBucketRequest, CreateBucketResponse,
@@ -254,4 +255,18 @@ impl BucketRepository for GarageBucketRepository {
         ).await?;
         Ok(self.convert_to_bucket_detail(response))
     }
+
+    // ============ Maintenance 操作 ============
+
+    async fn cleanup_incomplete_uploads(&self, bucket_id: &str, older_than_secs: u64) -> Result<i64, DomainError> {
+        let request = CleanupIncompleteUploadsRequest {
+            bucket_id: bucket_id.to_string(),
+            older_than_secs: older_than_secs as i64,
+        };
+        let response: CleanupIncompleteUploadsResponse = self.client.post(
+            GarageApiEndpoint::CleanupIncompleteUploads.path(),
+            &request
+        ).await?;
+        Ok(response.uploads_deleted)
+    }
 }
@@ -13,12 +13,14 @@ use crate::domain::repositories::{ClusterRepository, UpdateLayoutInput};
 use crate::infrastructure::garage::api::{
     ApplyLayoutRequest, ApplyLayoutResultResponse, ClusterHealthResponse, ClusterLayoutHistoryResponse,
     ClusterLayoutResponse, ClusterStatisticsResponse, ClusterStatusResponse, ConnectNodeResultResponse,
-    ConnectNodesRequest, LayoutParametersResponse, LayoutRoleResponse, LayoutVersionResponse,
+    ConnectNodesRequest, LayoutParametersRequest, LayoutParametersResponse, LayoutRoleResponse, LayoutVersionResponse,
     NodeRoleResponse, PartitionInfoResponse, PreviewLayoutChangesResponse, SkipDeadNodesRequest,
-    SkipDeadNodesResultResponse, UpdateLayoutRequest, UpdateTrackerResponse, ZoneRedundancyResponse,
+    SkipDeadNodesResultResponse, UpdateLayoutRequest, UpdateTrackerResponse, ZoneRedundancyRequest,
+    ZoneRedundancyResponse,
 };
 use crate::infrastructure::garage::client::GarageClient;
 use crate::infrastructure::garage::endpoints::GarageApiEndpoint;
+use crate::infrastructure::garage::statistics::parse_cluster_statistics;
 
 /// Cluster Repository 實現
 pub struct GarageClusterRepository {
@@ -43,9 +45,9 @@ impl ClusterRepository for GarageClusterRepository {
                 is_up: n.is_up,
                 last_seen_secs_ago: n.last_seen_secs_ago,
                 hostname: n.hostname,
-                role: n.role.map(|r| convert_node_role(r)),
-                data_partition: n.data_partition.map(|p| convert_partition_info(p)),
-                metadata_partition: n.metadata_partition.map(|p| convert_partition_info(p)),
+                role: n.role.map(convert_node_role),
+                data_partition: n.data_partition.map(convert_partition_info),
+                metadata_partition: n.metadata_partition.map(convert_partition_info),
             }).collect(),
         })
     }
@@ -66,9 +68,7 @@ impl ClusterRepository for GarageClusterRepository {
     
     async fn get_statistics(&self) -> Result<ClusterStatistics, DomainError> {
         let response: ClusterStatisticsResponse = self.client.get(GarageApiEndpoint::GetClusterStatistics.path()).await?;
-        Ok(ClusterStatistics {
-            freeform: response.freeform,
-        })
+        Ok(parse_cluster_statistics(response.freeform))
     }
     
     async fn connect_nodes(&self, nodes: Vec<String>) -> Result<Vec<ConnectNodeResult>, DomainError> {
@@ -85,18 +85,22 @@ impl ClusterRepository for GarageClusterRepository {
         Ok(convert_layout(response))
     }
     
-    async fn update_layout(&self, roles: Vec<UpdateLayoutInput>) -> Result<ClusterLayout, DomainError> {
+    async fn update_layout(
+        &self,
+        roles: Vec<UpdateLayoutInput>,
+        parameters: Option<LayoutParameters>,
+    ) -> Result<ClusterLayout, DomainError> {
         use crate::infrastructure::garage::api::RoleChangeRequest;
         
         let request = UpdateLayoutRequest {
             roles: Some(roles.into_iter().map(|r| RoleChangeRequest {
                 id: r.node_id,
-                remove: None,
+                remove: r.remove.then_some(true),
                 zone: r.zone,
                 capacity: r.capacity.map(|c| c as i64),
                 tags: r.tags,
             }).collect()),
-            parameters: None,
+            parameters: parameters.map(convert_layout_parameters_request),
         };
         let response: ClusterLayoutResponse = self.client.post(GarageApiEndpoint::UpdateClusterLayout.path(), &request).await?;
         Ok(convert_layout(response))
@@ -129,7 +133,7 @@ impl ClusterRepository for GarageClusterRepository {
         Ok(ClusterLayoutHistory {
             current_version: response.current_version,
             min_ack: response.min_ack,
-            versions: response.versions.into_iter().map(|v| convert_layout_version(v)).collect(),
+            versions: response.versions.into_iter().map(convert_layout_version).collect(),
             update_trackers: response.update_trackers.into_iter()
                 .map(|(k, v)| (k, convert_update_tracker(v)))
                 .collect(),
@@ -167,7 +171,7 @@ fn convert_layout(response: ClusterLayoutResponse) -> ClusterLayout {
     ClusterLayout {
         version: response.version,
         partition_size: response.partition_size,
-        roles: response.roles.into_iter().map(|r| convert_layout_role(r)).collect(),
+        roles: response.roles.into_iter().map(convert_layout_role).collect(),
         staged_role_changes: response.staged_role_changes.into_iter().map(|r| {
             StagedRoleChange {
                 id: r.id.clone(),
@@ -182,8 +186,8 @@ fn convert_layout(response: ClusterLayoutResponse) -> ClusterLayout {
                 },
             }
         }).collect(),
-        parameters: response.parameters.map(|p| convert_layout_parameters(p)),
-        staged_parameters: response.staged_parameters.map(|p| convert_layout_parameters(p)),
+        parameters: response.parameters.map(convert_layout_parameters),
+        staged_parameters: response.staged_parameters.map(convert_layout_parameters),
     }
 }
 
@@ -199,8 +203,17 @@ fn convert_layout_role(response: LayoutRoleResponse) -> LayoutRole {
 fn convert_layout_parameters(response: LayoutParametersResponse) -> LayoutParameters {
     LayoutParameters {
         zone_redundancy: response.zone_redundancy.map(|zr| match zr {
-            ZoneRedundancyResponse::Value(v) => ZoneRedundancy::Value(v),
-            ZoneRedundancyResponse::Maximum { maximum } => ZoneRedundancy::Maximum { maximum },
+            ZoneRedundancyResponse::AtLeast(v) => ZoneRedundancy::Value(v),
+            ZoneRedundancyResponse::Maximum => ZoneRedundancy::Maximum { maximum: true },
+        }),
+    }
+}
+
+fn convert_layout_parameters_request(parameters: LayoutParameters) -> LayoutParametersRequest {
+    LayoutParametersRequest {
+        zone_redundancy: parameters.zone_redundancy.map(|zr| match zr {
+            ZoneRedundancy::Value(v) => ZoneRedundancyRequest::AtLeast(v),
+            ZoneRedundancy::Maximum { .. } => ZoneRedundancyRequest::Maximum,
         }),
     }
 }
@@ -209,7 +222,7 @@ fn convert_layout_version(response: LayoutVersionResponse) -> LayoutVersion {
     LayoutVersion {
         version: response.version,
         partition_size: response.partition_size,
-        roles: response.roles.into_iter().map(|r| convert_layout_role(r)).collect(),
+        roles: response.roles.into_iter().map(convert_layout_role).collect(),
     }
 }
 
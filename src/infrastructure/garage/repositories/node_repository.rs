@@ -3,11 +3,12 @@
 //! Infrastructure 層的 Repository 具體實現
 
 use async_trait::async_trait;
-use crate::domain::entities::{MultiNodeResponse, NodeInfo, NodeStatistics};
+use crate::domain::entities::{MultiNodeResponse, NodeInfo, NodeStatistics, RepairType, ScrubCommand};
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::NodeRepository;
 use crate::infrastructure::garage::api::{
     LaunchRepairRequest, MultiNodeResponse as ApiMultiNodeResponse, NodeInfoResponse, NodeStatisticsResponse,
+    RepairTypeRequest, ScrubCommandRequest,
 };
 use crate::infrastructure::garage::client::GarageClient;
 use crate::infrastructure::garage::endpoints::GarageApiEndpoint;
@@ -48,9 +49,10 @@ impl NodeRepository for GarageNodeRepository {
         let path = format!("{}?node={}", GarageApiEndpoint::GetNodeStatistics.path(), node);
         let response: ApiMultiNodeResponse<NodeStatisticsResponse> = self.client.get(&path).await?;
         Ok(MultiNodeResponse {
-            success: response.success.into_iter().map(|(k, v)| (k, NodeStatistics {
-                freeform: v.freeform,
-            })).collect(),
+            success: response.success.into_iter().map(|(k, v)| {
+                let statistics = NodeStatistics::parse(&k, v.freeform);
+                (k, statistics)
+            }).collect(),
             error: response.error,
         })
     }
@@ -59,22 +61,40 @@ impl NodeRepository for GarageNodeRepository {
         let path = format!("{}?node={}", GarageApiEndpoint::CreateMetadataSnapshot.path(), node);
         let response: ApiMultiNodeResponse<()> = self.client.post_with_empty_body(&path).await?;
         Ok(MultiNodeResponse {
-            success: response.success.into_iter().map(|(k, _)| (k, ())).collect(),
+            success: response.success.into_keys().map(|k| (k, ())).collect(),
             error: response.error,
         })
     }
     
-    async fn launch_repair(&self, node: &str, repair_type: &str) -> Result<MultiNodeResponse<()>, DomainError> {
-        use crate::infrastructure::garage::api::RepairTypeRequest;
-        
+    async fn launch_repair(&self, node: &str, repair_type: RepairType) -> Result<MultiNodeResponse<()>, DomainError> {
         let path = format!("{}?node={}", GarageApiEndpoint::LaunchRepairOperation.path(), node);
         let request = LaunchRepairRequest {
-            repair_type: RepairTypeRequest::Simple(repair_type.to_string()),
+            repair_type: convert_repair_type(repair_type),
         };
         let response: ApiMultiNodeResponse<()> = self.client.post(&path, &request).await?;
         Ok(MultiNodeResponse {
-            success: response.success.into_iter().map(|(k, _)| (k, ())).collect(),
+            success: response.success.into_keys().map(|k| (k, ())).collect(),
             error: response.error,
         })
     }
 }
+
+fn convert_repair_type(repair_type: RepairType) -> RepairTypeRequest {
+    match repair_type {
+        RepairType::Tables => RepairTypeRequest::Tables,
+        RepairType::Blocks => RepairTypeRequest::Blocks,
+        RepairType::Versions => RepairTypeRequest::Versions,
+        RepairType::MultipartUploads => RepairTypeRequest::MultipartUploads,
+        RepairType::BlockRefs => RepairTypeRequest::BlockRefs,
+        RepairType::BlockRc => RepairTypeRequest::BlockRc,
+        RepairType::Rebalance => RepairTypeRequest::Rebalance,
+        RepairType::Aliases => RepairTypeRequest::Aliases,
+        RepairType::ClearResyncQueue => RepairTypeRequest::ClearResyncQueue,
+        RepairType::Scrub(command) => RepairTypeRequest::Scrub(match command {
+            ScrubCommand::Start => ScrubCommandRequest::Start,
+            ScrubCommand::Pause => ScrubCommandRequest::Pause,
+            ScrubCommand::Resume => ScrubCommandRequest::Resume,
+            ScrubCommand::Cancel => ScrubCommandRequest::Cancel,
+        }),
+    }
+}
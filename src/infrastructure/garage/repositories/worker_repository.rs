@@ -9,7 +9,7 @@ use crate::domain::entities::{
 use crate::domain::errors::DomainError;
 use crate::domain::repositories::WorkerRepository;
 use crate::infrastructure::garage::api::{
-    GetWorkerInfoRequest, GetWorkerVariableRequest, ListWorkersRequest, 
+    Freeform, GetWorkerInfoRequest, GetWorkerVariableRequest, ListWorkersRequest, 
     MultiNodeWorkerResponse, SetVariableResultResponse, SetWorkerVariableRequest, 
     WorkerInfoResponse, WorkerVariablesResponse,
 };
@@ -38,7 +38,7 @@ impl WorkerRepository for GarageWorkerRepository {
         let response: MultiNodeWorkerResponse<Vec<WorkerInfoResponse>> = self.client.post(&path, &request).await?;
         Ok(MultiNodeResponse {
             success: response.success.into_iter().map(|(k, v)| {
-                (k, v.into_iter().map(|w| convert_worker_info(w)).collect())
+                (k, v.into_iter().map(convert_worker_info).collect())
             }).collect(),
             error: response.error,
         })
@@ -94,6 +94,7 @@ fn convert_worker_info(response: WorkerInfoResponse) -> WorkerInfo {
             secs_ago: e.secs_ago,
         }),
         tranquility: response.tranquility,
-        freeform: response.freeform,
+        persistent_errors: response.persistent_errors,
+        freeform: response.freeform.map(Freeform::into_text).filter(|text| !text.is_empty()),
     }
 }
@@ -0,0 +1,179 @@
+//! Garage statistics parser
+//!
+//! Garage 的 `GetClusterStatistics` / `GetNodeStatistics` 只回傳給人看的 freeform 文字，
+//! 這裡將其解析為結構化資料。格式以 Garage v2 的輸出為準：
+//!
+//! ```text
+//! Storage nodes:
+//!   ID                Hostname  Zone  Capacity  Part.  DataAvail                 MetaAvail
+//!   563e1ac825ee3323  garage-a  dc1   100.0 GB  86     87.3 GB/100.0 GB (87.3%)  87.3 GB/100.0 GB (87.3%)
+//!
+//! Estimated available storage space cluster-wide (might be lower in practice):
+//!   data: 68.3 GB
+//!   metadata: 68.3 GB
+//! ```
+//!
+//! 區段與表格的解析見 `domain::entities::freeform`，單一節點的統計見 `NodeStatistics::parse`。
+//! 無法辨識的區段與欄位會被略過，解析永遠不會失敗，原始文字另外保留。
+
+use crate::domain::entities::freeform::{parse_bytes, parse_storage_nodes, parse_table_stats, sections};
+use crate::domain::entities::{ClusterStatistics, DiskUsage, NodeStorageStatistics, StorageTotals};
+
+/// 解析 `GetClusterStatistics` 的輸出
+pub fn parse_cluster_statistics(freeform: String) -> ClusterStatistics {
+    let mut nodes = Vec::new();
+    let mut tables = Vec::new();
+    let mut estimate = Estimate::default();
+
+    for section in sections(&freeform) {
+        let title = section.title.to_ascii_lowercase();
+        if title.starts_with("storage nodes") {
+            nodes = parse_storage_nodes(&section.lines);
+        } else if title.starts_with("table stats") {
+            tables.extend(parse_table_stats(&section.lines));
+        } else if title.starts_with("estimated available storage space") {
+            estimate = parse_estimate(&section.lines);
+        }
+    }
+
+    let totals = storage_totals(&nodes, estimate);
+    ClusterStatistics {
+        nodes,
+        tables,
+        totals,
+        freeform,
+    }
+}
+
+// ============ Estimate / Totals ============
+
+#[derive(Default)]
+struct Estimate {
+    data: Option<u64>,
+    metadata: Option<u64>,
+    upper_bound: bool,
+}
+
+/// `  data: < 68.3 GB` / `  metadata: 68.3 GB`
+fn parse_estimate(lines: &[&str]) -> Estimate {
+    let mut estimate = Estimate::default();
+
+    for line in lines {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let (value, upper_bound) = match value.strip_prefix('<') {
+            Some(rest) => (rest.trim(), true),
+            None => (value, false),
+        };
+        let Some(bytes) = parse_bytes(value) else {
+            continue;
+        };
+
+        match key.trim().to_ascii_lowercase().as_str() {
+            "data" => estimate.data = Some(bytes),
+            "metadata" => estimate.metadata = Some(bytes),
+            _ => continue,
+        }
+        estimate.upper_bound |= upper_bound;
+    }
+    estimate
+}
+
+fn storage_totals(nodes: &[NodeStorageStatistics], estimate: Estimate) -> StorageTotals {
+    let sum = |usage: fn(&NodeStorageStatistics) -> Option<DiskUsage>| {
+        nodes.iter().filter_map(usage).fold(DiskUsage::default(), |acc, u| acc + u)
+    };
+
+    StorageTotals {
+        capacity: nodes.iter().filter_map(|n| n.capacity).sum(),
+        data: sum(|n| n.data),
+        metadata: sum(|n| n.metadata),
+        estimated_data_available: estimate.data,
+        estimated_metadata_available: estimate.metadata,
+        estimate_is_upper_bound: estimate.upper_bound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Garage v2 三節點集群
+    const HEALTHY_CLUSTER: &str = "\
+Storage nodes:
+  ID                Hostname  Zone  Capacity  Part.  DataAvail                 MetaAvail
+  563e1ac825ee3323  garage-a  dc1   100.0 GB  86     87.3 GB/100.0 GB (87.3%)  9.5 GB/10.0 GB (95.0%)
+  86f0f26ae4afbd59  garage-b  dc2   100.0 GB  85     91.0 GB/100.0 GB (91.0%)  9.0 GB/10.0 GB (90.0%)
+  a2f2b4f7c2d0e8a1  garage-c  dc3   50.0 GB   85     40.2 GB/50.0 GB (80.4%)   4.5 GB/5.0 GB (90.0%)
+
+Estimated available storage space cluster-wide (might be lower in practice):
+  data: 68.3 GB
+  metadata: 7.7 GB
+";
+
+    /// 一個節點離線，Garage 無法取得其磁碟資訊
+    const DEGRADED_CLUSTER: &str = "\
+Storage nodes:
+  ID                Hostname  Zone  Capacity  Part.  DataAvail               MetaAvail
+  563e1ac825ee3323  garage-a  dc1   1.0 TB    128    512.0 GB/1.0 TB (51.2%)  512.0 GB/1.0 TB (51.2%)
+  86f0f26ae4afbd59  ?         dc2   1.0 TB    128    ?                        ?
+
+Estimated available storage space cluster-wide (might be lower in practice):
+  data: < 131.1 GB
+  metadata: < 131.1 GB
+A precise estimate could not be given as information is missing for some storage nodes.
+";
+
+    #[test]
+    fn test_parse_healthy_cluster() {
+        let stats = parse_cluster_statistics(HEALTHY_CLUSTER.to_string());
+
+        assert_eq!(stats.nodes.len(), 3);
+        assert_eq!(
+            stats.nodes[0],
+            NodeStorageStatistics {
+                id: "563e1ac825ee3323".to_string(),
+                hostname: Some("garage-a".to_string()),
+                zone: Some("dc1".to_string()),
+                capacity: Some(100_000_000_000),
+                partitions: Some(86),
+                data: Some(DiskUsage { available: 87_300_000_000, total: 100_000_000_000 }),
+                metadata: Some(DiskUsage { available: 9_500_000_000, total: 10_000_000_000 }),
+            }
+        );
+        assert_eq!(stats.nodes[2].capacity, Some(50_000_000_000));
+
+        assert_eq!(stats.totals.capacity, 250_000_000_000);
+        assert_eq!(stats.totals.data, DiskUsage { available: 218_500_000_000, total: 250_000_000_000 });
+        assert_eq!(stats.totals.data.used(), 31_500_000_000);
+        assert_eq!(stats.totals.estimated_data_available, Some(68_300_000_000));
+        assert_eq!(stats.totals.estimated_metadata_available, Some(7_700_000_000));
+        assert!(!stats.totals.estimate_is_upper_bound);
+        assert_eq!(stats.freeform, HEALTHY_CLUSTER);
+    }
+
+    #[test]
+    fn test_parse_degraded_cluster() {
+        let stats = parse_cluster_statistics(DEGRADED_CLUSTER.to_string());
+
+        assert_eq!(stats.nodes.len(), 2);
+        assert_eq!(stats.nodes[1].hostname, None);
+        assert_eq!(stats.nodes[1].capacity, Some(1_000_000_000_000));
+        assert_eq!(stats.nodes[1].data, None);
+        assert_eq!(stats.totals.data.total, 1_000_000_000_000);
+        assert_eq!(stats.totals.estimated_data_available, Some(131_100_000_000));
+        assert!(stats.totals.estimate_is_upper_bound);
+    }
+
+    #[test]
+    fn test_unrecognized_output_keeps_freeform() {
+        let stats = parse_cluster_statistics("something went sideways".to_string());
+
+        assert!(stats.nodes.is_empty());
+        assert!(stats.tables.is_empty());
+        assert_eq!(stats.totals, StorageTotals::default());
+        assert_eq!(stats.freeform, "something went sideways");
+    }
+}
@@ -0,0 +1,319 @@
+//! gRPC authentication / authorization middleware
+//!
+//! 從 gRPC metadata 讀取 `authorization: Bearer <token>`，
+//! 透過 Garage Admin Token API 解析呼叫者身分，
+//! 再依各 RPC 所需的 scope 使用 `AdminTokenAggregate::has_permission` 檢查權限
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use http::{Request, Response};
+use http_body::Body;
+use tonic::Status;
+use tower::{Layer, Service};
+use tracing::warn;
+
+use crate::domain::aggregates::{AdminTokenAggregate, AdminTokenScope};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::AdminTokenRepository;
+use crate::infrastructure::config::RateLimit;
+use crate::infrastructure::garage::{GarageAdminTokenRepository, GarageClient};
+use crate::shared::{current_context, with_context};
+use super::rate_limit::{AuthFailureLimiter, peer_key, resource_exhausted};
+use super::rpc_methods;
+
+// ============ Method → Scope ============
+
+/// 取得 gRPC method 所需的 Admin Token scope
+///
+/// `path` 為 gRPC 的完整路徑，例如 `/bucket.BucketService/ListBucket`。
+/// 回傳 `None` 表示只要通過認證即可呼叫；
+/// 未列在 `rpc_methods::RPC_METHODS` 的 method 一律要求 `admin:*`。
+pub fn required_scope(path: &str) -> Option<&'static str> {
+    rpc_methods::find(path).map_or(Some(AdminTokenScope::ADMIN), |m| m.scope)
+}
+
+/// 從 `authorization` header 解析 Bearer Token
+fn extract_bearer_token<B>(req: &Request<B>) -> Option<&str> {
+    let value = req.headers().get(http::header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("bearer") {
+        return None;
+    }
+    let token = token.trim();
+    (!token.is_empty()).then_some(token)
+}
+
+// ============ Token Authenticator ============
+
+/// 以 Garage Admin Token API 解析呼叫者的 token
+///
+/// 解析結果會依 `cache_ttl` 快取，避免每個 RPC 都多一次 Admin API 呼叫；
+/// 驗證失敗依來源 IP 限制次數，避免偽造的 token 大量觸發 Admin API 呼叫
+pub struct TokenAuthenticator {
+    garage_client: GarageClient,
+    cache_ttl: Duration,
+    cache: Mutex<HashMap<String, (AdminTokenAggregate, Instant)>>,
+    failures: AuthFailureLimiter,
+}
+
+impl TokenAuthenticator {
+    pub fn new(garage_client: GarageClient, cache_ttl: Duration, failure_limit: RateLimit) -> Self {
+        Self {
+            garage_client,
+            cache_ttl,
+            cache: Mutex::new(HashMap::new()),
+            failures: AuthFailureLimiter::new(failure_limit),
+        }
+    }
+
+    /// 解析 token 對應的 Admin Token
+    ///
+    /// 以呼叫者自己的 token 呼叫 `GetCurrentAdminTokenInfo`，
+    /// Garage 拒絕時視為未認證；`peer` 為連線來源，用於限制驗證失敗次數
+    pub async fn authenticate(&self, token: &str, peer: &str) -> Result<AdminTokenAggregate, Status> {
+        if let Some(cached) = self.cached(token) {
+            return Ok(cached);
+        }
+
+        if let Err(retry_after) = self.failures.check(peer) {
+            warn!("Too many failed authentications | client: {}", peer);
+            return Err(resource_exhausted(
+                "Too many failed authentication attempts".to_string(),
+                retry_after,
+            ));
+        }
+
+        let repository = GarageAdminTokenRepository::new(self.garage_client.with_api_key(token));
+        let info = repository.get_current().await.map_err(|e| match e {
+            DomainError::Unauthenticated(_) | DomainError::PermissionDenied(_) => {
+                self.failures.record_failure(peer);
+                Status::unauthenticated("Invalid admin token")
+            }
+            e => {
+                warn!("Failed to resolve admin token: {}", e);
+                Status::unavailable("Unable to verify admin token")
+            }
+        })?;
+
+        let token_aggregate = AdminTokenAggregate::reconstitute(
+            info.id,
+            info.name,
+            info.scope,
+            info.expired,
+            info.expiration,
+        );
+        if token_aggregate.is_expired() {
+            self.failures.record_failure(peer);
+            return Err(Status::unauthenticated("Admin token has expired"));
+        }
+
+        self.store(token, token_aggregate.clone());
+        Ok(token_aggregate)
+    }
+
+    fn cached(&self, token: &str) -> Option<AdminTokenAggregate> {
+        let cache = self.cache.lock().ok()?;
+        cache
+            .get(token)
+            .filter(|(_, cached_at)| cached_at.elapsed() < self.cache_ttl)
+            .map(|(token_aggregate, _)| token_aggregate.clone())
+    }
+
+    fn store(&self, token: &str, token_aggregate: AdminTokenAggregate) {
+        if self.cache_ttl.is_zero() {
+            return;
+        }
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.retain(|_, (_, cached_at)| cached_at.elapsed() < self.cache_ttl);
+            cache.insert(token.to_string(), (token_aggregate, Instant::now()));
+        }
+    }
+}
+
+/// 呼叫者識別字串：優先使用 token ID，設定檔中的 admin_token 沒有 ID 時改用名稱
+fn caller_identity(token: &AdminTokenAggregate) -> String {
+    if !token.id().is_empty() {
+        token.id().to_string()
+    } else {
+        token.name().unwrap_or("anonymous").to_string()
+    }
+}
+
+// ============ Layer ============
+
+/// Layer that authenticates callers and enforces per-method scopes
+#[derive(Clone)]
+pub struct AuthLayer {
+    authenticator: Option<Arc<TokenAuthenticator>>,
+}
+
+impl AuthLayer {
+    pub fn new(authenticator: Arc<TokenAuthenticator>) -> Self {
+        Self {
+            authenticator: Some(authenticator),
+        }
+    }
+
+    /// 停用認證，所有請求直接放行
+    pub fn disabled() -> Self {
+        Self { authenticator: None }
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        AuthMiddleware {
+            inner: service,
+            authenticator: self.authenticator.clone(),
+        }
+    }
+}
+
+/// Middleware that rejects requests without a sufficiently scoped admin token
+#[derive(Clone)]
+pub struct AuthMiddleware<S> {
+    inner: S,
+    authenticator: Option<Arc<TokenAuthenticator>>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for AuthMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Body + Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let Some(authenticator) = self.authenticator.clone() else {
+            return Box::pin(async move { inner.call(req).await });
+        };
+
+        let method = req.uri().path().to_string();
+        let token = extract_bearer_token(&req).map(str::to_string);
+        let peer = peer_key(&req);
+
+        Box::pin(async move {
+            let Some(token) = token else {
+                return Ok(Status::unauthenticated("Missing bearer token").into_http());
+            };
+
+            let admin_token = match authenticator.authenticate(&token, &peer).await {
+                Ok(admin_token) => admin_token,
+                Err(status) => return Ok(status.into_http()),
+            };
+
+            if let Some(scope) = required_scope(&method).filter(|s| !admin_token.has_permission(s)) {
+                warn!(
+                    "Permission denied | method: {} | token: {} | required_scope: {}",
+                    method,
+                    caller_identity(&admin_token),
+                    scope
+                );
+                return Ok(Status::permission_denied(format!(
+                    "Admin token lacks required scope: {}",
+                    scope
+                ))
+                .into_http());
+            }
+
+            let ctx = current_context()
+                .unwrap_or_default()
+                .with_caller(caller_identity(&admin_token));
+
+            // 讓下游 service 可透過 `Request::extensions()` 取得呼叫者的 token
+            req.extensions_mut().insert(admin_token);
+            with_context(ctx, inner.call(req)).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_with_authorization(value: &str) -> Request<()> {
+        Request::builder()
+            .header(http::header::AUTHORIZATION, value)
+            .body(())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_extract_bearer_token() {
+        let req = request_with_authorization("Bearer secret-token");
+        assert_eq!(extract_bearer_token(&req), Some("secret-token"));
+    }
+
+    #[test]
+    fn test_extract_bearer_token_case_insensitive_scheme() {
+        let req = request_with_authorization("bearer secret-token");
+        assert_eq!(extract_bearer_token(&req), Some("secret-token"));
+    }
+
+    #[test]
+    fn test_extract_bearer_token_rejects_other_schemes() {
+        let req = request_with_authorization("Basic dXNlcjpwYXNz");
+        assert_eq!(extract_bearer_token(&req), None);
+    }
+
+    #[test]
+    fn test_extract_bearer_token_missing_header() {
+        let req = Request::builder().body(()).unwrap();
+        assert_eq!(extract_bearer_token(&req), None);
+    }
+
+    #[test]
+    fn test_required_scope_read_and_write() {
+        assert_eq!(required_scope("/bucket.BucketService/ListBucket"), Some(AdminTokenScope::READ_BUCKETS));
+        assert_eq!(required_scope("/bucket.BucketService/DeleteBucket"), Some(AdminTokenScope::WRITE_BUCKETS));
+        assert_eq!(required_scope("/access_key.AccessKeyService/ReadKey"), Some(AdminTokenScope::READ_KEYS));
+        assert_eq!(required_scope("/access_key.AccessKeyService/RevealKeySecret"), Some(AdminTokenScope::WRITE_KEYS));
+        assert_eq!(required_scope("/cluster.ClusterService/ApplyLayout"), Some(AdminTokenScope::WRITE_CLUSTER_LAYOUT));
+    }
+
+    #[test]
+    fn test_required_scope_unknown_method_requires_admin() {
+        assert_eq!(required_scope("/unknown.Service/Method"), Some(AdminTokenScope::ADMIN));
+        assert_eq!(required_scope("/block.BlockService/PurgeBlocks"), Some(AdminTokenScope::ADMIN));
+        assert_eq!(required_scope("/audit.AuditService/ListAuditEvents"), Some(AdminTokenScope::ADMIN));
+        assert_eq!(required_scope("/system.SystemService/SetReadOnlyMode"), Some(AdminTokenScope::ADMIN));
+    }
+
+    #[test]
+    fn test_required_scope_current_token_needs_authentication_only() {
+        assert_eq!(required_scope("/admin_token.AdminTokenService/GetCurrentAdminToken"), None);
+        assert_eq!(required_scope("/system.SystemService/GetReadOnlyMode"), None);
+        assert_eq!(required_scope("/admin_token.AdminTokenService/CreateAdminToken"), Some(AdminTokenScope::ADMIN));
+    }
+
+    #[test]
+    fn test_scoped_token_permissions() {
+        let token = AdminTokenAggregate::reconstitute(
+            "token-id".to_string(),
+            None,
+            vec![AdminTokenScope::READ_BUCKETS.to_string()],
+            false,
+            None,
+        );
+        assert!(token.has_permission(required_scope("/bucket.BucketService/ReadBucket").unwrap()));
+        assert!(!token.has_permission(required_scope("/bucket.BucketService/DeleteBucket").unwrap()));
+    }
+}
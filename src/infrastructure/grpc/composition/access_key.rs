@@ -4,46 +4,90 @@
 
 use std::sync::Arc;
 
+use crate::domain::events::EventBus;
 use crate::infrastructure::garage::{
     GarageClient, GarageAccessKeyCommandRepository, GarageAccessKeyQueryRepository,
+    GarageBucketRepository,
 };
 use crate::application::commands::access_key::handlers::{
-    CreateKeyHandler, UpdateKeyHandler, DeleteKeyHandler,
+    CreateKeyHandler, ImportKeyHandler, ImportKeysHandler, UpdateKeyHandler, DeleteKeyHandler,
+    RotateKeyHandler,
 };
+use crate::application::commands::bucket::handlers::BatchAllowBucketKeyHandler;
 use crate::application::queries::access_key::handlers::{
-    ListKeysHandler, ReadKeyHandler,
+    ExportKeysHandler, ListKeysHandler, ReadKeyHandler, RevealKeySecretHandler,
 };
 use crate::infrastructure::grpc::services::AccessKeyGrpcService;
 
 /// Access Key Service 的依賴建構器
 pub struct AccessKeyServiceBuilder {
     client: GarageClient,
+    event_bus: Arc<dyn EventBus>,
 }
 
 impl AccessKeyServiceBuilder {
-    pub fn new(client: GarageClient) -> Self {
-        Self { client }
+    pub fn new(
+        client: GarageClient,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self { client, event_bus }
     }
 
     pub fn build(self) -> AccessKeyGrpcService {
         let command_repository = Arc::new(GarageAccessKeyCommandRepository::new(self.client.clone()));
         let query_repository = Arc::new(GarageAccessKeyQueryRepository::new(self.client.clone()));
+        let bucket_repository = Arc::new(GarageBucketRepository::new(self.client.clone()));
 
         // Command Handlers
         let create_key_handler = Arc::new(CreateKeyHandler::new(command_repository.clone()));
+        let import_key_handler = Arc::new(ImportKeyHandler::new(
+            command_repository.clone(),
+            self.event_bus.clone(),
+        ));
         let update_key_handler = Arc::new(UpdateKeyHandler::new(command_repository.clone()));
-        let delete_key_handler = Arc::new(DeleteKeyHandler::new(command_repository));
+        let delete_key_handler = Arc::new(DeleteKeyHandler::new(command_repository.clone()));
+        let rotate_key_handler = Arc::new(RotateKeyHandler::new(
+            command_repository,
+            bucket_repository.clone(),
+            self.event_bus.clone(),
+        ));
+
+        // 批次匯入透過 import-key 與 allow-bucket-key 重建
+        let batch_allow_handler = Arc::new(BatchAllowBucketKeyHandler::new(
+            bucket_repository.clone(),
+            self.event_bus.clone(),
+        ));
+        let import_keys_handler = Arc::new(ImportKeysHandler::new(
+            import_key_handler.clone(),
+            update_key_handler.clone(),
+            batch_allow_handler,
+            bucket_repository,
+            query_repository.clone(),
+        ));
         
         // Query Handlers
         let list_keys_handler = Arc::new(ListKeysHandler::new(query_repository.clone()));
-        let read_key_handler = Arc::new(ReadKeyHandler::new(query_repository));
+        let read_key_handler = Arc::new(ReadKeyHandler::new(query_repository.clone()));
+        let reveal_key_secret_handler = Arc::new(RevealKeySecretHandler::new(
+            query_repository.clone(),
+            self.event_bus.clone(),
+        ));
+        let export_keys_handler = Arc::new(ExportKeysHandler::new(
+            query_repository,
+            self.event_bus,
+        ));
 
         AccessKeyGrpcService::new(
             create_key_handler,
+            import_key_handler,
             update_key_handler,
             delete_key_handler,
+            rotate_key_handler,
+            import_keys_handler,
             list_keys_handler,
             read_key_handler,
+            reveal_key_secret_handler,
+            export_keys_handler,
         )
     }
 }
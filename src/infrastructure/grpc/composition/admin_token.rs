@@ -0,0 +1,62 @@
+//! Admin Token Service Composition
+//!
+//! 負責組合 AdminTokenGrpcService 及其所有 handlers
+
+use std::sync::Arc;
+
+use crate::domain::events::EventBus;
+use crate::infrastructure::garage::{GarageClient, GarageAdminTokenRepository};
+use crate::application::commands::admin_token::handlers::{
+    CreateAdminTokenHandler, UpdateAdminTokenHandler, DeleteAdminTokenHandler,
+};
+use crate::application::queries::admin_token::handlers::{
+    ListAdminTokensHandler, ReadAdminTokenHandler, GetCurrentAdminTokenHandler,
+};
+use crate::infrastructure::grpc::services::AdminTokenGrpcService;
+
+/// Admin Token Service 的依賴建構器
+pub struct AdminTokenServiceBuilder {
+    client: GarageClient,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl AdminTokenServiceBuilder {
+    pub fn new(
+        client: GarageClient,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self { client, event_bus }
+    }
+
+    pub fn build(self) -> AdminTokenGrpcService {
+        let repository = Arc::new(GarageAdminTokenRepository::new(self.client));
+
+        // Command Handlers
+        let create_admin_token_handler = Arc::new(CreateAdminTokenHandler::new(
+            repository.clone(),
+            self.event_bus.clone(),
+        ));
+        let update_admin_token_handler = Arc::new(UpdateAdminTokenHandler::new(
+            repository.clone(),
+            self.event_bus.clone(),
+        ));
+        let delete_admin_token_handler = Arc::new(DeleteAdminTokenHandler::new(
+            repository.clone(),
+            self.event_bus,
+        ));
+
+        // Query Handlers
+        let list_admin_tokens_handler = Arc::new(ListAdminTokensHandler::new(repository.clone()));
+        let read_admin_token_handler = Arc::new(ReadAdminTokenHandler::new(repository));
+        let get_current_admin_token_handler = Arc::new(GetCurrentAdminTokenHandler::new());
+
+        AdminTokenGrpcService::new(
+            create_admin_token_handler,
+            update_admin_token_handler,
+            delete_admin_token_handler,
+            list_admin_tokens_handler,
+            read_admin_token_handler,
+            get_current_admin_token_handler,
+        )
+    }
+}
@@ -0,0 +1,27 @@
+//! Audit Service Composition
+//!
+//! 負責組合 AuditGrpcService 及其所有 handlers
+
+use std::sync::Arc;
+
+use crate::domain::repositories::AuditRepository;
+use crate::application::queries::audit::handlers::ListAuditEventsHandler;
+use crate::infrastructure::grpc::services::AuditGrpcService;
+
+/// Audit Service 的依賴建構器
+pub struct AuditServiceBuilder {
+    repository: Arc<dyn AuditRepository>,
+}
+
+impl AuditServiceBuilder {
+    pub fn new(repository: Arc<dyn AuditRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub fn build(self) -> AuditGrpcService {
+        // Query Handlers
+        let list_audit_events_handler = Arc::new(ListAuditEventsHandler::new(self.repository));
+
+        AuditGrpcService::new(list_audit_events_handler)
+    }
+}
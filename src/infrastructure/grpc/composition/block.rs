@@ -4,41 +4,50 @@
 
 use std::sync::Arc;
 
-use crate::infrastructure::garage::{GarageClient, GarageBlockRepository};
-use crate::application::commands::block::handlers::{
-    PurgeBlocksHandler, RetryBlockResyncHandler,
-};
+use crate::domain::repositories::ConfirmationTokenRepository;
+use crate::infrastructure::garage::{GarageClient, GarageBlockRepository, GarageBucketRepository};
+use crate::application::commands::block::handlers::PurgeBlocksHandler;
 use crate::application::queries::block::handlers::{
-    GetBlockInfoHandler, ListBlockErrorsHandler,
+    GetBlockInfoHandler, ListBlockErrorsHandler, GetBlockErrorReportHandler,
 };
 use crate::infrastructure::grpc::services::BlockGrpcService;
+use super::MaintenanceHandlers;
 
 /// Block Service 的依賴建構器
 pub struct BlockServiceBuilder {
     client: GarageClient,
+    confirmations: Arc<dyn ConfirmationTokenRepository>,
+    maintenance: MaintenanceHandlers,
 }
 
 impl BlockServiceBuilder {
-    pub fn new(client: GarageClient) -> Self {
-        Self { client }
+    pub fn new(
+        client: GarageClient,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+        maintenance: MaintenanceHandlers,
+    ) -> Self {
+        Self { client, confirmations, maintenance }
     }
 
     pub fn build(self) -> BlockGrpcService {
-        let repository = Arc::new(GarageBlockRepository::new(self.client));
+        let repository = Arc::new(GarageBlockRepository::new(self.client.clone()));
+        let bucket_repository = Arc::new(GarageBucketRepository::new(self.client));
 
         // Command Handlers
-        let purge_blocks_handler = Arc::new(PurgeBlocksHandler::new(repository.clone()));
-        let retry_block_resync_handler = Arc::new(RetryBlockResyncHandler::new(repository.clone()));
+        let purge_blocks_handler = Arc::new(PurgeBlocksHandler::new(repository.clone(), self.confirmations));
+        let retry_block_resync_handler = self.maintenance.retry_block_resync;
 
         // Query Handlers
         let get_block_info_handler = Arc::new(GetBlockInfoHandler::new(repository.clone()));
-        let list_block_errors_handler = Arc::new(ListBlockErrorsHandler::new(repository));
+        let list_block_errors_handler = Arc::new(ListBlockErrorsHandler::new(repository.clone()));
+        let get_block_error_report_handler = Arc::new(GetBlockErrorReportHandler::new(repository, bucket_repository));
 
         BlockGrpcService::new(
             purge_blocks_handler,
             retry_block_resync_handler,
             get_block_info_handler,
             list_block_errors_handler,
+            get_block_error_report_handler,
         )
     }
 }
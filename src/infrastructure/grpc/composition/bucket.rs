@@ -5,6 +5,7 @@
 use std::sync::Arc;
 
 use crate::domain::events::EventBus;
+use crate::domain::repositories::ConfirmationTokenRepository;
 use crate::infrastructure::garage::{GarageClient, GarageBucketRepository};
 use crate::application::commands::bucket::handlers::{
     CreateBucketHandler, UpdateBucketHandler, DeleteBucketHandler,
@@ -20,14 +21,16 @@ use crate::infrastructure::grpc::services::BucketGrpcService;
 pub struct BucketServiceBuilder {
     client: GarageClient,
     event_bus: Arc<dyn EventBus>,
+    confirmations: Arc<dyn ConfirmationTokenRepository>,
 }
 
 impl BucketServiceBuilder {
     pub fn new(
         client: GarageClient,
         event_bus: Arc<dyn EventBus>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
     ) -> Self {
-        Self { client, event_bus }
+        Self { client, event_bus, confirmations }
     }
 
     pub fn build(self) -> BucketGrpcService {
@@ -45,6 +48,7 @@ impl BucketServiceBuilder {
         let delete_bucket_handler = Arc::new(DeleteBucketHandler::new(
             repository.clone(),
             self.event_bus.clone(),
+            self.confirmations,
         ));
         let add_bucket_alias_handler = Arc::new(AddBucketAliasHandler::new(
             repository.clone(),
@@ -3,44 +3,69 @@
 //! 負責組合 ClusterGrpcService 及其所有 handlers
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::infrastructure::garage::{GarageClient, GarageClusterRepository};
+use crate::domain::repositories::ConfirmationTokenRepository;
+use crate::infrastructure::config::ClusterConfig;
+use crate::infrastructure::garage::{GarageClient, GarageClusterRepository, GarageNodeRepository};
 use crate::application::commands::cluster::handlers::{
     ConnectNodesHandler, UpdateLayoutHandler, ApplyLayoutHandler,
-    RevertLayoutHandler, SkipDeadNodesHandler,
+    RevertLayoutHandler, SkipDeadNodesHandler, DecommissionNodeHandler, BootstrapClusterHandler,
 };
 use crate::application::queries::cluster::handlers::{
-    GetClusterStatusHandler, GetClusterHealthHandler, GetClusterLayoutHandler,
-    GetLayoutHistoryHandler, PreviewLayoutChangesHandler,
+    GetClusterStatusHandler, GetClusterHealthHandler, GetClusterStatisticsHandler, GetClusterLayoutHandler,
+    GetLayoutHistoryHandler, PreviewLayoutChangesHandler, DiffLayoutsHandler, SimulateLayoutHandler,
 };
 use crate::infrastructure::grpc::services::ClusterGrpcService;
+use crate::infrastructure::tasks::ClusterWatch;
 
 /// Cluster Service 的依賴建構器
 pub struct ClusterServiceBuilder {
     client: GarageClient,
+    confirmations: Arc<dyn ConfirmationTokenRepository>,
+    config: ClusterConfig,
+    watch: ClusterWatch,
 }
 
 impl ClusterServiceBuilder {
-    pub fn new(client: GarageClient) -> Self {
-        Self { client }
+    pub fn new(
+        client: GarageClient,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+        config: ClusterConfig,
+        watch: ClusterWatch,
+    ) -> Self {
+        Self { client, confirmations, config, watch }
     }
 
     pub fn build(self) -> ClusterGrpcService {
-        let repository = Arc::new(GarageClusterRepository::new(self.client));
+        let repository = Arc::new(GarageClusterRepository::new(self.client.clone()));
+        let node_repository = Arc::new(GarageNodeRepository::new(self.client));
 
         // Command Handlers
         let connect_nodes_handler = Arc::new(ConnectNodesHandler::new(repository.clone()));
         let update_layout_handler = Arc::new(UpdateLayoutHandler::new(repository.clone()));
         let apply_layout_handler = Arc::new(ApplyLayoutHandler::new(repository.clone()));
-        let revert_layout_handler = Arc::new(RevertLayoutHandler::new(repository.clone()));
-        let skip_dead_nodes_handler = Arc::new(SkipDeadNodesHandler::new(repository.clone()));
+        let revert_layout_handler = Arc::new(RevertLayoutHandler::new(repository.clone(), self.confirmations.clone()));
+        let skip_dead_nodes_handler = Arc::new(SkipDeadNodesHandler::new(repository.clone(), self.confirmations));
+        let decommission_node_handler = Arc::new(DecommissionNodeHandler::new(
+            repository.clone(),
+            node_repository,
+            self.config.replication_factor,
+            Duration::from_secs(self.config.decommission_poll_interval_secs),
+            Duration::from_secs(self.config.decommission_resync_timeout_secs),
+        ));
+
+        let bootstrap_cluster_handler = Arc::new(BootstrapClusterHandler::new(repository.clone()));
 
         // Query Handlers
         let get_cluster_status_handler = Arc::new(GetClusterStatusHandler::new(repository.clone()));
         let get_cluster_health_handler = Arc::new(GetClusterHealthHandler::new(repository.clone()));
+        let get_cluster_statistics_handler = Arc::new(GetClusterStatisticsHandler::new(repository.clone()));
         let get_cluster_layout_handler = Arc::new(GetClusterLayoutHandler::new(repository.clone()));
         let get_layout_history_handler = Arc::new(GetLayoutHistoryHandler::new(repository.clone()));
-        let preview_layout_changes_handler = Arc::new(PreviewLayoutChangesHandler::new(repository));
+        let preview_layout_changes_handler = Arc::new(PreviewLayoutChangesHandler::new(repository.clone()));
+        let diff_layouts_handler = Arc::new(DiffLayoutsHandler::new(repository.clone()));
+        let simulate_layout_handler = Arc::new(SimulateLayoutHandler::new(repository, self.config.replication_factor));
 
         ClusterGrpcService::new(
             connect_nodes_handler,
@@ -48,11 +73,17 @@ impl ClusterServiceBuilder {
             apply_layout_handler,
             revert_layout_handler,
             skip_dead_nodes_handler,
+            decommission_node_handler,
+            bootstrap_cluster_handler,
             get_cluster_status_handler,
             get_cluster_health_handler,
+            get_cluster_statistics_handler,
             get_cluster_layout_handler,
             get_layout_history_handler,
             preview_layout_changes_handler,
+            diff_layouts_handler,
+            simulate_layout_handler,
+            self.watch,
         )
     }
 }
@@ -0,0 +1,62 @@
+//! Maintenance Handlers Composition
+//!
+//! 維運操作的 handlers 由 Node / Block / Schedule Service 與背景 scheduler 共用，
+//! 手動呼叫與排程執行走同一組實例
+
+use std::sync::Arc;
+
+use crate::application::commands::block::handlers::RetryBlockResyncHandler;
+use crate::application::commands::bucket::handlers::CleanupIncompleteUploadsHandler;
+use crate::application::commands::node::handlers::{CreateMetadataSnapshotHandler, LaunchRepairHandler};
+use crate::application::commands::schedule::handlers::RunScheduleHandler;
+use crate::domain::events::EventBus;
+use crate::domain::repositories::ScheduleRepository;
+use crate::infrastructure::garage::{
+    GarageBlockRepository, GarageBucketRepository, GarageClient, GarageNodeRepository,
+    GarageWorkerRepository,
+};
+
+/// 共用的維運 handlers
+#[derive(Clone)]
+pub struct MaintenanceHandlers {
+    pub create_metadata_snapshot: Arc<CreateMetadataSnapshotHandler>,
+    pub launch_repair: Arc<LaunchRepairHandler>,
+    pub cleanup_incomplete_uploads: Arc<CleanupIncompleteUploadsHandler>,
+    pub retry_block_resync: Arc<RetryBlockResyncHandler>,
+    pub run_schedule: Arc<RunScheduleHandler>,
+}
+
+impl MaintenanceHandlers {
+    pub fn new(
+        client: GarageClient,
+        event_bus: Arc<dyn EventBus>,
+        schedule_repository: Arc<dyn ScheduleRepository>,
+    ) -> Self {
+        let node_repository = Arc::new(GarageNodeRepository::new(client.clone()));
+        let worker_repository = Arc::new(GarageWorkerRepository::new(client.clone()));
+        let bucket_repository = Arc::new(GarageBucketRepository::new(client.clone()));
+        let block_repository = Arc::new(GarageBlockRepository::new(client));
+
+        let create_metadata_snapshot = Arc::new(CreateMetadataSnapshotHandler::new(node_repository.clone()));
+        let launch_repair = Arc::new(LaunchRepairHandler::new(node_repository, worker_repository));
+        let cleanup_incomplete_uploads = Arc::new(CleanupIncompleteUploadsHandler::new(bucket_repository));
+        let retry_block_resync = Arc::new(RetryBlockResyncHandler::new(block_repository));
+
+        let run_schedule = Arc::new(RunScheduleHandler::new(
+            schedule_repository,
+            create_metadata_snapshot.clone(),
+            launch_repair.clone(),
+            cleanup_incomplete_uploads.clone(),
+            retry_block_resync.clone(),
+            event_bus,
+        ));
+
+        Self {
+            create_metadata_snapshot,
+            launch_repair,
+            cleanup_incomplete_uploads,
+            retry_block_resync,
+            run_schedule,
+        }
+    }
+}
@@ -4,17 +4,27 @@
 //! 從 server.rs 分離出來，使 server 專注於啟動與路由配置
 
 mod access_key;
+mod admin_token;
+mod audit;
 mod bucket;
 mod cluster;
+mod maintenance;
 mod node;
 mod block;
 mod object;
+mod schedule;
+mod system;
 mod worker;
 
 pub use access_key::AccessKeyServiceBuilder;
+pub use admin_token::AdminTokenServiceBuilder;
+pub use audit::AuditServiceBuilder;
 pub use bucket::BucketServiceBuilder;
 pub use cluster::ClusterServiceBuilder;
+pub use maintenance::MaintenanceHandlers;
 pub use node::NodeServiceBuilder;
 pub use block::BlockServiceBuilder;
 pub use object::ObjectServiceBuilder;
+pub use schedule::ScheduleServiceBuilder;
+pub use system::SystemServiceBuilder;
 pub use worker::WorkerServiceBuilder;
@@ -4,41 +4,44 @@
 
 use std::sync::Arc;
 
-use crate::infrastructure::garage::{GarageClient, GarageNodeRepository};
-use crate::application::commands::node::handlers::{
-    CreateMetadataSnapshotHandler, LaunchRepairHandler,
-};
+use crate::infrastructure::garage::{GarageClient, GarageNodeRepository, GarageWorkerRepository};
 use crate::application::queries::node::handlers::{
-    GetNodeInfoHandler, GetNodeStatisticsHandler,
+    GetNodeInfoHandler, GetNodeStatisticsHandler, GetScrubStatusHandler,
 };
 use crate::infrastructure::grpc::services::NodeGrpcService;
+use super::MaintenanceHandlers;
 
 /// Node Service 的依賴建構器
 pub struct NodeServiceBuilder {
     client: GarageClient,
+    maintenance: MaintenanceHandlers,
+    replication_factor: usize,
 }
 
 impl NodeServiceBuilder {
-    pub fn new(client: GarageClient) -> Self {
-        Self { client }
+    pub fn new(client: GarageClient, maintenance: MaintenanceHandlers, replication_factor: usize) -> Self {
+        Self { client, maintenance, replication_factor }
     }
 
     pub fn build(self) -> NodeGrpcService {
-        let repository = Arc::new(GarageNodeRepository::new(self.client));
+        let repository = Arc::new(GarageNodeRepository::new(self.client.clone()));
+        let worker_repository = Arc::new(GarageWorkerRepository::new(self.client));
 
         // Command Handlers
-        let create_metadata_snapshot_handler = Arc::new(CreateMetadataSnapshotHandler::new(repository.clone()));
-        let launch_repair_handler = Arc::new(LaunchRepairHandler::new(repository.clone()));
+        let create_metadata_snapshot_handler = self.maintenance.create_metadata_snapshot;
+        let launch_repair_handler = self.maintenance.launch_repair;
 
         // Query Handlers
         let get_node_info_handler = Arc::new(GetNodeInfoHandler::new(repository.clone()));
-        let get_node_statistics_handler = Arc::new(GetNodeStatisticsHandler::new(repository));
+        let get_node_statistics_handler = Arc::new(GetNodeStatisticsHandler::new(repository, self.replication_factor));
+        let get_scrub_status_handler = Arc::new(GetScrubStatusHandler::new(worker_repository));
 
         NodeGrpcService::new(
             create_metadata_snapshot_handler,
             launch_repair_handler,
             get_node_info_handler,
             get_node_statistics_handler,
+            get_scrub_status_handler,
         )
     }
 }
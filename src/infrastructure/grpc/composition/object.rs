@@ -10,40 +10,49 @@ use crate::application::commands::object::handlers::{
 use crate::application::queries::object::handlers::{
     GetObjectMetadataHandler, ListObjectsHandler,
 };
-use crate::domain::repositories::ObjectRepository;
+use crate::domain::repositories::{ConfirmationTokenRepository, ObjectRepositoryProvider};
 use crate::infrastructure::config::S3Config;
-use crate::infrastructure::garage::repositories::GarageObjectRepository;
+use crate::infrastructure::garage::{GarageClient, GarageAccessKeyQueryRepository};
 use crate::infrastructure::grpc::services::ObjectGrpcService;
+use crate::infrastructure::s3::S3ClientPool;
 
 /// Object Service 的依賴建構器
 pub struct ObjectServiceBuilder {
+    client: GarageClient,
     s3_config: S3Config,
+    confirmations: Arc<dyn ConfirmationTokenRepository>,
 }
 
 impl ObjectServiceBuilder {
-    pub fn new(s3_config: S3Config) -> Self {
-        Self { s3_config }
+    pub fn new(
+        client: GarageClient,
+        s3_config: S3Config,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
+    ) -> Self {
+        Self { client, s3_config, confirmations }
     }
 
     pub async fn build(self) -> ObjectGrpcService {
-        let repository: Arc<dyn ObjectRepository> =
-            Arc::new(GarageObjectRepository::new(&self.s3_config).await);
+        // 呼叫者只提供 Access Key ID 時，透過 Admin API 查詢 secret
+        let key_repository = Arc::new(GarageAccessKeyQueryRepository::new(self.client));
+        let repositories: Arc<dyn ObjectRepositoryProvider> =
+            Arc::new(S3ClientPool::new(self.s3_config, key_repository).await);
 
         // Query Handlers
-        let list_objects_handler = Arc::new(ListObjectsHandler::new(repository.clone()));
+        let list_objects_handler = Arc::new(ListObjectsHandler::new(repositories.clone()));
         let get_object_metadata_handler =
-            Arc::new(GetObjectMetadataHandler::new(repository.clone()));
+            Arc::new(GetObjectMetadataHandler::new(repositories.clone()));
 
         // Command Handlers
-        let delete_objects_handler = Arc::new(DeleteObjectsHandler::new(repository.clone()));
-        let copy_object_handler = Arc::new(CopyObjectHandler::new(repository.clone()));
+        let delete_objects_handler = Arc::new(DeleteObjectsHandler::new(repositories.clone(), self.confirmations));
+        let copy_object_handler = Arc::new(CopyObjectHandler::new(repositories.clone()));
 
         ObjectGrpcService::new(
             list_objects_handler,
             get_object_metadata_handler,
             delete_objects_handler,
             copy_object_handler,
-            repository,
+            repositories,
         )
     }
 }
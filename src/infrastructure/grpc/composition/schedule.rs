@@ -0,0 +1,61 @@
+//! Schedule Service Composition
+//!
+//! 負責組合 ScheduleGrpcService 及其所有 handlers
+
+use std::sync::Arc;
+
+use crate::domain::events::EventBus;
+use crate::domain::repositories::ScheduleRepository;
+use crate::application::commands::schedule::handlers::{
+    CreateScheduleHandler, UpdateScheduleHandler, DeleteScheduleHandler,
+};
+use crate::application::queries::schedule::handlers::{ListSchedulesHandler, GetScheduleHandler};
+use crate::infrastructure::grpc::services::ScheduleGrpcService;
+use super::MaintenanceHandlers;
+
+/// Schedule Service 的依賴建構器
+pub struct ScheduleServiceBuilder {
+    event_bus: Arc<dyn EventBus>,
+    repository: Arc<dyn ScheduleRepository>,
+    maintenance: MaintenanceHandlers,
+}
+
+impl ScheduleServiceBuilder {
+    pub fn new(
+        event_bus: Arc<dyn EventBus>,
+        repository: Arc<dyn ScheduleRepository>,
+        maintenance: MaintenanceHandlers,
+    ) -> Self {
+        Self { event_bus, repository, maintenance }
+    }
+
+    pub fn build(self) -> ScheduleGrpcService {
+        // Command Handlers
+        let create_schedule_handler = Arc::new(CreateScheduleHandler::new(
+            self.repository.clone(),
+            self.event_bus.clone(),
+        ));
+        let update_schedule_handler = Arc::new(UpdateScheduleHandler::new(
+            self.repository.clone(),
+            self.event_bus.clone(),
+        ));
+        let delete_schedule_handler = Arc::new(DeleteScheduleHandler::new(
+            self.repository.clone(),
+            self.event_bus,
+        ));
+        let run_schedule_handler = self.maintenance.run_schedule;
+
+        // Query Handlers
+        let list_schedules_handler = Arc::new(ListSchedulesHandler::new(self.repository.clone()));
+        let get_schedule_handler = Arc::new(GetScheduleHandler::new(self.repository));
+
+        ScheduleGrpcService::new(
+            create_schedule_handler,
+            update_schedule_handler,
+            delete_schedule_handler,
+            run_schedule_handler,
+            list_schedules_handler,
+            get_schedule_handler,
+        )
+    }
+}
@@ -0,0 +1,25 @@
+//! System Service Composition
+//!
+//! 負責組合 SystemGrpcService
+
+use std::sync::Arc;
+
+use crate::domain::events::EventBus;
+use crate::infrastructure::grpc::read_only::ReadOnlySwitch;
+use crate::infrastructure::grpc::services::SystemGrpcService;
+
+/// System Service 的依賴建構器
+pub struct SystemServiceBuilder {
+    read_only: ReadOnlySwitch,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl SystemServiceBuilder {
+    pub fn new(read_only: ReadOnlySwitch, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { read_only, event_bus }
+    }
+
+    pub fn build(self) -> SystemGrpcService {
+        SystemGrpcService::new(self.read_only, self.event_bus)
+    }
+}
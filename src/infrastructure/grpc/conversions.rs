@@ -1,9 +1,16 @@
 //! gRPC type conversions
 //!
-//! 提供 proto Nullable 類型到 UpdateField 的轉換，以及 Domain Error 到 gRPC Status 的轉換
+//! 提供 proto Nullable 類型到 UpdateField 的轉換、確認 token、儲存統計與 repair 類型的轉換，以及 Domain Error 到 gRPC Status 的轉換
 
-use crate::infrastructure::grpc::generated::utility::{NullableBool, NullableNumber, NullableString};
+use crate::infrastructure::grpc::generated::cluster::{
+    DiskUsage as ProtoDiskUsage, TableStatistics as ProtoTableStatistics,
+};
+use crate::infrastructure::grpc::generated::node::{RepairType as ProtoRepairType, ScrubCommand as ProtoScrubCommand};
+use crate::infrastructure::grpc::generated::utility::{
+    ConfirmationRequired as ProtoConfirmationRequired, NullableBool, NullableNumber, NullableString,
+};
 use crate::shared::UpdateField;
+use crate::domain::entities::{ConfirmationRequired, DiskUsage, RepairType, ScrubCommand, TableStatistics};
 use crate::domain::errors::DomainError;
 use tonic::Status;
 
@@ -80,86 +87,90 @@ impl NullableBoolExt for Option<NullableBool> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // ============== NullableString Tests ==============
-
-    #[test]
-    fn test_string_none_is_no_change() {
-        let input: Option<NullableString> = None;
-        assert_eq!(input.into_update_field(), UpdateField::NoChange);
-    }
+// ============== ConfirmationRequired ==============
 
-    #[test]
-    fn test_string_some_none_is_clear() {
-        let input = Some(NullableString { value: None });
-        assert_eq!(input.into_update_field(), UpdateField::Clear);
-    }
-
-    #[test]
-    fn test_string_some_empty_is_clear() {
-        let input = Some(NullableString { value: Some(String::new()) });
-        assert_eq!(input.into_update_field(), UpdateField::Clear);
-    }
-
-    #[test]
-    fn test_string_some_value_is_set() {
-        let input = Some(NullableString { value: Some("2025-12-31".to_string()) });
-        assert_eq!(input.into_update_field(), UpdateField::Set("2025-12-31".to_string()));
-    }
-
-    // ============== NullableNumber Tests ==============
-
-    #[test]
-    fn test_number_none_is_no_change() {
-        let input: Option<NullableNumber> = None;
-        assert_eq!(input.into_update_field(), UpdateField::NoChange);
+impl From<ConfirmationRequired> for ProtoConfirmationRequired {
+    fn from(required: ConfirmationRequired) -> Self {
+        Self {
+            token: required.token,
+            expires_at: required.expires_at.to_rfc3339(),
+            summary: required.impact.summary,
+            details: required.impact.details,
+        }
     }
+}
 
-    #[test]
-    fn test_number_some_none_is_clear() {
-        let input = Some(NullableNumber { value: None });
-        assert_eq!(input.into_update_field(), UpdateField::Clear);
-    }
+// ============== Storage Statistics ==============
 
-    #[test]
-    fn test_number_some_value_is_set() {
-        let input = Some(NullableNumber { value: Some(42) });
-        assert_eq!(input.into_update_field(), UpdateField::Set(42));
-    }
-
-    #[test]
-    fn test_number_some_zero_is_set() {
-        let input = Some(NullableNumber { value: Some(0) });
-        assert_eq!(input.into_update_field(), UpdateField::Set(0));
+impl From<DiskUsage> for ProtoDiskUsage {
+    fn from(usage: DiskUsage) -> Self {
+        Self {
+            available: usage.available as i64,
+            used: usage.used() as i64,
+            total: usage.total as i64,
+        }
     }
+}
 
-    // ============== NullableBool Tests ==============
-
-    #[test]
-    fn test_bool_none_is_no_change() {
-        let input: Option<NullableBool> = None;
-        assert_eq!(input.into_update_field(), UpdateField::NoChange);
+impl From<&TableStatistics> for ProtoTableStatistics {
+    fn from(table: &TableStatistics) -> Self {
+        Self {
+            name: table.name.clone(),
+            items: table.items.map(|v| v as i64),
+            merkle_items: table.merkle_items.map(|v| v as i64),
+            merkle_todo: table.merkle_todo.map(|v| v as i64),
+            gc_todo: table.gc_todo.map(|v| v as i64),
+            insert_queue: table.insert_queue.map(|v| v as i64),
+        }
     }
+}
 
-    #[test]
-    fn test_bool_some_none_is_clear() {
-        let input = Some(NullableBool { value: None });
-        assert_eq!(input.into_update_field(), UpdateField::Clear);
-    }
+// ============== Repair Type ==============
 
-    #[test]
-    fn test_bool_some_true_is_set() {
-        let input = Some(NullableBool { value: Some(true) });
-        assert_eq!(input.into_update_field(), UpdateField::Set(true));
-    }
+/// proto 的 repair_type + scrub_command 轉為 domain RepairType
+pub fn convert_repair_type(repair_type: ProtoRepairType, scrub_command: ProtoScrubCommand) -> Result<RepairType, Status> {
+    let repair_type = match repair_type {
+        ProtoRepairType::Unspecified => return Err(Status::invalid_argument("repair_type is required")),
+        ProtoRepairType::Tables => RepairType::Tables,
+        ProtoRepairType::Blocks => RepairType::Blocks,
+        ProtoRepairType::Versions => RepairType::Versions,
+        ProtoRepairType::MultipartUploads => RepairType::MultipartUploads,
+        ProtoRepairType::BlockRefs => RepairType::BlockRefs,
+        ProtoRepairType::BlockRc => RepairType::BlockRc,
+        ProtoRepairType::Rebalance => RepairType::Rebalance,
+        ProtoRepairType::Aliases => RepairType::Aliases,
+        ProtoRepairType::ClearResyncQueue => RepairType::ClearResyncQueue,
+        ProtoRepairType::Scrub => RepairType::Scrub(match scrub_command {
+            ProtoScrubCommand::Unspecified => {
+                return Err(Status::invalid_argument("scrub_command is required for scrub"));
+            }
+            ProtoScrubCommand::Start => ScrubCommand::Start,
+            ProtoScrubCommand::Pause => ScrubCommand::Pause,
+            ProtoScrubCommand::Resume => ScrubCommand::Resume,
+            ProtoScrubCommand::Cancel => ScrubCommand::Cancel,
+        }),
+    };
+    Ok(repair_type)
+}
 
-    #[test]
-    fn test_bool_some_false_is_set() {
-        let input = Some(NullableBool { value: Some(false) });
-        assert_eq!(input.into_update_field(), UpdateField::Set(false));
+/// domain RepairType 轉回 proto 的 repair_type + scrub_command
+pub fn repair_type_to_proto(repair_type: RepairType) -> (ProtoRepairType, ProtoScrubCommand) {
+    match repair_type {
+        RepairType::Tables => (ProtoRepairType::Tables, ProtoScrubCommand::Unspecified),
+        RepairType::Blocks => (ProtoRepairType::Blocks, ProtoScrubCommand::Unspecified),
+        RepairType::Versions => (ProtoRepairType::Versions, ProtoScrubCommand::Unspecified),
+        RepairType::MultipartUploads => (ProtoRepairType::MultipartUploads, ProtoScrubCommand::Unspecified),
+        RepairType::BlockRefs => (ProtoRepairType::BlockRefs, ProtoScrubCommand::Unspecified),
+        RepairType::BlockRc => (ProtoRepairType::BlockRc, ProtoScrubCommand::Unspecified),
+        RepairType::Rebalance => (ProtoRepairType::Rebalance, ProtoScrubCommand::Unspecified),
+        RepairType::Aliases => (ProtoRepairType::Aliases, ProtoScrubCommand::Unspecified),
+        RepairType::ClearResyncQueue => (ProtoRepairType::ClearResyncQueue, ProtoScrubCommand::Unspecified),
+        RepairType::Scrub(command) => (ProtoRepairType::Scrub, match command {
+            ScrubCommand::Start => ProtoScrubCommand::Start,
+            ScrubCommand::Pause => ProtoScrubCommand::Pause,
+            ScrubCommand::Resume => ProtoScrubCommand::Resume,
+            ScrubCommand::Cancel => ProtoScrubCommand::Cancel,
+        }),
     }
 }
 
@@ -174,7 +185,11 @@ mod tests {
 /// - `ValidationError` → `INVALID_ARGUMENT` (400)
 /// - `NotFound` 系列 → `NOT_FOUND` (404)
 /// - `AlreadyExists` 系列 → `ALREADY_EXISTS` (409)
+/// - `Unauthenticated` → `UNAUTHENTICATED` (401)
+/// - `PermissionDenied` → `PERMISSION_DENIED` (403)
 /// - `LayoutVersionMismatch` → `FAILED_PRECONDITION` (412)
+/// - `InvalidConfirmation` → `FAILED_PRECONDITION` (412)
+/// - `ClusterPreconditionFailed` → `FAILED_PRECONDITION` (412)
 /// - `GarageApiError` → `INTERNAL` (500)
 /// - `InternalError` → `INTERNAL` (500)
 /// - 其他未明確映射的錯誤 → `UNKNOWN` (500)
@@ -198,9 +213,15 @@ pub fn domain_error_to_status(err: DomainError) -> Status {
         DomainError::NodeNotFound(msg) => {
             Status::not_found(msg)
         }
+        DomainError::LayoutVersionNotFound(version) => {
+            Status::not_found(format!("Layout version {} is not in the layout history", version))
+        }
         DomainError::ObjectNotFound(msg) => {
             Status::not_found(msg)
         }
+        DomainError::ScheduleNotFound(msg) => {
+            Status::not_found(msg)
+        }
         
         // ============ Already Exists Errors (409) ============
         DomainError::BucketAlreadyExists(msg) => {
@@ -218,6 +239,14 @@ pub fn domain_error_to_status(err: DomainError) -> Status {
             Status::invalid_argument(format!("Invalid bucket name: {}", msg))
         }
         
+        // ============ Auth Errors (401/403) ============
+        DomainError::Unauthenticated(msg) => {
+            Status::unauthenticated(msg)
+        }
+        DomainError::PermissionDenied(msg) => {
+            Status::permission_denied(msg)
+        }
+        
         // ============ Precondition Failed (412) ============
         DomainError::LayoutVersionMismatch { expected, actual } => {
             Status::failed_precondition(format!(
@@ -226,6 +255,13 @@ pub fn domain_error_to_status(err: DomainError) -> Status {
             ))
         }
         
+        DomainError::InvalidConfirmation(msg) => {
+            Status::failed_precondition(msg)
+        }
+        DomainError::ClusterPreconditionFailed(msg) => {
+            Status::failed_precondition(msg)
+        }
+        
         // ============ Cluster Errors ============
         DomainError::ClusterOperationFailed(msg) => {
             Status::internal(format!("Cluster operation failed: {}", msg))
@@ -252,3 +288,86 @@ impl<T> DomainErrorExt<T> for Result<T, DomainError> {
         self.map_err(domain_error_to_status)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============== NullableString Tests ==============
+
+    #[test]
+    fn test_string_none_is_no_change() {
+        let input: Option<NullableString> = None;
+        assert_eq!(input.into_update_field(), UpdateField::NoChange);
+    }
+
+    #[test]
+    fn test_string_some_none_is_clear() {
+        let input = Some(NullableString { value: None });
+        assert_eq!(input.into_update_field(), UpdateField::Clear);
+    }
+
+    #[test]
+    fn test_string_some_empty_is_clear() {
+        let input = Some(NullableString { value: Some(String::new()) });
+        assert_eq!(input.into_update_field(), UpdateField::Clear);
+    }
+
+    #[test]
+    fn test_string_some_value_is_set() {
+        let input = Some(NullableString { value: Some("2025-12-31".to_string()) });
+        assert_eq!(input.into_update_field(), UpdateField::Set("2025-12-31".to_string()));
+    }
+
+    // ============== NullableNumber Tests ==============
+
+    #[test]
+    fn test_number_none_is_no_change() {
+        let input: Option<NullableNumber> = None;
+        assert_eq!(input.into_update_field(), UpdateField::NoChange);
+    }
+
+    #[test]
+    fn test_number_some_none_is_clear() {
+        let input = Some(NullableNumber { value: None });
+        assert_eq!(input.into_update_field(), UpdateField::Clear);
+    }
+
+    #[test]
+    fn test_number_some_value_is_set() {
+        let input = Some(NullableNumber { value: Some(42) });
+        assert_eq!(input.into_update_field(), UpdateField::Set(42));
+    }
+
+    #[test]
+    fn test_number_some_zero_is_set() {
+        let input = Some(NullableNumber { value: Some(0) });
+        assert_eq!(input.into_update_field(), UpdateField::Set(0));
+    }
+
+    // ============== NullableBool Tests ==============
+
+    #[test]
+    fn test_bool_none_is_no_change() {
+        let input: Option<NullableBool> = None;
+        assert_eq!(input.into_update_field(), UpdateField::NoChange);
+    }
+
+    #[test]
+    fn test_bool_some_none_is_clear() {
+        let input = Some(NullableBool { value: None });
+        assert_eq!(input.into_update_field(), UpdateField::Clear);
+    }
+
+    #[test]
+    fn test_bool_some_true_is_set() {
+        let input = Some(NullableBool { value: Some(true) });
+        assert_eq!(input.into_update_field(), UpdateField::Set(true));
+    }
+
+    #[test]
+    fn test_bool_some_false_is_set() {
+        let input = Some(NullableBool { value: Some(false) });
+        assert_eq!(input.into_update_field(), UpdateField::Set(false));
+    }
+}
@@ -19,6 +19,31 @@ pub struct KeyResponse {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RevealKeySecretResponse {
+    #[prost(string, tag = "1")]
+    pub trace_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    #[serde(serialize_with = "crate::shared::redacted")]
+    pub secret_access_key: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateKeyResponse {
+    #[prost(string, tag = "1")]
+    pub trace_id: ::prost::alloc::string::String,
+    /// New key, including its secret
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<Key>,
+    #[prost(string, tag = "3")]
+    pub old_key_id: ::prost::alloc::string::String,
+    /// Expiration given to the old key when a grace period was requested
+    #[prost(string, optional, tag = "4")]
+    pub old_key_expiration: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeleteKeyResponse {
     #[prost(string, tag = "1")]
     pub trace_id: ::prost::alloc::string::String,
@@ -27,6 +52,28 @@ pub struct DeleteKeyResponse {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExportKeysResponse {
+    #[prost(string, tag = "1")]
+    pub trace_id: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub chunk: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportKeysResponse {
+    #[prost(string, tag = "1")]
+    pub trace_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub dry_run: bool,
+    #[prost(int32, tag = "3")]
+    pub succeeded: i32,
+    #[prost(int32, tag = "4")]
+    pub failed: i32,
+    #[prost(message, repeated, tag = "5")]
+    pub data: ::prost::alloc::vec::Vec<KeyImportResult>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ListKeysRequest {
     #[prost(message, optional, tag = "1")]
     pub pagination: ::core::option::Option<super::utility::Pagination>,
@@ -45,6 +92,12 @@ pub struct ReadKeyRequest {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RevealKeySecretRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CreateKeyRequest {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
@@ -53,6 +106,54 @@ pub struct CreateKeyRequest {
     #[prost(bool, optional, tag = "3")]
     pub allow_create_bucket: ::core::option::Option<bool>,
 }
+/// Import an existing key (e.g. migrated from another S3 deployment)
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportKeyRequest {
+    #[prost(string, tag = "1")]
+    pub access_key_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    #[serde(serialize_with = "crate::shared::redacted")]
+    pub secret_access_key: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ExportKeysRequest {
+    #[prost(enumeration = "KeyFileFormat", tag = "1")]
+    pub format: i32,
+    /// Without secrets the exported file cannot be imported again
+    #[prost(bool, tag = "2")]
+    pub include_secret: bool,
+}
+/// First message must be metadata, followed by file chunks
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportKeysRequest {
+    #[prost(oneof = "import_keys_request::Data", tags = "1, 2")]
+    pub data: ::core::option::Option<import_keys_request::Data>,
+}
+/// Nested message and enum types in `ImportKeysRequest`.
+pub mod import_keys_request {
+    #[derive(serde::Serialize)]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Data {
+        #[prost(message, tag = "1")]
+        Metadata(super::ImportKeysMetadata),
+        #[prost(bytes, tag = "2")]
+        Chunk(::prost::alloc::vec::Vec<u8>),
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportKeysMetadata {
+    #[prost(enumeration = "KeyFileFormat", tag = "1")]
+    pub format: i32,
+    /// Validate every row and resolve buckets without writing anything
+    #[prost(bool, tag = "2")]
+    pub dry_run: bool,
+}
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateKeyRequest {
@@ -67,6 +168,18 @@ pub struct UpdateKeyRequest {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RotateKeyRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Defaults to the old key's name
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    /// Expire the old key after this many seconds (at most 365 days); the old key is left untouched when unset
+    #[prost(int64, optional, tag = "3")]
+    pub grace_period_secs: ::core::option::Option<i64>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeleteKeyRequest {
     #[prost(string, repeated, tag = "1")]
     pub id: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
@@ -89,8 +202,6 @@ pub struct KeyListItem {
     pub created: ::prost::alloc::string::String,
     #[prost(string, optional, tag = "4")]
     pub expiration: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(string, tag = "5")]
-    pub secret_access_key: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -103,8 +214,10 @@ pub struct Key {
     pub permissions: ::core::option::Option<KeyPermissions>,
     #[prost(message, repeated, tag = "4")]
     pub buckets: ::prost::alloc::vec::Vec<KeyBucket>,
-    #[prost(string, tag = "5")]
-    pub secret_access_key: ::prost::alloc::string::String,
+    /// Only set in the CreateKey response; use RevealKeySecret afterwards
+    #[prost(string, optional, tag = "5")]
+    #[serde(serialize_with = "crate::shared::redacted")]
+    pub secret_access_key: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag = "6")]
     pub expiration: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, tag = "7")]
@@ -138,6 +251,59 @@ pub struct KeyBucketPermissions {
     #[prost(bool, tag = "3")]
     pub owner: bool,
 }
+/// Per-row import report
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeyImportResult {
+    /// JSON: array index, CSV: line number (both 1-based)
+    #[prost(int32, tag = "1")]
+    pub row: i32,
+    #[prost(string, tag = "2")]
+    pub access_key_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub success: bool,
+    #[prost(string, optional, tag = "4")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "5")]
+    pub grants: ::prost::alloc::vec::Vec<KeyGrantResult>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct KeyGrantResult {
+    #[prost(string, tag = "1")]
+    pub bucket_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    #[prost(string, optional, tag = "3")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum KeyFileFormat {
+    Json = 0,
+    Csv = 1,
+}
+impl KeyFileFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Json => "KEY_FILE_FORMAT_JSON",
+            Self::Csv => "KEY_FILE_FORMAT_CSV",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "KEY_FILE_FORMAT_JSON" => Some(Self::Json),
+            "KEY_FILE_FORMAT_CSV" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod access_key_service_client {
     #![allow(
@@ -276,6 +442,33 @@ pub mod access_key_service_client {
                 .insert(GrpcMethod::new("access_key.AccessKeyService", "ReadKey"));
             self.inner.unary(req, path, codec).await
         }
+        /// Requires the write:keys scope; every call is recorded for audit
+        pub async fn reveal_key_secret(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevealKeySecretRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RevealKeySecretResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/access_key.AccessKeyService/RevealKeySecret",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("access_key.AccessKeyService", "RevealKeySecret"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Command operations
         pub async fn create_key(
             &mut self,
@@ -298,6 +491,27 @@ pub mod access_key_service_client {
                 .insert(GrpcMethod::new("access_key.AccessKeyService", "CreateKey"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn import_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportKeyRequest>,
+        ) -> std::result::Result<tonic::Response<super::KeyResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/access_key.AccessKeyService/ImportKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("access_key.AccessKeyService", "ImportKey"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn update_key(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateKeyRequest>,
@@ -343,6 +557,82 @@ pub mod access_key_service_client {
                 .insert(GrpcMethod::new("access_key.AccessKeyService", "DeleteKey"));
             self.inner.unary(req, path, codec).await
         }
+        /// Create a replacement key with the same bucket permissions and local aliases
+        pub async fn rotate_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RotateKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RotateKeyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/access_key.AccessKeyService/RotateKey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("access_key.AccessKeyService", "RotateKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Bulk operations
+        /// Export streams the key file in chunks; import takes metadata first, then file chunks
+        pub async fn export_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportKeysResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/access_key.AccessKeyService/ExportKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("access_key.AccessKeyService", "ExportKeys"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Importing bucket permissions or aliases additionally requires write:buckets
+        pub async fn import_keys(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::ImportKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/access_key.AccessKeyService/ImportKeys",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("access_key.AccessKeyService", "ImportKeys"));
+            self.inner.client_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -370,11 +660,23 @@ pub mod access_key_service_server {
             &self,
             request: tonic::Request<super::ReadKeyRequest>,
         ) -> std::result::Result<tonic::Response<super::KeyResponse>, tonic::Status>;
+        /// Requires the write:keys scope; every call is recorded for audit
+        async fn reveal_key_secret(
+            &self,
+            request: tonic::Request<super::RevealKeySecretRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RevealKeySecretResponse>,
+            tonic::Status,
+        >;
         /// Command operations
         async fn create_key(
             &self,
             request: tonic::Request<super::CreateKeyRequest>,
         ) -> std::result::Result<tonic::Response<super::KeyResponse>, tonic::Status>;
+        async fn import_key(
+            &self,
+            request: tonic::Request<super::ImportKeyRequest>,
+        ) -> std::result::Result<tonic::Response<super::KeyResponse>, tonic::Status>;
         async fn update_key(
             &self,
             request: tonic::Request<super::UpdateKeyRequest>,
@@ -386,6 +688,34 @@ pub mod access_key_service_server {
             tonic::Response<super::DeleteKeyResponse>,
             tonic::Status,
         >;
+        /// Create a replacement key with the same bucket permissions and local aliases
+        async fn rotate_key(
+            &self,
+            request: tonic::Request<super::RotateKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RotateKeyResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ExportKeys method.
+        type ExportKeysStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ExportKeysResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Bulk operations
+        /// Export streams the key file in chunks; import takes metadata first, then file chunks
+        async fn export_keys(
+            &self,
+            request: tonic::Request<super::ExportKeysRequest>,
+        ) -> std::result::Result<tonic::Response<Self::ExportKeysStream>, tonic::Status>;
+        /// Importing bucket permissions or aliases additionally requires write:buckets
+        async fn import_keys(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::ImportKeysRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportKeysResponse>,
+            tonic::Status,
+        >;
     }
     /// Access Key Service - gRPC API for access key management
     #[derive(Debug)]
@@ -554,6 +884,52 @@ pub mod access_key_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/access_key.AccessKeyService/RevealKeySecret" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevealKeySecretSvc<T: AccessKeyService>(pub Arc<T>);
+                    impl<
+                        T: AccessKeyService,
+                    > tonic::server::UnaryService<super::RevealKeySecretRequest>
+                    for RevealKeySecretSvc<T> {
+                        type Response = super::RevealKeySecretResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RevealKeySecretRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccessKeyService>::reveal_key_secret(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevealKeySecretSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/access_key.AccessKeyService/CreateKey" => {
                     #[allow(non_camel_case_types)]
                     struct CreateKeySvc<T: AccessKeyService>(pub Arc<T>);
@@ -599,6 +975,51 @@ pub mod access_key_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/access_key.AccessKeyService/ImportKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportKeySvc<T: AccessKeyService>(pub Arc<T>);
+                    impl<
+                        T: AccessKeyService,
+                    > tonic::server::UnaryService<super::ImportKeyRequest>
+                    for ImportKeySvc<T> {
+                        type Response = super::KeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccessKeyService>::import_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/access_key.AccessKeyService/UpdateKey" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateKeySvc<T: AccessKeyService>(pub Arc<T>);
@@ -689,6 +1110,144 @@ pub mod access_key_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/access_key.AccessKeyService/RotateKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct RotateKeySvc<T: AccessKeyService>(pub Arc<T>);
+                    impl<
+                        T: AccessKeyService,
+                    > tonic::server::UnaryService<super::RotateKeyRequest>
+                    for RotateKeySvc<T> {
+                        type Response = super::RotateKeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RotateKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccessKeyService>::rotate_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RotateKeySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/access_key.AccessKeyService/ExportKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportKeysSvc<T: AccessKeyService>(pub Arc<T>);
+                    impl<
+                        T: AccessKeyService,
+                    > tonic::server::ServerStreamingService<super::ExportKeysRequest>
+                    for ExportKeysSvc<T> {
+                        type Response = super::ExportKeysResponse;
+                        type ResponseStream = T::ExportKeysStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccessKeyService>::export_keys(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportKeysSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/access_key.AccessKeyService/ImportKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportKeysSvc<T: AccessKeyService>(pub Arc<T>);
+                    impl<
+                        T: AccessKeyService,
+                    > tonic::server::ClientStreamingService<super::ImportKeysRequest>
+                    for ImportKeysSvc<T> {
+                        type Response = super::ImportKeysResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::ImportKeysRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AccessKeyService>::import_keys(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportKeysSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
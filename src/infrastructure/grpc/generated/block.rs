@@ -5,7 +5,10 @@
 pub struct ApiResponse {
     #[prost(string, tag = "1")]
     pub trace_id: ::prost::alloc::string::String,
-    #[prost(oneof = "api_response::Data", tags = "2, 3, 4, 5")]
+    /// 破壞性操作需要確認時回傳，此時 data 為空
+    #[prost(message, optional, tag = "6")]
+    pub confirmation: ::core::option::Option<super::utility::ConfirmationRequired>,
+    #[prost(oneof = "api_response::Data", tags = "2, 3, 4, 5, 7")]
     pub data: ::core::option::Option<api_response::Data>,
 }
 /// Nested message and enum types in `ApiResponse`.
@@ -21,6 +24,8 @@ pub mod api_response {
         PurgeResult(super::MultiNodePurgeResultData),
         #[prost(message, tag = "5")]
         ResyncResult(super::MultiNodeResyncResultData),
+        #[prost(message, tag = "7")]
+        BlockErrorReport(super::BlockErrorReportData),
     }
 }
 #[derive(serde::Serialize)]
@@ -60,6 +65,22 @@ pub struct MultiNodeResyncResultData {
     >,
 }
 #[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockErrorReportData {
+    /// Sorted by block hash
+    #[prost(message, repeated, tag = "1")]
+    pub blocks: ::prost::alloc::vec::Vec<DamagedBlock>,
+    /// Sorted by first global alias
+    #[prost(message, repeated, tag = "2")]
+    pub buckets: ::prost::alloc::vec::Vec<BucketBlockErrors>,
+    /// Nodes that failed to list block errors
+    #[prost(map = "string, string", tag = "3")]
+    pub node_errors: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+#[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetBlockInfoRequest {
     /// Node ID or "\*" for all nodes
@@ -77,12 +98,22 @@ pub struct ListBlockErrorsRequest {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetBlockErrorReportRequest {
+    /// Node ID or "\*" for all nodes
+    #[prost(string, tag = "1")]
+    pub node: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PurgeBlocksRequest {
     /// Node ID or "\*" for all nodes
     #[prost(string, tag = "1")]
     pub node: ::prost::alloc::string::String,
     #[prost(string, repeated, tag = "2")]
     pub block_hashes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Token from a previous call's ConfirmationRequired
+    #[prost(string, optional, tag = "3")]
+    pub confirmation_token: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -188,6 +219,81 @@ pub struct BlockError {
     pub error: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DamagedBlock {
+    #[prost(string, tag = "1")]
+    pub block_hash: ::prost::alloc::string::String,
+    /// Node ID -> error reported for this block
+    #[prost(map = "string, string", tag = "2")]
+    pub node_errors: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(int64, optional, tag = "3")]
+    pub size: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "4")]
+    pub refcount: ::core::option::Option<i64>,
+    /// Set when block info could not be resolved
+    #[prost(string, optional, tag = "5")]
+    pub lookup_error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BucketBlockErrors {
+    #[prost(string, tag = "1")]
+    pub bucket_id: ::prost::alloc::string::String,
+    /// Empty if the bucket no longer exists
+    #[prost(string, repeated, tag = "2")]
+    pub global_aliases: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "3")]
+    pub local_aliases: ::prost::alloc::vec::Vec<BucketLocalAlias>,
+    #[prost(message, repeated, tag = "4")]
+    pub objects: ::prost::alloc::vec::Vec<DamagedObject>,
+    #[prost(message, repeated, tag = "5")]
+    pub uploads: ::prost::alloc::vec::Vec<DamagedUpload>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BucketLocalAlias {
+    #[prost(string, tag = "1")]
+    pub access_key_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub alias: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DamagedObject {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub version_uuid: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub deleted: bool,
+    #[prost(message, repeated, tag = "4")]
+    pub blocks: ::prost::alloc::vec::Vec<DamagedBlockRef>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DamagedUpload {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub upload_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub blocks: ::prost::alloc::vec::Vec<DamagedBlockRef>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DamagedBlockRef {
+    #[prost(string, tag = "1")]
+    pub block_hash: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub block_offset: i64,
+    /// Only set for multipart uploads
+    #[prost(int32, optional, tag = "3")]
+    pub part_number: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PurgeResult {
     #[prost(oneof = "purge_result::Result", tags = "1, 2")]
@@ -372,6 +478,28 @@ pub mod block_service_client {
                 .insert(GrpcMethod::new("block.BlockService", "ListBlockErrors"));
             self.inner.unary(req, path, codec).await
         }
+        /// 彙整所有節點的錯誤區塊，並對應到受影響的 bucket / object
+        pub async fn get_block_error_report(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockErrorReportRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/block.BlockService/GetBlockErrorReport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("block.BlockService", "GetBlockErrorReport"));
+            self.inner.unary(req, path, codec).await
+        }
         /// Command operations
         pub async fn purge_blocks(
             &mut self,
@@ -439,6 +567,11 @@ pub mod block_service_server {
             &self,
             request: tonic::Request<super::ListBlockErrorsRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        /// 彙整所有節點的錯誤區塊，並對應到受影響的 bucket / object
+        async fn get_block_error_report(
+            &self,
+            request: tonic::Request<super::GetBlockErrorReportRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
         /// Command operations
         async fn purge_blocks(
             &self,
@@ -617,6 +750,52 @@ pub mod block_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/block.BlockService/GetBlockErrorReport" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockErrorReportSvc<T: BlockService>(pub Arc<T>);
+                    impl<
+                        T: BlockService,
+                    > tonic::server::UnaryService<super::GetBlockErrorReportRequest>
+                    for GetBlockErrorReportSvc<T> {
+                        type Response = super::ApiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockErrorReportRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as BlockService>::get_block_error_report(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockErrorReportSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/block.BlockService/PurgeBlocks" => {
                     #[allow(non_camel_case_types)]
                     struct PurgeBlocksSvc<T: BlockService>(pub Arc<T>);
@@ -24,6 +24,9 @@ pub struct DeleteBucketResponse {
     pub trace_id: ::prost::alloc::string::String,
     #[prost(string, repeated, tag = "2")]
     pub id: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 需要確認時 id 為空，帶上 confirmation.token 再次呼叫
+    #[prost(message, optional, tag = "3")]
+    pub confirmation: ::core::option::Option<super::utility::ConfirmationRequired>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -74,6 +77,9 @@ pub struct UpdateBucketRequest {
 pub struct DeleteBucketRequest {
     #[prost(string, repeated, tag = "1")]
     pub id: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Token from a previous call's ConfirmationRequired
+    #[prost(string, optional, tag = "2")]
+    pub confirmation_token: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
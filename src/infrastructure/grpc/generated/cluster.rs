@@ -5,7 +5,13 @@
 pub struct ApiResponse {
     #[prost(string, tag = "1")]
     pub trace_id: ::prost::alloc::string::String,
-    #[prost(oneof = "api_response::Data", tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
+    /// 破壞性操作需要確認時回傳，此時 data 為空
+    #[prost(message, optional, tag = "12")]
+    pub confirmation: ::core::option::Option<super::utility::ConfirmationRequired>,
+    #[prost(
+        oneof = "api_response::Data",
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 14, 15, 16"
+    )]
     pub data: ::core::option::Option<api_response::Data>,
 }
 /// Nested message and enum types in `ApiResponse`.
@@ -33,6 +39,14 @@ pub mod api_response {
         RevertLayout(super::ClusterLayoutData),
         #[prost(message, tag = "11")]
         SkipDeadNodes(super::SkipDeadNodesData),
+        #[prost(message, tag = "13")]
+        ClusterStatistics(super::ClusterStatisticsData),
+        #[prost(message, tag = "14")]
+        BootstrapCluster(super::BootstrapReportData),
+        #[prost(message, tag = "15")]
+        DiffLayouts(super::LayoutDiffData),
+        #[prost(message, tag = "16")]
+        SimulateLayout(super::LayoutSimulationData),
     }
 }
 #[derive(serde::Serialize)]
@@ -65,6 +79,19 @@ pub struct ClusterHealthData {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusterStatisticsData {
+    #[prost(message, repeated, tag = "1")]
+    pub nodes: ::prost::alloc::vec::Vec<NodeStorageStatistics>,
+    #[prost(message, repeated, tag = "2")]
+    pub tables: ::prost::alloc::vec::Vec<TableStatistics>,
+    #[prost(message, optional, tag = "3")]
+    pub totals: ::core::option::Option<StorageTotals>,
+    /// Garage 原始輸出，解析不完整時可直接顯示
+    #[prost(string, tag = "4")]
+    pub freeform: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClusterLayoutData {
     #[prost(int64, tag = "1")]
     pub version: i64,
@@ -74,6 +101,47 @@ pub struct ClusterLayoutData {
     pub staged_role_changes: ::prost::alloc::vec::Vec<StagedRoleChange>,
     #[prost(message, optional, tag = "4")]
     pub parameters: ::core::option::Option<LayoutParameters>,
+    #[prost(message, optional, tag = "5")]
+    pub staged_parameters: ::core::option::Option<LayoutParameters>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LayoutDiffData {
+    #[prost(int64, optional, tag = "1")]
+    pub from_version: ::core::option::Option<i64>,
+    /// unset when comparing against the staged layout
+    #[prost(int64, optional, tag = "2")]
+    pub to_version: ::core::option::Option<i64>,
+    #[prost(message, repeated, tag = "3")]
+    pub roles: ::prost::alloc::vec::Vec<LayoutRoleDiff>,
+    /// 歷史版本不含布局參數，只有比較目前與暫存布局時才可能有值
+    #[prost(message, optional, tag = "4")]
+    pub zone_redundancy: ::core::option::Option<ZoneRedundancyChange>,
+    #[prost(message, repeated, tag = "5")]
+    pub zones: ::prost::alloc::vec::Vec<ZoneCapacity>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LayoutSimulationData {
+    #[prost(int32, tag = "1")]
+    pub replication_factor: i32,
+    #[prost(int32, tag = "2")]
+    pub zone_redundancy: i32,
+    /// bytes
+    #[prost(int64, tag = "3")]
+    pub partition_size: i64,
+    /// bytes
+    #[prost(int64, tag = "4")]
+    pub usable_capacity: i64,
+    #[prost(message, repeated, tag = "5")]
+    pub nodes: ::prost::alloc::vec::Vec<SimulatedNode>,
+    /// 目前布局以相同演算法模擬的可用容量，無法分配時不設定
+    #[prost(int64, optional, tag = "6")]
+    pub current_usable_capacity: ::core::option::Option<i64>,
+    #[prost(int32, tag = "7")]
+    pub partitions_moved: i32,
+    #[prost(int32, tag = "8")]
+    pub replicas_moved: i32,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -106,6 +174,22 @@ pub struct SkipDeadNodesData {
     pub partition_info: ::prost::alloc::vec::Vec<PartitionInfo>,
 }
 #[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BootstrapReportData {
+    #[prost(message, repeated, tag = "1")]
+    pub nodes: ::prost::alloc::vec::Vec<BootstrapNodeReport>,
+    #[prost(bool, tag = "2")]
+    pub parameters_staged: bool,
+    /// Garage 預覽新布局的訊息
+    #[prost(string, repeated, tag = "3")]
+    pub preview: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 本次套用的布局版本，沒有變更需要套用時為空
+    #[prost(int64, optional, tag = "4")]
+    pub applied_version: ::core::option::Option<i64>,
+    #[prost(int64, tag = "5")]
+    pub layout_version: i64,
+}
+#[derive(serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetClusterStatusRequest {}
 #[derive(serde::Serialize)]
@@ -113,6 +197,9 @@ pub struct GetClusterStatusRequest {}
 pub struct GetClusterHealthRequest {}
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetClusterStatisticsRequest {}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetClusterLayoutRequest {}
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
@@ -131,6 +218,12 @@ pub struct ConnectNodesRequest {
 pub struct UpdateLayoutRequest {
     #[prost(message, repeated, tag = "1")]
     pub role_changes: ::prost::alloc::vec::Vec<LayoutRoleChange>,
+    /// 未設定時不變更布局參數
+    #[prost(message, optional, tag = "2")]
+    pub parameters: ::core::option::Option<LayoutParameters>,
+    /// 呼叫者讀取到的目前布局版本，與實際版本不同時回傳 FAILED_PRECONDITION
+    #[prost(int64, optional, tag = "3")]
+    pub expected_version: ::core::option::Option<i64>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -149,19 +242,159 @@ pub struct LayoutRoleChange {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ApplyLayoutRequest {
+    /// 要套用的暫存版本（目前版本 + 1），不符時回傳 FAILED_PRECONDITION
     #[prost(int64, tag = "1")]
     pub version: i64,
 }
 #[derive(serde::Serialize)]
-#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct RevertLayoutRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RevertLayoutRequest {
+    /// Token from a previous call's ConfirmationRequired
+    #[prost(string, optional, tag = "1")]
+    pub confirmation_token: ::core::option::Option<::prost::alloc::string::String>,
+}
 #[derive(serde::Serialize)]
-#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SkipDeadNodesRequest {
     #[prost(int64, tag = "1")]
     pub version: i64,
     #[prost(bool, tag = "2")]
     pub allow_missing_data: bool,
+    /// Required when allow_missing_data is true
+    #[prost(string, optional, tag = "3")]
+    pub confirmation_token: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchClusterRequest {}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateLayoutRequest {
+    /// capacity 0 = gateway node, at most 1000 roles
+    #[prost(message, repeated, tag = "1")]
+    pub roles: ::prost::alloc::vec::Vec<LayoutRole>,
+    /// unset = GARAGE_REPLICATION_FACTOR
+    #[prost(int32, optional, tag = "2")]
+    pub replication_factor: ::core::option::Option<i32>,
+    /// unset = current layout parameters
+    #[prost(message, optional, tag = "3")]
+    pub parameters: ::core::option::Option<LayoutParameters>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DiffLayoutsRequest {
+    /// unset = current layout
+    #[prost(int64, optional, tag = "1")]
+    pub from_version: ::core::option::Option<i64>,
+    /// unset = staged layout
+    #[prost(int64, optional, tag = "2")]
+    pub to_version: ::core::option::Option<i64>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DecommissionNodeRequest {
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BootstrapClusterRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub nodes: ::prost::alloc::vec::Vec<BootstrapNode>,
+    #[prost(message, optional, tag = "2")]
+    pub parameters: ::core::option::Option<LayoutParameters>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BootstrapNode {
+    /// node_id@host:port
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub zone: ::prost::alloc::string::String,
+    /// bytes, unset for a gateway node
+    #[prost(int64, optional, tag = "3")]
+    pub capacity: ::core::option::Option<i64>,
+    #[prost(string, repeated, tag = "4")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DecommissionNodeResponse {
+    #[prost(string, tag = "1")]
+    pub trace_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub progress: ::core::option::Option<DecommissionProgress>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DecommissionProgress {
+    #[prost(enumeration = "DecommissionPhase", tag = "1")]
+    pub phase: i32,
+    /// 0-100
+    #[prost(int32, tag = "2")]
+    pub percent: i32,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+    /// 移除節點後的布局版本（套用後才有）
+    #[prost(int64, optional, tag = "4")]
+    pub layout_version: ::core::option::Option<i64>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchClusterResponse {
+    #[prost(string, tag = "1")]
+    pub trace_id: ::prost::alloc::string::String,
+    /// true 表示此訊息為完整狀態，客戶端應取代既有狀態
+    #[prost(bool, tag = "2")]
+    pub full: bool,
+    /// 以下欄位未設定或為空表示沒有變化
+    #[prost(message, optional, tag = "3")]
+    pub health: ::core::option::Option<ClusterHealthData>,
+    /// 新增或狀態改變的節點
+    #[prost(message, repeated, tag = "4")]
+    pub nodes: ::prost::alloc::vec::Vec<WatchNode>,
+    #[prost(string, repeated, tag = "5")]
+    pub removed_nodes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "6")]
+    pub layout_version: ::core::option::Option<i64>,
+    /// RFC 3339 format
+    #[prost(string, tag = "7")]
+    pub observed_at: ::prost::alloc::string::String,
+    /// 無法取得集群狀態時為 false，此時其餘狀態為最後一次成功輪詢的結果
+    #[prost(bool, optional, tag = "8")]
+    pub reachable: ::core::option::Option<bool>,
+    /// 輪詢失敗且原因改變時設定
+    #[prost(string, optional, tag = "9")]
+    pub poll_error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchNode {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub hostname: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub addr: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "4")]
+    pub is_up: bool,
+    #[prost(int64, optional, tag = "5")]
+    pub last_seen_secs_ago: ::core::option::Option<i64>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BootstrapNodeReport {
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(enumeration = "BootstrapConnectOutcome", tag = "3")]
+    pub connection: i32,
+    #[prost(string, optional, tag = "4")]
+    pub connection_error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "BootstrapRoleOutcome", tag = "5")]
+    pub role: i32,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -185,6 +418,72 @@ pub struct ClusterNode {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NodeStorageStatistics {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub hostname: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub zone: ::core::option::Option<::prost::alloc::string::String>,
+    /// bytes
+    #[prost(int64, optional, tag = "4")]
+    pub capacity: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub partitions: ::core::option::Option<i64>,
+    #[prost(message, optional, tag = "6")]
+    pub data: ::core::option::Option<DiskUsage>,
+    #[prost(message, optional, tag = "7")]
+    pub metadata: ::core::option::Option<DiskUsage>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DiskUsage {
+    /// bytes
+    #[prost(int64, tag = "1")]
+    pub available: i64,
+    #[prost(int64, tag = "2")]
+    pub used: i64,
+    #[prost(int64, tag = "3")]
+    pub total: i64,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TableStatistics {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// 未計算（NC）時為空
+    #[prost(int64, optional, tag = "2")]
+    pub items: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "3")]
+    pub merkle_items: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "4")]
+    pub merkle_todo: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub gc_todo: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "6")]
+    pub insert_queue: ::core::option::Option<i64>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StorageTotals {
+    /// bytes
+    #[prost(int64, tag = "1")]
+    pub capacity: i64,
+    #[prost(message, optional, tag = "2")]
+    pub data: ::core::option::Option<DiskUsage>,
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<DiskUsage>,
+    /// Garage 估算的集群可用空間
+    #[prost(int64, optional, tag = "4")]
+    pub estimated_data_available: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub estimated_metadata_available: ::core::option::Option<i64>,
+    /// 部分節點缺少資訊時，估算值只是上限
+    #[prost(bool, tag = "6")]
+    pub estimate_is_upper_bound: bool,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct LayoutRole {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
@@ -230,6 +529,65 @@ pub mod zone_redundancy {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LayoutRoleDiff {
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "LayoutRoleDiffKind", tag = "2")]
+    pub kind: i32,
+    #[prost(message, optional, tag = "3")]
+    pub before: ::core::option::Option<LayoutRole>,
+    #[prost(message, optional, tag = "4")]
+    pub after: ::core::option::Option<LayoutRole>,
+    #[prost(bool, tag = "5")]
+    pub zone_changed: bool,
+    #[prost(bool, tag = "6")]
+    pub capacity_changed: bool,
+    #[prost(string, repeated, tag = "7")]
+    pub tags_added: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "8")]
+    pub tags_removed: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ZoneRedundancyChange {
+    #[prost(message, optional, tag = "1")]
+    pub before: ::core::option::Option<ZoneRedundancy>,
+    #[prost(message, optional, tag = "2")]
+    pub after: ::core::option::Option<ZoneRedundancy>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ZoneCapacity {
+    #[prost(string, tag = "1")]
+    pub zone: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub nodes_before: i32,
+    #[prost(int32, tag = "3")]
+    pub nodes_after: i32,
+    /// bytes
+    #[prost(int64, tag = "4")]
+    pub capacity_before: i64,
+    /// bytes
+    #[prost(int64, tag = "5")]
+    pub capacity_after: i64,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SimulatedNode {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub zone: ::prost::alloc::string::String,
+    /// 0 for a gateway node
+    #[prost(int64, tag = "3")]
+    pub capacity: i64,
+    #[prost(int32, tag = "4")]
+    pub partitions: i32,
+    #[prost(int64, tag = "5")]
+    pub used_capacity: i64,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ConnectNodeResult {
     #[prost(bool, tag = "1")]
     pub success: bool,
@@ -268,6 +626,142 @@ pub struct NodeUpdateProgress {
     #[prost(int64, optional, tag = "3")]
     pub write_version: ::core::option::Option<i64>,
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DecommissionPhase {
+    Validating = 0,
+    StagingRemoval = 1,
+    Previewing = 2,
+    Applying = 3,
+    SyncingLayout = 4,
+    Resyncing = 5,
+    Completed = 6,
+}
+impl DecommissionPhase {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Validating => "DECOMMISSION_PHASE_VALIDATING",
+            Self::StagingRemoval => "DECOMMISSION_PHASE_STAGING_REMOVAL",
+            Self::Previewing => "DECOMMISSION_PHASE_PREVIEWING",
+            Self::Applying => "DECOMMISSION_PHASE_APPLYING",
+            Self::SyncingLayout => "DECOMMISSION_PHASE_SYNCING_LAYOUT",
+            Self::Resyncing => "DECOMMISSION_PHASE_RESYNCING",
+            Self::Completed => "DECOMMISSION_PHASE_COMPLETED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DECOMMISSION_PHASE_VALIDATING" => Some(Self::Validating),
+            "DECOMMISSION_PHASE_STAGING_REMOVAL" => Some(Self::StagingRemoval),
+            "DECOMMISSION_PHASE_PREVIEWING" => Some(Self::Previewing),
+            "DECOMMISSION_PHASE_APPLYING" => Some(Self::Applying),
+            "DECOMMISSION_PHASE_SYNCING_LAYOUT" => Some(Self::SyncingLayout),
+            "DECOMMISSION_PHASE_RESYNCING" => Some(Self::Resyncing),
+            "DECOMMISSION_PHASE_COMPLETED" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BootstrapConnectOutcome {
+    AlreadyConnected = 0,
+    Connected = 1,
+    Failed = 2,
+}
+impl BootstrapConnectOutcome {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::AlreadyConnected => "BOOTSTRAP_CONNECT_OUTCOME_ALREADY_CONNECTED",
+            Self::Connected => "BOOTSTRAP_CONNECT_OUTCOME_CONNECTED",
+            Self::Failed => "BOOTSTRAP_CONNECT_OUTCOME_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BOOTSTRAP_CONNECT_OUTCOME_ALREADY_CONNECTED" => Some(Self::AlreadyConnected),
+            "BOOTSTRAP_CONNECT_OUTCOME_CONNECTED" => Some(Self::Connected),
+            "BOOTSTRAP_CONNECT_OUTCOME_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BootstrapRoleOutcome {
+    Unchanged = 0,
+    AlreadyStaged = 1,
+    Staged = 2,
+    /// 有節點無法連線時不變更布局
+    Skipped = 3,
+}
+impl BootstrapRoleOutcome {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unchanged => "BOOTSTRAP_ROLE_OUTCOME_UNCHANGED",
+            Self::AlreadyStaged => "BOOTSTRAP_ROLE_OUTCOME_ALREADY_STAGED",
+            Self::Staged => "BOOTSTRAP_ROLE_OUTCOME_STAGED",
+            Self::Skipped => "BOOTSTRAP_ROLE_OUTCOME_SKIPPED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BOOTSTRAP_ROLE_OUTCOME_UNCHANGED" => Some(Self::Unchanged),
+            "BOOTSTRAP_ROLE_OUTCOME_ALREADY_STAGED" => Some(Self::AlreadyStaged),
+            "BOOTSTRAP_ROLE_OUTCOME_STAGED" => Some(Self::Staged),
+            "BOOTSTRAP_ROLE_OUTCOME_SKIPPED" => Some(Self::Skipped),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LayoutRoleDiffKind {
+    Added = 0,
+    Removed = 1,
+    Modified = 2,
+}
+impl LayoutRoleDiffKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Added => "LAYOUT_ROLE_DIFF_KIND_ADDED",
+            Self::Removed => "LAYOUT_ROLE_DIFF_KIND_REMOVED",
+            Self::Modified => "LAYOUT_ROLE_DIFF_KIND_MODIFIED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LAYOUT_ROLE_DIFF_KIND_ADDED" => Some(Self::Added),
+            "LAYOUT_ROLE_DIFF_KIND_REMOVED" => Some(Self::Removed),
+            "LAYOUT_ROLE_DIFF_KIND_MODIFIED" => Some(Self::Modified),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod cluster_service_client {
     #![allow(
@@ -403,6 +897,29 @@ pub mod cluster_service_client {
                 .insert(GrpcMethod::new("cluster.ClusterService", "GetClusterHealth"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_cluster_statistics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetClusterStatisticsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cluster.ClusterService/GetClusterStatistics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("cluster.ClusterService", "GetClusterStatistics"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_cluster_layout(
             &mut self,
             request: impl tonic::IntoRequest<super::GetClusterLayoutRequest>,
@@ -468,6 +985,75 @@ pub mod cluster_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// 比較兩個布局版本，或目前布局與暫存變更
+        pub async fn diff_layouts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DiffLayoutsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cluster.ClusterService/DiffLayouts",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("cluster.ClusterService", "DiffLayouts"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 在後端模擬 partition 分配，不會暫存任何變更
+        pub async fn simulate_layout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateLayoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cluster.ClusterService/SimulateLayout",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("cluster.ClusterService", "SimulateLayout"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 第一則訊息為完整狀態，之後只送出有變化的部分
+        pub async fn watch_cluster(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchClusterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchClusterResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cluster.ClusterService/WatchCluster",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("cluster.ClusterService", "WatchCluster"));
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Command operations
         pub async fn connect_nodes(
             &mut self,
@@ -513,7 +1099,49 @@ pub mod cluster_service_client {
         }
         pub async fn apply_layout(
             &mut self,
-            request: impl tonic::IntoRequest<super::ApplyLayoutRequest>,
+            request: impl tonic::IntoRequest<super::ApplyLayoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cluster.ClusterService/ApplyLayout",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("cluster.ClusterService", "ApplyLayout"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revert_layout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevertLayoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cluster.ClusterService/RevertLayout",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("cluster.ClusterService", "RevertLayout"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn skip_dead_nodes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SkipDeadNodesRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
             self.inner
                 .ready()
@@ -525,17 +1153,21 @@ pub mod cluster_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/cluster.ClusterService/ApplyLayout",
+                "/cluster.ClusterService/SkipDeadNodes",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("cluster.ClusterService", "ApplyLayout"));
+                .insert(GrpcMethod::new("cluster.ClusterService", "SkipDeadNodes"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn revert_layout(
+        /// 移除節點並持續回報資料搬移進度，直到不再有資料依賴該節點
+        pub async fn decommission_node(
             &mut self,
-            request: impl tonic::IntoRequest<super::RevertLayoutRequest>,
-        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::DecommissionNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::DecommissionNodeResponse>>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -546,16 +1178,17 @@ pub mod cluster_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/cluster.ClusterService/RevertLayout",
+                "/cluster.ClusterService/DecommissionNode",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("cluster.ClusterService", "RevertLayout"));
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("cluster.ClusterService", "DecommissionNode"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        pub async fn skip_dead_nodes(
+        /// 連線節點、暫存角色並套用布局；已完成的步驟會略過，可重複執行
+        pub async fn bootstrap_cluster(
             &mut self,
-            request: impl tonic::IntoRequest<super::SkipDeadNodesRequest>,
+            request: impl tonic::IntoRequest<super::BootstrapClusterRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
             self.inner
                 .ready()
@@ -567,11 +1200,11 @@ pub mod cluster_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/cluster.ClusterService/SkipDeadNodes",
+                "/cluster.ClusterService/BootstrapCluster",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("cluster.ClusterService", "SkipDeadNodes"));
+                .insert(GrpcMethod::new("cluster.ClusterService", "BootstrapCluster"));
             self.inner.unary(req, path, codec).await
         }
     }
@@ -598,6 +1231,10 @@ pub mod cluster_service_server {
             &self,
             request: tonic::Request<super::GetClusterHealthRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        async fn get_cluster_statistics(
+            &self,
+            request: tonic::Request<super::GetClusterStatisticsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
         async fn get_cluster_layout(
             &self,
             request: tonic::Request<super::GetClusterLayoutRequest>,
@@ -610,6 +1247,30 @@ pub mod cluster_service_server {
             &self,
             request: tonic::Request<super::PreviewLayoutChangesRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        /// 比較兩個布局版本，或目前布局與暫存變更
+        async fn diff_layouts(
+            &self,
+            request: tonic::Request<super::DiffLayoutsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        /// 在後端模擬 partition 分配，不會暫存任何變更
+        async fn simulate_layout(
+            &self,
+            request: tonic::Request<super::SimulateLayoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        /// Server streaming response type for the WatchCluster method.
+        type WatchClusterStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WatchClusterResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// 第一則訊息為完整狀態，之後只送出有變化的部分
+        async fn watch_cluster(
+            &self,
+            request: tonic::Request<super::WatchClusterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchClusterStream>,
+            tonic::Status,
+        >;
         /// Command operations
         async fn connect_nodes(
             &self,
@@ -631,6 +1292,28 @@ pub mod cluster_service_server {
             &self,
             request: tonic::Request<super::SkipDeadNodesRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        /// Server streaming response type for the DecommissionNode method.
+        type DecommissionNodeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::DecommissionNodeResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// 移除節點並持續回報資料搬移進度，直到不再有資料依賴該節點
+        async fn decommission_node(
+            &self,
+            request: tonic::Request<super::DecommissionNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::DecommissionNodeStream>,
+            tonic::Status,
+        >;
+        /// 連線節點、暫存角色並套用布局；已完成的步驟會略過，可重複執行
+        async fn bootstrap_cluster(
+            &self,
+            request: tonic::Request<super::BootstrapClusterRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
     }
     /// Cluster Service - gRPC API for cluster management
     #[derive(Debug)]
@@ -801,6 +1484,55 @@ pub mod cluster_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/cluster.ClusterService/GetClusterStatistics" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetClusterStatisticsSvc<T: ClusterService>(pub Arc<T>);
+                    impl<
+                        T: ClusterService,
+                    > tonic::server::UnaryService<super::GetClusterStatisticsRequest>
+                    for GetClusterStatisticsSvc<T> {
+                        type Response = super::ApiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetClusterStatisticsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ClusterService>::get_cluster_statistics(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetClusterStatisticsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/cluster.ClusterService/GetClusterLayout" => {
                     #[allow(non_camel_case_types)]
                     struct GetClusterLayoutSvc<T: ClusterService>(pub Arc<T>);
@@ -942,6 +1674,143 @@ pub mod cluster_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/cluster.ClusterService/DiffLayouts" => {
+                    #[allow(non_camel_case_types)]
+                    struct DiffLayoutsSvc<T: ClusterService>(pub Arc<T>);
+                    impl<
+                        T: ClusterService,
+                    > tonic::server::UnaryService<super::DiffLayoutsRequest>
+                    for DiffLayoutsSvc<T> {
+                        type Response = super::ApiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DiffLayoutsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ClusterService>::diff_layouts(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DiffLayoutsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cluster.ClusterService/SimulateLayout" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateLayoutSvc<T: ClusterService>(pub Arc<T>);
+                    impl<
+                        T: ClusterService,
+                    > tonic::server::UnaryService<super::SimulateLayoutRequest>
+                    for SimulateLayoutSvc<T> {
+                        type Response = super::ApiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateLayoutRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ClusterService>::simulate_layout(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SimulateLayoutSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cluster.ClusterService/WatchCluster" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchClusterSvc<T: ClusterService>(pub Arc<T>);
+                    impl<
+                        T: ClusterService,
+                    > tonic::server::ServerStreamingService<super::WatchClusterRequest>
+                    for WatchClusterSvc<T> {
+                        type Response = super::WatchClusterResponse;
+                        type ResponseStream = T::WatchClusterStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchClusterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ClusterService>::watch_cluster(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchClusterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/cluster.ClusterService/ConnectNodes" => {
                     #[allow(non_camel_case_types)]
                     struct ConnectNodesSvc<T: ClusterService>(pub Arc<T>);
@@ -1168,6 +2037,100 @@ pub mod cluster_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/cluster.ClusterService/DecommissionNode" => {
+                    #[allow(non_camel_case_types)]
+                    struct DecommissionNodeSvc<T: ClusterService>(pub Arc<T>);
+                    impl<
+                        T: ClusterService,
+                    > tonic::server::ServerStreamingService<
+                        super::DecommissionNodeRequest,
+                    > for DecommissionNodeSvc<T> {
+                        type Response = super::DecommissionNodeResponse;
+                        type ResponseStream = T::DecommissionNodeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DecommissionNodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ClusterService>::decommission_node(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DecommissionNodeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cluster.ClusterService/BootstrapCluster" => {
+                    #[allow(non_camel_case_types)]
+                    struct BootstrapClusterSvc<T: ClusterService>(pub Arc<T>);
+                    impl<
+                        T: ClusterService,
+                    > tonic::server::UnaryService<super::BootstrapClusterRequest>
+                    for BootstrapClusterSvc<T> {
+                        type Response = super::ApiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BootstrapClusterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ClusterService>::bootstrap_cluster(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BootstrapClusterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -55,3 +55,27 @@ pub mod worker {
 pub mod object {
     include!("object.rs");
 }
+
+#[allow(clippy::all)]
+#[allow(warnings)]
+pub mod admin_token {
+    include!("admin_token.rs");
+}
+
+#[allow(clippy::all)]
+#[allow(warnings)]
+pub mod audit {
+    include!("audit.rs");
+}
+
+#[allow(clippy::all)]
+#[allow(warnings)]
+pub mod system {
+    include!("system.rs");
+}
+
+#[allow(clippy::all)]
+#[allow(warnings)]
+pub mod schedule {
+    include!("schedule.rs");
+}
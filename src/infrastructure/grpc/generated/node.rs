@@ -5,7 +5,7 @@
 pub struct ApiResponse {
     #[prost(string, tag = "1")]
     pub trace_id: ::prost::alloc::string::String,
-    #[prost(oneof = "api_response::Data", tags = "2, 3, 4, 5")]
+    #[prost(oneof = "api_response::Data", tags = "2, 3, 4, 5, 6")]
     pub data: ::core::option::Option<api_response::Data>,
 }
 /// Nested message and enum types in `ApiResponse`.
@@ -21,6 +21,8 @@ pub mod api_response {
         MetadataSnapshot(super::MultiNodeEmptyData),
         #[prost(message, tag = "5")]
         LaunchRepair(super::MultiNodeEmptyData),
+        #[prost(message, tag = "6")]
+        ScrubStatus(super::MultiNodeScrubStatusData),
     }
 }
 #[derive(serde::Serialize)]
@@ -40,6 +42,9 @@ pub struct MultiNodeStatisticsData {
         ::prost::alloc::string::String,
         NodeStatisticsResult,
     >,
+    /// 所有成功回應節點的加總
+    #[prost(message, optional, tag = "2")]
+    pub totals: ::core::option::Option<NodeStatisticsTotals>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -51,6 +56,15 @@ pub struct MultiNodeEmptyData {
     >,
 }
 #[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultiNodeScrubStatusData {
+    #[prost(map = "string, message", tag = "1")]
+    pub results: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ScrubStatusResult,
+    >,
+}
+#[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetNodeInfoRequest {
     /// Node ID or "\*" for all nodes
@@ -66,6 +80,13 @@ pub struct GetNodeStatisticsRequest {
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetScrubStatusRequest {
+    /// Node ID or "\*" for all nodes
+    #[prost(string, tag = "1")]
+    pub node: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CreateMetadataSnapshotRequest {
     /// Node ID or "\*" for all nodes
     #[prost(string, tag = "1")]
@@ -77,9 +98,14 @@ pub struct LaunchRepairRequest {
     /// Node ID or "\*" for all nodes
     #[prost(string, tag = "1")]
     pub node: ::prost::alloc::string::String,
-    /// "tables", "blocks", "versions", "block_refs", "block_rc", "scrub"
-    #[prost(string, tag = "2")]
-    pub repair_type: ::prost::alloc::string::String,
+    #[prost(enumeration = "RepairType", tag = "3")]
+    pub repair_type: i32,
+    /// 僅 REPAIR_TYPE_SCRUB 使用
+    #[prost(enumeration = "ScrubCommand", tag = "4")]
+    pub scrub_command: i32,
+    /// 執行 scrub 命令前設定 scrub-tranquility，僅 REPAIR_TYPE_SCRUB 可用
+    #[prost(uint32, optional, tag = "5")]
+    pub scrub_tranquility: ::core::option::Option<u32>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -121,7 +147,7 @@ pub struct NodeInfo {
     pub db_engine: ::prost::alloc::string::String,
 }
 #[derive(serde::Serialize)]
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NodeStatisticsResult {
     #[prost(oneof = "node_statistics_result::Result", tags = "1, 2")]
     pub result: ::core::option::Option<node_statistics_result::Result>,
@@ -129,7 +155,7 @@ pub struct NodeStatisticsResult {
 /// Nested message and enum types in `NodeStatisticsResult`.
 pub mod node_statistics_result {
     #[derive(serde::Serialize)]
-    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
         #[prost(message, tag = "1")]
         Statistics(super::NodeStatistics),
@@ -138,10 +164,99 @@ pub mod node_statistics_result {
     }
 }
 #[derive(serde::Serialize)]
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NodeStatistics {
+    /// Garage 原始輸出，解析不完整時可直接顯示
     #[prost(string, tag = "1")]
     pub freeform: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub garage_version: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "3")]
+    pub garage_features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub rust_version: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub db_engine: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "6")]
+    pub tables: ::prost::alloc::vec::Vec<super::cluster::TableStatistics>,
+    #[prost(message, optional, tag = "7")]
+    pub blocks: ::core::option::Option<BlockManagerStatistics>,
+    /// 只有提供 storage nodes 區段的 Garage 版本才有
+    #[prost(message, optional, tag = "8")]
+    pub data_disk: ::core::option::Option<super::cluster::DiskUsage>,
+    #[prost(message, optional, tag = "9")]
+    pub metadata_disk: ::core::option::Option<super::cluster::DiskUsage>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BlockManagerStatistics {
+    /// ~= number of blocks
+    #[prost(int64, optional, tag = "1")]
+    pub rc_entries: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "2")]
+    pub resync_queue_length: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "3")]
+    pub resync_errors: ::core::option::Option<i64>,
+}
+/// Table items and RC entries are divided by the replication factor when all nodes are queried
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeStatisticsTotals {
+    #[prost(int64, tag = "1")]
+    pub node_count: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub tables: ::prost::alloc::vec::Vec<super::cluster::TableStatistics>,
+    #[prost(message, optional, tag = "3")]
+    pub blocks: ::core::option::Option<BlockManagerStatistics>,
+    #[prost(message, optional, tag = "4")]
+    pub data_disk: ::core::option::Option<super::cluster::DiskUsage>,
+    #[prost(message, optional, tag = "5")]
+    pub metadata_disk: ::core::option::Option<super::cluster::DiskUsage>,
+    /// some nodes failed or did not report every value
+    #[prost(bool, tag = "6")]
+    pub partial: bool,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScrubStatusResult {
+    #[prost(oneof = "scrub_status_result::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<scrub_status_result::Result>,
+}
+/// Nested message and enum types in `ScrubStatusResult`.
+pub mod scrub_status_result {
+    #[derive(serde::Serialize)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        Status(super::ScrubStatus),
+        #[prost(string, tag = "2")]
+        Error(::prost::alloc::string::String),
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScrubStatus {
+    #[prost(int64, tag = "1")]
+    pub worker_id: i64,
+    #[prost(enumeration = "ScrubState", tag = "2")]
+    pub state: i32,
+    /// 0-100, while running or paused
+    #[prost(double, optional, tag = "3")]
+    pub progress_percent: ::core::option::Option<f64>,
+    #[prost(int64, optional, tag = "4")]
+    pub tranquility: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub corruptions_detected: ::core::option::Option<i64>,
+    #[prost(string, optional, tag = "7")]
+    pub paused_until: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "8")]
+    pub last_completed_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "9")]
+    pub next_run_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, tag = "10")]
+    pub errors: i64,
+    #[prost(string, optional, tag = "11")]
+    pub last_error: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -160,6 +275,126 @@ pub mod empty_result {
         Error(::prost::alloc::string::String),
     }
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RepairType {
+    Unspecified = 0,
+    Tables = 1,
+    Blocks = 2,
+    Versions = 3,
+    MultipartUploads = 4,
+    BlockRefs = 5,
+    BlockRc = 6,
+    Rebalance = 7,
+    Aliases = 8,
+    ClearResyncQueue = 9,
+    Scrub = 10,
+}
+impl RepairType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "REPAIR_TYPE_UNSPECIFIED",
+            Self::Tables => "REPAIR_TYPE_TABLES",
+            Self::Blocks => "REPAIR_TYPE_BLOCKS",
+            Self::Versions => "REPAIR_TYPE_VERSIONS",
+            Self::MultipartUploads => "REPAIR_TYPE_MULTIPART_UPLOADS",
+            Self::BlockRefs => "REPAIR_TYPE_BLOCK_REFS",
+            Self::BlockRc => "REPAIR_TYPE_BLOCK_RC",
+            Self::Rebalance => "REPAIR_TYPE_REBALANCE",
+            Self::Aliases => "REPAIR_TYPE_ALIASES",
+            Self::ClearResyncQueue => "REPAIR_TYPE_CLEAR_RESYNC_QUEUE",
+            Self::Scrub => "REPAIR_TYPE_SCRUB",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REPAIR_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "REPAIR_TYPE_TABLES" => Some(Self::Tables),
+            "REPAIR_TYPE_BLOCKS" => Some(Self::Blocks),
+            "REPAIR_TYPE_VERSIONS" => Some(Self::Versions),
+            "REPAIR_TYPE_MULTIPART_UPLOADS" => Some(Self::MultipartUploads),
+            "REPAIR_TYPE_BLOCK_REFS" => Some(Self::BlockRefs),
+            "REPAIR_TYPE_BLOCK_RC" => Some(Self::BlockRc),
+            "REPAIR_TYPE_REBALANCE" => Some(Self::Rebalance),
+            "REPAIR_TYPE_ALIASES" => Some(Self::Aliases),
+            "REPAIR_TYPE_CLEAR_RESYNC_QUEUE" => Some(Self::ClearResyncQueue),
+            "REPAIR_TYPE_SCRUB" => Some(Self::Scrub),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScrubCommand {
+    Unspecified = 0,
+    Start = 1,
+    Pause = 2,
+    Resume = 3,
+    Cancel = 4,
+}
+impl ScrubCommand {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SCRUB_COMMAND_UNSPECIFIED",
+            Self::Start => "SCRUB_COMMAND_START",
+            Self::Pause => "SCRUB_COMMAND_PAUSE",
+            Self::Resume => "SCRUB_COMMAND_RESUME",
+            Self::Cancel => "SCRUB_COMMAND_CANCEL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SCRUB_COMMAND_UNSPECIFIED" => Some(Self::Unspecified),
+            "SCRUB_COMMAND_START" => Some(Self::Start),
+            "SCRUB_COMMAND_PAUSE" => Some(Self::Pause),
+            "SCRUB_COMMAND_RESUME" => Some(Self::Resume),
+            "SCRUB_COMMAND_CANCEL" => Some(Self::Cancel),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScrubState {
+    Idle = 0,
+    Running = 1,
+    Paused = 2,
+}
+impl ScrubState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Idle => "SCRUB_STATE_IDLE",
+            Self::Running => "SCRUB_STATE_RUNNING",
+            Self::Paused => "SCRUB_STATE_PAUSED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SCRUB_STATE_IDLE" => Some(Self::Idle),
+            "SCRUB_STATE_RUNNING" => Some(Self::Running),
+            "SCRUB_STATE_PAUSED" => Some(Self::Paused),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod node_service_client {
     #![allow(
@@ -295,6 +530,27 @@ pub mod node_service_client {
                 .insert(GrpcMethod::new("node.NodeService", "GetNodeStatistics"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_scrub_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetScrubStatusRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/node.NodeService/GetScrubStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("node.NodeService", "GetScrubStatus"));
+            self.inner.unary(req, path, codec).await
+        }
         /// Command operations
         pub async fn create_metadata_snapshot(
             &mut self,
@@ -362,6 +618,10 @@ pub mod node_service_server {
             &self,
             request: tonic::Request<super::GetNodeStatisticsRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
+        async fn get_scrub_status(
+            &self,
+            request: tonic::Request<super::GetScrubStatusRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApiResponse>, tonic::Status>;
         /// Command operations
         async fn create_metadata_snapshot(
             &self,
@@ -540,6 +800,51 @@ pub mod node_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/node.NodeService/GetScrubStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetScrubStatusSvc<T: NodeService>(pub Arc<T>);
+                    impl<
+                        T: NodeService,
+                    > tonic::server::UnaryService<super::GetScrubStatusRequest>
+                    for GetScrubStatusSvc<T> {
+                        type Response = super::ApiResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetScrubStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as NodeService>::get_scrub_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetScrubStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/node.NodeService/CreateMetadataSnapshot" => {
                     #[allow(non_camel_case_types)]
                     struct CreateMetadataSnapshotSvc<T: NodeService>(pub Arc<T>);
@@ -59,6 +59,9 @@ pub struct DeleteObjectResponse {
     pub deleted: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(message, repeated, tag = "3")]
     pub errors: ::prost::alloc::vec::Vec<DeleteError>,
+    /// 刪除資料夾（遞迴）需要確認時回傳，此時不會刪除任何物件
+    #[prost(message, optional, tag = "4")]
+    pub confirmation: ::core::option::Option<super::utility::ConfirmationRequired>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -104,6 +107,9 @@ pub struct ListObjectsRequest {
     /// 分隔符，通常是 "/" 用於虛擬資料夾分頁
     #[prost(string, optional, tag = "5")]
     pub delimiter: ::core::option::Option<::prost::alloc::string::String>,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "6")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -112,6 +118,9 @@ pub struct GetObjectMetadataRequest {
     pub bucket: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub key: ::prost::alloc::string::String,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "3")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 /// Upload request - first message must be metadata, followed by chunks
 #[derive(serde::Serialize)]
@@ -144,6 +153,9 @@ pub struct UploadMetadata {
     pub content_type: ::prost::alloc::string::String,
     #[prost(int64, tag = "4")]
     pub content_length: i64,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "5")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 /// Upload response - server sends upload_id after receiving metadata, then final result
 #[derive(serde::Serialize)]
@@ -199,6 +211,9 @@ pub struct DownloadObjectRequest {
     pub bucket: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub key: ::prost::alloc::string::String,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "3")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 /// Download response - first message is metadata, followed by chunks
 #[derive(serde::Serialize)]
@@ -250,6 +265,9 @@ pub struct GetUploadUrlRequest {
     /// Default: 3600 (1 hour)
     #[prost(int32, optional, tag = "4")]
     pub expires_in_seconds: ::core::option::Option<i32>,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "5")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -261,6 +279,9 @@ pub struct GetDownloadUrlRequest {
     /// Default: 3600 (1 hour)
     #[prost(int32, optional, tag = "3")]
     pub expires_in_seconds: ::core::option::Option<i32>,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "4")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -269,6 +290,12 @@ pub struct DeleteObjectRequest {
     pub bucket: ::prost::alloc::string::String,
     #[prost(string, repeated, tag = "2")]
     pub keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "3")]
+    pub credentials: ::core::option::Option<S3Credentials>,
+    /// Required when keys contain folders (ending with "/"); token from a previous call's ConfirmationRequired
+    #[prost(string, optional, tag = "4")]
+    pub confirmation_token: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -281,6 +308,9 @@ pub struct CopyObjectRequest {
     pub dest_bucket: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub dest_key: ::prost::alloc::string::String,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "5")]
+    pub credentials: ::core::option::Option<S3Credentials>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -291,6 +321,19 @@ pub struct AbortUploadRequest {
     pub key: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub upload_id: ::prost::alloc::string::String,
+    /// Run as this access key instead of the server's default S3 key
+    #[prost(message, optional, tag = "4")]
+    pub credentials: ::core::option::Option<S3Credentials>,
+}
+/// Access key used for the S3 call; the secret is looked up from Garage when omitted (requires scope write:keys)
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct S3Credentials {
+    #[prost(string, tag = "1")]
+    pub access_key_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    #[serde(serialize_with = "crate::shared::redacted")]
+    pub secret_access_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
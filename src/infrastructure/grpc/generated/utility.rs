@@ -35,3 +35,18 @@ pub struct NullableBool {
     #[prost(bool, optional, tag = "1")]
     pub value: ::core::option::Option<bool>,
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ConfirmationRequired {
+    #[prost(string, tag = "1")]
+    #[serde(serialize_with = "crate::shared::redacted")]
+    pub token: ::prost::alloc::string::String,
+    /// RFC 3339 format
+    #[prost(string, tag = "2")]
+    pub expires_at: ::prost::alloc::string::String,
+    /// e.g. "Deleting 2 bucket(s) with 12345 objects / 40.0 GB"
+    #[prost(string, tag = "3")]
+    pub summary: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "4")]
+    pub details: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
@@ -1,12 +1,13 @@
 //! gRPC logging utilities
 //!
 //! Provides clean logging for gRPC requests and responses
+//! Request / response 在寫入 log 前會先遮蔽敏感欄位（見 `shared::redact`）
 
 use std::panic::Location;
 use std::time::Instant;
 use tracing::{info, error};
 use serde::Serialize;
-use crate::shared::get_trace_id;
+use crate::shared::{get_caller, get_peer_subject, get_trace_id, redact_text, to_redacted_json};
 
 /// Truncate string for logging (max 500 chars)
 fn truncate_for_log(s: &str) -> String {
@@ -31,19 +32,23 @@ impl GrpcLogger {
             proto,
             method,
             start: Instant::now(),
-            request_json: serde_json::to_string(request).unwrap_or_else(|_| "{}".to_string()),
+            request_json: to_redacted_json(request),
         }
     }
 
     pub fn ok<T: Serialize>(&self, response: &T) {
         let trace_id = get_trace_id();
         let req = truncate_for_log(&self.request_json);
-        let res = truncate_for_log(&serde_json::to_string(response).unwrap_or_else(|_| "{}".to_string()));
+        let res = truncate_for_log(&to_redacted_json(response));
         let duration_ms = self.start.elapsed().as_millis();
+        let caller = get_caller().unwrap_or_default();
+        let peer = get_peer_subject().unwrap_or_default();
         
         info!(
             target: "grpc",
             trace_id = %trace_id,
+            caller = %caller,
+            peer = %peer,
             proto = %self.proto,
             method = %self.method,
             request = %req,
@@ -64,12 +69,17 @@ impl GrpcLogger {
     pub fn err(&self, error: &str) {
         let trace_id = get_trace_id();
         let req = truncate_for_log(&self.request_json);
+        let error = redact_text(error);
         let duration_ms = self.start.elapsed().as_millis();
         let location = Location::caller();
+        let caller = get_caller().unwrap_or_default();
+        let peer = get_peer_subject().unwrap_or_default();
         
         error!(
             target: "error",
             trace_id = %trace_id,
+            caller = %caller,
+            peer = %peer,
             proto = %self.proto,
             method = %self.method,
             request = %req,
@@ -94,6 +104,7 @@ impl GrpcLogger {
     pub fn err_with_backtrace(&self, error: &str) {
         let trace_id = get_trace_id();
         let req = truncate_for_log(&self.request_json);
+        let error = redact_text(error);
         let duration_ms = self.start.elapsed().as_millis();
         let location = Location::caller();
         let backtrace = std::backtrace::Backtrace::capture();
@@ -131,3 +142,87 @@ macro_rules! grpc_log {
         $crate::infrastructure::grpc::logging::GrpcLogger::new($proto, $method, $request)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use crate::infrastructure::grpc::generated::access_key::{Key, RevealKeySecretResponse};
+    use crate::infrastructure::grpc::generated::admin_token::CreateAdminTokenResponse;
+
+    const SECRET: &str = "7f3c9a1e5b2d8f4a6c0e-secret";
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// 執行 f 並回傳期間輸出的所有 log
+    fn capture_logs(f: impl FnOnce()) -> String {
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+        let bytes = capture.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[derive(Serialize)]
+    struct ImportReq<'a> {
+        access_key_id: &'a str,
+        secret_access_key: &'a str,
+    }
+
+    #[test]
+    fn test_ok_redacts_request_and_response() {
+        let output = capture_logs(|| {
+            let log = GrpcLogger::new("AccessKeyService", "ImportKey", &ImportReq {
+                access_key_id: "GK123",
+                secret_access_key: SECRET,
+            });
+            log.ok(&RevealKeySecretResponse {
+                trace_id: "t".into(),
+                id: "GK123".into(),
+                secret_access_key: SECRET.into(),
+            });
+        });
+
+        assert!(output.contains("GK123"));
+        assert!(output.contains(crate::shared::REDACTED));
+        assert!(!output.contains(SECRET));
+    }
+
+    #[test]
+    fn test_err_redacts_bearer_token() {
+        let output = capture_logs(|| {
+            let log = GrpcLogger::new("AccessKeyService", "ReadKey", &Key::default());
+            log.err(&format!("upstream rejected Bearer {}", SECRET));
+        });
+
+        assert!(output.contains("upstream rejected"));
+        assert!(!output.contains(SECRET));
+    }
+
+    #[test]
+    fn test_proto_secret_fields_are_annotated() {
+        let key = Key { id: "GK1".into(), secret_access_key: Some(SECRET.into()), ..Default::default() };
+        let created = CreateAdminTokenResponse { secret_token: SECRET.into(), ..Default::default() };
+
+        // 不經過黑名單，直接序列化也不得出現 secret
+        assert!(!serde_json::to_string(&key).unwrap().contains(SECRET));
+        assert!(!serde_json::to_string(&created).unwrap().contains(SECRET));
+    }
+}
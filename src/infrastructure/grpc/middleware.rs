@@ -8,6 +8,7 @@ use http_body::Body;
 use tower::{Layer, Service};
 
 use crate::shared::{with_context, TraceContext, generate_trace_id};
+use super::tls::TlsPeerInfo;
 
 /// Layer that adds logging middleware to gRPC services
 #[derive(Clone)]
@@ -50,7 +51,11 @@ where
         let mut inner = std::mem::replace(&mut self.inner, clone);
         
         // Create trace context for this request
-        let ctx = TraceContext::with_trace_id(trace_id.clone());
+        let peer_subject = req
+            .extensions()
+            .get::<TlsPeerInfo>()
+            .and_then(|info| info.subject.clone());
+        let ctx = TraceContext::with_trace_id(trace_id.clone()).with_peer_subject(peer_subject);
         
         Box::pin(async move {
             // Run the request within the trace context
@@ -1,5 +1,6 @@
 //! gRPC server implementation
 
+pub mod auth;
 pub mod composition;
 pub mod conversions;
 pub mod generated;
@@ -7,7 +8,12 @@ pub mod logging;
 pub mod server;
 pub mod services;
 pub mod middleware;
+pub mod rate_limit;
+pub mod read_only;
+pub mod rpc_methods;
+pub mod tls;
 
+pub use auth::*;
 pub use server::*;
 pub use services::*;
 pub use middleware::*;
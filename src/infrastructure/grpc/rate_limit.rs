@@ -0,0 +1,610 @@
+//! gRPC rate limiting
+//!
+//! - 每個 client 對每個 method 各自一個 token bucket，超過時回傳 `RESOURCE_EXHAUSTED`
+//!   並附上 `retry-after` metadata（秒）
+//! - 全域限制同時處理中的請求數量，回應在整個 body 送完前都佔用名額；
+//!   server streaming RPC（例如 WatchCluster）可能長時間保持開啟，另外使用獨立的名額，
+//!   避免開著的串流佔滿名額而拒絕所有一般請求
+//!
+//! `ConcurrencyLimitLayer` 放在認證之前，限制同時向 Garage 驗證的請求數；
+//! `RateLimitLayer` 放在認證之後，以驗證過的 token ID 區分 client，
+//! 偽造的 token 無法各自取得新的 bucket。
+//! 認證本身由 `AuthFailureLimiter` 依來源 IP 限制驗證失敗的次數，
+//! 避免大量偽造的 token 各自觸發一次 Garage 查詢
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use http::{Request, Response};
+use http_body::{Body, Frame, SizeHint};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tonic::metadata::MetadataMap;
+use tonic::transport::server::TcpConnectInfo;
+use tonic::{Code, Status};
+use tower::{Layer, Service};
+use tracing::warn;
+
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::infrastructure::config::{RateLimit, RateLimitConfig, RateLimitKey};
+use super::rpc_methods;
+use super::tls::TlsPeerInfo;
+
+/// 追蹤的 bucket 超過此數量時，清除已補滿（閒置）的 bucket
+const MAX_TRACKED_BUCKETS: usize = 4096;
+
+// ============ Token Bucket ============
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn full(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: limit.burst as f64,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, limit: &RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.requests_per_sec).min(limit.burst as f64);
+        self.last_refill = now;
+    }
+
+    /// 檢查是否還有 token（不取用），不足時回傳需等待的時間
+    fn available(&mut self, limit: &RateLimit, now: Instant) -> Result<(), Duration> {
+        self.refill(limit, now);
+        if self.tokens >= 1.0 {
+            Ok(())
+        } else {
+            Err(Duration::try_from_secs_f64((1.0 - self.tokens) / limit.requests_per_sec).unwrap_or(Duration::MAX))
+        }
+    }
+
+    /// 取用一個 token，不足時回傳需等待的時間
+    fn try_take(&mut self, limit: &RateLimit, now: Instant) -> Result<(), Duration> {
+        self.available(limit, now)?;
+        self.tokens -= 1.0;
+        Ok(())
+    }
+
+    fn is_full(&self, limit: &RateLimit, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens + elapsed * limit.requests_per_sec >= limit.burst as f64
+    }
+}
+
+// ============ Rate Limiter ============
+
+/// Per-client, per-method token buckets
+pub struct RateLimiter {
+    default_limit: RateLimit,
+    method_limits: HashMap<String, RateLimit>,
+    buckets: Mutex<HashMap<(String, String), TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(default_limit: RateLimit, method_limits: HashMap<String, RateLimit>) -> Self {
+        Self {
+            default_limit,
+            method_limits,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Method 適用的限制，`requests_per_sec` 為 0 時不限制
+    fn limit_for(&self, method: &str) -> Option<&RateLimit> {
+        let limit = self.method_limits.get(method).unwrap_or(&self.default_limit);
+        (limit.requests_per_sec > 0.0).then_some(limit)
+    }
+
+    /// 檢查並記錄一次請求，超過限制時回傳建議的重試等待時間
+    pub fn check(&self, client: &str, method: &str) -> Result<(), Duration> {
+        self.check_at(client, method, Instant::now())
+    }
+
+    fn check_at(&self, client: &str, method: &str, now: Instant) -> Result<(), Duration> {
+        let Some(limit) = self.limit_for(method) else {
+            return Ok(());
+        };
+
+        let mut buckets = self.buckets.lock().expect("rate limiter poisoned");
+        if buckets.len() >= MAX_TRACKED_BUCKETS {
+            buckets.retain(|(_, m), bucket| {
+                let limit = self.method_limits.get(m).unwrap_or(&self.default_limit);
+                !bucket.is_full(limit, now)
+            });
+        }
+
+        buckets
+            .entry((client.to_string(), method.to_string()))
+            .or_insert_with(|| TokenBucket::full(limit, now))
+            .try_take(limit, now)
+    }
+}
+
+// ============ Auth Failure Limiter ============
+
+/// Per-peer-IP limit on failed authentications
+///
+/// 只有驗證失敗才取用 token；用完時該來源未快取的 token 不再送往 Garage 驗證，
+/// 直到 token 補回為止。已快取的有效 token 不受影響
+pub struct AuthFailureLimiter {
+    limit: RateLimit,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl AuthFailureLimiter {
+    /// `requests_per_sec` 為 0 時不限制
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 該來源是否還能嘗試驗證，不行時回傳建議的重試等待時間
+    pub fn check(&self, peer: &str) -> Result<(), Duration> {
+        self.check_at(peer, Instant::now())
+    }
+
+    /// 記錄一次驗證失敗
+    pub fn record_failure(&self, peer: &str) {
+        self.record_failure_at(peer, Instant::now());
+    }
+
+    fn check_at(&self, peer: &str, now: Instant) -> Result<(), Duration> {
+        if self.limit.requests_per_sec <= 0.0 {
+            return Ok(());
+        }
+        let mut buckets = self.buckets.lock().expect("auth failure limiter poisoned");
+        match buckets.get_mut(peer) {
+            Some(bucket) => bucket.available(&self.limit, now),
+            None => Ok(()),
+        }
+    }
+
+    fn record_failure_at(&self, peer: &str, now: Instant) {
+        if self.limit.requests_per_sec <= 0.0 {
+            return;
+        }
+        let mut buckets = self.buckets.lock().expect("auth failure limiter poisoned");
+        if buckets.len() >= MAX_TRACKED_BUCKETS {
+            buckets.retain(|_, bucket| !bucket.is_full(&self.limit, now));
+        }
+        let _ = buckets
+            .entry(peer.to_string())
+            .or_insert_with(|| TokenBucket::full(&self.limit, now))
+            .try_take(&self.limit, now);
+    }
+}
+
+/// 建立帶 `retry-after` metadata 的 `RESOURCE_EXHAUSTED` 狀態
+pub(super) fn resource_exhausted(message: String, retry_after: Duration) -> Status {
+    // 無條件進位到秒，至少 1 秒
+    let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    let mut metadata = MetadataMap::new();
+    metadata.insert("retry-after", secs.max(1).into());
+    Status::with_metadata(Code::ResourceExhausted, message, metadata)
+}
+
+/// 依設定取得 client 識別字串
+///
+/// 以 auth middleware 驗證過的 token ID 區分；未啟用認證時改用連線來源
+fn client_key<B>(req: &Request<B>, key: RateLimitKey) -> String {
+    if key == RateLimitKey::Token {
+        if let Some(token) = req.extensions().get::<AdminTokenAggregate>() {
+            return format!("token:{}", token.id());
+        }
+    }
+    peer_key(req)
+}
+
+/// 連線來源 IP 的識別字串
+pub(super) fn peer_key<B>(req: &Request<B>) -> String {
+    let remote_addr = req
+        .extensions()
+        .get::<TlsPeerInfo>()
+        .map(|info| info.remote_addr)
+        .or_else(|| req.extensions().get::<TcpConnectInfo>().and_then(|info| info.remote_addr()));
+    match remote_addr {
+        Some(addr) => format!("peer:{}", addr.ip()),
+        None => "peer:unknown".to_string(),
+    }
+}
+
+// ============ Layer ============
+
+/// Layer that enforces per-client, per-method rate limits (after authentication)
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Option<Arc<RateLimiter>>,
+    key: RateLimitKey,
+}
+
+impl RateLimitLayer {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let limiter = (config.default_limit.requests_per_sec > 0.0
+            || config.method_limits.values().any(|l| l.requests_per_sec > 0.0))
+            .then(|| Arc::new(RateLimiter::new(config.default_limit, config.method_limits.clone())));
+
+        Self {
+            limiter,
+            key: config.key,
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        RateLimitMiddleware {
+            inner: service,
+            limiter: self.limiter.clone(),
+            key: self.key,
+        }
+    }
+}
+
+/// Middleware that rejects requests over the configured rate limits
+#[derive(Clone)]
+pub struct RateLimitMiddleware<S> {
+    inner: S,
+    limiter: Option<Arc<RateLimiter>>,
+    key: RateLimitKey,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RateLimitMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Body + Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        if let Some(limiter) = &self.limiter {
+            let method = req.uri().path();
+            let client = client_key(&req, self.key);
+            if let Err(retry_after) = limiter.check(&client, method) {
+                warn!(
+                    "Rate limit exceeded | method: {} | client: {} | retry_after_ms: {}",
+                    method,
+                    client,
+                    retry_after.as_millis()
+                );
+                let status = resource_exhausted(format!("Rate limit exceeded for {}", method), retry_after);
+                return Box::pin(async move { Ok(status.into_http()) });
+            }
+        }
+
+        Box::pin(async move { inner.call(req).await })
+    }
+}
+
+/// Layer that limits the number of requests in flight (before authentication)
+#[derive(Clone)]
+pub struct ConcurrencyLimitLayer {
+    requests: Option<Arc<Semaphore>>,
+    streams: Option<Arc<Semaphore>>,
+}
+
+impl ConcurrencyLimitLayer {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let semaphore = |limit: usize| (limit > 0).then(|| Arc::new(Semaphore::new(limit)));
+        Self {
+            requests: semaphore(config.max_concurrent_requests),
+            streams: semaphore(config.max_concurrent_streams),
+        }
+    }
+}
+
+impl<S> Layer<S> for ConcurrencyLimitLayer {
+    type Service = ConcurrencyLimitMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        ConcurrencyLimitMiddleware {
+            inner: service,
+            requests: self.requests.clone(),
+            streams: self.streams.clone(),
+        }
+    }
+}
+
+/// Middleware that rejects requests while too many are in flight
+#[derive(Clone)]
+pub struct ConcurrencyLimitMiddleware<S> {
+    inner: S,
+    requests: Option<Arc<Semaphore>>,
+    streams: Option<Arc<Semaphore>>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for ConcurrencyLimitMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Body + Default + Send + 'static,
+{
+    type Response = Response<PermitBody<ResBody>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let (semaphore, kind) = if rpc_methods::is_streaming(req.uri().path()) {
+            (&self.streams, "streams")
+        } else {
+            (&self.requests, "requests")
+        };
+        let permit = match semaphore.clone().map(Semaphore::try_acquire_owned) {
+            Some(Ok(permit)) => Some(permit),
+            Some(Err(_)) => {
+                warn!("Too many concurrent {} | method: {}", kind, req.uri().path());
+                let status = resource_exhausted(format!("Server is busy, too many concurrent {}", kind), Duration::from_secs(1));
+                return Box::pin(async move { Ok(status.into_http().map(|body| PermitBody::new(body, None))) });
+            }
+            None => None,
+        };
+
+        Box::pin(async move {
+            // 名額交給 response body，串流送完（或 client 中斷）時才釋放
+            let response = inner.call(req).await?;
+            Ok(response.map(|body| PermitBody::new(body, permit)))
+        })
+    }
+}
+
+/// Response body that holds a concurrency permit until the body ends or is dropped
+pub struct PermitBody<B> {
+    inner: Pin<Box<B>>,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl<B> PermitBody<B> {
+    fn new(inner: B, permit: Option<OwnedSemaphorePermit>) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            permit,
+        }
+    }
+}
+
+impl<B: Body> Body for PermitBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        let frame = this.inner.as_mut().poll_frame(cx);
+        if matches!(frame, Poll::Ready(None)) {
+            this.permit = None;
+        }
+        frame
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METHOD: &str = "/bucket.BucketService/ReadBucket";
+
+    fn limit(requests_per_sec: f64, burst: u32) -> RateLimit {
+        RateLimit { requests_per_sec, burst }
+    }
+
+    #[test]
+    fn test_burst_then_reject_with_retry_after() {
+        let limiter = RateLimiter::new(limit(2.0, 3), HashMap::new());
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at("client", METHOD, now).is_ok());
+        }
+        let retry_after = limiter.check_at("client", METHOD, now).unwrap_err();
+        assert_eq!(retry_after, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_refill_over_time() {
+        let limiter = RateLimiter::new(limit(2.0, 1), HashMap::new());
+        let now = Instant::now();
+
+        assert!(limiter.check_at("client", METHOD, now).is_ok());
+        assert!(limiter.check_at("client", METHOD, now + Duration::from_millis(100)).is_err());
+        assert!(limiter.check_at("client", METHOD, now + Duration::from_millis(600)).is_ok());
+    }
+
+    #[test]
+    fn test_buckets_are_per_client_and_method() {
+        let limiter = RateLimiter::new(limit(1.0, 1), HashMap::new());
+        let now = Instant::now();
+
+        assert!(limiter.check_at("a", METHOD, now).is_ok());
+        assert!(limiter.check_at("a", METHOD, now).is_err());
+        assert!(limiter.check_at("b", METHOD, now).is_ok());
+        assert!(limiter.check_at("a", "/bucket.BucketService/ListBucket", now).is_ok());
+    }
+
+    #[test]
+    fn test_method_override_and_unlimited() {
+        let methods = HashMap::from([
+            (METHOD.to_string(), limit(1.0, 1)),
+            ("/cluster.ClusterService/GetClusterHealth".to_string(), limit(0.0, 1)),
+        ]);
+        let limiter = RateLimiter::new(limit(100.0, 100), methods);
+        let now = Instant::now();
+
+        assert!(limiter.check_at("a", METHOD, now).is_ok());
+        assert!(limiter.check_at("a", METHOD, now).is_err());
+        for _ in 0..1000 {
+            assert!(limiter.check_at("a", "/cluster.ClusterService/GetClusterHealth", now).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_resource_exhausted_rounds_retry_after_up() {
+        let status = resource_exhausted("slow down".to_string(), Duration::from_millis(1200));
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(status.metadata().get("retry-after").unwrap(), "2");
+
+        let status = resource_exhausted("slow down".to_string(), Duration::from_millis(10));
+        assert_eq!(status.metadata().get("retry-after").unwrap(), "1");
+    }
+
+    #[test]
+    fn test_client_key_uses_verified_token_id() {
+        let mut req = Request::builder()
+            .header(http::header::AUTHORIZATION, "Bearer secret-token")
+            .body(())
+            .unwrap();
+        // 尚未驗證的 bearer token 不會產生獨立的 bucket
+        assert_eq!(client_key(&req, RateLimitKey::Token), "peer:unknown");
+
+        req.extensions_mut().insert(AdminTokenAggregate::reconstitute(
+            "token-id".to_string(),
+            None,
+            vec![],
+            false,
+            None,
+        ));
+        assert_eq!(client_key(&req, RateLimitKey::Token), "token:token-id");
+        assert_eq!(client_key(&req, RateLimitKey::Peer), "peer:unknown");
+    }
+
+    #[test]
+    fn test_auth_failures_limited_per_peer() {
+        let limiter = AuthFailureLimiter::new(limit(1.0, 2));
+        let now = Instant::now();
+
+        // 成功的驗證不取用 token
+        for _ in 0..10 {
+            assert!(limiter.check_at("peer:a", now).is_ok());
+        }
+
+        limiter.record_failure_at("peer:a", now);
+        assert!(limiter.check_at("peer:a", now).is_ok());
+        limiter.record_failure_at("peer:a", now);
+        assert_eq!(limiter.check_at("peer:a", now).unwrap_err(), Duration::from_secs(1));
+        assert!(limiter.check_at("peer:b", now).is_ok());
+        assert!(limiter.check_at("peer:a", now + Duration::from_secs(1)).is_ok());
+
+        let unlimited = AuthFailureLimiter::new(limit(0.0, 1));
+        for _ in 0..10 {
+            unlimited.record_failure_at("peer:a", now);
+        }
+        assert!(unlimited.check_at("peer:a", now).is_ok());
+    }
+
+    #[test]
+    fn test_tiny_rate_does_not_overflow_retry_after() {
+        let limiter = RateLimiter::new(limit(1e-300, 1), HashMap::new());
+        let now = Instant::now();
+
+        assert!(limiter.check_at("client", METHOD, now).is_ok());
+        assert_eq!(limiter.check_at("client", METHOD, now).unwrap_err(), Duration::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_permit_is_held_until_body_ends() {
+        let semaphore = Arc::new(Semaphore::new(1));
+        let permit = semaphore.clone().try_acquire_owned().unwrap();
+        let mut body = PermitBody::new("data".to_string(), Some(permit));
+
+        // 第一個 frame 送出後仍佔用名額
+        assert!(std::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await.is_some());
+        assert_eq!(semaphore.available_permits(), 0);
+
+        assert!(std::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await.is_none());
+        assert_eq!(semaphore.available_permits(), 1);
+    }
+
+    /// 回傳空 body 的 service
+    #[derive(Clone)]
+    struct EmptyService;
+
+    impl Service<Request<()>> for EmptyService {
+        type Response = Response<String>;
+        type Error = std::convert::Infallible;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: Request<()>) -> Self::Future {
+            std::future::ready(Ok(Response::new(String::new())))
+        }
+    }
+
+    async fn call(service: &mut ConcurrencyLimitMiddleware<EmptyService>, path: &str) -> Response<PermitBody<String>> {
+        let req = Request::builder().uri(path).body(()).unwrap();
+        service.call(req).await.unwrap()
+    }
+
+    fn is_rejected<B>(response: &Response<B>) -> bool {
+        response.headers().get("grpc-status").is_some_and(|v| v == "8")
+    }
+
+    #[tokio::test]
+    async fn test_open_stream_does_not_block_unary_calls() {
+        let config = RateLimitConfig {
+            default_limit: limit(0.0, 1),
+            method_limits: HashMap::new(),
+            key: RateLimitKey::Token,
+            max_concurrent_requests: 1,
+            max_concurrent_streams: 1,
+            auth_failure_limit: limit(0.0, 1),
+        };
+        let mut service = ConcurrencyLimitLayer::new(&config).layer(EmptyService);
+
+        // 尚未讀完的串流持續佔用串流名額
+        let stream = call(&mut service, "/cluster.ClusterService/WatchCluster").await;
+        assert!(!is_rejected(&stream));
+
+        let unary = call(&mut service, METHOD).await;
+        assert!(!is_rejected(&unary));
+        drop(unary);
+        assert!(!is_rejected(&call(&mut service, METHOD).await));
+
+        assert!(is_rejected(&call(&mut service, "/cluster.ClusterService/DecommissionNode").await));
+        drop(stream);
+        assert!(!is_rejected(&call(&mut service, "/cluster.ClusterService/DecommissionNode").await));
+    }
+}
@@ -0,0 +1,138 @@
+//! Read-only mode
+//!
+//! 啟用後所有 Command RPC 一律回傳 `FAILED_PRECONDITION`，Query RPC 不受影響。
+//! RPC 的分類見 `rpc_methods`，與認證所需的 scope 共用同一張表。
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use http::{Request, Response};
+use http_body::Body;
+use tonic::Status;
+use tower::{Layer, Service};
+use tracing::warn;
+
+use super::rpc_methods::{self, RpcKind};
+
+/// 唯讀模式下仍允許的 Command（否則無法關閉唯讀模式）
+const ALWAYS_ALLOWED: &[&str] = &["/system.SystemService/SetReadOnlyMode"];
+
+/// 唯讀模式下是否拒絕此 method，未列在 `rpc_methods::RPC_METHODS` 的 method 視為 Command
+fn is_blocked(path: &str) -> bool {
+    rpc_methods::find(path).is_none_or(|m| m.kind != RpcKind::Query) && !ALWAYS_ALLOWED.contains(&path)
+}
+
+// ============ Switch ============
+
+/// 可於執行期間切換的唯讀模式旗標，所有 clone 共用同一個狀態
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlySwitch {
+    enabled: Arc<AtomicBool>,
+}
+
+impl ReadOnlySwitch {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled: Arc::new(AtomicBool::new(enabled)),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    /// 設定唯讀模式，回傳原本的狀態
+    pub fn set(&self, enabled: bool) -> bool {
+        self.enabled.swap(enabled, Ordering::SeqCst)
+    }
+}
+
+// ============ Layer ============
+
+/// Layer that rejects command RPCs while read-only mode is enabled
+#[derive(Clone)]
+pub struct ReadOnlyLayer {
+    switch: ReadOnlySwitch,
+}
+
+impl ReadOnlyLayer {
+    pub fn new(switch: ReadOnlySwitch) -> Self {
+        Self { switch }
+    }
+}
+
+impl<S> Layer<S> for ReadOnlyLayer {
+    type Service = ReadOnlyMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        ReadOnlyMiddleware {
+            inner: service,
+            switch: self.switch.clone(),
+        }
+    }
+}
+
+/// Middleware that enforces read-only mode
+#[derive(Clone)]
+pub struct ReadOnlyMiddleware<S> {
+    inner: S,
+    switch: ReadOnlySwitch,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for ReadOnlyMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: Body + Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let method = req.uri().path();
+        if self.switch.is_enabled() && is_blocked(method) {
+            warn!("Rejected command in read-only mode | method: {}", method);
+            let status = Status::failed_precondition("Server is in read-only mode");
+            return Box::pin(async move { Ok(status.into_http()) });
+        }
+
+        Box::pin(async move { inner.call(req).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_commands_only() {
+        assert!(is_blocked("/bucket.BucketService/CreateBucket"));
+        assert!(is_blocked("/block.BlockService/PurgeBlocks"));
+        assert!(is_blocked("/object.ObjectService/UploadObject"));
+        assert!(is_blocked("/unknown.Service/Method"));
+        assert!(!is_blocked("/bucket.BucketService/ListBucket"));
+        assert!(!is_blocked("/access_key.AccessKeyService/ExportKeys"));
+        assert!(!is_blocked("/system.SystemService/SetReadOnlyMode"));
+    }
+
+    #[test]
+    fn test_switch_is_shared() {
+        let switch = ReadOnlySwitch::new(false);
+        let other = switch.clone();
+
+        assert!(!other.set(true));
+        assert!(switch.is_enabled());
+    }
+}
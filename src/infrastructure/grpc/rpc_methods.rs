@@ -0,0 +1,219 @@
+//! gRPC method registry
+//!
+//! 每個 RPC 的類型（對應 `application/commands` 與 `application/queries`）、所需的
+//! Admin Token scope 與是否為串流集中在同一張表，認證、唯讀模式與並行限制都由此查詢。
+//! 未列出的 RPC 視為需要 `admin:*` 的 Command，避免新增 RPC 時意外開放。
+
+use crate::domain::aggregates::AdminTokenScope as Scope;
+
+/// RPC 類型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcKind {
+    /// 會變更 Garage 或本服務狀態的操作
+    Command,
+    /// 只讀取資料的操作
+    Query,
+}
+
+/// 一個 RPC 的類型與權限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpcMethod {
+    /// gRPC 完整路徑，例如 `/bucket.BucketService/ListBucket`
+    pub path: &'static str,
+    pub kind: RpcKind,
+    /// 所需的 scope，`None` 表示只要通過認證即可呼叫
+    pub scope: Option<&'static str>,
+    /// 是否為 server streaming（回應可能長時間保持開啟）
+    pub streaming: bool,
+}
+
+impl RpcMethod {
+    const fn streaming(self) -> Self {
+        Self { streaming: true, ..self }
+    }
+}
+
+const fn query(path: &'static str, scope: &'static str) -> RpcMethod {
+    RpcMethod { path, kind: RpcKind::Query, scope: Some(scope), streaming: false }
+}
+
+const fn command(path: &'static str, scope: &'static str) -> RpcMethod {
+    RpcMethod { path, kind: RpcKind::Command, scope: Some(scope), streaming: false }
+}
+
+/// 任何有效的 token 都能呼叫的 Query
+const fn authenticated_query(path: &'static str) -> RpcMethod {
+    RpcMethod { path, kind: RpcKind::Query, scope: None, streaming: false }
+}
+
+/// 所有 RPC
+pub const RPC_METHODS: &[RpcMethod] = &[
+    // ============ Bucket ============
+    query("/bucket.BucketService/ListBucket", Scope::READ_BUCKETS),
+    query("/bucket.BucketService/ReadBucket", Scope::READ_BUCKETS),
+    command("/bucket.BucketService/CreateBucket", Scope::WRITE_BUCKETS),
+    command("/bucket.BucketService/UpdateBucket", Scope::WRITE_BUCKETS),
+    command("/bucket.BucketService/DeleteBucket", Scope::WRITE_BUCKETS),
+    command("/bucket.BucketService/AddBucketAlias", Scope::WRITE_BUCKETS),
+    command("/bucket.BucketService/RemoveBucketAlias", Scope::WRITE_BUCKETS),
+    command("/bucket.BucketService/AllowBucketKey", Scope::WRITE_BUCKETS),
+    command("/bucket.BucketService/DenyBucketKey", Scope::WRITE_BUCKETS),
+
+    // ============ Object (S3) ============
+    // Upload URL 讓呼叫者直接寫入 S3，視為 Command
+    query("/object.ObjectService/ListObjects", Scope::READ_BUCKETS),
+    query("/object.ObjectService/GetObjectMetadata", Scope::READ_BUCKETS),
+    query("/object.ObjectService/DownloadObject", Scope::READ_BUCKETS).streaming(),
+    query("/object.ObjectService/GetDownloadUrl", Scope::READ_BUCKETS),
+    command("/object.ObjectService/UploadObject", Scope::WRITE_BUCKETS).streaming(),
+    command("/object.ObjectService/GetUploadUrl", Scope::WRITE_BUCKETS),
+    command("/object.ObjectService/DeleteObject", Scope::WRITE_BUCKETS),
+    command("/object.ObjectService/CopyObject", Scope::WRITE_BUCKETS),
+    command("/object.ObjectService/AbortUpload", Scope::WRITE_BUCKETS),
+
+    // ============ Access Key ============
+    // ExportKeys 帶 include_secret 時另外要求 write:keys；ImportKeys 帶有 Bucket 授權或 alias 時
+    // 另外要求 write:buckets（皆於 service 內檢查）
+    query("/access_key.AccessKeyService/ListKey", Scope::READ_KEYS),
+    query("/access_key.AccessKeyService/ReadKey", Scope::READ_KEYS),
+    query("/access_key.AccessKeyService/ExportKeys", Scope::READ_KEYS).streaming(),
+    query("/access_key.AccessKeyService/RevealKeySecret", Scope::WRITE_KEYS),
+    command("/access_key.AccessKeyService/CreateKey", Scope::WRITE_KEYS),
+    command("/access_key.AccessKeyService/ImportKey", Scope::WRITE_KEYS),
+    command("/access_key.AccessKeyService/ImportKeys", Scope::WRITE_KEYS),
+    command("/access_key.AccessKeyService/UpdateKey", Scope::WRITE_KEYS),
+    command("/access_key.AccessKeyService/DeleteKey", Scope::WRITE_KEYS),
+    command("/access_key.AccessKeyService/RotateKey", Scope::WRITE_KEYS),
+
+    // ============ Admin Token ============
+    // 任何有效的 token 都能查詢自己的資訊，其餘需要完整權限
+    authenticated_query("/admin_token.AdminTokenService/GetCurrentAdminToken"),
+    query("/admin_token.AdminTokenService/ListAdminToken", Scope::ADMIN),
+    query("/admin_token.AdminTokenService/ReadAdminToken", Scope::ADMIN),
+    command("/admin_token.AdminTokenService/CreateAdminToken", Scope::ADMIN),
+    command("/admin_token.AdminTokenService/UpdateAdminToken", Scope::ADMIN),
+    command("/admin_token.AdminTokenService/DeleteAdminToken", Scope::ADMIN),
+
+    // ============ Cluster ============
+    query("/cluster.ClusterService/GetClusterStatus", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/GetClusterHealth", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/GetClusterStatistics", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/GetClusterLayout", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/GetLayoutHistory", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/PreviewLayoutChanges", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/DiffLayouts", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/SimulateLayout", Scope::READ_CLUSTER_STATUS),
+    query("/cluster.ClusterService/WatchCluster", Scope::READ_CLUSTER_STATUS).streaming(),
+    command("/cluster.ClusterService/ConnectNodes", Scope::WRITE_CLUSTER_LAYOUT),
+    command("/cluster.ClusterService/UpdateLayout", Scope::WRITE_CLUSTER_LAYOUT),
+    command("/cluster.ClusterService/ApplyLayout", Scope::WRITE_CLUSTER_LAYOUT),
+    command("/cluster.ClusterService/RevertLayout", Scope::WRITE_CLUSTER_LAYOUT),
+    command("/cluster.ClusterService/SkipDeadNodes", Scope::WRITE_CLUSTER_LAYOUT),
+    command("/cluster.ClusterService/DecommissionNode", Scope::WRITE_CLUSTER_LAYOUT).streaming(),
+    command("/cluster.ClusterService/BootstrapCluster", Scope::WRITE_CLUSTER_LAYOUT),
+
+    // ============ Node / Block / Worker ============
+    // 維運操作（snapshot、repair、purge、worker 變數）需要完整權限
+    query("/node.NodeService/GetNodeInfo", Scope::READ_CLUSTER_STATUS),
+    query("/node.NodeService/GetNodeStatistics", Scope::READ_CLUSTER_STATUS),
+    query("/node.NodeService/GetScrubStatus", Scope::READ_CLUSTER_STATUS),
+    command("/node.NodeService/CreateMetadataSnapshot", Scope::ADMIN),
+    command("/node.NodeService/LaunchRepair", Scope::ADMIN),
+    query("/block.BlockService/GetBlockInfo", Scope::READ_CLUSTER_STATUS),
+    query("/block.BlockService/ListBlockErrors", Scope::READ_CLUSTER_STATUS),
+    query("/block.BlockService/GetBlockErrorReport", Scope::READ_CLUSTER_STATUS),
+    command("/block.BlockService/PurgeBlocks", Scope::ADMIN),
+    command("/block.BlockService/RetryBlockResync", Scope::ADMIN),
+    query("/worker.WorkerService/ListWorkers", Scope::READ_CLUSTER_STATUS),
+    query("/worker.WorkerService/GetWorkerInfo", Scope::READ_CLUSTER_STATUS),
+    query("/worker.WorkerService/GetWorkerVariable", Scope::READ_CLUSTER_STATUS),
+    command("/worker.WorkerService/SetWorkerVariable", Scope::ADMIN),
+
+    // ============ Schedule ============
+    query("/schedule.ScheduleService/ListSchedules", Scope::READ_CLUSTER_STATUS),
+    query("/schedule.ScheduleService/GetSchedule", Scope::READ_CLUSTER_STATUS),
+    command("/schedule.ScheduleService/CreateSchedule", Scope::ADMIN),
+    command("/schedule.ScheduleService/UpdateSchedule", Scope::ADMIN),
+    command("/schedule.ScheduleService/DeleteSchedule", Scope::ADMIN),
+    command("/schedule.ScheduleService/RunSchedule", Scope::ADMIN),
+
+    // ============ Audit / System ============
+    // 所有使用者都需要知道目前是否為唯讀模式；切換需要 admin:*
+    query("/audit.AuditService/ListAuditEvents", Scope::ADMIN),
+    authenticated_query("/system.SystemService/GetReadOnlyMode"),
+    command("/system.SystemService/SetReadOnlyMode", Scope::ADMIN),
+];
+
+/// 依 gRPC 完整路徑查詢，未列出的 RPC 回傳 `None`
+pub fn find(path: &str) -> Option<&'static RpcMethod> {
+    RPC_METHODS.iter().find(|m| m.path == path)
+}
+
+/// 是否為 server streaming RPC
+pub fn is_streaming(path: &str) -> bool {
+    find(path).is_some_and(|m| m.streaming)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// 從 proto 檔列出所有 RPC 的完整路徑，以及是否為 server streaming
+    fn proto_methods() -> Vec<(String, bool)> {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("proto");
+        let mut methods = Vec::new();
+
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let source = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            let package = source
+                .lines()
+                .find_map(|l| l.trim().strip_prefix("package "))
+                .map(|p| p.trim_end_matches(';').trim().to_string());
+            let mut service = None;
+
+            for line in source.lines().map(str::trim) {
+                if let Some(rest) = line.strip_prefix("service ") {
+                    service = rest.split_whitespace().next().map(str::to_string);
+                } else if let Some(rest) = line.strip_prefix("rpc ") {
+                    let name = rest.split('(').next().unwrap().trim();
+                    let path = format!(
+                        "/{}.{}/{}",
+                        package.as_deref().unwrap(),
+                        service.as_deref().unwrap(),
+                        name
+                    );
+                    methods.push((path, rest.contains("returns (stream")));
+                }
+            }
+        }
+        methods
+    }
+
+    #[test]
+    fn test_registry_matches_proto() {
+        let methods = proto_methods();
+        assert!(!methods.is_empty());
+
+        let unregistered: Vec<_> = methods.iter().filter(|(m, _)| find(m).is_none()).collect();
+        assert!(unregistered.is_empty(), "unregistered RPCs: {:?}", unregistered);
+
+        let mismatched: Vec<_> = methods.iter().filter(|(m, streaming)| is_streaming(m) != *streaming).collect();
+        assert!(mismatched.is_empty(), "streaming flag differs from proto: {:?}", mismatched);
+
+        let stale: Vec<_> = RPC_METHODS
+            .iter()
+            .filter(|m| !methods.iter().any(|(p, _)| p == m.path))
+            .map(|m| m.path)
+            .collect();
+        assert!(stale.is_empty(), "RPCs missing from proto: {:?}", stale);
+    }
+
+    #[test]
+    fn test_no_duplicate_entries() {
+        let mut seen = HashSet::new();
+        for method in RPC_METHODS {
+            assert!(seen.insert(method.path), "duplicate entry: {}", method.path);
+        }
+    }
+}
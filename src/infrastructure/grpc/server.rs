@@ -2,46 +2,90 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
 use tonic::transport::Server;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::domain::events::EventBus;
-use crate::infrastructure::config::S3Config;
+use crate::domain::repositories::{AuditRepository, ConfirmationTokenRepository, ScheduleRepository};
+use crate::infrastructure::config::{AuthConfig, ClusterConfig, RateLimitConfig, S3Config, TlsConfig};
 use crate::infrastructure::garage::GarageClient;
+use crate::infrastructure::tasks::ClusterWatch;
 
 use super::generated::bucket::bucket_service_server::BucketServiceServer;
 use super::generated::access_key::access_key_service_server::AccessKeyServiceServer;
+use super::generated::admin_token::admin_token_service_server::AdminTokenServiceServer;
+use super::generated::audit::audit_service_server::AuditServiceServer;
 use super::generated::cluster::cluster_service_server::ClusterServiceServer;
 use super::generated::node::node_service_server::NodeServiceServer;
 use super::generated::block::block_service_server::BlockServiceServer;
 use super::generated::object::object_service_server::ObjectServiceServer;
+use super::generated::schedule::schedule_service_server::ScheduleServiceServer;
+use super::generated::system::system_service_server::SystemServiceServer;
 use super::generated::worker::worker_service_server::WorkerServiceServer;
 
 use super::composition::{
-    AccessKeyServiceBuilder, BucketServiceBuilder, ClusterServiceBuilder,
-    NodeServiceBuilder, BlockServiceBuilder, ObjectServiceBuilder, WorkerServiceBuilder,
+    AccessKeyServiceBuilder, AdminTokenServiceBuilder, AuditServiceBuilder, BucketServiceBuilder, ClusterServiceBuilder,
+    MaintenanceHandlers, NodeServiceBuilder, BlockServiceBuilder, ObjectServiceBuilder, ScheduleServiceBuilder,
+    SystemServiceBuilder, WorkerServiceBuilder,
 };
+use super::auth::{AuthLayer, TokenAuthenticator};
 use super::middleware::LoggingLayer;
+use super::rate_limit::{ConcurrencyLimitLayer, RateLimitLayer};
+use super::read_only::{ReadOnlyLayer, ReadOnlySwitch};
+use super::tls::ReloadableTlsAcceptor;
 
 pub struct GrpcServer {
     addr: SocketAddr,
     garage_client: GarageClient,
     event_bus: Arc<dyn EventBus>,
+    audit_repository: Arc<dyn AuditRepository>,
+    confirmations: Arc<dyn ConfirmationTokenRepository>,
     s3_config: S3Config,
+    auth_config: AuthConfig,
+    rate_limit_config: RateLimitConfig,
+    tls_config: Option<TlsConfig>,
+    read_only: ReadOnlySwitch,
+    cluster_config: ClusterConfig,
+    cluster_watch: ClusterWatch,
+    schedule_repository: Arc<dyn ScheduleRepository>,
+    maintenance: MaintenanceHandlers,
 }
 
 impl GrpcServer {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         addr: SocketAddr,
         garage_client: GarageClient,
         event_bus: Arc<dyn EventBus>,
+        audit_repository: Arc<dyn AuditRepository>,
+        confirmations: Arc<dyn ConfirmationTokenRepository>,
         s3_config: S3Config,
+        auth_config: AuthConfig,
+        rate_limit_config: RateLimitConfig,
+        tls_config: Option<TlsConfig>,
+        read_only: ReadOnlySwitch,
+        cluster_config: ClusterConfig,
+        cluster_watch: ClusterWatch,
+        schedule_repository: Arc<dyn ScheduleRepository>,
+        maintenance: MaintenanceHandlers,
     ) -> Self {
         Self {
             addr,
             garage_client,
             event_bus,
+            audit_repository,
+            confirmations,
             s3_config,
+            auth_config,
+            rate_limit_config,
+            tls_config,
+            read_only,
+            cluster_config,
+            cluster_watch,
+            schedule_repository,
+            maintenance,
         }
     }
 
@@ -49,36 +93,111 @@ impl GrpcServer {
         let bucket_service = BucketServiceBuilder::new(
             self.garage_client.clone(),
             self.event_bus.clone(),
+            self.confirmations.clone(),
         ).build();
 
-        let access_key_service = AccessKeyServiceBuilder::new(self.garage_client.clone()).build();
+        let access_key_service = AccessKeyServiceBuilder::new(
+            self.garage_client.clone(),
+            self.event_bus.clone(),
+        ).build();
 
-        let cluster_service = ClusterServiceBuilder::new(self.garage_client.clone()).build();
+        let admin_token_service = AdminTokenServiceBuilder::new(
+            self.garage_client.clone(),
+            self.event_bus.clone(),
+        ).build();
 
-        let node_service = NodeServiceBuilder::new(self.garage_client.clone()).build();
+        let audit_service = AuditServiceBuilder::new(self.audit_repository.clone()).build();
 
-        let block_service = BlockServiceBuilder::new(self.garage_client.clone()).build();
+        let replication_factor = self.cluster_config.replication_factor;
+        let cluster_service = ClusterServiceBuilder::new(
+            self.garage_client.clone(),
+            self.confirmations.clone(),
+            self.cluster_config,
+            self.cluster_watch,
+        ).build();
+
+        let node_service = NodeServiceBuilder::new(
+            self.garage_client.clone(),
+            self.maintenance.clone(),
+            replication_factor,
+        ).build();
+
+        let block_service = BlockServiceBuilder::new(
+            self.garage_client.clone(),
+            self.confirmations.clone(),
+            self.maintenance.clone(),
+        ).build();
 
         let worker_service = WorkerServiceBuilder::new(self.garage_client.clone()).build();
 
-        let object_service = ObjectServiceBuilder::new(self.s3_config).build().await;
+        let object_service = ObjectServiceBuilder::new(
+            self.garage_client.clone(),
+            self.s3_config,
+            self.confirmations.clone(),
+        ).build().await;
+
+        let schedule_service = ScheduleServiceBuilder::new(
+            self.event_bus.clone(),
+            self.schedule_repository.clone(),
+            self.maintenance.clone(),
+        ).build();
+
+        let system_service = SystemServiceBuilder::new(self.read_only.clone(), self.event_bus.clone()).build();
+
+        let auth_layer = if self.auth_config.enabled {
+            AuthLayer::new(Arc::new(TokenAuthenticator::new(
+                self.garage_client.clone(),
+                Duration::from_secs(self.auth_config.cache_ttl_secs),
+                self.rate_limit_config.auth_failure_limit,
+            )))
+        } else {
+            warn!("gRPC authentication is disabled, every caller acts with GARAGE_API_KEY");
+            AuthLayer::disabled()
+        };
 
         info!(
-            "Starting gRPC server |\n addr: {}",
-            self.addr
+            "Starting gRPC server |\n addr: {} |\n auth_enabled: {} |\n rate_limit_rps: {} |\n read_only: {} |\n confirmation_tokens: {} |\n tls: {}",
+            self.addr,
+            self.auth_config.enabled,
+            self.rate_limit_config.default_limit.requests_per_sec,
+            self.read_only.is_enabled(),
+            self.confirmations.enabled(),
+            match &self.tls_config {
+                Some(tls) if tls.client_ca_path.is_some() => "mtls",
+                Some(_) => "tls",
+                None => "disabled",
+            }
         );        
 
-        Server::builder()
+        let router = Server::builder()
             .layer(LoggingLayer)
+            .layer(ConcurrencyLimitLayer::new(&self.rate_limit_config))
+            .layer(auth_layer)
+            .layer(RateLimitLayer::new(&self.rate_limit_config))
+            .layer(ReadOnlyLayer::new(self.read_only.clone()))
             .add_service(BucketServiceServer::new(bucket_service))
             .add_service(AccessKeyServiceServer::new(access_key_service))
+            .add_service(AdminTokenServiceServer::new(admin_token_service))
+            .add_service(AuditServiceServer::new(audit_service))
             .add_service(ClusterServiceServer::new(cluster_service))
             .add_service(NodeServiceServer::new(node_service))
             .add_service(BlockServiceServer::new(block_service))
             .add_service(ObjectServiceServer::new(object_service))
-            .add_service(WorkerServiceServer::new(worker_service))
-            .serve(self.addr)
-            .await?;
+            .add_service(ScheduleServiceServer::new(schedule_service))
+            .add_service(SystemServiceServer::new(system_service))
+            .add_service(WorkerServiceServer::new(worker_service));
+
+        match self.tls_config {
+            Some(tls_config) => {
+                let acceptor = Arc::new(ReloadableTlsAcceptor::load(tls_config)?);
+                acceptor.spawn_reload_watcher();
+                let listener = TcpListener::bind(self.addr).await?;
+                router.serve_with_incoming(acceptor.incoming(listener)).await?;
+            }
+            None => {
+                router.serve(self.addr).await?;
+            }
+        }
 
         Ok(())
     }
@@ -1,56 +1,88 @@
 //! Access Key gRPC service implementation
 
+use std::pin::Pin;
 use std::sync::Arc;
+use futures::{Stream, StreamExt};
 use serde::Serialize;
-use tonic::{Request, Response, Status};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
 
 use crate::application::commands::access_key::{
-    CreateKeyCommand, UpdateKeyCommand, DeleteKeyCommand
+    CreateKeyCommand, ImportKeyCommand, ImportKeysCommand, UpdateKeyCommand, DeleteKeyCommand,
+    RotateKeyCommand,
 };
 use crate::application::commands::access_key::handlers::{
-    CreateKeyHandler, UpdateKeyHandler, DeleteKeyHandler,
+    CreateKeyHandler, ImportKeyHandler, ImportKeysHandler, KeyImportResult as KeyImportOutcome,
+    UpdateKeyHandler, DeleteKeyHandler, RotateKeyHandler,
 };
 use crate::application::queries::access_key::{
-    ListKeysQuery, ReadKeyQuery,
+    ExportKeysQuery, ListKeysQuery, ReadKeyQuery, RevealKeySecretQuery,
 };
 use crate::application::queries::access_key::handlers::{
-    ListKeysHandler, ReadKeyHandler,
+    ExportKeysHandler, ListKeysHandler, ReadKeyHandler, RevealKeySecretHandler,
+};
+use crate::domain::aggregates::{AdminTokenAggregate, AdminTokenScope};
+use crate::domain::entities::{
+    AccessKey, KeyTransferEncoder, KeyTransferFormat, KeyTransferRecord, KeyTransferRow,
 };
 use crate::grpc_log;
-use crate::shared::get_trace_id;
+use crate::shared::{current_context, get_trace_id, with_context};
 use crate::infrastructure::grpc::conversions::{NullableStringExt, domain_error_to_status};
 
 use crate::infrastructure::grpc::generated::access_key::{
     access_key_service_server::AccessKeyService,
     ListKeysRequest, ListKeysResponse, KeyListItem,
     KeyResponse, Key, ReadKeyRequest, DeleteKeyResponse,
-    CreateKeyRequest, UpdateKeyRequest, DeleteKeyRequest,
+    CreateKeyRequest, ImportKeyRequest, UpdateKeyRequest, DeleteKeyRequest,
     KeyPermissions, KeyBucket, KeyBucketPermissions,
+    RevealKeySecretRequest, RevealKeySecretResponse, RotateKeyRequest, RotateKeyResponse,
+    ExportKeysRequest, ExportKeysResponse, ImportKeysRequest, ImportKeysResponse,
+    KeyFileFormat, KeyImportResult, KeyGrantResult, import_keys_request,
 };
 
+/// 匯入檔大小上限
+const MAX_IMPORT_BYTES: usize = 16 * 1024 * 1024;
+
 /// gRPC service for access key operations
 pub struct AccessKeyGrpcService {
     create_key_handler: Arc<CreateKeyHandler>,
+    import_key_handler: Arc<ImportKeyHandler>,
     update_key_handler: Arc<UpdateKeyHandler>,
     delete_key_handler: Arc<DeleteKeyHandler>,
+    rotate_key_handler: Arc<RotateKeyHandler>,
+    import_keys_handler: Arc<ImportKeysHandler>,
     list_keys_handler: Arc<ListKeysHandler>,
     read_key_handler: Arc<ReadKeyHandler>,
+    reveal_key_secret_handler: Arc<RevealKeySecretHandler>,
+    export_keys_handler: Arc<ExportKeysHandler>,
 }
 
 impl AccessKeyGrpcService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         create_key_handler: Arc<CreateKeyHandler>,
+        import_key_handler: Arc<ImportKeyHandler>,
         update_key_handler: Arc<UpdateKeyHandler>,
         delete_key_handler: Arc<DeleteKeyHandler>,
+        rotate_key_handler: Arc<RotateKeyHandler>,
+        import_keys_handler: Arc<ImportKeysHandler>,
         list_keys_handler: Arc<ListKeysHandler>,
         read_key_handler: Arc<ReadKeyHandler>,
+        reveal_key_secret_handler: Arc<RevealKeySecretHandler>,
+        export_keys_handler: Arc<ExportKeysHandler>,
     ) -> Self {
         Self {
             create_key_handler,
+            import_key_handler,
             update_key_handler,
             delete_key_handler,
+            rotate_key_handler,
+            import_keys_handler,
             list_keys_handler,
             read_key_handler,
+            reveal_key_secret_handler,
+            export_keys_handler,
         }
     }
 }
@@ -62,7 +94,7 @@ impl AccessKeyService for AccessKeyGrpcService {
         request: Request<ListKeysRequest>,
     ) -> Result<Response<ListKeysResponse>, Status> {
         let req = request.into_inner();
-        let pagination = req.pagination.clone().unwrap_or_default();
+        let pagination = req.pagination.unwrap_or_default();
         
         let query = ListKeysQuery::from_grpc_request(
             pagination.page,
@@ -101,7 +133,6 @@ impl AccessKeyService for AccessKeyGrpcService {
                 name: k.name,
                 created: k.created.to_rfc3339(),
                 expiration: k.expiration.map(|dt| dt.to_rfc3339()),
-                secret_access_key: k.secret_access_key,
             })
             .collect();
 
@@ -156,6 +187,33 @@ impl AccessKeyService for AccessKeyGrpcService {
         Ok(Response::new(response))
     }
 
+    async fn reveal_key_secret(
+        &self,
+        request: Request<RevealKeySecretRequest>,
+    ) -> Result<Response<RevealKeySecretResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AccessKeyService", "RevealKeySecret", &SingleIdRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        let secret = self
+            .reveal_key_secret_handler
+            .handle(RevealKeySecretQuery::new(req.id.clone()))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = RevealKeySecretResponse {
+            trace_id: trace_id.clone(),
+            id: req.id,
+            secret_access_key: secret,
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: &response });
+        Ok(Response::new(response))
+    }
+
     async fn create_key(
         &self,
         request: Request<CreateKeyRequest>,
@@ -193,6 +251,47 @@ impl AccessKeyService for AccessKeyGrpcService {
         Ok(Response::new(response))
     }
 
+    async fn import_key(
+        &self,
+        request: Request<ImportKeyRequest>,
+    ) -> Result<Response<KeyResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AccessKeyService", "ImportKey", &ImportKeyReq {
+            access_key_id: &req.access_key_id,
+            name: &req.name,
+        });
+        let trace_id = get_trace_id();
+
+        let key = self
+            .import_key_handler
+            .handle(ImportKeyCommand::new(
+                req.access_key_id,
+                req.secret_access_key,
+                req.name,
+            ))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        // 呼叫者已持有 secret，不再回傳
+        let key_info = convert_key_info(AccessKey { secret_access_key: None, ..key });
+        let response = KeyResponse {
+            trace_id: trace_id.clone(),
+            data: Some(key_info.clone()),
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: KeyLogSimple {
+                id: &key_info.id,
+                name: &key_info.name,
+            },
+        });
+        Ok(Response::new(response))
+    }
+
     async fn update_key(
         &self,
         request: Request<UpdateKeyRequest>,
@@ -259,6 +358,225 @@ impl AccessKeyService for AccessKeyGrpcService {
         });
         Ok(Response::new(response))
     }
+
+    async fn rotate_key(
+        &self,
+        request: Request<RotateKeyRequest>,
+    ) -> Result<Response<RotateKeyResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AccessKeyService", "RotateKey", &RotateKeyReq {
+            id: &req.id,
+            name: &req.name,
+            grace_period_secs: &req.grace_period_secs,
+        });
+        let trace_id = get_trace_id();
+
+        let result = self
+            .rotate_key_handler
+            .handle(RotateKeyCommand::new(req.id, req.name, req.grace_period_secs))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let key_info = convert_key_info(result.new_key);
+        let response = RotateKeyResponse {
+            trace_id: trace_id.clone(),
+            data: Some(key_info.clone()),
+            old_key_id: result.old_key_id,
+            old_key_expiration: result.old_key_expiration.map(|e| e.to_rfc3339()),
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: KeyLogSimple {
+                id: &key_info.id,
+                name: &key_info.name,
+            },
+        });
+        Ok(Response::new(response))
+    }
+
+    type ExportKeysStream = Pin<Box<dyn Stream<Item = Result<ExportKeysResponse, Status>> + Send>>;
+
+    async fn export_keys(
+        &self,
+        request: Request<ExportKeysRequest>,
+    ) -> Result<Response<Self::ExportKeysStream>, Status> {
+        // 匯出 secret 等同 RevealKeySecret，需要 write:keys
+        let can_reveal = request
+            .extensions()
+            .get::<AdminTokenAggregate>()
+            .is_none_or(|t| t.has_permission(AdminTokenScope::WRITE_KEYS));
+        let req = request.into_inner();
+        let log = grpc_log!("AccessKeyService", "ExportKeys", &ExportKeysReq {
+            format: req.format,
+            include_secret: req.include_secret,
+        });
+        let trace_id = get_trace_id();
+
+        if req.include_secret && !can_reveal {
+            log.err("Missing scope write:keys for include_secret");
+            return Err(Status::permission_denied(format!(
+                "Exporting secrets requires scope {}",
+                AdminTokenScope::WRITE_KEYS
+            )));
+        }
+
+        let format = convert_format(req.format).inspect_err(|e| log.err(e.message()))?;
+
+        let (record_tx, record_rx) = mpsc::channel::<KeyTransferRecord>(16);
+        let (response_tx, response_rx) = mpsc::channel::<Result<ExportKeysResponse, Status>>(16);
+        let handler = self.export_keys_handler.clone();
+        let query = ExportKeysQuery::new(format, req.include_secret);
+
+        // 每個 Key 一個 chunk，前後加上檔頭 / 檔尾；查詢與傳送同時進行，不在記憶體中累積整份檔案
+        let context = current_context().unwrap_or_default();
+        tokio::spawn(with_context(context, async move {
+            let chunk = |chunk: String| ExportKeysResponse {
+                trace_id: trace_id.clone(),
+                chunk: chunk.into_bytes(),
+            };
+
+            let mut encoder = KeyTransferEncoder::new(format);
+            let forward = async {
+                // 移入 future：client 中斷時 forward 結束即 drop 接收端，handler 便會停止查詢
+                let mut record_rx = record_rx;
+                if response_tx.send(Ok(chunk(encoder.begin()))).await.is_err() {
+                    return;
+                }
+                while let Some(record) = record_rx.recv().await {
+                    let encoded = encoder.record(&record);
+                    if response_tx.send(Ok(chunk(encoded))).await.is_err() {
+                        return;
+                    }
+                }
+            };
+            let (result, ()) = tokio::join!(handler.handle(query, record_tx), forward);
+
+            match result {
+                Ok(total) => {
+                    let tail = encoder.finish();
+                    if !tail.is_empty() {
+                        let _ = response_tx.send(Ok(chunk(tail))).await;
+                    }
+                    log.ok(&ApiResponseLog {
+                        trace_id: &trace_id,
+                        data: ExportKeysResponseLog { total },
+                    });
+                }
+                Err(e) => {
+                    log.err(&e.to_string());
+                    let _ = response_tx.send(Err(domain_error_to_status(e))).await;
+                }
+            }
+        }));
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(response_rx))))
+    }
+
+    async fn import_keys(
+        &self,
+        request: Request<Streaming<ImportKeysRequest>>,
+    ) -> Result<Response<ImportKeysResponse>, Status> {
+        let token = request.extensions().get::<AdminTokenAggregate>().cloned();
+        let mut stream = request.into_inner();
+
+        // First message must be metadata
+        let metadata = match stream.next().await {
+            Some(Ok(ImportKeysRequest { data: Some(import_keys_request::Data::Metadata(m)) })) => m,
+            Some(Err(e)) => return Err(Status::internal(format!("Stream error: {}", e))),
+            _ => return Err(Status::invalid_argument("First message must be metadata")),
+        };
+
+        let log = grpc_log!("AccessKeyService", "ImportKeys", &ImportKeysReq {
+            format: metadata.format,
+            dry_run: metadata.dry_run,
+        });
+        let trace_id = get_trace_id();
+
+        let format = convert_format(metadata.format).inspect_err(|e| log.err(e.message()))?;
+
+        let mut content = Vec::new();
+        while let Some(msg) = stream.next().await {
+            let chunk = match msg.map(|m| m.data) {
+                Ok(Some(import_keys_request::Data::Chunk(chunk))) => chunk,
+                Ok(Some(import_keys_request::Data::Metadata(_))) => {
+                    log.err("Unexpected metadata");
+                    return Err(Status::invalid_argument("Unexpected metadata"));
+                }
+                Ok(None) => continue,
+                Err(e) => {
+                    log.err(&e.to_string());
+                    return Err(Status::internal(format!("Stream error: {}", e)));
+                }
+            };
+            if content.len() + chunk.len() > MAX_IMPORT_BYTES {
+                log.err("Key file too large");
+                return Err(Status::invalid_argument(format!(
+                    "Key file exceeds {} bytes",
+                    MAX_IMPORT_BYTES
+                )));
+            }
+            content.extend_from_slice(&chunk);
+        }
+
+        let content = String::from_utf8(content).map_err(|_| {
+            log.err("Key file is not valid UTF-8");
+            Status::invalid_argument("Key file is not valid UTF-8")
+        })?;
+
+        let rows = format.decode(&content).map_err(|e| {
+            log.err(&e.to_string());
+            domain_error_to_status(e)
+        })?;
+        let rows = restrict_bucket_grants(rows, token.as_ref());
+
+        let results = self
+            .import_keys_handler
+            .handle(ImportKeysCommand::new(rows, metadata.dry_run))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let data: Vec<KeyImportResult> = results.into_iter().map(convert_import_result).collect();
+        let succeeded = data.iter().filter(|r| r.success).count() as i32;
+        let response = ImportKeysResponse {
+            trace_id: trace_id.clone(),
+            dry_run: metadata.dry_run,
+            succeeded,
+            failed: data.len() as i32 - succeeded,
+            data,
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: &response });
+        Ok(Response::new(response))
+    }
+}
+
+/// 匯入時授權 Bucket 或重建 local alias 等同 AllowBucketKey / AddBucketAlias，需要 write:buckets；
+/// 沒有該 scope 時帶有 Bucket 的列視為失敗（未啟用認證時不限制）
+fn restrict_bucket_grants(rows: Vec<KeyTransferRow>, token: Option<&AdminTokenAggregate>) -> Vec<KeyTransferRow> {
+    if token.is_none_or(|t| t.has_permission(AdminTokenScope::WRITE_BUCKETS)) {
+        return rows;
+    }
+
+    rows.into_iter()
+        .map(|row| match row.record {
+            Ok(record) if !record.buckets.is_empty() => KeyTransferRow {
+                row: row.row,
+                record: Err(format!(
+                    "Key {} has bucket permissions or aliases, which require scope {}",
+                    record.access_key_id,
+                    AdminTokenScope::WRITE_BUCKETS
+                )),
+            },
+            _ => row,
+        })
+        .collect()
 }
 
 // ============ Log Structs ============
@@ -289,6 +607,31 @@ struct CreateKeyReq<'a> {
     name: &'a Option<String>,
 }
 
+#[derive(Serialize)]
+struct ImportKeyReq<'a> {
+    access_key_id: &'a str,
+    name: &'a Option<String>,
+}
+
+#[derive(Serialize)]
+struct RotateKeyReq<'a> {
+    id: &'a str,
+    name: &'a Option<String>,
+    grace_period_secs: &'a Option<i64>,
+}
+
+#[derive(Serialize)]
+struct ExportKeysReq {
+    format: i32,
+    include_secret: bool,
+}
+
+#[derive(Serialize)]
+struct ImportKeysReq {
+    format: i32,
+    dry_run: bool,
+}
+
 #[derive(Serialize)]
 struct ApiResponseLog<'a, T: Serialize> {
     trace_id: &'a str,
@@ -301,6 +644,11 @@ struct ListKeysResponseLog {
     total: usize,
 }
 
+#[derive(Serialize)]
+struct ExportKeysResponseLog {
+    total: usize,
+}
+
 #[derive(Serialize)]
 struct KeyLogSimple<'a> {
     id: &'a str,
@@ -309,7 +657,7 @@ struct KeyLogSimple<'a> {
 
 // ============ Helpers ============
 
-fn convert_key_info(key: crate::domain::entities::AccessKey) -> Key {
+fn convert_key_info(key: AccessKey) -> Key {
     Key {
         id: key.id,
         secret_access_key: key.secret_access_key,
@@ -335,3 +683,85 @@ fn convert_key_info(key: crate::domain::entities::AccessKey) -> Key {
             .collect(),
     }
 }
+
+fn convert_format(format: i32) -> Result<KeyTransferFormat, Status> {
+    match KeyFileFormat::try_from(format) {
+        Ok(KeyFileFormat::Json) => Ok(KeyTransferFormat::Json),
+        Ok(KeyFileFormat::Csv) => Ok(KeyTransferFormat::Csv),
+        Err(_) => Err(Status::invalid_argument(format!("Unknown key file format: {}", format))),
+    }
+}
+
+fn convert_import_result(result: KeyImportOutcome) -> KeyImportResult {
+    KeyImportResult {
+        row: result.row as i32,
+        access_key_id: result.access_key_id,
+        success: result.success,
+        error: result.error,
+        grants: result
+            .grants
+            .into_iter()
+            .map(|g| KeyGrantResult {
+                bucket_id: g.bucket_id,
+                success: g.success,
+                error: g.error,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::KeyTransferBucket;
+
+    fn row(row: usize, buckets: Vec<KeyTransferBucket>) -> KeyTransferRow {
+        KeyTransferRow {
+            row,
+            record: Ok(KeyTransferRecord {
+                access_key_id: format!("GK{}", row),
+                name: String::new(),
+                secret_access_key: Some("secret".to_string()),
+                created: None,
+                expiration: None,
+                allow_create_bucket: false,
+                buckets,
+            }),
+        }
+    }
+
+    fn token(scope: &[&str]) -> AdminTokenAggregate {
+        AdminTokenAggregate::reconstitute(
+            "token".to_string(),
+            None,
+            scope.iter().map(|s| s.to_string()).collect(),
+            false,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_import_bucket_grants_require_write_buckets() {
+        let bucket = KeyTransferBucket {
+            bucket_id: "b1".to_string(),
+            global_aliases: vec![],
+            local_aliases: vec!["mine".to_string()],
+            read: true,
+            write: false,
+            owner: false,
+        };
+        let rows = || vec![row(1, vec![]), row(2, vec![bucket.clone()])];
+
+        // 只有 write:keys：沒有 Bucket 的列照常匯入，帶有授權或 alias 的列被拒絕
+        let restricted = restrict_bucket_grants(rows(), Some(&token(&[AdminTokenScope::WRITE_KEYS])));
+        assert!(restricted[0].record.is_ok());
+        assert!(restricted[1].record.as_ref().is_err_and(|e| e.contains(AdminTokenScope::WRITE_BUCKETS)));
+
+        let allowed = restrict_bucket_grants(
+            rows(),
+            Some(&token(&[AdminTokenScope::WRITE_KEYS, AdminTokenScope::WRITE_BUCKETS])),
+        );
+        assert!(allowed.iter().all(|r| r.record.is_ok()));
+        assert!(restrict_bucket_grants(rows(), None).iter().all(|r| r.record.is_ok()));
+    }
+}
@@ -0,0 +1,323 @@
+//! Admin Token gRPC service implementation
+
+use std::sync::Arc;
+use serde::Serialize;
+use tonic::{Request, Response, Status};
+
+use crate::application::commands::admin_token::{
+    CreateAdminTokenCommand, UpdateAdminTokenCommand, DeleteAdminTokenCommand,
+};
+use crate::application::commands::admin_token::handlers::{
+    CreateAdminTokenHandler, UpdateAdminTokenHandler, DeleteAdminTokenHandler,
+};
+use crate::application::queries::admin_token::{
+    ListAdminTokensQuery, ReadAdminTokenQuery, GetCurrentAdminTokenQuery,
+};
+use crate::application::queries::admin_token::handlers::{
+    ListAdminTokensHandler, ReadAdminTokenHandler, GetCurrentAdminTokenHandler,
+};
+use crate::domain::aggregates::AdminTokenAggregate;
+use crate::domain::entities::{AdminTokenInfo, AdminTokenListItem};
+use crate::grpc_log;
+use crate::shared::get_trace_id;
+use crate::infrastructure::grpc::conversions::{NullableStringExt, domain_error_to_status};
+
+use crate::infrastructure::grpc::generated::admin_token::{
+    admin_token_service_server::AdminTokenService,
+    ListAdminTokensRequest, ListAdminTokensResponse, ReadAdminTokenRequest,
+    GetCurrentAdminTokenRequest, AdminTokenResponse, AdminToken,
+    CreateAdminTokenRequest, CreateAdminTokenResponse, UpdateAdminTokenRequest,
+    DeleteAdminTokenRequest, DeleteAdminTokenResponse,
+};
+
+/// gRPC service for admin token operations
+pub struct AdminTokenGrpcService {
+    create_admin_token_handler: Arc<CreateAdminTokenHandler>,
+    update_admin_token_handler: Arc<UpdateAdminTokenHandler>,
+    delete_admin_token_handler: Arc<DeleteAdminTokenHandler>,
+    list_admin_tokens_handler: Arc<ListAdminTokensHandler>,
+    read_admin_token_handler: Arc<ReadAdminTokenHandler>,
+    get_current_admin_token_handler: Arc<GetCurrentAdminTokenHandler>,
+}
+
+impl AdminTokenGrpcService {
+    pub fn new(
+        create_admin_token_handler: Arc<CreateAdminTokenHandler>,
+        update_admin_token_handler: Arc<UpdateAdminTokenHandler>,
+        delete_admin_token_handler: Arc<DeleteAdminTokenHandler>,
+        list_admin_tokens_handler: Arc<ListAdminTokensHandler>,
+        read_admin_token_handler: Arc<ReadAdminTokenHandler>,
+        get_current_admin_token_handler: Arc<GetCurrentAdminTokenHandler>,
+    ) -> Self {
+        Self {
+            create_admin_token_handler,
+            update_admin_token_handler,
+            delete_admin_token_handler,
+            list_admin_tokens_handler,
+            read_admin_token_handler,
+            get_current_admin_token_handler,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl AdminTokenService for AdminTokenGrpcService {
+    async fn list_admin_token(
+        &self,
+        request: Request<ListAdminTokensRequest>,
+    ) -> Result<Response<ListAdminTokensResponse>, Status> {
+        let req = request.into_inner();
+        let pagination = req.pagination.unwrap_or_default();
+
+        let query = ListAdminTokensQuery::new(pagination.page, pagination.page_size)
+            .with_name(req.name.clone());
+
+        let log = grpc_log!("AdminTokenService", "ListAdminToken", &ListRequest {
+            name: &req.name,
+            page: &query.page,
+            page_size: &query.page_size,
+        });
+        let trace_id = get_trace_id();
+
+        let (tokens, total) = self
+            .list_admin_tokens_handler
+            .handle(query)
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let data: Vec<AdminToken> = tokens.into_iter().map(convert_token_list_item).collect();
+
+        let response = ListAdminTokensResponse {
+            trace_id: trace_id.clone(),
+            data: data.clone(),
+            total: total as i32,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: ListAdminTokensResponseLog { data, total },
+        });
+        Ok(Response::new(response))
+    }
+
+    async fn read_admin_token(
+        &self,
+        request: Request<ReadAdminTokenRequest>,
+    ) -> Result<Response<AdminTokenResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AdminTokenService", "ReadAdminToken", &SingleIdRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        let token = self
+            .read_admin_token_handler
+            .handle(ReadAdminTokenQuery::new(req.id))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let token = convert_token_info(token);
+        let response = AdminTokenResponse {
+            trace_id: trace_id.clone(),
+            data: Some(token.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: token });
+        Ok(Response::new(response))
+    }
+
+    async fn get_current_admin_token(
+        &self,
+        request: Request<GetCurrentAdminTokenRequest>,
+    ) -> Result<Response<AdminTokenResponse>, Status> {
+        // 由 auth middleware 放入的呼叫者 token
+        let caller = request.extensions().get::<AdminTokenAggregate>().cloned();
+        let log = grpc_log!("AdminTokenService", "GetCurrentAdminToken", &EmptyRequest {});
+        let trace_id = get_trace_id();
+
+        let token = self
+            .get_current_admin_token_handler
+            .handle(GetCurrentAdminTokenQuery::new(caller))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let token = convert_token_info(token);
+        let response = AdminTokenResponse {
+            trace_id: trace_id.clone(),
+            data: Some(token.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: token });
+        Ok(Response::new(response))
+    }
+
+    async fn create_admin_token(
+        &self,
+        request: Request<CreateAdminTokenRequest>,
+    ) -> Result<Response<CreateAdminTokenResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AdminTokenService", "CreateAdminToken", &CreateAdminTokenReq {
+            name: &req.name,
+            expiration: &req.expiration,
+            scope: &req.scope,
+        });
+        let trace_id = get_trace_id();
+
+        let created = self
+            .create_admin_token_handler
+            .handle(CreateAdminTokenCommand::new(req.name, req.expiration, req.scope))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let token = AdminToken {
+            id: created.id,
+            name: created.name.unwrap_or_default(),
+            created: created.created.unwrap_or_default(),
+            expiration: created.expiration,
+            expired: created.expired,
+            scope: created.scope,
+        };
+        let response = CreateAdminTokenResponse {
+            trace_id: trace_id.clone(),
+            data: Some(token.clone()),
+            secret_token: created.secret_token,
+        };
+
+        // secret token 只回傳給呼叫者，不寫入 log
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: token });
+        Ok(Response::new(response))
+    }
+
+    async fn update_admin_token(
+        &self,
+        request: Request<UpdateAdminTokenRequest>,
+    ) -> Result<Response<AdminTokenResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AdminTokenService", "UpdateAdminToken", &SingleIdRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        let token = self
+            .update_admin_token_handler
+            .handle(UpdateAdminTokenCommand::from_grpc_request(
+                req.id,
+                req.name,
+                req.expiration.into_update_field(),
+                req.scope.map(|s| s.values),
+            ))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let token = convert_token_info(token);
+        let response = AdminTokenResponse {
+            trace_id: trace_id.clone(),
+            data: Some(token.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: token });
+        Ok(Response::new(response))
+    }
+
+    async fn delete_admin_token(
+        &self,
+        request: Request<DeleteAdminTokenRequest>,
+    ) -> Result<Response<DeleteAdminTokenResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("AdminTokenService", "DeleteAdminToken", &IdsRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        let deleted_ids = self
+            .delete_admin_token_handler
+            .handle(DeleteAdminTokenCommand::new(req.id))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = DeleteAdminTokenResponse {
+            trace_id: trace_id.clone(),
+            id: deleted_ids.clone(),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: deleted_ids });
+        Ok(Response::new(response))
+    }
+}
+
+// ============ Log Structs ============
+
+#[derive(Serialize)]
+struct ListRequest<'a> {
+    name: &'a Option<String>,
+    page: &'a i32,
+    page_size: &'a i32,
+}
+
+#[derive(Serialize)]
+struct EmptyRequest {}
+
+#[derive(Serialize)]
+struct SingleIdRequest<'a> {
+    id: &'a str,
+}
+
+#[derive(Serialize)]
+struct IdsRequest<'a> {
+    id: &'a Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CreateAdminTokenReq<'a> {
+    name: &'a str,
+    expiration: &'a Option<String>,
+    scope: &'a Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ApiResponseLog<'a, T: Serialize> {
+    trace_id: &'a str,
+    data: T,
+}
+
+#[derive(Serialize)]
+struct ListAdminTokensResponseLog {
+    data: Vec<AdminToken>,
+    total: usize,
+}
+
+// ============ Helpers ============
+
+fn convert_token_info(token: AdminTokenInfo) -> AdminToken {
+    AdminToken {
+        id: token.id,
+        name: token.name.unwrap_or_default(),
+        created: token.created.unwrap_or_default(),
+        expiration: token.expiration,
+        expired: token.expired,
+        scope: token.scope,
+    }
+}
+
+fn convert_token_list_item(token: AdminTokenListItem) -> AdminToken {
+    AdminToken {
+        id: token.id,
+        name: token.name.unwrap_or_default(),
+        created: token.created.unwrap_or_default(),
+        expiration: token.expiration,
+        expired: token.expired,
+        scope: token.scope,
+    }
+}
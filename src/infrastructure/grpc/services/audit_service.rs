@@ -0,0 +1,124 @@
+//! Audit gRPC service implementation
+
+use std::sync::Arc;
+use serde::Serialize;
+use tonic::{Request, Response, Status};
+
+use crate::application::queries::audit::ListAuditEventsQuery;
+use crate::application::queries::audit::handlers::ListAuditEventsHandler;
+use crate::domain::entities::AuditRecord;
+use crate::grpc_log;
+use crate::shared::get_trace_id;
+use crate::infrastructure::grpc::conversions::domain_error_to_status;
+
+use crate::infrastructure::grpc::generated::audit::{
+    audit_service_server::AuditService,
+    ListAuditEventsRequest, ListAuditEventsResponse, AuditEvent,
+};
+
+/// gRPC service for audit trail queries
+pub struct AuditGrpcService {
+    list_audit_events_handler: Arc<ListAuditEventsHandler>,
+}
+
+impl AuditGrpcService {
+    pub fn new(list_audit_events_handler: Arc<ListAuditEventsHandler>) -> Self {
+        Self { list_audit_events_handler }
+    }
+}
+
+#[tonic::async_trait]
+impl AuditService for AuditGrpcService {
+    async fn list_audit_events(
+        &self,
+        request: Request<ListAuditEventsRequest>,
+    ) -> Result<Response<ListAuditEventsResponse>, Status> {
+        let req = request.into_inner();
+        let pagination = req.pagination.unwrap_or_default();
+        let start = req.time_range.as_ref().map(|r| r.start_date.clone());
+        let end = req.time_range.as_ref().map(|r| r.end_date.clone());
+
+        let log = grpc_log!("AuditService", "ListAuditEvents", &ListRequest {
+            page: &pagination.page,
+            page_size: &pagination.page_size,
+            start: &start,
+            end: &end,
+            resource_id: &req.resource_id,
+            event_type: &req.event_type,
+            actor: &req.actor,
+        });
+        let trace_id = get_trace_id();
+
+        let query = ListAuditEventsQuery::from_grpc_request(
+            pagination.page,
+            pagination.page_size,
+            start,
+            end,
+            req.resource_id,
+            req.event_type,
+            req.actor,
+        );
+
+        let (records, total) = self
+            .list_audit_events_handler
+            .handle(query)
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let data: Vec<AuditEvent> = records.into_iter().map(convert_record).collect();
+
+        let response = ListAuditEventsResponse {
+            trace_id: trace_id.clone(),
+            data: data.clone(),
+            total: total as i32,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: ListAuditEventsResponseLog { data, total },
+        });
+        Ok(Response::new(response))
+    }
+}
+
+// ============ Log Structs ============
+
+#[derive(Serialize)]
+struct ListRequest<'a> {
+    page: &'a i32,
+    page_size: &'a i32,
+    start: &'a Option<String>,
+    end: &'a Option<String>,
+    resource_id: &'a Option<String>,
+    event_type: &'a Option<String>,
+    actor: &'a Option<String>,
+}
+
+#[derive(Serialize)]
+struct ApiResponseLog<'a, T: Serialize> {
+    trace_id: &'a str,
+    data: T,
+}
+
+#[derive(Serialize)]
+struct ListAuditEventsResponseLog {
+    data: Vec<AuditEvent>,
+    total: usize,
+}
+
+// ============ Helpers ============
+
+fn convert_record(record: AuditRecord) -> AuditEvent {
+    AuditEvent {
+        occurred_at: record.occurred_at.to_rfc3339(),
+        event_type: record.event_type,
+        resource_ids: record.resource_ids,
+        actor: record.actor,
+        peer_subject: record.peer_subject,
+        trace_id: record.trace_id,
+        details: record.details.to_string(),
+    }
+}
@@ -12,11 +12,12 @@ use crate::application::commands::block::handlers::{
     PurgeBlocksHandler, RetryBlockResyncHandler,
 };
 use crate::application::queries::block::{
-    GetBlockInfoQuery, ListBlockErrorsQuery,
+    GetBlockInfoQuery, ListBlockErrorsQuery, GetBlockErrorReportQuery,
 };
 use crate::application::queries::block::handlers::{
-    GetBlockInfoHandler, ListBlockErrorsHandler,
+    GetBlockInfoHandler, ListBlockErrorsHandler, GetBlockErrorReportHandler,
 };
+use crate::domain::entities::{BlockErrorReport, Confirmable, ConfirmationRequired, DamagedBlockRef as DomainDamagedBlockRef};
 use crate::infrastructure::grpc::conversions::domain_error_to_status;
 use crate::grpc_log;
 use crate::shared::get_trace_id;
@@ -26,6 +27,8 @@ use crate::infrastructure::grpc::generated::block::{
     ApiResponse, api_response::Data,
     GetBlockInfoRequest, MultiNodeBlockInfoData, BlockInfoResult, block_info_result,
     ListBlockErrorsRequest, MultiNodeBlockErrorsData, BlockErrorsResult, block_errors_result, BlockErrors,
+    GetBlockErrorReportRequest, BlockErrorReportData, DamagedBlock, BucketBlockErrors, BucketLocalAlias,
+    DamagedObject, DamagedUpload, DamagedBlockRef,
     PurgeBlocksRequest, MultiNodePurgeResultData, PurgeResult, purge_result,
     RetryBlockResyncRequest, MultiNodeResyncResultData, ResyncResult, resync_result,
     BlockInfo, BlockVersionRef, BlockUploadRef, BlockError, 
@@ -40,6 +43,7 @@ pub struct BlockGrpcService {
     // Query handlers
     get_block_info_handler: Arc<GetBlockInfoHandler>,
     list_block_errors_handler: Arc<ListBlockErrorsHandler>,
+    get_block_error_report_handler: Arc<GetBlockErrorReportHandler>,
 }
 
 impl BlockGrpcService {
@@ -48,12 +52,14 @@ impl BlockGrpcService {
         retry_block_resync_handler: Arc<RetryBlockResyncHandler>,
         get_block_info_handler: Arc<GetBlockInfoHandler>,
         list_block_errors_handler: Arc<ListBlockErrorsHandler>,
+        get_block_error_report_handler: Arc<GetBlockErrorReportHandler>,
     ) -> Self {
         Self {
             purge_blocks_handler,
             retry_block_resync_handler,
             get_block_info_handler,
             list_block_errors_handler,
+            get_block_error_report_handler,
         }
     }
 }
@@ -112,6 +118,7 @@ impl BlockService for BlockGrpcService {
         let api_response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::BlockInfo(MultiNodeBlockInfoData { results: results.clone() })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -163,6 +170,7 @@ impl BlockService for BlockGrpcService {
         let api_response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::BlockErrors(MultiNodeBlockErrorsData { results: results.clone() })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -175,6 +183,42 @@ impl BlockService for BlockGrpcService {
         Ok(Response::new(api_response))
     }
 
+    async fn get_block_error_report(
+        &self,
+        request: Request<GetBlockErrorReportRequest>,
+    ) -> Result<Response<ApiResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("BlockService", "GetBlockErrorReport", &NodeRequest { node: &req.node });
+        let trace_id = get_trace_id();
+
+        let report = self
+            .get_block_error_report_handler
+            .handle(GetBlockErrorReportQuery::new(req.node))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let log_data = BlockErrorReportLog {
+            block_count: report.blocks.len(),
+            bucket_count: report.buckets.len(),
+            node_error_count: report.node_errors.len(),
+        };
+
+        let api_response = ApiResponse {
+            trace_id: trace_id.clone(),
+            data: Some(Data::BlockErrorReport(block_error_report_to_proto(report))),
+            confirmation: None,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: log_data,
+        });
+        Ok(Response::new(api_response))
+    }
+
     async fn purge_blocks(
         &self,
         request: Request<PurgeBlocksRequest>,
@@ -183,15 +227,28 @@ impl BlockService for BlockGrpcService {
         let log = grpc_log!("BlockService", "PurgeBlocks", &PurgeRequest { node: &req.node, block_count: req.block_hashes.len() });
         let trace_id = get_trace_id();
         
+        let command = PurgeBlocksCommand::new(req.node, req.block_hashes)
+            .with_confirmation_token(req.confirmation_token);
         let response = self
             .purge_blocks_handler
-            .handle(PurgeBlocksCommand::new(req.node, req.block_hashes))
+            .handle(command)
             .await
             .map_err(|e| {
                 log.err(&e.to_string());
                 domain_error_to_status(e)
             })?;
 
+        let response = match response {
+            Confirmable::Completed(response) => response,
+            Confirmable::Required(required) => {
+                log.ok(&ApiResponseLog {
+                    trace_id: &trace_id,
+                    data: ConfirmationLog { summary: &required.impact.summary },
+                });
+                return Ok(Response::new(confirmation_response(trace_id, required)));
+            }
+        };
+
         let mut results: HashMap<String, PurgeResult> = HashMap::new();
         
         for (node_id, result) in response.success.iter() {
@@ -213,6 +270,7 @@ impl BlockService for BlockGrpcService {
         let api_response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::PurgeResult(MultiNodePurgeResultData { results: results.clone() })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -267,6 +325,7 @@ impl BlockService for BlockGrpcService {
         let api_response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::ResyncResult(MultiNodeResyncResultData { results: results.clone() })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -280,6 +339,57 @@ impl BlockService for BlockGrpcService {
     }
 }
 
+/// 破壞性操作尚未確認時的回應
+fn confirmation_response(trace_id: String, required: ConfirmationRequired) -> ApiResponse {
+    ApiResponse {
+        trace_id,
+        data: None,
+        confirmation: Some(required.into()),
+    }
+}
+
+// ============ Helpers ============
+
+fn block_error_report_to_proto(report: BlockErrorReport) -> BlockErrorReportData {
+    let block_refs = |blocks: Vec<DomainDamagedBlockRef>| -> Vec<DamagedBlockRef> {
+        blocks.into_iter().map(|b| DamagedBlockRef {
+            block_hash: b.block_hash,
+            block_offset: b.block_offset,
+            part_number: b.part_number,
+        }).collect()
+    };
+
+    BlockErrorReportData {
+        blocks: report.blocks.into_iter().map(|b| DamagedBlock {
+            block_hash: b.block_hash,
+            node_errors: b.node_errors.into_iter().collect(),
+            size: b.size,
+            refcount: b.refcount,
+            lookup_error: b.lookup_error,
+        }).collect(),
+        buckets: report.buckets.into_iter().map(|b| BucketBlockErrors {
+            bucket_id: b.bucket_id,
+            global_aliases: b.global_aliases,
+            local_aliases: b.local_aliases.into_iter().map(|a| BucketLocalAlias {
+                access_key_id: a.access_key_id,
+                alias: a.alias,
+            }).collect(),
+            objects: b.objects.into_iter().map(|o| DamagedObject {
+                key: o.key,
+                version_uuid: o.version_uuid,
+                deleted: o.deleted,
+                blocks: block_refs(o.blocks),
+            }).collect(),
+            uploads: b.uploads.into_iter().map(|u| DamagedUpload {
+                key: u.key,
+                upload_id: u.upload_id,
+                blocks: block_refs(u.blocks),
+            }).collect(),
+        }).collect(),
+        node_errors: report.node_errors.into_iter().collect(),
+    }
+}
+
 // ============ Log Structs ============
 
 #[derive(Serialize)]
@@ -311,6 +421,18 @@ struct ApiResponseLog<'a, T: Serialize> {
     data: T,
 }
 
+#[derive(Serialize)]
+struct ConfirmationLog<'a> {
+    summary: &'a str,
+}
+
+#[derive(Serialize)]
+struct BlockErrorReportLog {
+    block_count: usize,
+    bucket_count: usize,
+    node_error_count: usize,
+}
+
 #[derive(Serialize)]
 struct MultiNodeResultLog {
     success_count: usize,
@@ -21,7 +21,7 @@ use crate::application::queries::bucket::{
 use crate::application::queries::bucket::handlers::{
     ListBucketsHandler, GetBucketHandler,
 };
-use crate::domain::entities::WebsiteConfig;
+use crate::domain::entities::{Confirmable, WebsiteConfig};
 use crate::domain::value_objects::Quotas;
 use crate::grpc_log;
 use crate::shared::get_trace_id;
@@ -56,6 +56,7 @@ pub struct BucketGrpcService {
 }
 
 impl BucketGrpcService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         create_bucket_handler: Arc<CreateBucketHandler>,
         update_bucket_handler: Arc<UpdateBucketHandler>,
@@ -88,7 +89,7 @@ impl BucketService for BucketGrpcService {
         request: Request<ListBucketsRequest>,
     ) -> Result<Response<ListBucketsResponse>, Status> {
         let req = request.into_inner();
-        let pagination = req.pagination.clone().unwrap_or_default();
+        let pagination = req.pagination.unwrap_or_default();
         
         let query = ListBucketsQuery::from_grpc_request(
             pagination.page,
@@ -294,9 +295,10 @@ impl BucketService for BucketGrpcService {
         let log = grpc_log!("BucketService", "DeleteBucket", &IdsRequest { ids: &req.id });
         let trace_id = get_trace_id();
         
-        let command = DeleteBucketCommand::new(req.id);
+        let command = DeleteBucketCommand::new(req.id)
+            .with_confirmation_token(req.confirmation_token);
 
-        let deleted_ids = self.delete_bucket_handler
+        let result = self.delete_bucket_handler
             .handle(command)
             .await
             .map_err(|e| {
@@ -304,9 +306,22 @@ impl BucketService for BucketGrpcService {
                 domain_error_to_status(e)
             })?;
 
+        let deleted_ids = match result {
+            Confirmable::Completed(ids) => ids,
+            Confirmable::Required(required) => {
+                log.ok(&ConfirmationLog { trace_id: &trace_id, summary: &required.impact.summary });
+                return Ok(Response::new(DeleteBucketResponse {
+                    trace_id,
+                    id: vec![],
+                    confirmation: Some(required.into()),
+                }));
+            }
+        };
+
         let response = DeleteBucketResponse {
             trace_id: trace_id.clone(),
             id: deleted_ids.clone(),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLogIds { trace_id: &trace_id, data: IdsData { ids: &deleted_ids } });
@@ -334,14 +349,14 @@ impl BucketService for BucketGrpcService {
                     match self.add_bucket_alias_handler.handle(command).await {
                         Ok((bucket, _event)) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: true,
                             error: None,
                             bucket: Some(convert_bucket(bucket)),
                         },
                         Err(e) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: false,
                             error: Some(e.to_string()),
                             bucket: None,
@@ -358,14 +373,14 @@ impl BucketService for BucketGrpcService {
                     match self.add_bucket_alias_handler.handle(command).await {
                         Ok((bucket, _event)) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: true,
                             error: None,
                             bucket: Some(convert_bucket(bucket)),
                         },
                         Err(e) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: false,
                             error: Some(e.to_string()),
                             bucket: None,
@@ -415,14 +430,14 @@ impl BucketService for BucketGrpcService {
                     match self.remove_bucket_alias_handler.handle(command).await {
                         Ok((bucket, _event)) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: true,
                             error: None,
                             bucket: Some(convert_bucket(bucket)),
                         },
                         Err(e) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: false,
                             error: Some(e.to_string()),
                             bucket: None,
@@ -439,14 +454,14 @@ impl BucketService for BucketGrpcService {
                     match self.remove_bucket_alias_handler.handle(command).await {
                         Ok((bucket, _event)) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: true,
                             error: None,
                             bucket: Some(convert_bucket(bucket)),
                         },
                         Err(e) => GrpcAliasResult {
                             bucket_id: item.bucket_id,
-                            alias: alias,
+                            alias,
                             success: false,
                             error: Some(e.to_string()),
                             bucket: None,
@@ -617,6 +632,12 @@ struct BatchResponseLog<'a> {
     trace_id: &'a str,
 }
 
+#[derive(Serialize)]
+struct ConfirmationLog<'a> {
+    trace_id: &'a str,
+    summary: &'a str,
+}
+
 #[derive(Serialize)]
 struct ApiResponseLog<'a, T: Serialize> {
     trace_id: &'a str,
@@ -1,40 +1,59 @@
 //! Cluster gRPC service implementation
 
+use std::pin::Pin;
 use std::sync::Arc;
+use futures::Stream;
 use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 
 use crate::application::commands::cluster::{
     ConnectNodesCommand, UpdateLayoutCommand, ApplyLayoutCommand, 
-    RevertLayoutCommand, SkipDeadNodesCommand, LayoutRoleChange,
+    RevertLayoutCommand, SkipDeadNodesCommand, DecommissionNodeCommand, LayoutRoleChange,
+    BootstrapClusterCommand, BootstrapNode,
 };
 use crate::application::commands::cluster::handlers::{
     ConnectNodesHandler, UpdateLayoutHandler, ApplyLayoutHandler,
-    RevertLayoutHandler, SkipDeadNodesHandler,
+    RevertLayoutHandler, SkipDeadNodesHandler, DecommissionNodeHandler, BootstrapClusterHandler,
 };
 use crate::application::queries::cluster::{
-    GetClusterStatusQuery, GetClusterHealthQuery, GetClusterLayoutQuery,
-    GetLayoutHistoryQuery, PreviewLayoutChangesQuery,
+    GetClusterStatusQuery, GetClusterHealthQuery, GetClusterStatisticsQuery, GetClusterLayoutQuery,
+    GetLayoutHistoryQuery, PreviewLayoutChangesQuery, DiffLayoutsQuery, SimulateLayoutQuery,
 };
 use crate::application::queries::cluster::handlers::{
-    GetClusterStatusHandler, GetClusterHealthHandler, GetClusterLayoutHandler,
-    GetLayoutHistoryHandler, PreviewLayoutChangesHandler,
+    GetClusterStatusHandler, GetClusterHealthHandler, GetClusterStatisticsHandler, GetClusterLayoutHandler,
+    GetLayoutHistoryHandler, PreviewLayoutChangesHandler, DiffLayoutsHandler, SimulateLayoutHandler,
+};
+use crate::domain::entities::{
+    BootstrapConnectOutcome as DomainBootstrapConnectOutcome, BootstrapReport,
+    BootstrapRoleOutcome as DomainBootstrapRoleOutcome,
+    ClusterChanges, ClusterHealth, ClusterSnapshot, ClusterStatistics, Confirmable, ConfirmationRequired, DecommissionPhase as DomainDecommissionPhase,
+    DecommissionProgress as DomainDecommissionProgress, LayoutDiff,
+    LayoutRole as DomainLayoutRole, LayoutRoleDiffKind as DomainLayoutRoleDiffKind, LayoutSimulationReport,
 };
 use crate::infrastructure::grpc::conversions::domain_error_to_status;
+use crate::infrastructure::tasks::ClusterWatch;
 use crate::grpc_log;
-use crate::shared::get_trace_id;
+use crate::shared::{current_context, get_trace_id, with_context};
 
 use crate::infrastructure::grpc::generated::cluster::{
     cluster_service_server::ClusterService,
     ApiResponse, api_response::Data,
     GetClusterStatusRequest, ClusterStatusData, ClusterNode,
     GetClusterHealthRequest, ClusterHealthData,
+    GetClusterStatisticsRequest, ClusterStatisticsData, NodeStorageStatistics, StorageTotals,
     GetClusterLayoutRequest, ClusterLayoutData,
     GetLayoutHistoryRequest, LayoutHistoryData,
     PreviewLayoutChangesRequest, ApplyLayoutResultData,
+    SimulateLayoutRequest, LayoutSimulationData, SimulatedNode,
+    DiffLayoutsRequest, LayoutDiffData, LayoutRoleDiff, LayoutRoleDiffKind, ZoneRedundancyChange, ZoneCapacity,
     ConnectNodesRequest, ConnectNodesData, ConnectNodeResult,
     UpdateLayoutRequest, ApplyLayoutRequest, RevertLayoutRequest,
     SkipDeadNodesRequest, SkipDeadNodesData,
+    WatchClusterRequest, WatchClusterResponse, WatchNode,
+    DecommissionNodeRequest, DecommissionNodeResponse, DecommissionProgress, DecommissionPhase,
+    BootstrapClusterRequest, BootstrapReportData, BootstrapNodeReport, BootstrapConnectOutcome, BootstrapRoleOutcome,
     LayoutRole, StagedRoleChange, LayoutParameters, ZoneRedundancy,
     zone_redundancy, LayoutVersion, UpdateTracker, NodeUpdateProgress,
 };
@@ -47,12 +66,19 @@ pub struct ClusterGrpcService {
     apply_layout_handler: Arc<ApplyLayoutHandler>,
     revert_layout_handler: Arc<RevertLayoutHandler>,
     skip_dead_nodes_handler: Arc<SkipDeadNodesHandler>,
+    decommission_node_handler: Arc<DecommissionNodeHandler>,
+    bootstrap_cluster_handler: Arc<BootstrapClusterHandler>,
     // Query handlers
     get_cluster_status_handler: Arc<GetClusterStatusHandler>,
     get_cluster_health_handler: Arc<GetClusterHealthHandler>,
+    get_cluster_statistics_handler: Arc<GetClusterStatisticsHandler>,
     get_cluster_layout_handler: Arc<GetClusterLayoutHandler>,
     get_layout_history_handler: Arc<GetLayoutHistoryHandler>,
     preview_layout_changes_handler: Arc<PreviewLayoutChangesHandler>,
+    diff_layouts_handler: Arc<DiffLayoutsHandler>,
+    simulate_layout_handler: Arc<SimulateLayoutHandler>,
+    // 背景輪詢的集群快照
+    cluster_watch: ClusterWatch,
 }
 
 impl ClusterGrpcService {
@@ -63,11 +89,17 @@ impl ClusterGrpcService {
         apply_layout_handler: Arc<ApplyLayoutHandler>,
         revert_layout_handler: Arc<RevertLayoutHandler>,
         skip_dead_nodes_handler: Arc<SkipDeadNodesHandler>,
+        decommission_node_handler: Arc<DecommissionNodeHandler>,
+        bootstrap_cluster_handler: Arc<BootstrapClusterHandler>,
         get_cluster_status_handler: Arc<GetClusterStatusHandler>,
         get_cluster_health_handler: Arc<GetClusterHealthHandler>,
+        get_cluster_statistics_handler: Arc<GetClusterStatisticsHandler>,
         get_cluster_layout_handler: Arc<GetClusterLayoutHandler>,
         get_layout_history_handler: Arc<GetLayoutHistoryHandler>,
         preview_layout_changes_handler: Arc<PreviewLayoutChangesHandler>,
+        diff_layouts_handler: Arc<DiffLayoutsHandler>,
+        simulate_layout_handler: Arc<SimulateLayoutHandler>,
+        cluster_watch: ClusterWatch,
     ) -> Self {
         Self {
             connect_nodes_handler,
@@ -75,11 +107,17 @@ impl ClusterGrpcService {
             apply_layout_handler,
             revert_layout_handler,
             skip_dead_nodes_handler,
+            decommission_node_handler,
+            bootstrap_cluster_handler,
             get_cluster_status_handler,
             get_cluster_health_handler,
+            get_cluster_statistics_handler,
             get_cluster_layout_handler,
             get_layout_history_handler,
             preview_layout_changes_handler,
+            diff_layouts_handler,
+            simulate_layout_handler,
+            cluster_watch,
         }
     }
 }
@@ -123,6 +161,7 @@ impl ClusterService for ClusterGrpcService {
                 layout_version: status.layout_version,
                 nodes: nodes.clone(),
             })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -153,16 +192,8 @@ impl ClusterService for ClusterGrpcService {
 
         let response = ApiResponse {
             trace_id: trace_id.clone(),
-            data: Some(Data::ClusterHealth(ClusterHealthData {
-                status: health.status.clone(),
-                known_nodes: health.known_nodes,
-                connected_nodes: health.connected_nodes,
-                storage_nodes: health.storage_nodes,
-                storage_nodes_ok: health.storage_nodes_up,
-                partitions: health.partitions,
-                partitions_quorum: health.partitions_quorum,
-                partitions_all_ok: health.partitions_all_ok,
-            })),
+            data: Some(Data::ClusterHealth(convert_cluster_health(&health))),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -175,6 +206,38 @@ impl ClusterService for ClusterGrpcService {
         Ok(Response::new(response))
     }
 
+    async fn get_cluster_statistics(
+        &self,
+        _request: Request<GetClusterStatisticsRequest>,
+    ) -> Result<Response<ApiResponse>, Status> {
+        let log = grpc_log!("ClusterService", "GetClusterStatistics", &EmptyRequest {});
+        let trace_id = get_trace_id();
+
+        let statistics = self
+            .get_cluster_statistics_handler
+            .handle(GetClusterStatisticsQuery)
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = ApiResponse {
+            trace_id: trace_id.clone(),
+            data: Some(Data::ClusterStatistics(convert_statistics_response(&statistics))),
+            confirmation: None,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: ClusterStatisticsLogSimple {
+                node_count: statistics.nodes.len(),
+                table_count: statistics.tables.len(),
+            },
+        });
+        Ok(Response::new(response))
+    }
+
     async fn get_cluster_layout(
         &self,
         _request: Request<GetClusterLayoutRequest>,
@@ -195,6 +258,7 @@ impl ClusterService for ClusterGrpcService {
         let response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::ClusterLayout(layout_data)),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -252,6 +316,7 @@ impl ClusterService for ClusterGrpcService {
                 versions: versions.clone(),
                 update_tracker,
             })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -285,6 +350,7 @@ impl ClusterService for ClusterGrpcService {
                 current_layout_version: result.layout.version,
                 partition_info: vec![],
             })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -296,6 +362,97 @@ impl ClusterService for ClusterGrpcService {
         Ok(Response::new(response))
     }
 
+    async fn diff_layouts(
+        &self,
+        request: Request<DiffLayoutsRequest>,
+    ) -> Result<Response<ApiResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ClusterService", "DiffLayouts", &DiffLayoutsReq {
+            from_version: req.from_version,
+            to_version: req.to_version,
+        });
+        let trace_id = get_trace_id();
+
+        let diff = self
+            .diff_layouts_handler
+            .handle(DiffLayoutsQuery::new(req.from_version, req.to_version))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = ApiResponse {
+            trace_id: trace_id.clone(),
+            data: Some(Data::DiffLayouts(convert_layout_diff(&diff))),
+            confirmation: None,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: LayoutDiffLogSimple {
+                roles_changed: diff.roles.len(),
+                zone_redundancy_changed: diff.zone_redundancy.is_some(),
+            },
+        });
+        Ok(Response::new(response))
+    }
+
+    async fn simulate_layout(
+        &self,
+        request: Request<SimulateLayoutRequest>,
+    ) -> Result<Response<ApiResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ClusterService", "SimulateLayout", &SimulateLayoutReq {
+            count: req.roles.len(),
+            replication_factor: req.replication_factor,
+        });
+        let trace_id = get_trace_id();
+
+        if req.replication_factor.is_some_and(|rf| rf < 1) {
+            log.err("replication_factor must be at least 1");
+            return Err(Status::invalid_argument("replication_factor must be at least 1"));
+        }
+
+        let roles = req
+            .roles
+            .into_iter()
+            .map(|r| DomainLayoutRole {
+                id: r.id,
+                zone: r.zone,
+                capacity: (r.capacity > 0).then_some(r.capacity),
+                tags: r.tags,
+            })
+            .collect();
+        let query = SimulateLayoutQuery::new(roles)
+            .with_replication_factor(req.replication_factor.map(|rf| rf as usize))
+            .with_zone_redundancy(req.parameters.map(convert_layout_parameters_request).and_then(|p| p.zone_redundancy));
+
+        let report = self
+            .simulate_layout_handler
+            .handle(query)
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = ApiResponse {
+            trace_id: trace_id.clone(),
+            data: Some(Data::SimulateLayout(convert_simulation_report(&report))),
+            confirmation: None,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: SimulationLogSimple {
+                partition_size: report.simulation.partition_size,
+                replicas_moved: report.replicas_moved,
+            },
+        });
+        Ok(Response::new(response))
+    }
+
     async fn connect_nodes(
         &self,
         request: Request<ConnectNodesRequest>,
@@ -326,6 +483,7 @@ impl ClusterService for ClusterGrpcService {
             data: Some(Data::ConnectNodes(ConnectNodesData {
                 results: connect_results.clone(),
             })),
+            confirmation: None,
         };
 
         let success_count = connect_results.iter().filter(|r| r.success).count();
@@ -339,12 +497,71 @@ impl ClusterService for ClusterGrpcService {
         Ok(Response::new(response))
     }
 
+    async fn bootstrap_cluster(
+        &self,
+        request: Request<BootstrapClusterRequest>,
+    ) -> Result<Response<ApiResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ClusterService", "BootstrapCluster", &BootstrapClusterReq {
+            nodes: req.nodes.iter().map(|n| n.address.as_str()).collect(),
+            parameters: req.parameters.is_some(),
+        });
+        let trace_id = get_trace_id();
+
+        let nodes = req
+            .nodes
+            .into_iter()
+            .map(|n| BootstrapNode {
+                address: n.address,
+                zone: n.zone,
+                capacity: n.capacity.map(|c| c.max(0) as u64),
+                tags: n.tags,
+            })
+            .collect();
+
+        let report = self
+            .bootstrap_cluster_handler
+            .handle(BootstrapClusterCommand::new(
+                nodes,
+                req.parameters.map(convert_layout_parameters_request),
+            ))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = ApiResponse {
+            trace_id: trace_id.clone(),
+            data: Some(Data::BootstrapCluster(convert_bootstrap_report(&report))),
+            confirmation: None,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: BootstrapLogSimple {
+                staged: report.nodes.iter().filter(|n| n.role == DomainBootstrapRoleOutcome::Staged).count(),
+                failed: report
+                    .nodes
+                    .iter()
+                    .filter(|n| matches!(n.connection, DomainBootstrapConnectOutcome::Failed { .. }))
+                    .count(),
+                applied_version: report.applied_version,
+            },
+        });
+        Ok(Response::new(response))
+    }
+
     async fn update_layout(
         &self,
         request: Request<UpdateLayoutRequest>,
     ) -> Result<Response<ApiResponse>, Status> {
         let req = request.into_inner();
-        let log = grpc_log!("ClusterService", "UpdateLayout", &UpdateLayoutReq { changes: req.role_changes.len() });
+        let log = grpc_log!("ClusterService", "UpdateLayout", &UpdateLayoutReq {
+            changes: req.role_changes.len(),
+            parameters: req.parameters.is_some(),
+            expected_version: req.expected_version,
+        });
         let trace_id = get_trace_id();
         
         let role_changes: Vec<LayoutRoleChange> = req
@@ -361,7 +578,11 @@ impl ClusterService for ClusterGrpcService {
 
         let layout = self
             .update_layout_handler
-            .handle(UpdateLayoutCommand::new(role_changes))
+            .handle(
+                UpdateLayoutCommand::new(role_changes)
+                    .with_parameters(req.parameters.map(convert_layout_parameters_request))
+                    .with_expected_version(req.expected_version),
+            )
             .await
             .map_err(|e| {
                 log.err(&e.to_string());
@@ -372,6 +593,7 @@ impl ClusterService for ClusterGrpcService {
         let response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::UpdateLayout(layout_data)),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -406,6 +628,7 @@ impl ClusterService for ClusterGrpcService {
                 current_layout_version: result.layout.version,
                 partition_info: vec![],
             })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -419,24 +642,37 @@ impl ClusterService for ClusterGrpcService {
 
     async fn revert_layout(
         &self,
-        _request: Request<RevertLayoutRequest>,
+        request: Request<RevertLayoutRequest>,
     ) -> Result<Response<ApiResponse>, Status> {
+        let req = request.into_inner();
         let log = grpc_log!("ClusterService", "RevertLayout", &EmptyRequest {});
         let trace_id = get_trace_id();
         
-        let layout = self
+        let result = self
             .revert_layout_handler
-            .handle(RevertLayoutCommand::new())
+            .handle(RevertLayoutCommand::new().with_confirmation_token(req.confirmation_token))
             .await
             .map_err(|e| {
                 log.err(&e.to_string());
                 domain_error_to_status(e)
             })?;
 
+        let layout = match result {
+            Confirmable::Completed(layout) => layout,
+            Confirmable::Required(required) => {
+                log.ok(&ApiResponseLog {
+                    trace_id: &trace_id,
+                    data: ConfirmationLog { summary: &required.impact.summary },
+                });
+                return Ok(Response::new(confirmation_response(trace_id, required)));
+            }
+        };
+
         let layout_data = convert_layout_response(&layout);
         let response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::RevertLayout(layout_data)),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -456,21 +692,35 @@ impl ClusterService for ClusterGrpcService {
         let log = grpc_log!("ClusterService", "SkipDeadNodes", &SkipDeadNodesReq { version: req.version, allow_missing: req.allow_missing_data });
         let trace_id = get_trace_id();
         
+        let command = SkipDeadNodesCommand::new(req.version, req.allow_missing_data)
+            .with_confirmation_token(req.confirmation_token);
         let result = self
             .skip_dead_nodes_handler
-            .handle(SkipDeadNodesCommand::new(req.version, req.allow_missing_data))
+            .handle(command)
             .await
             .map_err(|e| {
                 log.err(&e.to_string());
                 domain_error_to_status(e)
             })?;
 
+        let result = match result {
+            Confirmable::Completed(result) => result,
+            Confirmable::Required(required) => {
+                log.ok(&ApiResponseLog {
+                    trace_id: &trace_id,
+                    data: ConfirmationLog { summary: &required.impact.summary },
+                });
+                return Ok(Response::new(confirmation_response(trace_id, required)));
+            }
+        };
+
         let response = ApiResponse {
             trace_id: trace_id.clone(),
             data: Some(Data::SkipDeadNodes(SkipDeadNodesData {
                 current_layout_version: req.version,
                 partition_info: vec![],
             })),
+            confirmation: None,
         };
 
         log.ok(&ApiResponseLog {
@@ -482,6 +732,108 @@ impl ClusterService for ClusterGrpcService {
         });
         Ok(Response::new(response))
     }
+
+    type DecommissionNodeStream = Pin<Box<dyn Stream<Item = Result<DecommissionNodeResponse, Status>> + Send>>;
+
+    async fn decommission_node(
+        &self,
+        request: Request<DecommissionNodeRequest>,
+    ) -> Result<Response<Self::DecommissionNodeStream>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ClusterService", "DecommissionNode", &NodeIdReq { node_id: &req.node_id });
+        let trace_id = get_trace_id();
+
+        if req.node_id.is_empty() {
+            log.err("node_id is required");
+            return Err(Status::invalid_argument("node_id is required"));
+        }
+
+        let (progress_tx, mut progress_rx) = mpsc::channel::<DomainDecommissionProgress>(16);
+        let (response_tx, response_rx) = mpsc::channel::<Result<DecommissionNodeResponse, Status>>(16);
+        let handler = self.decommission_node_handler.clone();
+        let command = DecommissionNodeCommand::new(req.node_id);
+
+        // 流程可能持續數小時，在背景執行並沿用請求的 trace context
+        let context = current_context().unwrap_or_default();
+        tokio::spawn(with_context(context, async move {
+            // progress_rx 移入 forward，client 斷線時隨之 drop，handler 的下一次回報即會失敗並結束
+            let forward = {
+                let trace_id = trace_id.clone();
+                let response_tx = response_tx.clone();
+                async move {
+                    while let Some(progress) = progress_rx.recv().await {
+                        let response = DecommissionNodeResponse {
+                            trace_id: trace_id.clone(),
+                            progress: Some(convert_decommission_progress(progress)),
+                        };
+                        if response_tx.send(Ok(response)).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            };
+            let (result, ()) = tokio::join!(handler.handle(command, progress_tx), forward);
+
+            match result {
+                Ok(()) => log.ok(&ApiResponseLog { trace_id: &trace_id, data: "completed" }),
+                Err(e) => {
+                    log.err(&e.to_string());
+                    let _ = response_tx.send(Err(domain_error_to_status(e))).await;
+                }
+            }
+        }));
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(response_rx))))
+    }
+
+    type WatchClusterStream = Pin<Box<dyn Stream<Item = Result<WatchClusterResponse, Status>> + Send>>;
+
+    async fn watch_cluster(
+        &self,
+        _request: Request<WatchClusterRequest>,
+    ) -> Result<Response<Self::WatchClusterStream>, Status> {
+        let log = grpc_log!("ClusterService", "WatchCluster", &EmptyRequest {});
+        let trace_id = get_trace_id();
+
+        let Some(mut snapshots) = self.cluster_watch.subscribe() else {
+            log.err("cluster watcher is disabled");
+            return Err(Status::unavailable("Cluster watcher is disabled (CLUSTER_WATCH_INTERVAL_SECS=0)"));
+        };
+
+        let (response_tx, response_rx) = mpsc::channel::<Result<WatchClusterResponse, Status>>(16);
+
+        // 所有訂閱者共用同一個輪詢，各自與上次送出的快照比對
+        let context = current_context().unwrap_or_default();
+        tokio::spawn(with_context(context, async move {
+            let mut sent: Option<Arc<ClusterSnapshot>> = None;
+            let mut updates = 0usize;
+            loop {
+                let latest = snapshots.borrow_and_update().clone();
+                if let Some(snapshot) = latest {
+                    let changes = match &sent {
+                        None => Some((true, snapshot.full())),
+                        Some(previous) => Some((false, previous.diff(&snapshot))).filter(|(_, c)| !c.is_empty()),
+                    };
+                    if let Some((full, changes)) = changes {
+                        let response = convert_watch_response(trace_id.clone(), full, changes, &snapshot);
+                        if response_tx.send(Ok(response)).await.is_err() {
+                            break;
+                        }
+                        updates += 1;
+                    }
+                    sent = Some(snapshot);
+                }
+
+                tokio::select! {
+                    changed = snapshots.changed() => if changed.is_err() { break },
+                    _ = response_tx.closed() => break,
+                }
+            }
+            log.ok(&ApiResponseLog { trace_id: &trace_id, data: WatchClusterLogSimple { updates } });
+        }));
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(response_rx))))
+    }
 }
 
 // ============ Log Structs ============
@@ -489,6 +841,35 @@ impl ClusterService for ClusterGrpcService {
 #[derive(Serialize)]
 struct EmptyRequest {}
 
+#[derive(Serialize)]
+struct DiffLayoutsReq {
+    from_version: Option<i64>,
+    to_version: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct SimulateLayoutReq {
+    count: usize,
+    replication_factor: Option<i32>,
+}
+
+#[derive(Serialize)]
+struct SimulationLogSimple {
+    partition_size: u64,
+    replicas_moved: usize,
+}
+
+#[derive(Serialize)]
+struct LayoutDiffLogSimple {
+    roles_changed: usize,
+    zone_redundancy_changed: bool,
+}
+
+#[derive(Serialize)]
+struct WatchClusterLogSimple {
+    updates: usize,
+}
+
 #[derive(Serialize)]
 struct VersionReq {
     version: i64,
@@ -499,9 +880,29 @@ struct ConnectNodesReq {
     count: usize,
 }
 
+#[derive(Serialize)]
+struct NodeIdReq<'a> {
+    node_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct BootstrapClusterReq<'a> {
+    nodes: Vec<&'a str>,
+    parameters: bool,
+}
+
+#[derive(Serialize)]
+struct BootstrapLogSimple {
+    staged: usize,
+    failed: usize,
+    applied_version: Option<i64>,
+}
+
 #[derive(Serialize)]
 struct UpdateLayoutReq {
     changes: usize,
+    parameters: bool,
+    expected_version: Option<i64>,
 }
 
 #[derive(Serialize)]
@@ -516,6 +917,11 @@ struct ApiResponseLog<'a, T: Serialize> {
     data: T,
 }
 
+#[derive(Serialize)]
+struct ConfirmationLog<'a> {
+    summary: &'a str,
+}
+
 #[derive(Serialize)]
 struct ClusterStatusLogSimple {
     layout_version: i64,
@@ -528,6 +934,12 @@ struct ClusterHealthLogSimple<'a> {
     connected_nodes: i32,
 }
 
+#[derive(Serialize)]
+struct ClusterStatisticsLogSimple {
+    node_count: usize,
+    table_count: usize,
+}
+
 #[derive(Serialize)]
 struct LayoutLogSimple {
     version: i64,
@@ -552,17 +964,49 @@ struct SkipDeadNodesLogSimple {
 
 // ============ Helpers ============
 
+/// 破壞性操作尚未確認時的回應
+fn confirmation_response(trace_id: String, required: ConfirmationRequired) -> ApiResponse {
+    ApiResponse {
+        trace_id,
+        data: None,
+        confirmation: Some(required.into()),
+    }
+}
+
+fn convert_statistics_response(statistics: &ClusterStatistics) -> ClusterStatisticsData {
+    ClusterStatisticsData {
+        nodes: statistics
+            .nodes
+            .iter()
+            .map(|n| NodeStorageStatistics {
+                id: n.id.clone(),
+                hostname: n.hostname.clone(),
+                zone: n.zone.clone(),
+                capacity: n.capacity.map(|c| c as i64),
+                partitions: n.partitions.map(|p| p as i64),
+                data: n.data.map(Into::into),
+                metadata: n.metadata.map(Into::into),
+            })
+            .collect(),
+        tables: statistics
+            .tables
+            .iter()
+            .map(Into::into)
+            .collect(),
+        totals: Some(StorageTotals {
+            capacity: statistics.totals.capacity as i64,
+            data: Some(statistics.totals.data.into()),
+            metadata: Some(statistics.totals.metadata.into()),
+            estimated_data_available: statistics.totals.estimated_data_available.map(|v| v as i64),
+            estimated_metadata_available: statistics.totals.estimated_metadata_available.map(|v| v as i64),
+            estimate_is_upper_bound: statistics.totals.estimate_is_upper_bound,
+        }),
+        freeform: statistics.freeform.clone(),
+    }
+}
+
 fn convert_layout_response(layout: &crate::domain::entities::ClusterLayout) -> ClusterLayoutData {
-    let roles: Vec<LayoutRole> = layout
-        .roles
-        .iter()
-        .map(|r| LayoutRole {
-            id: r.id.clone(),
-            zone: r.zone.clone(),
-            capacity: r.capacity.unwrap_or(0),
-            tags: r.tags.clone(),
-        })
-        .collect();
+    let roles: Vec<LayoutRole> = layout.roles.iter().map(convert_layout_role).collect();
 
     let staged_changes: Vec<StagedRoleChange> = layout
         .staged_role_changes
@@ -589,24 +1033,214 @@ fn convert_layout_response(layout: &crate::domain::entities::ClusterLayout) -> C
         })
         .collect();
 
-    let zone_redundancy = layout.parameters.as_ref().and_then(|p| p.zone_redundancy.as_ref()).map(|zr| {
-        use crate::domain::entities::ZoneRedundancy as DomainZoneRedundancy;
-        match zr {
-            DomainZoneRedundancy::Maximum { maximum: _ } => ZoneRedundancy {
-                value: Some(zone_redundancy::Value::Maximum(true)),
-            },
-            DomainZoneRedundancy::Value(v) => ZoneRedundancy {
-                value: Some(zone_redundancy::Value::Fixed(*v)),
-            },
-        }
-    });
-
     ClusterLayoutData {
         version: layout.version,
         roles,
         staged_role_changes: staged_changes,
         parameters: Some(LayoutParameters {
-            zone_redundancy,
+            zone_redundancy: layout
+                .parameters
+                .as_ref()
+                .and_then(|p| p.zone_redundancy.as_ref())
+                .map(convert_zone_redundancy),
+        }),
+        staged_parameters: layout.staged_parameters.as_ref().map(|p| LayoutParameters {
+            zone_redundancy: p.zone_redundancy.as_ref().map(convert_zone_redundancy),
+        }),
+    }
+}
+
+fn convert_layout_role(role: &DomainLayoutRole) -> LayoutRole {
+    LayoutRole {
+        id: role.id.clone(),
+        zone: role.zone.clone(),
+        capacity: role.capacity.unwrap_or(0),
+        tags: role.tags.clone(),
+    }
+}
+
+fn convert_layout_diff(diff: &LayoutDiff) -> LayoutDiffData {
+    let roles = diff
+        .roles
+        .iter()
+        .map(|r| {
+            let kind = match r.kind {
+                DomainLayoutRoleDiffKind::Added => LayoutRoleDiffKind::Added,
+                DomainLayoutRoleDiffKind::Removed => LayoutRoleDiffKind::Removed,
+                DomainLayoutRoleDiffKind::Modified => LayoutRoleDiffKind::Modified,
+            };
+            LayoutRoleDiff {
+                node_id: r.node_id.clone(),
+                kind: kind as i32,
+                before: r.before.as_ref().map(convert_layout_role),
+                after: r.after.as_ref().map(convert_layout_role),
+                zone_changed: r.zone_changed,
+                capacity_changed: r.capacity_changed,
+                tags_added: r.tags_added.clone(),
+                tags_removed: r.tags_removed.clone(),
+            }
+        })
+        .collect();
+
+    LayoutDiffData {
+        from_version: diff.from_version,
+        to_version: diff.to_version,
+        roles,
+        zone_redundancy: diff.zone_redundancy.as_ref().map(|c| ZoneRedundancyChange {
+            before: c.before.as_ref().map(convert_zone_redundancy),
+            after: c.after.as_ref().map(convert_zone_redundancy),
+        }),
+        zones: diff
+            .zones
+            .iter()
+            .map(|z| ZoneCapacity {
+                zone: z.zone.clone(),
+                nodes_before: z.nodes_before as i32,
+                nodes_after: z.nodes_after as i32,
+                capacity_before: z.capacity_before as i64,
+                capacity_after: z.capacity_after as i64,
+            })
+            .collect(),
+    }
+}
+
+fn convert_simulation_report(report: &LayoutSimulationReport) -> LayoutSimulationData {
+    let simulation = &report.simulation;
+    LayoutSimulationData {
+        replication_factor: simulation.replication_factor as i32,
+        zone_redundancy: simulation.zone_redundancy as i32,
+        partition_size: simulation.partition_size as i64,
+        usable_capacity: simulation.usable_capacity as i64,
+        nodes: simulation
+            .nodes
+            .iter()
+            .map(|n| SimulatedNode {
+                id: n.id.clone(),
+                zone: n.zone.clone(),
+                capacity: n.capacity.unwrap_or(0) as i64,
+                partitions: n.partitions as i32,
+                used_capacity: n.used_capacity as i64,
+            })
+            .collect(),
+        current_usable_capacity: report.current_usable_capacity.map(|c| c as i64),
+        partitions_moved: report.partitions_moved as i32,
+        replicas_moved: report.replicas_moved as i32,
+    }
+}
+
+fn convert_bootstrap_report(report: &BootstrapReport) -> BootstrapReportData {
+    let nodes = report
+        .nodes
+        .iter()
+        .map(|n| {
+            let (connection, connection_error) = match &n.connection {
+                DomainBootstrapConnectOutcome::AlreadyConnected => (BootstrapConnectOutcome::AlreadyConnected, None),
+                DomainBootstrapConnectOutcome::Connected => (BootstrapConnectOutcome::Connected, None),
+                DomainBootstrapConnectOutcome::Failed { error } => (BootstrapConnectOutcome::Failed, Some(error.clone())),
+            };
+            let role = match n.role {
+                DomainBootstrapRoleOutcome::Unchanged => BootstrapRoleOutcome::Unchanged,
+                DomainBootstrapRoleOutcome::AlreadyStaged => BootstrapRoleOutcome::AlreadyStaged,
+                DomainBootstrapRoleOutcome::Staged => BootstrapRoleOutcome::Staged,
+                DomainBootstrapRoleOutcome::Skipped => BootstrapRoleOutcome::Skipped,
+            };
+            BootstrapNodeReport {
+                node_id: n.node_id.clone(),
+                address: n.address.clone(),
+                connection: connection as i32,
+                connection_error,
+                role: role as i32,
+            }
+        })
+        .collect();
+
+    BootstrapReportData {
+        nodes,
+        parameters_staged: report.parameters_staged,
+        preview: report.preview.clone(),
+        applied_version: report.applied_version,
+        layout_version: report.layout_version,
+    }
+}
+
+fn convert_cluster_health(health: &ClusterHealth) -> ClusterHealthData {
+    ClusterHealthData {
+        status: health.status.clone(),
+        known_nodes: health.known_nodes,
+        connected_nodes: health.connected_nodes,
+        storage_nodes: health.storage_nodes,
+        storage_nodes_ok: health.storage_nodes_up,
+        partitions: health.partitions,
+        partitions_quorum: health.partitions_quorum,
+        partitions_all_ok: health.partitions_all_ok,
+    }
+}
+
+fn convert_watch_response(
+    trace_id: String,
+    full: bool,
+    changes: ClusterChanges,
+    snapshot: &ClusterSnapshot,
+) -> WatchClusterResponse {
+    WatchClusterResponse {
+        trace_id,
+        full,
+        health: changes.health.as_ref().map(convert_cluster_health),
+        nodes: changes
+            .nodes
+            .into_iter()
+            .map(|n| WatchNode {
+                id: n.id,
+                hostname: n.hostname,
+                addr: n.addr,
+                is_up: n.is_up,
+                last_seen_secs_ago: n.last_seen_secs_ago,
+            })
+            .collect(),
+        removed_nodes: changes.removed_nodes,
+        layout_version: changes.layout_version,
+        observed_at: snapshot.observed_at.to_rfc3339(),
+        reachable: changes.reachable,
+        poll_error: changes.poll_error,
+    }
+}
+
+fn convert_decommission_progress(progress: DomainDecommissionProgress) -> DecommissionProgress {
+    let phase = match progress.phase {
+        DomainDecommissionPhase::Validating => DecommissionPhase::Validating,
+        DomainDecommissionPhase::StagingRemoval => DecommissionPhase::StagingRemoval,
+        DomainDecommissionPhase::Previewing => DecommissionPhase::Previewing,
+        DomainDecommissionPhase::Applying => DecommissionPhase::Applying,
+        DomainDecommissionPhase::SyncingLayout => DecommissionPhase::SyncingLayout,
+        DomainDecommissionPhase::Resyncing => DecommissionPhase::Resyncing,
+        DomainDecommissionPhase::Completed => DecommissionPhase::Completed,
+    };
+    DecommissionProgress {
+        phase: phase as i32,
+        percent: progress.percent as i32,
+        message: progress.message,
+        layout_version: progress.layout_version,
+    }
+}
+
+fn convert_zone_redundancy(zone_redundancy: &crate::domain::entities::ZoneRedundancy) -> ZoneRedundancy {
+    use crate::domain::entities::ZoneRedundancy as DomainZoneRedundancy;
+    match zone_redundancy {
+        DomainZoneRedundancy::Maximum { maximum: _ } => ZoneRedundancy {
+            value: Some(zone_redundancy::Value::Maximum(true)),
+        },
+        DomainZoneRedundancy::Value(v) => ZoneRedundancy {
+            value: Some(zone_redundancy::Value::Fixed(*v)),
+        },
+    }
+}
+
+fn convert_layout_parameters_request(parameters: LayoutParameters) -> crate::domain::entities::LayoutParameters {
+    use crate::domain::entities::ZoneRedundancy as DomainZoneRedundancy;
+    crate::domain::entities::LayoutParameters {
+        zone_redundancy: parameters.zone_redundancy.and_then(|zr| zr.value).map(|value| match value {
+            zone_redundancy::Value::Fixed(v) => DomainZoneRedundancy::Value(v),
+            zone_redundancy::Value::Maximum(maximum) => DomainZoneRedundancy::Maximum { maximum },
         }),
     }
 }
@@ -4,17 +4,25 @@
 //! the protobuf-generated service traits to the application layer handlers.
 
 mod access_key_service;
+mod admin_token_service;
+mod audit_service;
 mod block_service;
 mod bucket_service;
 mod cluster_service;
 mod node_service;
 mod object_service;
+mod schedule_service;
+mod system_service;
 mod worker_service;
 
 pub use access_key_service::AccessKeyGrpcService;
+pub use admin_token_service::AdminTokenGrpcService;
+pub use audit_service::AuditGrpcService;
 pub use block_service::BlockGrpcService;
 pub use bucket_service::BucketGrpcService;
 pub use cluster_service::ClusterGrpcService;
 pub use node_service::NodeGrpcService;
 pub use object_service::ObjectGrpcService;
+pub use schedule_service::ScheduleGrpcService;
+pub use system_service::SystemGrpcService;
 pub use worker_service::WorkerGrpcService;
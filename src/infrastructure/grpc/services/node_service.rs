@@ -12,12 +12,13 @@ use crate::application::commands::node::handlers::{
     CreateMetadataSnapshotHandler, LaunchRepairHandler,
 };
 use crate::application::queries::node::{
-    GetNodeInfoQuery, GetNodeStatisticsQuery,
+    GetNodeInfoQuery, GetNodeStatisticsQuery, GetScrubStatusQuery,
 };
 use crate::application::queries::node::handlers::{
-    GetNodeInfoHandler, GetNodeStatisticsHandler,
+    GetNodeInfoHandler, GetNodeStatisticsHandler, GetScrubStatusHandler,
 };
-use crate::infrastructure::grpc::conversions::domain_error_to_status;
+use crate::domain::entities::ScrubState as DomainScrubState;
+use crate::infrastructure::grpc::conversions::{convert_repair_type, domain_error_to_status};
 use crate::grpc_log;
 use crate::shared::get_trace_id;
 
@@ -26,9 +27,10 @@ use crate::infrastructure::grpc::generated::node::{
     ApiResponse, api_response::Data,
     GetNodeInfoRequest, MultiNodeInfoData, NodeInfoResult, node_info_result,
     GetNodeStatisticsRequest, MultiNodeStatisticsData, NodeStatisticsResult, node_statistics_result,
-    CreateMetadataSnapshotRequest, LaunchRepairRequest, 
+    GetScrubStatusRequest, MultiNodeScrubStatusData, ScrubStatusResult, scrub_status_result, ScrubStatus, ScrubState,
+    CreateMetadataSnapshotRequest, LaunchRepairRequest,
     MultiNodeEmptyData, EmptyResult, empty_result,
-    NodeInfo, NodeStatistics,
+    NodeInfo, NodeStatistics, NodeStatisticsTotals, BlockManagerStatistics,
 };
 
 /// gRPC service for node operations
@@ -39,6 +41,7 @@ pub struct NodeGrpcService {
     // Query handlers
     get_node_info_handler: Arc<GetNodeInfoHandler>,
     get_node_statistics_handler: Arc<GetNodeStatisticsHandler>,
+    get_scrub_status_handler: Arc<GetScrubStatusHandler>,
 }
 
 impl NodeGrpcService {
@@ -47,12 +50,14 @@ impl NodeGrpcService {
         launch_repair_handler: Arc<LaunchRepairHandler>,
         get_node_info_handler: Arc<GetNodeInfoHandler>,
         get_node_statistics_handler: Arc<GetNodeStatisticsHandler>,
+        get_scrub_status_handler: Arc<GetScrubStatusHandler>,
     ) -> Self {
         Self {
             create_metadata_snapshot_handler,
             launch_repair_handler,
             get_node_info_handler,
             get_node_statistics_handler,
+            get_scrub_status_handler,
         }
     }
 }
@@ -123,7 +128,7 @@ impl NodeService for NodeGrpcService {
         let log = grpc_log!("NodeService", "GetNodeStatistics", &NodeRequest { node: &req.node });
         let trace_id = get_trace_id();
         
-        let response = self
+        let (response, totals) = self
             .get_node_statistics_handler
             .handle(GetNodeStatisticsQuery::new(req.node))
             .await
@@ -136,9 +141,7 @@ impl NodeService for NodeGrpcService {
         
         for (node_id, stats) in response.success.iter() {
             results.insert(node_id.clone(), NodeStatisticsResult {
-                result: Some(node_statistics_result::Result::Statistics(NodeStatistics {
-                    freeform: stats.freeform.clone(),
-                })),
+                result: Some(node_statistics_result::Result::Statistics(convert_node_statistics(stats))),
             });
         }
         
@@ -150,7 +153,56 @@ impl NodeService for NodeGrpcService {
 
         let api_response = ApiResponse {
             trace_id: trace_id.clone(),
-            data: Some(Data::NodeStatistics(MultiNodeStatisticsData { results: results.clone() })),
+            data: Some(Data::NodeStatistics(MultiNodeStatisticsData {
+                results: results.clone(),
+                totals: Some(convert_node_statistics_totals(&totals)),
+            })),
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: MultiNodeResultLog {
+                success_count: response.success.len(),
+                error_count: response.error.len(),
+            },
+        });
+        Ok(Response::new(api_response))
+    }
+
+    async fn get_scrub_status(
+        &self,
+        request: Request<GetScrubStatusRequest>,
+    ) -> Result<Response<ApiResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("NodeService", "GetScrubStatus", &NodeRequest { node: &req.node });
+        let trace_id = get_trace_id();
+
+        let response = self
+            .get_scrub_status_handler
+            .handle(GetScrubStatusQuery::new(req.node))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let mut results: HashMap<String, ScrubStatusResult> = HashMap::new();
+
+        for (node_id, status) in response.success.iter() {
+            results.insert(node_id.clone(), ScrubStatusResult {
+                result: Some(scrub_status_result::Result::Status(convert_scrub_status(status))),
+            });
+        }
+
+        for (node_id, error) in response.error.iter() {
+            results.insert(node_id.clone(), ScrubStatusResult {
+                result: Some(scrub_status_result::Result::Error(error.clone())),
+            });
+        }
+
+        let api_response = ApiResponse {
+            trace_id: trace_id.clone(),
+            data: Some(Data::ScrubStatus(MultiNodeScrubStatusData { results })),
         };
 
         log.ok(&ApiResponseLog {
@@ -214,12 +266,20 @@ impl NodeService for NodeGrpcService {
         request: Request<LaunchRepairRequest>,
     ) -> Result<Response<ApiResponse>, Status> {
         let req = request.into_inner();
-        let log = grpc_log!("NodeService", "LaunchRepair", &RepairRequest { node: &req.node, repair_type: &req.repair_type });
+        let log = grpc_log!("NodeService", "LaunchRepair", &RepairRequest {
+            node: &req.node,
+            repair_type: req.repair_type().as_str_name(),
+            scrub_command: req.scrub_command().as_str_name(),
+            scrub_tranquility: req.scrub_tranquility,
+        });
         let trace_id = get_trace_id();
-        
+
+        let repair_type = convert_repair_type(req.repair_type(), req.scrub_command())
+            .inspect_err(|e| log.err(e.message()))?;
+
         let response = self
             .launch_repair_handler
-            .handle(LaunchRepairCommand::new(req.node, req.repair_type))
+            .handle(LaunchRepairCommand::new(req.node, repair_type).with_scrub_tranquility(req.scrub_tranquility))
             .await
             .map_err(|e| {
                 log.err(&e.to_string());
@@ -256,6 +316,61 @@ impl NodeService for NodeGrpcService {
     }
 }
 
+// ============ Helpers ============
+
+fn convert_block_manager(blocks: &crate::domain::entities::BlockManagerStatistics) -> BlockManagerStatistics {
+    BlockManagerStatistics {
+        rc_entries: blocks.rc_entries.map(|v| v as i64),
+        resync_queue_length: blocks.resync_queue_length.map(|v| v as i64),
+        resync_errors: blocks.resync_errors.map(|v| v as i64),
+    }
+}
+
+fn convert_node_statistics(stats: &crate::domain::entities::NodeStatistics) -> NodeStatistics {
+    NodeStatistics {
+        freeform: stats.freeform.clone(),
+        garage_version: stats.garage_version.clone(),
+        garage_features: stats.garage_features.clone(),
+        rust_version: stats.rust_version.clone(),
+        db_engine: stats.db_engine.clone(),
+        tables: stats.tables.iter().map(Into::into).collect(),
+        blocks: Some(convert_block_manager(&stats.blocks)),
+        data_disk: stats.data_disk.map(Into::into),
+        metadata_disk: stats.metadata_disk.map(Into::into),
+    }
+}
+
+fn convert_scrub_status(status: &crate::domain::entities::ScrubStatus) -> ScrubStatus {
+    let state = match status.state {
+        DomainScrubState::Idle => ScrubState::Idle,
+        DomainScrubState::Running => ScrubState::Running,
+        DomainScrubState::Paused => ScrubState::Paused,
+    };
+    ScrubStatus {
+        worker_id: status.worker_id,
+        state: state as i32,
+        progress_percent: status.progress_percent,
+        tranquility: status.tranquility,
+        corruptions_detected: status.corruptions_detected.map(|c| c as i64),
+        paused_until: status.paused_until.clone(),
+        last_completed_at: status.last_completed_at.clone(),
+        next_run_at: status.next_run_at.clone(),
+        errors: status.errors,
+        last_error: status.last_error.clone(),
+    }
+}
+
+fn convert_node_statistics_totals(totals: &crate::domain::entities::NodeStatisticsTotals) -> NodeStatisticsTotals {
+    NodeStatisticsTotals {
+        node_count: totals.node_count as i64,
+        tables: totals.tables.iter().map(Into::into).collect(),
+        blocks: Some(convert_block_manager(&totals.blocks)),
+        data_disk: Some(totals.data_disk.into()),
+        metadata_disk: Some(totals.metadata_disk.into()),
+        partial: totals.partial,
+    }
+}
+
 // ============ Log Structs ============
 
 #[derive(Serialize)]
@@ -267,6 +382,8 @@ struct NodeRequest<'a> {
 struct RepairRequest<'a> {
     node: &'a str,
     repair_type: &'a str,
+    scrub_command: &'a str,
+    scrub_tranquility: Option<u32>,
 }
 
 #[derive(Serialize)]
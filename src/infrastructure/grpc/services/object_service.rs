@@ -17,7 +17,10 @@ use crate::application::commands::object::handlers::{CopyObjectHandler, DeleteOb
 use crate::application::queries::object::{GetObjectMetadataQuery, ListObjectsQuery};
 use crate::application::queries::object::handlers::{GetObjectMetadataHandler, ListObjectsHandler};
 use crate::infrastructure::grpc::conversions::domain_error_to_status;
-use crate::domain::repositories::ObjectRepository;
+use crate::domain::aggregates::{AdminTokenAggregate, AdminTokenScope};
+use crate::domain::entities::Confirmable;
+use crate::domain::repositories::{ObjectRepository, ObjectRepositoryProvider};
+use crate::domain::value_objects::S3Credentials;
 use crate::grpc_log;
 use crate::shared::get_trace_id;
 
@@ -39,7 +42,7 @@ use crate::infrastructure::grpc::generated::object::{
     ObjectInfo, ObjectMetadata, UploadResult, UploadInitiated, UploadProgress as ProtoUploadProgress,
     CopyResult, DeleteError, PreSignedUrl,
     DownloadMetadata, DownloadChunkResponse,
-    FolderStats,
+    FolderStats, S3Credentials as ProtoS3Credentials,
 };
 use crate::infrastructure::s3::UploadProgress;
 
@@ -54,8 +57,8 @@ pub struct ObjectGrpcService {
     // Command handlers
     delete_objects_handler: Arc<DeleteObjectsHandler>,
     copy_object_handler: Arc<CopyObjectHandler>,
-    // Object repository for streaming and presigned URL operations
-    object_repository: Arc<dyn ObjectRepository>,
+    // Object repositories (per access key) for streaming and presigned URL operations
+    object_repositories: Arc<dyn ObjectRepositoryProvider>,
 }
 
 impl ObjectGrpcService {
@@ -64,20 +67,21 @@ impl ObjectGrpcService {
         get_object_metadata_handler: Arc<GetObjectMetadataHandler>,
         delete_objects_handler: Arc<DeleteObjectsHandler>,
         copy_object_handler: Arc<CopyObjectHandler>,
-        object_repository: Arc<dyn ObjectRepository>,
+        object_repositories: Arc<dyn ObjectRepositoryProvider>,
     ) -> Self {
         Self {
             list_objects_handler,
             get_object_metadata_handler,
             delete_objects_handler,
             copy_object_handler,
-            object_repository,
+            object_repositories,
         }
     }
 
     /// 並行計算每個資料夾的統計資訊（檔案數量和大小）
     async fn compute_folder_stats(
         &self,
+        repository: &Arc<dyn ObjectRepository>,
         bucket: &str,
         prefixes: &[String],
     ) -> HashMap<String, FolderStats> {
@@ -92,7 +96,7 @@ impl ObjectGrpcService {
             .map(|prefix| {
                 let bucket = bucket.to_string();
                 let prefix = prefix.clone();
-                let repo = Arc::clone(&self.object_repository);
+                let repo = Arc::clone(repository);
                 
                 async move {
                     // 列出資料夾內所有物件（不使用 delimiter，最多 1000 個）
@@ -134,6 +138,19 @@ impl ObjectGrpcService {
     }
 }
 
+impl ObjectGrpcService {
+    /// 依呼叫者指定的 Access Key 取得 repository
+    async fn repository(
+        &self,
+        credentials: Option<&S3Credentials>,
+    ) -> Result<Arc<dyn ObjectRepository>, Status> {
+        self.object_repositories
+            .resolve(credentials)
+            .await
+            .map_err(domain_error_to_status)
+    }
+}
+
 #[tonic::async_trait]
 impl ObjectService for ObjectGrpcService {
     // ============ Query Operations ============
@@ -142,11 +159,13 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<ListObjectsRequest>,
     ) -> Result<Response<ListObjectsResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "ListObjects", &req);
         let trace_id = get_trace_id();
 
         let bucket = req.bucket.clone();
+        let credentials = convert_credentials(req.credentials, can_look_up_secret)?;
         let query = ListObjectsQuery::new(
             req.bucket,
             req.prefix,
@@ -156,14 +175,18 @@ impl ObjectService for ObjectGrpcService {
         )
         .map_err(domain_error_to_status)?;
 
+        // 列表與資料夾統計共用同一個 repository
+        let repository = self.repository(credentials.as_ref()).await?;
         let result = self
             .list_objects_handler
-            .handle(query)
+            .handle_with(repository.as_ref(), query)
             .await
             .map_err(domain_error_to_status)?;
 
         // 並行計算每個資料夾的統計資訊
-        let folder_stats = self.compute_folder_stats(&bucket, &result.common_prefixes).await;
+        let folder_stats = self
+            .compute_folder_stats(&repository, &bucket, &result.common_prefixes)
+            .await;
 
         let response = ListObjectsResponse {
             trace_id: trace_id.to_string(),
@@ -192,12 +215,15 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<GetObjectMetadataRequest>,
     ) -> Result<Response<ObjectMetadataResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "GetObjectMetadata", &req);
         let trace_id = get_trace_id();
 
+        let credentials = convert_credentials(req.credentials, can_look_up_secret)?;
         let query = GetObjectMetadataQuery::new(req.bucket, req.key)
-            .map_err(domain_error_to_status)?;
+            .map_err(domain_error_to_status)?
+            .with_credentials(credentials);
 
         let metadata = self
             .get_object_metadata_handler
@@ -231,6 +257,7 @@ impl ObjectService for ObjectGrpcService {
         request: Request<Streaming<UploadChunkRequest>>,
     ) -> Result<Response<Self::UploadObjectStream>, Status> {
         let trace_id = get_trace_id();
+        let can_look_up_secret = can_look_up_secret(&request);
         let mut stream = request.into_inner();
 
         // First message must be metadata
@@ -254,8 +281,11 @@ impl ObjectService for ObjectGrpcService {
             "Starting bidirectional streaming upload"
         );
 
+        let credentials = convert_credentials(metadata.credentials.clone(), can_look_up_secret)?;
+        let object_repository = self.repository(credentials.as_ref()).await?;
+
         if metadata.content_length == 0 {
-            self.object_repository
+            object_repository
                 .upload(
                     &metadata.bucket,
                     &metadata.key,
@@ -291,8 +321,7 @@ impl ObjectService for ObjectGrpcService {
         let bucket = metadata.bucket.clone();
         let key = metadata.key.clone();
         let content_type = metadata.content_type.clone();
-        let content_length = Some(metadata.content_length.clone());
-        let object_repository = self.object_repository.clone();
+        let content_length = Some(metadata.content_length);
 
         // Spawn task to forward chunks from gRPC stream to S3
         tokio::spawn(async move {
@@ -319,8 +348,7 @@ impl ObjectService for ObjectGrpcService {
                     },
                     Err(e) => {
                         let _ = chunk_tx
-                            .send(Err(std::io::Error::new(
-                                std::io::ErrorKind::Other,
+                            .send(Err(std::io::Error::other(
                                 format!("Stream error: {}", e),
                             )))
                             .await;
@@ -424,6 +452,7 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<DownloadObjectRequest>,
     ) -> Result<Response<Self::DownloadObjectStream>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let trace_id = get_trace_id();
 
@@ -435,8 +464,10 @@ impl ObjectService for ObjectGrpcService {
         );
 
         // Get the object from S3
+        let credentials = convert_credentials(req.credentials, can_look_up_secret)?;
         let download_result = self
-            .object_repository
+            .repository(credentials.as_ref())
+            .await?
             .download(&req.bucket, &req.key)
             .await
             .map_err(domain_error_to_status)?;
@@ -519,15 +550,18 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<GetUploadUrlRequest>,
     ) -> Result<Response<PreSignedUrlResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "GetUploadUrl", &req);
         let trace_id = get_trace_id();
 
         let expires_in = req.expires_in_seconds.unwrap_or(DEFAULT_PRESIGNED_EXPIRATION) as u64;
         let content_type = req.content_type.as_deref();
+        let credentials = convert_credentials(req.credentials.clone(), can_look_up_secret)?;
 
         let url = self
-            .object_repository
+            .repository(credentials.as_ref())
+            .await?
             .generate_presigned_upload_url(
                 &req.bucket,
                 &req.key,
@@ -560,14 +594,18 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<GetDownloadUrlRequest>,
     ) -> Result<Response<PreSignedUrlResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "GetDownloadUrl", &req);
         let trace_id = get_trace_id();
 
         let expires_in = req.expires_in_seconds.unwrap_or(DEFAULT_PRESIGNED_EXPIRATION) as u64;
 
+        let credentials = convert_credentials(req.credentials.clone(), can_look_up_secret)?;
+
         let url = self
-            .object_repository
+            .repository(credentials.as_ref())
+            .await?
             .generate_presigned_download_url(
                 &req.bucket,
                 &req.key,
@@ -601,31 +639,43 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<DeleteObjectRequest>,
     ) -> Result<Response<DeleteObjectResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "DeleteObject", &req);
         let trace_id = get_trace_id();
 
+        let credentials = convert_credentials(req.credentials, can_look_up_secret)?;
         let command = DeleteObjectsCommand::new(req.bucket, req.keys)
-            .map_err(domain_error_to_status)?;
+            .map_err(domain_error_to_status)?
+            .with_credentials(credentials)
+            .with_confirmation_token(req.confirmation_token);
 
-        let result = self
+        let response = match self
             .delete_objects_handler
             .handle(command)
             .await
-            .map_err(domain_error_to_status)?;
-
-        let response = DeleteObjectResponse {
-            trace_id: trace_id.to_string(),
-            deleted: result.deleted,
-            errors: result
-                .errors
-                .into_iter()
-                .map(|e| DeleteError {
-                    key: e.key,
-                    code: e.code,
-                    message: e.message,
-                })
-                .collect(),
+            .map_err(domain_error_to_status)?
+        {
+            Confirmable::Required(required) => DeleteObjectResponse {
+                trace_id: trace_id.to_string(),
+                deleted: vec![],
+                errors: vec![],
+                confirmation: Some(required.into()),
+            },
+            Confirmable::Completed(result) => DeleteObjectResponse {
+                trace_id: trace_id.to_string(),
+                deleted: result.deleted,
+                errors: result
+                    .errors
+                    .into_iter()
+                    .map(|e| DeleteError {
+                        key: e.key,
+                        code: e.code,
+                        message: e.message,
+                    })
+                    .collect(),
+                confirmation: None,
+            },
         };
 
         log.ok(&response);
@@ -636,6 +686,7 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<CopyObjectRequest>,
     ) -> Result<Response<CopyObjectResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "CopyObject", &req);
         let trace_id = get_trace_id();
@@ -646,7 +697,8 @@ impl ObjectService for ObjectGrpcService {
             req.dest_bucket,
             req.dest_key,
         )
-        .map_err(domain_error_to_status)?;
+        .map_err(domain_error_to_status)?
+        .with_credentials(convert_credentials(req.credentials, can_look_up_secret)?);
 
         let result = self
             .copy_object_handler
@@ -670,6 +722,7 @@ impl ObjectService for ObjectGrpcService {
         &self,
         request: Request<AbortUploadRequest>,
     ) -> Result<Response<AbortUploadResponse>, Status> {
+        let can_look_up_secret = can_look_up_secret(&request);
         let req = request.into_inner();
         let log = grpc_log!("ObjectService", "AbortUpload", &req);
         let trace_id = get_trace_id();
@@ -682,7 +735,9 @@ impl ObjectService for ObjectGrpcService {
             "Aborting multipart upload"
         );
 
-        self.object_repository
+        let credentials = convert_credentials(req.credentials.clone(), can_look_up_secret)?;
+        self.repository(credentials.as_ref())
+            .await?
             .abort_upload(&req.bucket, &req.key, &req.upload_id)
             .await
             .map_err(domain_error_to_status)?;
@@ -699,3 +754,31 @@ impl ObjectService for ObjectGrpcService {
         Ok(Response::new(response))
     }
 }
+
+// ============ Helpers ============
+
+/// 由後端查詢 Key 的 secret 等同 RevealKeySecret，需要 write:keys（未啟用驗證時不限制）
+fn can_look_up_secret<T>(request: &Request<T>) -> bool {
+    request
+        .extensions()
+        .get::<AdminTokenAggregate>()
+        .is_none_or(|t| t.has_permission(AdminTokenScope::WRITE_KEYS))
+}
+
+fn convert_credentials(
+    credentials: Option<ProtoS3Credentials>,
+    can_look_up_secret: bool,
+) -> Result<Option<S3Credentials>, Status> {
+    let credentials = credentials
+        .map(|c| S3Credentials::new(c.access_key_id, c.secret_access_key))
+        .transpose()
+        .map_err(domain_error_to_status)?;
+
+    if credentials.as_ref().is_some_and(|c| c.secret_access_key().is_none()) && !can_look_up_secret {
+        return Err(Status::permission_denied(format!(
+            "Credentials without a secret require scope {}",
+            AdminTokenScope::WRITE_KEYS
+        )));
+    }
+    Ok(credentials)
+}
@@ -0,0 +1,378 @@
+//! Schedule gRPC service implementation
+
+use std::sync::Arc;
+use chrono::Utc;
+use serde::Serialize;
+use tonic::{Request, Response, Status};
+
+use crate::application::commands::schedule::{
+    CreateScheduleCommand, UpdateScheduleCommand, DeleteScheduleCommand, RunScheduleCommand,
+};
+use crate::application::commands::schedule::handlers::{
+    CreateScheduleHandler, UpdateScheduleHandler, DeleteScheduleHandler, RunScheduleHandler,
+};
+use crate::application::queries::schedule::{ListSchedulesQuery, GetScheduleQuery};
+use crate::application::queries::schedule::handlers::{ListSchedulesHandler, GetScheduleHandler};
+use crate::domain::entities::{
+    Schedule as DomainSchedule, ScheduleAction as DomainScheduleAction, ScheduleRun as DomainScheduleRun,
+    ScheduleTrigger as DomainScheduleTrigger,
+};
+use crate::grpc_log;
+use crate::shared::get_trace_id;
+use crate::infrastructure::grpc::conversions::{convert_repair_type, domain_error_to_status, repair_type_to_proto};
+
+use crate::infrastructure::grpc::generated::schedule::{
+    schedule_service_server::ScheduleService,
+    ListSchedulesRequest, ListSchedulesResponse, GetScheduleRequest, ScheduleResponse,
+    CreateScheduleRequest, UpdateScheduleRequest, DeleteScheduleRequest, DeleteScheduleResponse,
+    RunScheduleRequest, Schedule, ScheduleAction, schedule_action::Action, ScheduleRun, ScheduleTrigger,
+    CreateMetadataSnapshotAction, LaunchRepairAction, CleanupIncompleteUploadsAction, RetryBlockResyncAction,
+};
+
+/// gRPC service for scheduled maintenance jobs
+pub struct ScheduleGrpcService {
+    // Command handlers
+    create_schedule_handler: Arc<CreateScheduleHandler>,
+    update_schedule_handler: Arc<UpdateScheduleHandler>,
+    delete_schedule_handler: Arc<DeleteScheduleHandler>,
+    run_schedule_handler: Arc<RunScheduleHandler>,
+    // Query handlers
+    list_schedules_handler: Arc<ListSchedulesHandler>,
+    get_schedule_handler: Arc<GetScheduleHandler>,
+}
+
+impl ScheduleGrpcService {
+    pub fn new(
+        create_schedule_handler: Arc<CreateScheduleHandler>,
+        update_schedule_handler: Arc<UpdateScheduleHandler>,
+        delete_schedule_handler: Arc<DeleteScheduleHandler>,
+        run_schedule_handler: Arc<RunScheduleHandler>,
+        list_schedules_handler: Arc<ListSchedulesHandler>,
+        get_schedule_handler: Arc<GetScheduleHandler>,
+    ) -> Self {
+        Self {
+            create_schedule_handler,
+            update_schedule_handler,
+            delete_schedule_handler,
+            run_schedule_handler,
+            list_schedules_handler,
+            get_schedule_handler,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ScheduleService for ScheduleGrpcService {
+    async fn list_schedules(
+        &self,
+        request: Request<ListSchedulesRequest>,
+    ) -> Result<Response<ListSchedulesResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ScheduleService", "ListSchedules", &ListRequest { enabled_only: req.enabled_only });
+        let trace_id = get_trace_id();
+
+        let schedules = self
+            .list_schedules_handler
+            .handle(ListSchedulesQuery::new(req.enabled_only))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let data: Vec<Schedule> = schedules.into_iter().map(convert_schedule).collect();
+        let total = data.len();
+
+        let response = ListSchedulesResponse {
+            trace_id: trace_id.clone(),
+            data: data.clone(),
+            total: total as i32,
+        };
+
+        log.ok(&ApiResponseLog {
+            trace_id: &trace_id,
+            data: ListSchedulesResponseLog { data, total },
+        });
+        Ok(Response::new(response))
+    }
+
+    async fn get_schedule(
+        &self,
+        request: Request<GetScheduleRequest>,
+    ) -> Result<Response<ScheduleResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ScheduleService", "GetSchedule", &SingleIdRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        let schedule = self
+            .get_schedule_handler
+            .handle(GetScheduleQuery::new(req.id))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let schedule = convert_schedule(schedule);
+        let response = ScheduleResponse {
+            trace_id: trace_id.clone(),
+            data: Some(schedule.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: schedule });
+        Ok(Response::new(response))
+    }
+
+    async fn create_schedule(
+        &self,
+        request: Request<CreateScheduleRequest>,
+    ) -> Result<Response<ScheduleResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ScheduleService", "CreateSchedule", &CreateScheduleReq {
+            name: &req.name,
+            cron: &req.cron,
+            action: &req.action,
+            enabled: req.enabled,
+        });
+        let trace_id = get_trace_id();
+
+        let action = req
+            .action
+            .ok_or_else(|| Status::invalid_argument("action is required"))
+            .and_then(convert_action)
+            .inspect_err(|e| log.err(e.message()))?;
+
+        let schedule = self
+            .create_schedule_handler
+            .handle(CreateScheduleCommand::new(req.name, req.cron, action, req.enabled))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let schedule = convert_schedule(schedule);
+        let response = ScheduleResponse {
+            trace_id: trace_id.clone(),
+            data: Some(schedule.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: schedule });
+        Ok(Response::new(response))
+    }
+
+    async fn update_schedule(
+        &self,
+        request: Request<UpdateScheduleRequest>,
+    ) -> Result<Response<ScheduleResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ScheduleService", "UpdateSchedule", &UpdateScheduleReq {
+            id: &req.id,
+            name: &req.name,
+            cron: &req.cron,
+            action: &req.action,
+            enabled: req.enabled,
+        });
+        let trace_id = get_trace_id();
+
+        let action = req
+            .action
+            .map(convert_action)
+            .transpose()
+            .inspect_err(|e| log.err(e.message()))?;
+
+        let command = UpdateScheduleCommand::new(req.id)
+            .with_name(req.name)
+            .with_cron(req.cron)
+            .with_action(action)
+            .with_enabled(req.enabled);
+
+        let schedule = self
+            .update_schedule_handler
+            .handle(command)
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let schedule = convert_schedule(schedule);
+        let response = ScheduleResponse {
+            trace_id: trace_id.clone(),
+            data: Some(schedule.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: schedule });
+        Ok(Response::new(response))
+    }
+
+    async fn delete_schedule(
+        &self,
+        request: Request<DeleteScheduleRequest>,
+    ) -> Result<Response<DeleteScheduleResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ScheduleService", "DeleteSchedule", &SingleIdRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        self.delete_schedule_handler
+            .handle(DeleteScheduleCommand::new(req.id.clone()))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let response = DeleteScheduleResponse {
+            trace_id: trace_id.clone(),
+            id: req.id.clone(),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: req.id });
+        Ok(Response::new(response))
+    }
+
+    async fn run_schedule(
+        &self,
+        request: Request<RunScheduleRequest>,
+    ) -> Result<Response<ScheduleResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("ScheduleService", "RunSchedule", &SingleIdRequest { id: &req.id });
+        let trace_id = get_trace_id();
+
+        let schedule = self
+            .run_schedule_handler
+            .handle(RunScheduleCommand::manual(req.id))
+            .await
+            .map_err(|e| {
+                log.err(&e.to_string());
+                domain_error_to_status(e)
+            })?;
+
+        let schedule = convert_schedule(schedule);
+        let response = ScheduleResponse {
+            trace_id: trace_id.clone(),
+            data: Some(schedule.clone()),
+        };
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: schedule });
+        Ok(Response::new(response))
+    }
+}
+
+// ============ Log Structs ============
+
+#[derive(Serialize)]
+struct ListRequest {
+    enabled_only: bool,
+}
+
+#[derive(Serialize)]
+struct SingleIdRequest<'a> {
+    id: &'a str,
+}
+
+#[derive(Serialize)]
+struct CreateScheduleReq<'a> {
+    name: &'a str,
+    cron: &'a str,
+    action: &'a Option<ScheduleAction>,
+    enabled: bool,
+}
+
+#[derive(Serialize)]
+struct UpdateScheduleReq<'a> {
+    id: &'a str,
+    name: &'a Option<String>,
+    cron: &'a Option<String>,
+    action: &'a Option<ScheduleAction>,
+    enabled: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct ApiResponseLog<'a, T: Serialize> {
+    trace_id: &'a str,
+    data: T,
+}
+
+#[derive(Serialize)]
+struct ListSchedulesResponseLog {
+    data: Vec<Schedule>,
+    total: usize,
+}
+
+// ============ Helpers ============
+
+fn convert_action(action: ScheduleAction) -> Result<DomainScheduleAction, Status> {
+    let action = match action.action {
+        None => return Err(Status::invalid_argument("action is required")),
+        Some(Action::CreateMetadataSnapshot(a)) => DomainScheduleAction::CreateMetadataSnapshot { node: a.node },
+        Some(Action::LaunchRepair(a)) => DomainScheduleAction::LaunchRepair {
+            repair_type: convert_repair_type(a.repair_type(), a.scrub_command())?,
+            node: a.node,
+            scrub_tranquility: a.scrub_tranquility,
+        },
+        Some(Action::CleanupIncompleteUploads(a)) => DomainScheduleAction::CleanupIncompleteUploads {
+            bucket_id: a.bucket_id,
+            older_than_secs: a.older_than_secs,
+        },
+        Some(Action::RetryBlockResync(a)) => DomainScheduleAction::RetryBlockResync {
+            node: a.node,
+            block_hashes: (!a.block_hashes.is_empty()).then_some(a.block_hashes),
+        },
+    };
+    Ok(action)
+}
+
+fn convert_domain_action(action: DomainScheduleAction) -> ScheduleAction {
+    let action = match action {
+        DomainScheduleAction::CreateMetadataSnapshot { node } => {
+            Action::CreateMetadataSnapshot(CreateMetadataSnapshotAction { node })
+        }
+        DomainScheduleAction::LaunchRepair { node, repair_type, scrub_tranquility } => {
+            let (repair_type, scrub_command) = repair_type_to_proto(repair_type);
+            Action::LaunchRepair(LaunchRepairAction {
+                node,
+                repair_type: repair_type as i32,
+                scrub_command: scrub_command as i32,
+                scrub_tranquility,
+            })
+        }
+        DomainScheduleAction::CleanupIncompleteUploads { bucket_id, older_than_secs } => {
+            Action::CleanupIncompleteUploads(CleanupIncompleteUploadsAction { bucket_id, older_than_secs })
+        }
+        DomainScheduleAction::RetryBlockResync { node, block_hashes } => {
+            Action::RetryBlockResync(RetryBlockResyncAction { node, block_hashes: block_hashes.unwrap_or_default() })
+        }
+    };
+    ScheduleAction { action: Some(action) }
+}
+
+fn convert_run(run: DomainScheduleRun) -> ScheduleRun {
+    let trigger = match run.trigger {
+        DomainScheduleTrigger::Cron => ScheduleTrigger::Cron,
+        DomainScheduleTrigger::Manual => ScheduleTrigger::Manual,
+    };
+    ScheduleRun {
+        trigger: trigger as i32,
+        started_at: run.started_at.to_rfc3339(),
+        finished_at: run.finished_at.to_rfc3339(),
+        success: run.success,
+        summary: run.summary,
+        error: run.error,
+    }
+}
+
+fn convert_schedule(schedule: DomainSchedule) -> Schedule {
+    let next_run_at = schedule.next_run_at(Utc::now()).map(|t| t.to_rfc3339());
+    Schedule {
+        id: schedule.id,
+        name: schedule.name,
+        cron: schedule.cron.to_string(),
+        action: Some(convert_domain_action(schedule.action)),
+        enabled: schedule.enabled,
+        created_at: schedule.created_at.to_rfc3339(),
+        updated_at: schedule.updated_at.to_rfc3339(),
+        next_run_at,
+        last_run: schedule.last_run.map(convert_run),
+    }
+}
@@ -0,0 +1,80 @@
+//! System gRPC service implementation
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use tonic::{Request, Response, Status};
+
+use crate::domain::events::{EventBus, ReadOnlyModeChangedEvent, SystemEvent};
+use crate::grpc_log;
+use crate::shared::get_trace_id;
+use crate::infrastructure::grpc::read_only::ReadOnlySwitch;
+
+use crate::infrastructure::grpc::generated::system::{
+    system_service_server::SystemService,
+    GetReadOnlyModeRequest, SetReadOnlyModeRequest, ReadOnlyModeResponse,
+};
+
+/// gRPC service for server-wide runtime settings
+pub struct SystemGrpcService {
+    read_only: ReadOnlySwitch,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl SystemGrpcService {
+    pub fn new(read_only: ReadOnlySwitch, event_bus: Arc<dyn EventBus>) -> Self {
+        Self { read_only, event_bus }
+    }
+}
+
+#[tonic::async_trait]
+impl SystemService for SystemGrpcService {
+    async fn get_read_only_mode(
+        &self,
+        _request: Request<GetReadOnlyModeRequest>,
+    ) -> Result<Response<ReadOnlyModeResponse>, Status> {
+        let log = grpc_log!("SystemService", "GetReadOnlyMode", &EmptyRequest {});
+        let trace_id = get_trace_id();
+
+        let enabled = self.read_only.is_enabled();
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: ReadOnlyModeLog { enabled } });
+        Ok(Response::new(ReadOnlyModeResponse { trace_id, enabled }))
+    }
+
+    async fn set_read_only_mode(
+        &self,
+        request: Request<SetReadOnlyModeRequest>,
+    ) -> Result<Response<ReadOnlyModeResponse>, Status> {
+        let req = request.into_inner();
+        let log = grpc_log!("SystemService", "SetReadOnlyMode", &ReadOnlyModeLog { enabled: req.enabled });
+        let trace_id = get_trace_id();
+
+        // 只在狀態實際改變時記錄到稽核紀錄
+        let previous = self.read_only.set(req.enabled);
+        if previous != req.enabled {
+            self.event_bus
+                .publish_system(SystemEvent::ReadOnlyModeChanged(ReadOnlyModeChangedEvent::new(req.enabled)))
+                .await;
+        }
+
+        log.ok(&ApiResponseLog { trace_id: &trace_id, data: ReadOnlyModeLog { enabled: req.enabled } });
+        Ok(Response::new(ReadOnlyModeResponse { trace_id, enabled: req.enabled }))
+    }
+}
+
+// ============ Log Structs ============
+
+#[derive(Serialize)]
+struct EmptyRequest {}
+
+#[derive(Serialize)]
+struct ReadOnlyModeLog {
+    enabled: bool,
+}
+
+#[derive(Serialize)]
+struct ApiResponseLog<'a, T: Serialize> {
+    trace_id: &'a str,
+    data: T,
+}
@@ -0,0 +1,448 @@
+//! TLS / mTLS support for the gRPC server
+//!
+//! - 從設定的 PEM 檔載入 server 憑證與私鑰
+//! - 設定 client CA 時啟用 mTLS，要求並驗證 client 憑證
+//! - 定期檢查憑證檔案的修改時間，變更時重新載入，不需重啟 server
+//! - 將 client 憑證的 subject 透過 `TlsPeerInfo` 放入 request extensions
+
+use std::io;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::server::WebPkiClientVerifier;
+use tokio_rustls::rustls::crypto::{aws_lc_rs, CryptoProvider};
+use tokio_rustls::rustls::{RootCertStore, ServerConfig};
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::server::Connected;
+use tracing::{error, info, warn};
+use x509_parser::objects::{oid2abbrev, oid_registry};
+use x509_parser::oid_registry::Oid;
+use x509_parser::prelude::{AttributeTypeAndValue, FromDer, X509Certificate};
+
+use crate::infrastructure::config::TlsConfig;
+
+/// TLS setup errors
+#[derive(Debug, thiserror::Error)]
+pub enum TlsError {
+    #[error("Failed to read {path}: {source}")]
+    Io { path: String, source: io::Error },
+
+    #[error("Invalid PEM in {path}: {message}")]
+    Pem { path: String, message: String },
+
+    #[error("Invalid TLS configuration: {0}")]
+    Config(String),
+}
+
+// ============ Reloadable Acceptor ============
+
+/// 單一連線完成 TLS handshake 的時限
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// accept 失敗（例如 EMFILE）後的等待時間，連續失敗時加倍直到上限
+const ACCEPT_BACKOFF_MIN: Duration = Duration::from_millis(5);
+const ACCEPT_BACKOFF_MAX: Duration = Duration::from_secs(1);
+
+/// TLS acceptor whose certificates can be swapped at runtime
+///
+/// 每個新連線都會取用當下的 `ServerConfig`，既有連線不受重新載入影響
+pub struct ReloadableTlsAcceptor {
+    config: TlsConfig,
+    server_config: RwLock<Arc<ServerConfig>>,
+    modified: RwLock<Vec<Option<SystemTime>>>,
+}
+
+impl ReloadableTlsAcceptor {
+    /// 載入憑證並建立 acceptor
+    pub fn load(config: TlsConfig) -> Result<Self, TlsError> {
+        let server_config = build_server_config(&config)?;
+        let modified = file_modified_times(&config);
+        Ok(Self {
+            config,
+            server_config: RwLock::new(Arc::new(server_config)),
+            modified: RwLock::new(modified),
+        })
+    }
+
+    /// 是否要求 client 憑證（mTLS）
+    pub fn is_mutual(&self) -> bool {
+        self.config.client_ca_path.is_some()
+    }
+
+    fn acceptor(&self) -> TlsAcceptor {
+        let server_config = self
+            .server_config
+            .read()
+            .map(|c| c.clone())
+            .unwrap_or_else(|e| e.into_inner().clone());
+        TlsAcceptor::from(server_config)
+    }
+
+    /// 檔案有變更時重新載入憑證
+    ///
+    /// 載入失敗時保留原本的設定並記錄錯誤，避免憑證更新到一半導致 server 無法接受連線
+    pub fn reload_if_changed(&self) -> bool {
+        let modified = file_modified_times(&self.config);
+        let changed = self
+            .modified
+            .read()
+            .map(|m| *m != modified)
+            .unwrap_or(true);
+        if !changed {
+            return false;
+        }
+
+        match build_server_config(&self.config) {
+            Ok(server_config) => {
+                if let Ok(mut current) = self.server_config.write() {
+                    *current = Arc::new(server_config);
+                }
+                if let Ok(mut m) = self.modified.write() {
+                    *m = modified;
+                }
+                info!("gRPC TLS certificates reloaded | cert: {}", self.config.cert_path);
+                true
+            }
+            Err(e) => {
+                error!("Failed to reload gRPC TLS certificates, keeping previous ones: {}", e);
+                false
+            }
+        }
+    }
+
+    /// 背景定期檢查憑證檔案
+    pub fn spawn_reload_watcher(self: &Arc<Self>) {
+        let interval = Duration::from_secs(self.config.reload_interval_secs);
+        if interval.is_zero() {
+            return;
+        }
+
+        let acceptor = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                acceptor.reload_if_changed();
+            }
+        });
+    }
+
+    /// 接受 TCP 連線並完成 TLS handshake
+    ///
+    /// handshake 在獨立 task 中進行，單一慢速或失敗的連線不會阻塞 accept loop
+    pub fn incoming(
+        self: Arc<Self>,
+        listener: TcpListener,
+    ) -> ReceiverStream<Result<TlsConnection, io::Error>> {
+        let (tx, rx) = mpsc::channel(64);
+
+        tokio::spawn(async move {
+            let mut backoff = ACCEPT_BACKOFF_MIN;
+            loop {
+                let (stream, remote_addr) = match listener.accept().await {
+                    Ok(conn) => {
+                        backoff = ACCEPT_BACKOFF_MIN;
+                        conn
+                    }
+                    Err(e) => {
+                        // 錯誤通常會持續一段時間（例如 file descriptor 用盡），立即重試只會空轉 CPU
+                        warn!("Failed to accept TCP connection, retrying in {:?}: {}", backoff, e);
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(ACCEPT_BACKOFF_MAX);
+                        continue;
+                    }
+                };
+
+                // server 已停止接收連線
+                if tx.is_closed() {
+                    break;
+                }
+
+                let acceptor = self.acceptor();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                        Ok(Ok(tls_stream)) => {
+                            let connection = TlsConnection::new(tls_stream, remote_addr);
+                            let _ = tx.send(Ok(connection)).await;
+                        }
+                        Ok(Err(e)) => {
+                            warn!("TLS handshake failed | peer: {} | error: {}", remote_addr, e);
+                        }
+                        Err(_) => {
+                            warn!("TLS handshake timed out | peer: {}", remote_addr);
+                        }
+                    }
+                });
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+fn file_modified_times(config: &TlsConfig) -> Vec<Option<SystemTime>> {
+    [Some(&config.cert_path), Some(&config.key_path), config.client_ca_path.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn read_file(path: &str) -> Result<Vec<u8>, TlsError> {
+    std::fs::read(Path::new(path)).map_err(|source| TlsError::Io {
+        path: path.to_string(),
+        source,
+    })
+}
+
+fn load_certs(path: &str) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+    let pem = read_file(path)?;
+    let certs = CertificateDer::pem_slice_iter(&pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| TlsError::Pem {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?;
+    if certs.is_empty() {
+        return Err(TlsError::Pem {
+            path: path.to_string(),
+            message: "no certificate found".to_string(),
+        });
+    }
+    Ok(certs)
+}
+
+fn load_private_key(path: &str) -> Result<PrivateKeyDer<'static>, TlsError> {
+    let pem = read_file(path)?;
+    PrivateKeyDer::from_pem_slice(&pem).map_err(|e| TlsError::Pem {
+        path: path.to_string(),
+        message: e.to_string(),
+    })
+}
+
+fn build_server_config(config: &TlsConfig) -> Result<ServerConfig, TlsError> {
+    let certs = load_certs(&config.cert_path)?;
+    let key = load_private_key(&config.key_path)?;
+
+    // 明確指定 crypto provider，不依賴 process 層級的預設值
+    let provider: Arc<CryptoProvider> = Arc::new(aws_lc_rs::default_provider());
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| TlsError::Config(e.to_string()))?;
+
+    let builder = match &config.client_ca_path {
+        Some(ca_path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in load_certs(ca_path)? {
+                roots
+                    .add(cert)
+                    .map_err(|e| TlsError::Config(format!("invalid client CA: {}", e)))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(|e| TlsError::Config(e.to_string()))?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder
+        .with_single_cert(certs, key)
+        .map_err(|e| TlsError::Config(e.to_string()))?;
+    server_config.alpn_protocols = vec![b"h2".to_vec()];
+    Ok(server_config)
+}
+
+// ============ Connection ============
+
+/// Connection info exposed to services through request extensions
+#[derive(Debug, Clone)]
+pub struct TlsPeerInfo {
+    pub remote_addr: SocketAddr,
+    /// Client 憑證的 subject（RFC 4514 格式），僅在 mTLS 時存在
+    pub subject: Option<String>,
+}
+
+/// TLS connection accepted by `ReloadableTlsAcceptor`
+pub struct TlsConnection {
+    inner: TlsStream<TcpStream>,
+    peer_info: TlsPeerInfo,
+}
+
+impl TlsConnection {
+    fn new(inner: TlsStream<TcpStream>, remote_addr: SocketAddr) -> Self {
+        let subject = inner
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certs| certs.first())
+            .and_then(|cert| certificate_subject(cert.as_ref()));
+        Self {
+            inner,
+            peer_info: TlsPeerInfo { remote_addr, subject },
+        }
+    }
+}
+
+impl Connected for TlsConnection {
+    type ConnectInfo = TlsPeerInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.peer_info.clone()
+    }
+}
+
+impl AsyncRead for TlsConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TlsConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
+
+// ============ Certificate Subject ============
+
+/// 從 DER 編碼的 X.509 憑證取出 subject，格式為 RFC 4514（例如 `CN=client,O=Acme`）
+pub fn certificate_subject(der: &[u8]) -> Option<String> {
+    let (_, certificate) = X509Certificate::from_der(der).ok()?;
+
+    let mut rdns: Vec<String> = certificate
+        .subject()
+        .iter_rdn()
+        .map(|rdn| {
+            rdn.iter()
+                .map(|attribute| {
+                    format!(
+                        "{}={}",
+                        attribute_name(attribute.attr_type()),
+                        attribute_value(attribute)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("+")
+        })
+        .collect();
+
+    // RFC 4514：由最後一個 RDN 開始輸出
+    rdns.reverse();
+    Some(rdns.join(","))
+}
+
+fn attribute_name(oid: &Oid) -> String {
+    oid2abbrev(oid, oid_registry())
+        .map(str::to_string)
+        .unwrap_or_else(|_| oid.to_id_string())
+}
+
+fn attribute_value(attribute: &AttributeTypeAndValue) -> String {
+    match attribute.as_str() {
+        Ok(value) => escape_value(value),
+        // 非字串型別依 RFC 4514 以 `#` 加上十六進位表示
+        Err(_) => format!(
+            "#{}",
+            attribute.as_slice().iter().map(|b| format!("{:02x}", b)).collect::<String>()
+        ),
+    }
+}
+
+fn escape_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for (i, c) in value.chars().enumerate() {
+        let needs_escape = matches!(c, ',' | '+' | '"' | '\\' | '<' | '>' | ';' | '=')
+            || (i == 0 && matches!(c, ' ' | '#'))
+            || (i == value.chars().count() - 1 && c == ' ');
+        if needs_escape {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Subject: C=TW, O=Acme, Inc., CN=ops-client
+    const CLIENT_CERT: &str = "-----BEGIN CERTIFICATE-----
+MIIBxDCCAWugAwIBAgIUbIrBn61SjCiY3rD5RVwesswL7WIwCgYIKoZIzj0EAwIw
+NzELMAkGA1UEBhMCVFcxEzARBgNVBAoMCkFjbWUsIEluYy4xEzARBgNVBAMMCm9w
+cy1jbGllbnQwIBcNMjYxMDE3MDI0OTQ5WhgPMjEyNjA5MjMwMjQ5NDlaMDcxCzAJ
+BgNVBAYTAlRXMRMwEQYDVQQKDApBY21lLCBJbmMuMRMwEQYDVQQDDApvcHMtY2xp
+ZW50MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEREIfJOKC8JKJNs3AX7AKr19Z
+HSKwGnbxcFaZ+QrS8/iFVAYUPaWzh4xdeotR1/AR2rfb9SxHPdtpExlAPoWGB6NT
+MFEwHQYDVR0OBBYEFPLhVRL0r2fiQruHAn9X354oBwxMMB8GA1UdIwQYMBaAFPLh
+VRL0r2fiQruHAn9X354oBwxMMA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwID
+RwAwRAIgOQ5UbwvfecK94229x90ddGe6QMW2+j5GpzNJYjI9xjECIBQ1fslHB++w
+41k8x0sbteiaPMcjIRxbWSo06ZNuPwXq
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_certificate_subject_rfc4514_order_and_escaping() {
+        let cert = CertificateDer::from_pem_slice(CLIENT_CERT.as_bytes()).unwrap();
+
+        assert_eq!(
+            certificate_subject(cert.as_ref()).as_deref(),
+            Some("CN=ops-client,O=Acme\\, Inc.,C=TW")
+        );
+    }
+
+    #[test]
+    fn test_escape_value() {
+        assert_eq!(escape_value("a+b"), "a\\+b");
+        assert_eq!(escape_value("#x "), "\\#x\\ ");
+        assert_eq!(escape_value("plain"), "plain");
+    }
+
+    #[test]
+    fn test_certificate_subject_invalid_der() {
+        assert_eq!(certificate_subject(&[0x30, 0x05, 0x01]), None);
+        assert_eq!(certificate_subject(&[]), None);
+    }
+}
@@ -5,6 +5,10 @@
 //! - Garage API client
 //! - S3 client for object operations
 //! - Repository implementations
+//! - Background tasks
+//! - Audit trail storage
+//! - Scheduled maintenance job storage
+//! - Confirmation tokens for destructive operations
 //! - Configuration
 //! - Logging
 //!
@@ -13,5 +17,9 @@
 pub mod grpc;
 pub mod garage;
 pub mod s3;
+pub mod tasks;
+pub mod audit;
+pub mod schedule;
+pub mod confirmation;
 pub mod config;
 pub mod logging;
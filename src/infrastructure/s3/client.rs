@@ -121,6 +121,7 @@ impl GarageS3Client {
     /// * `chunk_receiver` - Channel receiver for streaming chunks as bytes::Bytes
     /// * `part_size` - Size of each part (default 5MB, minimum 5MB required by S3)
     /// * `progress_sender` - Optional channel to send progress updates (upload_id, part_number, bytes_uploaded)
+    #[allow(clippy::too_many_arguments)]
     pub async fn upload_object_multipart(
         &self,
         bucket: &str,
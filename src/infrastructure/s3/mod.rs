@@ -3,5 +3,7 @@
 //! AWS SDK S3 client for Garage S3-compatible API
 
 pub mod client;
+pub mod pool;
 
 pub use client::*;
+pub use pool::S3ClientPool;
@@ -0,0 +1,277 @@
+//! Per-key S3 client pool
+//!
+//! 每個 Access Key 各自使用一個 S3 client，讓 Object 操作受 Garage 對該 Key 的
+//! Bucket 權限限制。快取以 Access Key ID 加上 secret 的雜湊為 key，
+//! 只有提供相同 secret 的呼叫者才會命中。Pool 有容量上限，閒置超過 TTL 的
+//! client 會被移除；容量已滿時移除最久未使用的 client。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::{AccessKeyQueryRepository, ObjectRepository, ObjectRepositoryProvider};
+use crate::domain::value_objects::S3Credentials;
+use crate::infrastructure::config::S3Config;
+use crate::infrastructure::garage::repositories::GarageObjectRepository;
+use crate::infrastructure::s3::GarageS3Client;
+
+/// Access Key ID 與 secret 的 SHA-256，避免在記憶體中以 secret 作為 key
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PoolKey {
+    access_key_id: String,
+    secret_hash: [u8; 32],
+}
+
+impl PoolKey {
+    fn new(access_key_id: &str, secret_access_key: &str) -> Self {
+        Self {
+            access_key_id: access_key_id.to_string(),
+            secret_hash: Sha256::digest(secret_access_key.as_bytes()).into(),
+        }
+    }
+}
+
+struct PoolEntry {
+    repository: Arc<dyn ObjectRepository>,
+    last_used: Instant,
+}
+
+/// S3 client pool keyed by access key ID and secret
+pub struct S3ClientPool {
+    config: S3Config,
+    default_repository: Arc<dyn ObjectRepository>,
+    key_repository: Arc<dyn AccessKeyQueryRepository>,
+    entries: Mutex<HashMap<PoolKey, PoolEntry>>,
+    max_size: usize,
+    ttl: Duration,
+}
+
+impl S3ClientPool {
+    pub async fn new(config: S3Config, key_repository: Arc<dyn AccessKeyQueryRepository>) -> Self {
+        let default_repository: Arc<dyn ObjectRepository> =
+            Arc::new(GarageObjectRepository::new(&config).await);
+
+        Self {
+            max_size: config.client_pool_size.max(1),
+            ttl: Duration::from_secs(config.client_pool_ttl_secs),
+            config,
+            default_repository,
+            key_repository,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 目前快取的 client 數量
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("s3 client pool poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 取得相同 Key 與 secret 的快取 client
+    fn lookup(&self, key: &PoolKey) -> Option<Arc<dyn ObjectRepository>> {
+        let mut entries = self.entries.lock().expect("s3 client pool poisoned");
+        self.evict_expired(&mut entries);
+
+        let entry = entries.get_mut(key)?;
+        entry.last_used = Instant::now();
+        Some(entry.repository.clone())
+    }
+
+    fn insert(&self, key: PoolKey, repository: Arc<dyn ObjectRepository>) {
+        let mut entries = self.entries.lock().expect("s3 client pool poisoned");
+        self.evict_expired(&mut entries);
+
+        if !entries.contains_key(&key) && entries.len() >= self.max_size {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                debug!(access_key_id = %oldest.access_key_id, "S3 client pool full, evicting least recently used client");
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(key, PoolEntry {
+            repository,
+            last_used: Instant::now(),
+        });
+    }
+
+    fn evict_expired(&self, entries: &mut HashMap<PoolKey, PoolEntry>) {
+        if self.ttl.is_zero() {
+            entries.clear();
+            return;
+        }
+        entries.retain(|_, e| e.last_used.elapsed() < self.ttl);
+    }
+
+    async fn build_repository(&self, access_key_id: &str, secret_access_key: &str) -> Arc<dyn ObjectRepository> {
+        let config = S3Config {
+            access_key_id: access_key_id.to_string(),
+            secret_access_key: secret_access_key.to_string(),
+            ..self.config.clone()
+        };
+        let client = GarageS3Client::new(&config).await;
+        Arc::new(GarageObjectRepository::from_client(client))
+    }
+}
+
+#[async_trait]
+impl ObjectRepositoryProvider for S3ClientPool {
+    async fn resolve(
+        &self,
+        credentials: Option<&S3Credentials>,
+    ) -> Result<Arc<dyn ObjectRepository>, DomainError> {
+        let Some(credentials) = credentials else {
+            return Ok(self.default_repository.clone());
+        };
+        let access_key_id = credentials.access_key_id();
+
+        // 未提供 secret 時透過 Admin API 查詢（呼叫端需先確認權限）；
+        // 每次都重新查詢，Key 的 secret 變更後不會沿用舊 client
+        let secret = match credentials.secret_access_key() {
+            Some(secret) => secret.to_string(),
+            None => self.key_repository.find_secret(access_key_id).await?,
+        };
+
+        let key = PoolKey::new(access_key_id, &secret);
+        if let Some(repository) = self.lookup(&key) {
+            return Ok(repository);
+        }
+
+        let repository = self.build_repository(access_key_id, &secret).await;
+        self.insert(key, repository.clone());
+        Ok(repository)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use crate::domain::entities::{AccessKey, garage::KeyListItemResponse};
+
+    const SECRET: &str = "b892c0665f0ada8a4755dae98baa3b133590e11dae3bcc1f9d769d67f16c3835";
+
+    /// 只回傳固定 secret 並記錄查詢次數
+    #[derive(Default)]
+    struct SecretLookup {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AccessKeyQueryRepository for SecretLookup {
+        async fn list(&self) -> Result<Vec<KeyListItemResponse>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn find_by_id(&self, id: &str) -> Result<AccessKey, DomainError> {
+            Err(DomainError::AccessKeyNotFound(id.to_string()))
+        }
+
+        async fn find_secret(&self, _id: &str) -> Result<String, DomainError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(SECRET.to_string())
+        }
+    }
+
+    fn key_id(n: usize) -> String {
+        format!("GK{:024x}", n)
+    }
+
+    async fn pool(size: usize, ttl_secs: u64, lookup: Arc<SecretLookup>) -> S3ClientPool {
+        let config = S3Config {
+            endpoint_url: "http://localhost:3900".to_string(),
+            region: "garage".to_string(),
+            access_key_id: key_id(0),
+            secret_access_key: SECRET.to_string(),
+            client_pool_size: size,
+            client_pool_ttl_secs: ttl_secs,
+        };
+        S3ClientPool::new(config, lookup).await
+    }
+
+    #[tokio::test]
+    async fn test_default_repository_without_credentials() {
+        let pool = pool(4, 60, Arc::default()).await;
+        pool.resolve(None).await.unwrap();
+        assert!(pool.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reuses_client_for_looked_up_secret() {
+        let lookup = Arc::new(SecretLookup::default());
+        let pool = pool(4, 60, lookup.clone()).await;
+        let creds = S3Credentials::new(key_id(1), None).unwrap();
+
+        let first = pool.resolve(Some(&creds)).await.unwrap();
+        let second = pool.resolve(Some(&creds)).await.unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(lookup.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_caller_secret_skips_lookup() {
+        let lookup = Arc::new(SecretLookup::default());
+        let pool = pool(4, 60, lookup.clone()).await;
+        let creds = S3Credentials::new(key_id(1), Some(SECRET.to_string())).unwrap();
+
+        let first = pool.resolve(Some(&creds)).await.unwrap();
+        let second = pool.resolve(Some(&creds)).await.unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(lookup.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_secret_neither_hits_nor_replaces_cached_client() {
+        let pool = pool(4, 60, Arc::default()).await;
+        let valid = S3Credentials::new(key_id(1), Some(SECRET.to_string())).unwrap();
+        let wrong = S3Credentials::new(key_id(1), Some("0".repeat(64))).unwrap();
+
+        let first = pool.resolve(Some(&valid)).await.unwrap();
+        let second = pool.resolve(Some(&wrong)).await.unwrap();
+
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(pool.len(), 2);
+        assert!(Arc::ptr_eq(&first, &pool.resolve(Some(&valid)).await.unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_evicts_least_recently_used_when_full() {
+        let pool = pool(2, 60, Arc::default()).await;
+        let creds: Vec<_> = (1..=3).map(|n| S3Credentials::new(key_id(n), None).unwrap()).collect();
+
+        let first = pool.resolve(Some(&creds[0])).await.unwrap();
+        pool.resolve(Some(&creds[1])).await.unwrap();
+        // 使用 key 1，讓 key 2 成為最久未使用
+        pool.resolve(Some(&creds[0])).await.unwrap();
+        pool.resolve(Some(&creds[2])).await.unwrap();
+
+        assert_eq!(pool.len(), 2);
+        assert!(Arc::ptr_eq(&first, &pool.resolve(Some(&creds[0])).await.unwrap()));
+        assert!(pool.lookup(&PoolKey::new(creds[1].access_key_id(), SECRET)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_zero_ttl_disables_caching() {
+        let pool = pool(4, 0, Arc::default()).await;
+        let creds = S3Credentials::new(key_id(1), None).unwrap();
+
+        let first = pool.resolve(Some(&creds)).await.unwrap();
+        let second = pool.resolve(Some(&creds)).await.unwrap();
+
+        assert!(!Arc::ptr_eq(&first, &second));
+    }
+}
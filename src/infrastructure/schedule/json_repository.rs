@@ -0,0 +1,235 @@
+//! JSON file schedule repository
+//!
+//! 所有排程以 JSON 陣列保存在單一檔案並快取於記憶體。
+//! 每次修改先寫入暫存檔、`fsync` 後再 rename，避免寫入中斷留下損壞的檔案。
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::domain::entities::{Schedule, ScheduleRun};
+use crate::domain::errors::DomainError;
+use crate::domain::repositories::ScheduleRepository;
+
+struct Inner {
+    path: PathBuf,
+    schedules: Mutex<Vec<Schedule>>,
+}
+
+/// Schedule repository backed by a local JSON file
+pub struct JsonScheduleRepository {
+    inner: Arc<Inner>,
+}
+
+impl JsonScheduleRepository {
+    /// 載入既有檔案；檔案不存在時從空白開始，內容無法解析時回傳錯誤而不是覆寫
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, DomainError> {
+        let path = path.into();
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+        }
+
+        let mut schedules: Vec<Schedule> = match fs::read(&path) {
+            Ok(content) => serde_json::from_slice(&content).map_err(|e| {
+                DomainError::InternalError(format!("Schedule file {}: {}", path.display(), e))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(io_error(&path, e)),
+        };
+        schedules.sort_by_key(|s| s.created_at);
+        info!(path = %path.display(), schedules = schedules.len(), "Loaded schedules");
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                path,
+                schedules: Mutex::new(schedules),
+            }),
+        })
+    }
+
+    /// 在 blocking thread 上修改並寫回檔案，寫入失敗時記憶體內容不變
+    async fn modify<T, F>(&self, f: F) -> Result<T, DomainError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Vec<Schedule>) -> Result<T, DomainError> + Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || inner.modify(f))
+            .await
+            .map_err(|e| DomainError::InternalError(e.to_string()))?
+    }
+}
+
+impl Inner {
+    fn modify<T>(&self, f: impl FnOnce(&mut Vec<Schedule>) -> Result<T, DomainError>) -> Result<T, DomainError> {
+        let mut schedules = self.schedules.lock().expect("schedules poisoned");
+
+        let mut updated = schedules.clone();
+        let result = f(&mut updated)?;
+        self.persist(&updated)?;
+        *schedules = updated;
+        Ok(result)
+    }
+
+    fn persist(&self, schedules: &[Schedule]) -> Result<(), DomainError> {
+        let content = serde_json::to_vec_pretty(schedules)
+            .map_err(|e| DomainError::InternalError(format!("Failed to serialize schedules: {}", e)))?;
+
+        let tmp = self.path.with_extension("tmp");
+        let mut file = File::create(&tmp).map_err(|e| io_error(&tmp, e))?;
+        file.write_all(&content).map_err(|e| io_error(&tmp, e))?;
+        file.sync_all().map_err(|e| io_error(&tmp, e))?;
+        fs::rename(&tmp, &self.path).map_err(|e| io_error(&self.path, e))
+    }
+
+    fn snapshot(&self) -> Vec<Schedule> {
+        self.schedules.lock().expect("schedules poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl ScheduleRepository for JsonScheduleRepository {
+    async fn list(&self) -> Result<Vec<Schedule>, DomainError> {
+        Ok(self.inner.snapshot())
+    }
+
+    async fn get(&self, id: &str) -> Result<Schedule, DomainError> {
+        self.inner
+            .snapshot()
+            .into_iter()
+            .find(|s| s.id == id)
+            .ok_or_else(|| DomainError::ScheduleNotFound(id.to_string()))
+    }
+
+    async fn save(&self, schedule: &Schedule) -> Result<(), DomainError> {
+        let schedule = schedule.clone();
+        self.modify(move |schedules| {
+            match schedules.iter_mut().find(|s| s.id == schedule.id) {
+                Some(existing) => *existing = schedule,
+                None => schedules.push(schedule),
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), DomainError> {
+        let id = id.to_string();
+        self.modify(move |schedules| {
+            let before = schedules.len();
+            schedules.retain(|s| s.id != id);
+            if schedules.len() == before {
+                return Err(DomainError::ScheduleNotFound(id));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn record_run(&self, id: &str, run: &ScheduleRun) -> Result<Schedule, DomainError> {
+        let id = id.to_string();
+        let run = run.clone();
+        self.modify(move |schedules| {
+            let schedule = schedules
+                .iter_mut()
+                .find(|s| s.id == id)
+                .ok_or(DomainError::ScheduleNotFound(id))?;
+            schedule.last_run = Some(run);
+            Ok(schedule.clone())
+        })
+        .await
+    }
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> DomainError {
+    DomainError::InternalError(format!("Schedule store {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::domain::entities::{CronExpression, ScheduleAction, ScheduleTrigger};
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "garage-ui-schedule-{}-{}",
+                name,
+                crate::shared::generate_trace_id()
+            ));
+            Self(dir)
+        }
+
+        fn file(&self) -> PathBuf {
+            self.0.join("schedules.json")
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn schedule(id: &str) -> Schedule {
+        Schedule::new(
+            id.to_string(),
+            format!("Snapshot {}", id),
+            CronExpression::parse("0 2 * * *").unwrap(),
+            ScheduleAction::CreateMetadataSnapshot { node: "*".to_string() },
+            true,
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_persists_across_reload() {
+        let dir = TempDir::new("reload");
+        let repo = JsonScheduleRepository::new(dir.file()).unwrap();
+
+        repo.save(&schedule("a")).await.unwrap();
+        repo.save(&schedule("b")).await.unwrap();
+        let run = ScheduleRun {
+            trigger: ScheduleTrigger::Manual,
+            started_at: Utc::now(),
+            finished_at: Utc::now(),
+            success: true,
+            summary: "3/3 nodes succeeded".to_string(),
+            error: None,
+        };
+        repo.record_run("a", &run).await.unwrap();
+        repo.delete("b").await.unwrap();
+
+        let reloaded = JsonScheduleRepository::new(dir.file()).unwrap();
+        let schedules = reloaded.list().await.unwrap();
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].last_run, Some(run));
+        assert!(!dir.file().with_extension("tmp").exists());
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let dir = TempDir::new("not-found");
+        let repo = JsonScheduleRepository::new(dir.file()).unwrap();
+
+        assert!(matches!(repo.get("missing").await, Err(DomainError::ScheduleNotFound(_))));
+        assert!(matches!(repo.delete("missing").await, Err(DomainError::ScheduleNotFound(_))));
+    }
+
+    #[test]
+    fn test_rejects_corrupt_file() {
+        let dir = TempDir::new("corrupt");
+        fs::create_dir_all(&dir.0).unwrap();
+        fs::write(dir.file(), b"[{\"id\":").unwrap();
+
+        assert!(JsonScheduleRepository::new(dir.file()).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Schedule storage
+//!
+//! 排程設定保存在本機 JSON 檔案
+
+mod json_repository;
+
+pub use json_repository::JsonScheduleRepository;
@@ -0,0 +1,79 @@
+//! Cluster state watcher
+//!
+//! 單一背景輪詢集群狀態，發布狀態變化事件，並將最新快照分享給所有 WatchCluster 訂閱者
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+use crate::application::commands::cluster::ObserveClusterCommand;
+use crate::application::commands::cluster::handlers::ObserveClusterHandler;
+use crate::domain::entities::ClusterSnapshot;
+use crate::domain::events::EventBus;
+use crate::infrastructure::garage::{GarageClient, GarageClusterRepository};
+
+/// 最新快照；第一次輪詢完成前為 `None`
+pub type ClusterSnapshotReceiver = watch::Receiver<Option<Arc<ClusterSnapshot>>>;
+
+/// Background task polling cluster health and status
+pub struct ClusterWatcher {
+    handler: Arc<ObserveClusterHandler>,
+    interval: Duration,
+}
+
+/// 訂閱 [`ClusterWatcher`] 快照的 handle，可自由 clone
+#[derive(Clone)]
+pub struct ClusterWatch {
+    receiver: Option<ClusterSnapshotReceiver>,
+}
+
+impl ClusterWatcher {
+    pub fn new(client: GarageClient, event_bus: Arc<dyn EventBus>, interval_secs: u64) -> Self {
+        let handler = Arc::new(ObserveClusterHandler::new(
+            Arc::new(GarageClusterRepository::new(client)),
+            event_bus,
+        ));
+
+        Self {
+            handler,
+            interval: Duration::from_secs(interval_secs),
+        }
+    }
+
+    /// 在背景定期執行，間隔為 0 時不啟動
+    pub fn spawn(self) -> ClusterWatch {
+        if self.interval.is_zero() {
+            info!("Cluster watcher disabled");
+            return ClusterWatch::disabled();
+        }
+
+        let (sender, receiver) = watch::channel(None);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            loop {
+                ticker.tick().await;
+                // 沒有訂閱者時也持續輪詢，事件仍需發布；失敗時的快照同樣送出，讓訂閱者得知無法連線
+                let snapshot = self.handler.handle(ObserveClusterCommand::new()).await;
+                if let Some(error) = &snapshot.poll_error {
+                    warn!("Cluster watch poll failed: {}", error);
+                }
+                sender.send_replace(Some(Arc::new(snapshot)));
+            }
+        });
+        info!("Cluster watcher started");
+
+        ClusterWatch { receiver: Some(receiver) }
+    }
+}
+
+impl ClusterWatch {
+    pub fn disabled() -> Self {
+        Self { receiver: None }
+    }
+
+    /// 未啟用輪詢時回傳 `None`
+    pub fn subscribe(&self) -> Option<ClusterSnapshotReceiver> {
+        self.receiver.clone()
+    }
+}
@@ -0,0 +1,92 @@
+//! Access Key expiration watcher
+//!
+//! 定期執行到期檢查：發出即將到期 / 已到期事件，並依設定刪除過期的 Key
+
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::application::commands::access_key::CheckKeyExpirationCommand;
+use crate::application::commands::access_key::handlers::CheckKeyExpirationHandler;
+use crate::domain::entities::KeyExpirationPolicy;
+use crate::domain::events::EventBus;
+use crate::infrastructure::config::KeyExpirationConfig;
+use crate::infrastructure::grpc::read_only::ReadOnlySwitch;
+use crate::infrastructure::garage::{
+    GarageClient, GarageAccessKeyCommandRepository, GarageAccessKeyQueryRepository,
+    GarageBucketRepository,
+};
+
+/// Background task for access key expiration
+pub struct KeyExpirationWatcher {
+    handler: Arc<CheckKeyExpirationHandler>,
+    interval: Duration,
+    read_only: ReadOnlySwitch,
+}
+
+impl KeyExpirationWatcher {
+    pub fn new(
+        client: GarageClient,
+        event_bus: Arc<dyn EventBus>,
+        config: KeyExpirationConfig,
+        read_only: ReadOnlySwitch,
+    ) -> Self {
+        let policy = KeyExpirationPolicy::new(
+            config.warn_lead_secs.iter().filter_map(|s| config_duration(*s)).collect(),
+            config.purge_after_secs.and_then(config_duration),
+        );
+
+        let handler = Arc::new(CheckKeyExpirationHandler::new(
+            Arc::new(GarageAccessKeyQueryRepository::new(client.clone())),
+            Arc::new(GarageAccessKeyCommandRepository::new(client.clone())),
+            Arc::new(GarageBucketRepository::new(client)),
+            event_bus,
+            policy,
+        ));
+
+        Self {
+            handler,
+            interval: Duration::from_secs(config.interval_secs),
+            read_only,
+        }
+    }
+
+    /// 在背景定期執行，間隔為 0 時不啟動
+    pub fn spawn(self) {
+        if self.interval.is_zero() {
+            info!("Key expiration watcher disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            loop {
+                ticker.tick().await;
+                // 唯讀模式下仍發出通知，但不刪除 Key
+                let command = CheckKeyExpirationCommand::new()
+                    .with_purge_paused(self.read_only.is_enabled());
+                match self.handler.handle(command).await {
+                    Ok(report) => info!(
+                        expiring_soon = report.expiring_soon,
+                        expired = report.expired,
+                        deleted = report.deleted.len(),
+                        retained = report.retained.len(),
+                        skipped_read_only = report.skipped.len(),
+                        "Key expiration check finished"
+                    ),
+                    Err(e) => error!("Key expiration check failed: {}", e),
+                }
+            }
+        });
+        info!("Key expiration watcher started");
+    }
+}
+
+/// 設定的秒數轉為 `chrono::Duration`，超出範圍的值忽略並記錄
+fn config_duration(secs: u64) -> Option<chrono::Duration> {
+    let duration = i64::try_from(secs).ok().and_then(chrono::Duration::try_seconds);
+    if duration.is_none() {
+        warn!("Ignoring out-of-range key expiration setting: {} seconds", secs);
+    }
+    duration
+}
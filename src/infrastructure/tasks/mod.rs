@@ -0,0 +1,11 @@
+//! Background tasks
+//!
+//! 與 gRPC server 一同啟動的定期背景任務
+
+mod cluster_watcher;
+mod key_expiration;
+mod scheduler;
+
+pub use cluster_watcher::{ClusterSnapshotReceiver, ClusterWatch, ClusterWatcher};
+pub use key_expiration::KeyExpirationWatcher;
+pub use scheduler::Scheduler;
@@ -0,0 +1,82 @@
+//! Scheduled maintenance job runner
+//!
+//! 依各排程的 cron 表示式執行維運操作。每輪執行 `(上一次成功的一輪, 現在]` 之間到期的排程，
+//! 再睡到最早的下一次觸發時間；新增或修改的排程最晚在 `MAX_IDLE` 後生效。
+//! 某一輪失敗（例如讀不到排程檔）時不推進區間，恢復後到期的排程各補執行一次。
+
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use tracing::{error, info, warn};
+
+use crate::application::commands::schedule::RunDueSchedulesCommand;
+use crate::application::commands::schedule::handlers::{RunDueSchedulesHandler, RunScheduleHandler};
+use crate::domain::repositories::ScheduleRepository;
+use crate::infrastructure::grpc::read_only::ReadOnlySwitch;
+
+/// 兩輪檢查之間的最長間隔
+const MAX_IDLE: Duration = Duration::from_secs(10);
+
+/// Background task for scheduled maintenance jobs
+pub struct Scheduler {
+    handler: Arc<RunDueSchedulesHandler>,
+    read_only: ReadOnlySwitch,
+    enabled: bool,
+}
+
+impl Scheduler {
+    /// `run_schedule_handler` 與 ScheduleService 的手動執行共用
+    pub fn new(
+        repository: Arc<dyn ScheduleRepository>,
+        run_schedule_handler: Arc<RunScheduleHandler>,
+        read_only: ReadOnlySwitch,
+        enabled: bool,
+    ) -> Self {
+        Self {
+            handler: Arc::new(RunDueSchedulesHandler::new(repository, run_schedule_handler)),
+            read_only,
+            enabled,
+        }
+    }
+
+    /// 在背景依 cron 執行，停用時不啟動
+    pub fn spawn(self) {
+        if !self.enabled {
+            info!("Scheduler disabled, schedules only run when triggered manually");
+            return;
+        }
+
+        tokio::spawn(async move {
+            let mut since = Utc::now();
+            loop {
+                let now = Utc::now();
+                // 唯讀模式下到期的排程仍會記錄一次略過的執行，方便事後追查
+                let paused = self.read_only.is_enabled();
+                let command = RunDueSchedulesCommand::new(since, now).with_paused(paused);
+                let next_run_at = match self.handler.handle(command).await {
+                    Ok(report) => {
+                        for schedule in &report.ran {
+                            if paused {
+                                warn!(schedule_id = %schedule.id, name = %schedule.name, "Scheduled job skipped: read-only mode is enabled");
+                            } else {
+                                info!(schedule_id = %schedule.id, name = %schedule.name, "Scheduled job finished");
+                            }
+                        }
+                        since = now;
+                        report.next_run_at
+                    }
+                    Err(e) => {
+                        error!("Scheduler pass failed, retrying since {}: {}", since, e);
+                        None
+                    }
+                };
+
+                let wait = next_run_at
+                    .and_then(|next| (next - Utc::now()).to_std().ok())
+                    .map_or(MAX_IDLE, |wait| wait.min(MAX_IDLE));
+                tokio::time::sleep(wait).await;
+            }
+        });
+        info!("Scheduler started");
+    }
+}
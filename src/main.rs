@@ -7,10 +7,15 @@ use tracing::info;
 use garage_ui::infrastructure::{
     config::AppConfig,
     garage::GarageClient,
-    grpc::GrpcServer,
+    grpc::{GrpcServer, composition::MaintenanceHandlers, read_only::ReadOnlySwitch},
     logging::init_logging,
+    tasks::{ClusterWatcher, KeyExpirationWatcher, Scheduler},
+    audit::NdjsonAuditRepository,
+    schedule::JsonScheduleRepository,
+    confirmation::HmacConfirmationTokenRepository,
 };
-use garage_ui::domain::events::{ChannelEventBus, EventProcessor, LoggingEventHandler};
+use garage_ui::domain::events::{AuditEventHandler, ChannelEventBus, EventProcessor, LoggingEventHandler};
+use garage_ui::domain::repositories::{AuditRepository, ConfirmationTokenRepository, ScheduleRepository};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -29,6 +34,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         config.s3_config.endpoint_url
     );
 
+    // Create audit trail store
+    let audit_repository: Arc<dyn AuditRepository> = Arc::new(NdjsonAuditRepository::new(
+        &config.audit_config.dir,
+        config.audit_config.retention_days,
+    )?);
+
+    // Load scheduled maintenance jobs
+    let schedule_repository: Arc<dyn ScheduleRepository> =
+        Arc::new(JsonScheduleRepository::new(&config.schedule_config.file)?);
+
+    // Create confirmation token issuer for destructive operations
+    let confirmations: Arc<dyn ConfirmationTokenRepository> =
+        Arc::new(HmacConfirmationTokenRepository::new(&config.confirmation_config)?);
+
     // Create event bus and processor
     let (event_bus, receiver) = ChannelEventBus::new();
     let event_bus = Arc::new(event_bus);
@@ -36,6 +55,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Start event processor in background
     let event_processor = EventProcessor::new(vec![
         Box::new(LoggingEventHandler),
+        Box::new(AuditEventHandler::new(audit_repository.clone())),
         // Add more event handlers here
     ]);
     tokio::spawn(async move {
@@ -44,13 +64,47 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Event processor started");
 
     // Create Garage client
-    let garage_client = GarageClient::new(config.garage_api_url, config.garage_api_key);
+    let garage_client = GarageClient::new(config.garage_api_url, config.garage_api_key)
+        .with_max_concurrency(config.garage_api_max_concurrency);
+
+    // Runtime-toggleable read-only mode, shared by the gRPC layer and background writers
+    let read_only = ReadOnlySwitch::new(config.read_only);
+
+    // Start access key expiration watcher in background
+    KeyExpirationWatcher::new(
+        garage_client.clone(),
+        event_bus.clone(),
+        config.key_expiration_config,
+        read_only.clone(),
+    ).spawn();
+
+    // Start shared cluster state poller for WatchCluster subscribers
+    let cluster_watch = ClusterWatcher::new(
+        garage_client.clone(),
+        event_bus.clone(),
+        config.cluster_config.watch_interval_secs,
+    ).spawn();
+
+    // Maintenance handlers shared by the gRPC services and the scheduler
+    let maintenance = MaintenanceHandlers::new(
+        garage_client.clone(),
+        event_bus.clone(),
+        schedule_repository.clone(),
+    );
+
+    // Start scheduled maintenance job runner
+    Scheduler::new(
+        schedule_repository.clone(),
+        maintenance.run_schedule.clone(),
+        read_only.clone(),
+        config.schedule_config.enabled,
+    ).spawn();
 
     // Parse server address
     let addr: SocketAddr = config.grpc_server_addr.parse()?;
 
     // Create and run gRPC server with S3 config for object operations
-    let server = GrpcServer::new(addr, garage_client, event_bus, config.s3_config);
+    let server = GrpcServer::new(addr, garage_client, event_bus, audit_repository, confirmations, config.s3_config, config.auth_config, config.rate_limit_config, config.tls_config, read_only, config.cluster_config, cluster_watch, schedule_repository, maintenance);
     server.run().await?;
 
     Ok(())
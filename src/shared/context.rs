@@ -15,17 +15,39 @@ task_local! {
 #[derive(Clone, Debug)]
 pub struct TraceContext {
     pub trace_id: String,
+    /// 已通過認證的呼叫者（Admin Token ID 或名稱）
+    pub caller: Option<String>,
+    /// mTLS client 憑證的 subject
+    pub peer_subject: Option<String>,
 }
 
 impl TraceContext {
     pub fn new() -> Self {
         Self {
             trace_id: generate_trace_id(),
+            caller: None,
+            peer_subject: None,
         }
     }
 
     pub fn with_trace_id(trace_id: String) -> Self {
-        Self { trace_id }
+        Self {
+            trace_id,
+            caller: None,
+            peer_subject: None,
+        }
+    }
+
+    /// 附加已認證的呼叫者
+    pub fn with_caller(mut self, caller: String) -> Self {
+        self.caller = Some(caller);
+        self
+    }
+
+    /// 附加 mTLS client 憑證的 subject
+    pub fn with_peer_subject(mut self, peer_subject: Option<String>) -> Self {
+        self.peer_subject = peer_subject;
+        self
     }
 }
 
@@ -50,6 +72,27 @@ pub fn get_trace_id() -> String {
         .unwrap_or_else(|_| generate_trace_id())
 }
 
+/// Get a copy of the current trace context, if any
+pub fn current_context() -> Option<TraceContext> {
+    TRACE_CONTEXT.try_with(|ctx| ctx.clone()).ok()
+}
+
+/// Get the authenticated caller of the current request, if any
+pub fn get_caller() -> Option<String> {
+    TRACE_CONTEXT
+        .try_with(|ctx| ctx.caller.clone())
+        .ok()
+        .flatten()
+}
+
+/// Get the mTLS client certificate subject of the current request, if any
+pub fn get_peer_subject() -> Option<String> {
+    TRACE_CONTEXT
+        .try_with(|ctx| ctx.peer_subject.clone())
+        .ok()
+        .flatten()
+}
+
 /// Check if we're currently in a trace context
 pub fn has_context() -> bool {
     TRACE_CONTEXT.try_with(|_| ()).is_ok()
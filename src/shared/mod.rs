@@ -7,15 +7,23 @@
 //! - `update_field`: 更新欄位三態語義
 //! - `trace_id`: 請求追蹤 ID 生成
 //! - `context`: 請求上下文
+//! - `redact`: log 敏感欄位遮蔽
+//! - `byte_size`: 檔案大小格式化
 
+mod byte_size;
 mod context;
 mod datetime;
 mod pagination;
+mod redact;
 mod trace_id;
 mod update_field;
 
-pub use context::{get_trace_id, has_context, with_context, TraceContext};
+pub use byte_size::format_bytes;
+pub use context::{
+    current_context, get_caller, get_peer_subject, get_trace_id, has_context, with_context, TraceContext,
+};
 pub use datetime::parse_datetime;
-pub use pagination::{paginate, PaginationResult};
+pub use pagination::{paginate, paginate_with_info, PaginationResult};
+pub use redact::{is_sensitive_field, redact_text, redact_value, redacted, to_redacted_json, REDACTED};
 pub use trace_id::{generate_trace_id, parse_trace_id_time, trace_id_to_time_string};
 pub use update_field::UpdateField;
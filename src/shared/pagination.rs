@@ -22,7 +22,7 @@ impl<T> PaginationResult<T> {
         let total_pages = if page_size == 0 {
             0
         } else {
-            (total + page_size - 1) / page_size
+            total.div_ceil(page_size)
         };
         
         Self {
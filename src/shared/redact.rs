@@ -0,0 +1,215 @@
+//! Secret redaction for logs
+//!
+//! 在任何內容寫入 `tracing` 之前遮蔽敏感欄位，兩種機制並用：
+//!
+//! - 欄位名稱黑名單：序列化成 JSON 後，遞迴遮蔽名稱命中黑名單的欄位
+//!   （不分大小寫，忽略 `_` / `-`，因此 `secret_access_key` 與 `secretAccessKey` 皆會命中）
+//! - 型別標註：在 log struct 欄位上加 `#[serde(serialize_with = "crate::shared::redacted")]`，
+//!   不論欄位名稱為何都輸出遮蔽字串
+
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+/// 取代敏感值的字串
+pub const REDACTED: &str = "[REDACTED]";
+
+/// 敏感欄位名稱（已正規化：小寫、去除 `_` 與 `-`）
+const SENSITIVE_FIELDS: &[&str] = &[
+    // S3 credentials
+    "secretaccesskey",
+    "secretkey",
+    "sessiontoken",
+    // Garage admin / metrics token
+    "secrettoken",
+    "admintoken",
+    "metricstoken",
+    "rpcsecret",
+    "apikey",
+    // 破壞性操作確認 token
+    "confirmationtoken",
+    // HTTP headers
+    "authorization",
+    "proxyauthorization",
+    "cookie",
+    "setcookie",
+    // 通用
+    "secret",
+    "password",
+];
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 欄位名稱是否屬於敏感欄位
+pub fn is_sensitive_field(name: &str) -> bool {
+    let normalized = normalize(name);
+    SENSITIVE_FIELDS.contains(&normalized.as_str())
+}
+
+/// 遞迴遮蔽 JSON 中的敏感欄位
+pub fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                if is_sensitive_field(key) {
+                    if !v.is_null() {
+                        *v = Value::String(REDACTED.to_string());
+                    }
+                } else {
+                    redact_value(v);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+/// 序列化為已遮蔽的 JSON 字串，失敗時回傳 `{}`
+pub fn to_redacted_json<T: Serialize + ?Sized>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(mut v) => {
+            redact_value(&mut v);
+            v.to_string()
+        }
+        Err(_) => "{}".to_string(),
+    }
+}
+
+/// 遮蔽任意文字（例如 Garage 回應 body 或錯誤訊息）
+///
+/// 可解析為 JSON 時依欄位名稱遮蔽；否則只遮蔽 `Bearer <token>`
+pub fn redact_text(text: &str) -> String {
+    match serde_json::from_str::<Value>(text) {
+        Ok(mut v) if v.is_object() || v.is_array() => {
+            redact_value(&mut v);
+            v.to_string()
+        }
+        _ => redact_bearer(text),
+    }
+}
+
+/// 遮蔽文字中的 `Bearer <token>`
+fn redact_bearer(text: &str) -> String {
+    const SCHEME: &str = "bearer ";
+
+    let lower = text.to_ascii_lowercase();
+    let mut out = String::with_capacity(text.len());
+    let mut rest = 0;
+
+    while let Some(pos) = lower[rest..].find(SCHEME) {
+        let token_start = rest + pos + SCHEME.len();
+        let token_end = text[token_start..]
+            .find(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == ',')
+            .map(|i| token_start + i)
+            .unwrap_or(text.len());
+
+        out.push_str(&text[rest..token_start]);
+        if token_end > token_start {
+            out.push_str(REDACTED);
+        }
+        rest = token_end;
+    }
+    out.push_str(&text[rest..]);
+    out
+}
+
+/// serde `serialize_with` 用：不論值為何都輸出遮蔽字串
+///
+/// ```ignore
+/// #[derive(Serialize)]
+/// struct LoginLog<'a> {
+///     #[serde(serialize_with = "crate::shared::redacted")]
+///     credential: &'a str,
+/// }
+/// ```
+pub fn redacted<T, S>(_value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized,
+    S: Serializer,
+{
+    serializer.serialize_str(REDACTED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SECRET: &str = "a1b2c3d4e5f6-super-secret";
+
+    #[test]
+    fn test_field_name_matching() {
+        assert!(is_sensitive_field("secret_access_key"));
+        assert!(is_sensitive_field("secretAccessKey"));
+        assert!(is_sensitive_field("Authorization"));
+        assert!(is_sensitive_field("secret-token"));
+        assert!(is_sensitive_field("confirmationToken"));
+        assert!(!is_sensitive_field("access_key_id"));
+        assert!(!is_sensitive_field("name"));
+    }
+
+    #[test]
+    fn test_redact_nested_json() {
+        let value = json!({
+            "trace_id": "abc",
+            "data": [
+                { "id": "GK1", "secret_access_key": SECRET },
+                { "id": "GK2", "secretAccessKey": SECRET, "nested": { "password": SECRET } },
+            ],
+        });
+        let out = to_redacted_json(&value);
+
+        assert!(!out.contains(SECRET));
+        assert!(out.contains("GK1"));
+        assert!(out.contains(REDACTED));
+    }
+
+    #[test]
+    fn test_null_secret_kept_null() {
+        let mut value = json!({ "secretAccessKey": null });
+        redact_value(&mut value);
+        assert_eq!(value, json!({ "secretAccessKey": null }));
+    }
+
+    #[test]
+    fn test_redact_text_json_body() {
+        let body = format!(r#"{{"id":"tok","secretToken":"{}","scope":["*"]}}"#, SECRET);
+        let out = redact_text(&body);
+        assert!(!out.contains(SECRET));
+        assert!(out.contains("tok"));
+    }
+
+    #[test]
+    fn test_redact_text_bearer() {
+        let text = format!("request failed: Authorization: Bearer {} (401)", SECRET);
+        let out = redact_text(&text);
+        assert!(!out.contains(SECRET));
+        assert!(out.contains("Bearer [REDACTED]"));
+        assert!(out.ends_with("(401)"));
+    }
+
+    #[test]
+    fn test_redact_text_plain() {
+        assert_eq!(redact_text("not found"), "not found");
+        assert_eq!(redact_text("42"), "42");
+    }
+
+    #[test]
+    fn test_serialize_with_annotation() {
+        #[derive(Serialize)]
+        struct Log<'a> {
+            id: &'a str,
+            #[serde(serialize_with = "redacted")]
+            credential: &'a str,
+        }
+
+        let out = to_redacted_json(&Log { id: "GK1", credential: SECRET });
+        assert!(!out.contains(SECRET));
+        assert!(out.contains("GK1"));
+    }
+}
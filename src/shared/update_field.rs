@@ -9,9 +9,10 @@ use serde::{Deserialize, Serialize};
 /// - `NoChange`: 不更新此欄位，保留原值
 /// - `Clear`: 清除此欄位（設為 null/空值）
 /// - `Set(T)`: 設為新值
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum UpdateField<T> {
     /// 不更新此欄位
+    #[default]
     NoChange,
     /// 清除此欄位（設為 null/空值）
     Clear,
@@ -19,12 +20,6 @@ pub enum UpdateField<T> {
     Set(T),
 }
 
-impl<T> Default for UpdateField<T> {
-    fn default() -> Self {
-        UpdateField::NoChange
-    }
-}
-
 impl<T> UpdateField<T> {
     /// 檢查是否有變更
     pub fn has_change(&self) -> bool {